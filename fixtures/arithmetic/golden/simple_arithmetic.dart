@@ -0,0 +1,213 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class MathException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterMathException { static MathException lift( RustBuffer buffer) { return FfiConverterMathException.read(buffer.asUint8List()).value; } static LiftRetVal<MathException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return DivisionByZeroMathException.read(subview);case 2: return InvalidInputMathException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( MathException value) { return value.lower(); } static int allocationSize(MathException value) { return value.allocationSize(); } static int write( MathException value, Uint8List buf) { return value.write(buf); } } class DivisionByZeroMathException extends MathException { DivisionByZeroMathException(); DivisionByZeroMathException._(); static LiftRetVal<DivisionByZeroMathException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(DivisionByZeroMathException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "DivisionByZeroMathException"; } }class InvalidInputMathException extends MathException { final String message; InvalidInputMathException(this.message,); InvalidInputMathException._(this.message,); static LiftRetVal<InvalidInputMathException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final messageLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final message = messageLifted.value; newOffset += messageLifted.bytesRead; return LiftRetVal(InvalidInputMathException._( message, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterString.allocationSize(message) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterString.write(message, Uint8List.view(buf.buffer, newOffset)); return newOffset; } @override String toString() { return "InvalidInputMathException($message)"; } } class MathExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterMathException.lift(errorBuf); } } final MathExceptionErrorHandler mathExceptionErrorHandler = MathExceptionErrorHandler();   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiSimpleArithmeticRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiSimpleArithmeticRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1); } static int lower(int value) { if (value < 0 || value > 255) { throw ArgumentError("Value out of range for u8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, lower(value)); return 1; } } class FfiConverterInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1); } static int lower(int value) { if (value < -128 || value > 127) { throw ArgumentError("Value out of range for i8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, lower(value)); return 1; } } class FfiConverterUInt16 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2); } static int lower(int value) { if (value < 0 || value > 65535) { throw ArgumentError("Value out of range for u16: $value"); } return value; } static int allocationSize([int value = 0]) { return 2; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, lower(value)); return 2; } } class FfiConverterInt16 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2); } static int lower(int value) { if (value < -32768 || value > 32767) { throw ArgumentError("Value out of range for i16: $value"); } return value; } static int allocationSize([int value = 0]) { return 2; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, lower(value)); return 2; } } class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8); } static int lower(int value) { if (value < -9223372036854775808 || value > 9223372036854775807) { throw ArgumentError("Value out of range for i64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, lower(value)); return 8; } } class FfiConverterDouble32 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 4; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value); return FfiConverterDouble32.allocationSize(); } } class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalUInt8 { static int? lift( RustBuffer buf) { return FfiConverterOptionalUInt8.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterUInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterUInt8.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalUInt8.allocationSize(value)); FfiConverterOptionalUInt8.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterUInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalInt8 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt8.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt8.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt8.allocationSize(value)); FfiConverterOptionalInt8.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalUInt16 { static int? lift( RustBuffer buf) { return FfiConverterOptionalUInt16.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterUInt16.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterUInt16.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalUInt16.allocationSize(value)); FfiConverterOptionalUInt16.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterUInt16.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalInt16 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt16.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt16.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt16.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt16.allocationSize(value)); FfiConverterOptionalInt16.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt16.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalUInt32 { static int? lift( RustBuffer buf) { return FfiConverterOptionalUInt32.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterUInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterUInt32.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalUInt32.allocationSize(value)); FfiConverterOptionalUInt32.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterUInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalInt32 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt32.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt32.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value)); FfiConverterOptionalInt32.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalUInt64 { static int? lift( RustBuffer buf) { return FfiConverterOptionalUInt64.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterUInt64.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value)); FfiConverterOptionalUInt64.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalInt64 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt64.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt64.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt64.allocationSize(value)); FfiConverterOptionalInt64.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalDouble32 { static double? lift( RustBuffer buf) { return FfiConverterOptionalDouble32.read(buf.asUint8List()).value; } static LiftRetVal<double?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterDouble32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<double?>(result.value, result.bytesRead + 1); } static int allocationSize([double? value]) { if (value == null) { return 1; } return FfiConverterDouble32.allocationSize(value) + 1; } static RustBuffer lower( double? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalDouble32.allocationSize(value)); FfiConverterOptionalDouble32.write(value, buf); return toRustBuffer(buf); } static int write( double? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterDouble32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalDouble64 { static double? lift( RustBuffer buf) { return FfiConverterOptionalDouble64.read(buf.asUint8List()).value; } static LiftRetVal<double?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterDouble64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<double?>(result.value, result.bytesRead + 1); } static int allocationSize([double? value]) { if (value == null) { return 1; } return FfiConverterDouble64.allocationSize(value) + 1; } static RustBuffer lower( double? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalDouble64.allocationSize(value)); FfiConverterOptionalDouble64.write(value, buf); return toRustBuffer(buf); } static int write( double? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterDouble64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalBool { static bool? lift( RustBuffer buf) { return FfiConverterOptionalBool.read(buf.asUint8List()).value; } static LiftRetVal<bool?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterBool.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<bool?>(result.value, result.bytesRead + 1); } static int allocationSize([bool? value]) { if (value == null) { return 1; } return FfiConverterBool.allocationSize(value) + 1; } static RustBuffer lower( bool? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalBool.allocationSize(value)); FfiConverterOptionalBool.write(value, buf); return toRustBuffer(buf); } static int write( bool? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterBool.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:simple_arithmetic"; int add(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAdd( FfiConverterUInt32.lower(left),FfiConverterUInt32.lower(right), status ), FfiConverterUInt32.lift, null ); } double? addF32(double left,double right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddF32( left,right, status ), FfiConverterOptionalDouble32.lift, null ); } double? addF64(double left,double right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddF64( left,right, status ), FfiConverterOptionalDouble64.lift, null ); } int? addI16(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddI16( FfiConverterInt16.lower(left),FfiConverterInt16.lower(right), status ), FfiConverterOptionalInt16.lift, null ); } int? addI32(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddI32( FfiConverterInt32.lower(left),FfiConverterInt32.lower(right), status ), FfiConverterOptionalInt32.lift, null ); } int? addI64(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddI64( FfiConverterInt64.lower(left),FfiConverterInt64.lower(right), status ), FfiConverterOptionalInt64.lift, null ); } int? addI8(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddI8( FfiConverterInt8.lower(left),FfiConverterInt8.lower(right), status ), FfiConverterOptionalInt8.lift, null ); } int? addU16(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddU16( FfiConverterUInt16.lower(left),FfiConverterUInt16.lower(right), status ), FfiConverterOptionalUInt16.lift, null ); } int? addU64(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddU64( FfiConverterUInt64.lower(left),FfiConverterUInt64.lower(right), status ), FfiConverterOptionalUInt64.lift, null ); } int? addU8(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncAddU8( FfiConverterUInt8.lower(left),FfiConverterUInt8.lower(right), status ), FfiConverterOptionalUInt8.lift, null ); } bool? canDivide(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncCanDivide( FfiConverterUInt32.lower(left),FfiConverterUInt32.lower(right), status ), FfiConverterOptionalBool.lift, null ); } int? divide(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncDivide( FfiConverterUInt32.lower(left),FfiConverterUInt32.lower(right), status ), FfiConverterOptionalUInt32.lift, null ); } int divideByZero(int numerator,int denominator,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncDivideByZero( FfiConverterUInt64.lower(numerator),FfiConverterUInt64.lower(denominator), status ), FfiConverterUInt64.lift, mathExceptionErrorHandler ); } int? divideChecked(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncDivideChecked( FfiConverterUInt32.lower(left),FfiConverterUInt32.lower(right), status ), FfiConverterOptionalUInt32.lift, null ); } double? getBackF32(double value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackF32( value, status ), FfiConverterOptionalDouble32.lift, null ); } double? getBackF64(double value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackF64( value, status ), FfiConverterOptionalDouble64.lift, null ); } int? getBackI16(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackI16( FfiConverterInt16.lower(value), status ), FfiConverterOptionalInt16.lift, null ); } int? getBackI32(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackI32( FfiConverterInt32.lower(value), status ), FfiConverterOptionalInt32.lift, null ); } int? getBackI64(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackI64( FfiConverterInt64.lower(value), status ), FfiConverterOptionalInt64.lift, null ); } int? getBackI8(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackI8( FfiConverterInt8.lower(value), status ), FfiConverterOptionalInt8.lift, null ); } int? getBackU16(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackU16( FfiConverterUInt16.lower(value), status ), FfiConverterOptionalUInt16.lift, null ); } int? getBackU32(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackU32( FfiConverterUInt32.lower(value), status ), FfiConverterOptionalUInt32.lift, null ); } int? getBackU64(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackU64( FfiConverterUInt64.lower(value), status ), FfiConverterOptionalUInt64.lift, null ); } int? getBackU8(int value,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncGetBackU8( FfiConverterUInt8.lower(value), status ), FfiConverterOptionalUInt8.lift, null ); } int multiply(int left,int right,) { return rustCallWithLifter( (status) => uniffiSimpleArithmeticFnFuncMultiply( FfiConverterUInt32.lower(left),FfiConverterUInt32.lower(right), status ), FfiConverterUInt32.lift, null ); } @Native<Uint32 Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticFnFuncAdd( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Float, Float, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_f32", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddF32( double left, double right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Double, Double, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_f64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddF64( double left, double right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int16, Int16, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_i16", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddI16( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int32, Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_i32", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddI32( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int64, Int64, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_i64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddI64( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int8, Int8, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_i8", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddI8( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint16, Uint16, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_u16", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddU16( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_u64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddU64( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint8, Uint8, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_add_u8", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncAddU8( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_can_divide", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncCanDivide( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_divide", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncDivide( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Uint64, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_divide_by_zero", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticFnFuncDivideByZero( int numerator, int denominator, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_divide_checked", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncDivideChecked( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Float, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_f32", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackF32( double value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Double, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_f64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackF64( double value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int16, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_i16", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackI16( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_i32", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackI32( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int64, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_i64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackI64( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int8, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_i8", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackI8( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint16, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_u16", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackU16( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_u32", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackU32( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_u64", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackU64( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint8, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_get_back_u8", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleArithmeticFnFuncGetBackU8( int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_arithmetic_fn_func_multiply", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticFnFuncMultiply( int left, int right, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleArithmeticRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleArithmeticRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleArithmeticRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiSimpleArithmeticRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiSimpleArithmeticRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiSimpleArithmeticRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleArithmeticRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_arithmetic_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_arithmetic_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiSimpleArithmeticRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAdd();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_f32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddF32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_f64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddF64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_i16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddI16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_i32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddI32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_i64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddI64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_i8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddI8();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_u16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddU16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_u64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddU64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_add_u8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncAddU8();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_can_divide", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncCanDivide();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_divide", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncDivide();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_divide_by_zero", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncDivideByZero();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_divide_checked", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncDivideChecked();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_f32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackF32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_f64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackF64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_i16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackI16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_i32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackI32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_i64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackI64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_i8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackI8();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_u16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackU16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_u32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackU32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_u64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackU64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_get_back_u8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncGetBackU8();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_arithmetic_checksum_func_multiply", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleArithmeticChecksumFuncMultiply();
+
+@Native<Uint32 Function()>( symbol: "ffi_simple_arithmetic_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiSimpleArithmeticUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiSimpleArithmeticUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiSimpleArithmeticChecksumFuncAdd() != 54507) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add"); }if (uniffiSimpleArithmeticChecksumFuncAddF32() != 9289) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_f32"); }if (uniffiSimpleArithmeticChecksumFuncAddF64() != 63279) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_f64"); }if (uniffiSimpleArithmeticChecksumFuncAddI16() != 29192) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_i16"); }if (uniffiSimpleArithmeticChecksumFuncAddI32() != 6063) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_i32"); }if (uniffiSimpleArithmeticChecksumFuncAddI64() != 10343) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_i64"); }if (uniffiSimpleArithmeticChecksumFuncAddI8() != 38917) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_i8"); }if (uniffiSimpleArithmeticChecksumFuncAddU16() != 7352) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_u16"); }if (uniffiSimpleArithmeticChecksumFuncAddU64() != 48723) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_u64"); }if (uniffiSimpleArithmeticChecksumFuncAddU8() != 9945) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_add_u8"); }if (uniffiSimpleArithmeticChecksumFuncCanDivide() != 6927) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_can_divide"); }if (uniffiSimpleArithmeticChecksumFuncDivide() != 60468) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_divide"); }if (uniffiSimpleArithmeticChecksumFuncDivideByZero() != 62441) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_divide_by_zero"); }if (uniffiSimpleArithmeticChecksumFuncDivideChecked() != 46135) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_divide_checked"); }if (uniffiSimpleArithmeticChecksumFuncGetBackF32() != 49070) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_f32"); }if (uniffiSimpleArithmeticChecksumFuncGetBackF64() != 64872) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_f64"); }if (uniffiSimpleArithmeticChecksumFuncGetBackI16() != 33524) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_i16"); }if (uniffiSimpleArithmeticChecksumFuncGetBackI32() != 44924) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_i32"); }if (uniffiSimpleArithmeticChecksumFuncGetBackI64() != 4920) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_i64"); }if (uniffiSimpleArithmeticChecksumFuncGetBackI8() != 57927) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_i8"); }if (uniffiSimpleArithmeticChecksumFuncGetBackU16() != 49101) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_u16"); }if (uniffiSimpleArithmeticChecksumFuncGetBackU32() != 46306) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_u32"); }if (uniffiSimpleArithmeticChecksumFuncGetBackU64() != 59630) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_u64"); }if (uniffiSimpleArithmeticChecksumFuncGetBackU8() != 8951) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_get_back_u8"); }if (uniffiSimpleArithmeticChecksumFuncMultiply() != 27814) { mismatchedChecksums.add("uniffi_simple_arithmetic_checksum_func_multiply"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -4,3 +4,13 @@ use anyhow::Result;
 fn simple_arithmetic() -> Result<()> {
     uniffi_dart::testing::run_test("simple_arithmetic", "src/api.udl", None)
 }
+
+#[test]
+fn simple_arithmetic_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("simple_arithmetic", "src/api.udl", None)
+}
+
+#[test]
+fn simple_arithmetic_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("simple_arithmetic", "src/api.udl", None)
+}
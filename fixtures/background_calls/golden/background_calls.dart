@@ -0,0 +1,154 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Summary { final int count;final int total;final String label; Summary(this.count,this.total,this.label,); } class FfiConverterSummary { static Summary lift( RustBuffer buf) { return FfiConverterSummary.read(buf.asUint8List()).value; } static LiftRetVal<Summary> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final countLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final count = countLifted.value; newOffset += countLifted.bytesRead;final totalLifted = FfiConverterUInt64.read(Uint8List.view(buf.buffer, newOffset)); final total = totalLifted.value; newOffset += totalLifted.bytesRead;final labelLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final label = labelLifted.value; newOffset += labelLifted.bytesRead; return LiftRetVal(Summary( count,total,label, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Summary value) { final totalLength = FfiConverterUInt32.allocationSize(value.count) +FfiConverterUInt64.allocationSize(value.total) +FfiConverterString.allocationSize(value.label) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Summary value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterUInt32.write(value.count, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt64.write(value.total, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterString.write(value.label, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Summary value) { return FfiConverterUInt32.allocationSize(value.count) +FfiConverterUInt64.allocationSize(value.total) +FfiConverterString.allocationSize(value.label) + 0; } } abstract class BackgroundException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterBackgroundException { static BackgroundException lift( RustBuffer buffer) { return FfiConverterBackgroundException.read(buffer.asUint8List()).value; } static LiftRetVal<BackgroundException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return DivisionByZeroBackgroundException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( BackgroundException value) { return value.lower(); } static int allocationSize(BackgroundException value) { return value.allocationSize(); } static int write( BackgroundException value, Uint8List buf) { return value.write(buf); } } class DivisionByZeroBackgroundException extends BackgroundException { DivisionByZeroBackgroundException(); DivisionByZeroBackgroundException._(); static LiftRetVal<DivisionByZeroBackgroundException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(DivisionByZeroBackgroundException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "DivisionByZeroBackgroundException"; } } class BackgroundExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterBackgroundException.lift(errorBuf); } } final BackgroundExceptionErrorHandler backgroundExceptionErrorHandler = BackgroundExceptionErrorHandler(); abstract class AccumulatorInterface { int add(int value);int total(); } final _accumulatorFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiBackgroundCallsFnFreeAccumulator(ptr, status)); }); class Accumulator implements AccumulatorInterface { late final Pointer<Void> _ptr; Accumulator._(this._ptr) { _accumulatorFinalizer.attach(this, _ptr, detach: this); } Accumulator() : _ptr = rustCall((status) => uniffiBackgroundCallsFnConstructorAccumulatorNew( status ), null ) { _accumulatorFinalizer.attach(this, _ptr, detach: this); } factory Accumulator.lift(Pointer<Void> ptr) { return Accumulator._(ptr); } static Pointer<Void> lower(Accumulator value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiBackgroundCallsFnCloneAccumulator(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Accumulator.fromSendableHandle(int handle) { return Accumulator._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Accumulator value) { return 8; } static LiftRetVal<Accumulator> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Accumulator.lift(pointer), 8); } static int write(Accumulator value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _accumulatorFinalizer.detach(this); rustCall((status) => uniffiBackgroundCallsFnFreeAccumulator(_ptr, status)); } @override int add(int value,) { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnMethodAccumulatorAdd( uniffiClonePointer(), FfiConverterUInt64.lower(value), status ), FfiConverterUInt64.lift, null ); }  Future<int> addInBackground(int value,) { return _addInBackground(uniffiClonePointer().address, value,); } static Future<int> _addInBackground(int uniffiHandle, int value,) { return Isolate.run(() => rustCallWithLifter((status) => uniffiBackgroundCallsFnMethodAccumulatorAdd( Pointer<Void>.fromAddress(uniffiHandle), FfiConverterUInt64.lower(value), status ), FfiConverterUInt64.lift, null)); }@override int total() { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnMethodAccumulatorTotal( uniffiClonePointer(), status ), FfiConverterUInt64.lift, null ); }  Future<int> totalInBackground() { return _totalInBackground(uniffiClonePointer().address, ); } static Future<int> _totalInBackground(int uniffiHandle, ) { return Isolate.run(() => rustCallWithLifter((status) => uniffiBackgroundCallsFnMethodAccumulatorTotal( Pointer<Void>.fromAddress(uniffiHandle), status ), FfiConverterUInt64.lift, null)); } }  abstract class Progress { void report(int step,); } class FfiConverterCallbackInterfaceProgress { static final _handleMap = UniffiHandleMap<Progress>(); static bool _vtableInitialized = false; static Progress lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Progress value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initProgressVTable(); _vtableInitialized = true; } } static LiftRetVal<Progress> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Progress value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Progress value) { return 8; } } typedef UniffiCallbackInterfaceProgressMethod0 = Void Function( Uint64, Uint32, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceProgressMethod0Dart = void Function( int, int, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceProgressFree = Void Function(Uint64); typedef UniffiCallbackInterfaceProgressFreeDart = void Function(int); typedef UniffiCallbackInterfaceProgressClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceProgressCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceProgress extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceProgressFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceProgressClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceProgressMethod0>> report; }  void progressReport(int uniffiHandle, int step, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceProgress._handleMap.get(uniffiHandle); final arg0 = FfiConverterUInt32.lift(step); obj.report(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceProgressMethod0>> progressReportPointer = Pointer.fromFunction<UniffiCallbackInterfaceProgressMethod0>(progressReport); void progressFreeCallback(int handle) { FfiConverterCallbackInterfaceProgress._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceProgressFree>> progressFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceProgressFree>(progressFreeCallback); int progressCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceProgress._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceProgress._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceProgressClone>> progressClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceProgressClone>(progressCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceProgress> progressVTable; void initProgressVTable() { if (FfiConverterCallbackInterfaceProgress._vtableInitialized) { return; } progressVTable = calloc<UniffiVTableCallbackInterfaceProgress>(); progressVTable.ref.uniffiFree = progressFreePointer; progressVTable.ref.uniffiClone = progressClonePointer; progressVTable.ref.report = progressReportPointer; rustCall((status) { uniffiBackgroundCallsFnInitCallbackVtableProgress( progressVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceProgress._vtableInitialized = true; }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiBackgroundCallsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiBackgroundCallsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterSequenceUInt64 { static List<int> lift( RustBuffer buf) { return FfiConverterSequenceUInt64.read(buf.asUint8List()).value; } static LiftRetVal<List<int>> read( Uint8List buf) { List<int> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<int> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += FfiConverterUInt64.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<int> value) { return value.map((l) => FfiConverterUInt64.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<int> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:background_calls"; int checkedDivide(int numerator,int denominator,) { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnFuncCheckedDivide( FfiConverterUInt64.lower(numerator),FfiConverterUInt64.lower(denominator), status ), FfiConverterUInt64.lift, backgroundExceptionErrorHandler ); }  Future<int> checkedDivideInBackground(int numerator,int denominator,) { return Isolate.run(() => checkedDivide(numerator,denominator,)); }void countUp(int steps,Progress progress,) { return rustCall((status) { uniffiBackgroundCallsFnFuncCountUp( FfiConverterUInt32.lower(steps),FfiConverterCallbackInterfaceProgress.lower(progress).address, status ); }, null); } int fibonacci(int n,) { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnFuncFibonacci( FfiConverterUInt32.lower(n), status ), FfiConverterUInt64.lift, null ); }  Future<int> fibonacciInBackground(int n,) { return Isolate.run(() => fibonacci(n,)); }Accumulator newAccumulator() { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnFuncNewAccumulator( status ), Accumulator.lift, null ); } Summary summarize(List<int> values,String label,) { return rustCallWithLifter( (status) => uniffiBackgroundCallsFnFuncSummarize( FfiConverterSequenceUInt64.lower(values),FfiConverterString.lower(label), status ), FfiConverterSummary.lift, null ); }  Future<Summary> summarizeInBackground(List<int> values,String label,) { return Isolate.run(() => summarize(values,label,)); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_clone_accumulator", assetId: _uniffiAssetId ) external Pointer<Void> uniffiBackgroundCallsFnCloneAccumulator( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_free_accumulator", assetId: _uniffiAssetId ) external void uniffiBackgroundCallsFnFreeAccumulator( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_constructor_accumulator_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiBackgroundCallsFnConstructorAccumulatorNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_method_accumulator_add", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsFnMethodAccumulatorAdd( Pointer<Void> ptr, int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_method_accumulator_total", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsFnMethodAccumulatorTotal( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceProgress>)>( symbol: "uniffi_background_calls_fn_init_callback_vtable_progress", assetId: _uniffiAssetId ) external void uniffiBackgroundCallsFnInitCallbackVtableProgress( Pointer<UniffiVTableCallbackInterfaceProgress> vtable);
+
+@Native<Uint64 Function( Uint64, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_func_checked_divide", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsFnFuncCheckedDivide( int numerator, int denominator, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Uint32, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_func_count_up", assetId: _uniffiAssetId ) external void uniffiBackgroundCallsFnFuncCountUp( int steps, int progress, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_func_fibonacci", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsFnFuncFibonacci( int n, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_func_new_accumulator", assetId: _uniffiAssetId ) external Pointer<Void> uniffiBackgroundCallsFnFuncNewAccumulator( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_background_calls_fn_func_summarize", assetId: _uniffiAssetId ) external RustBuffer uniffiBackgroundCallsFnFuncSummarize( RustBuffer values, RustBuffer label, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiBackgroundCallsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiBackgroundCallsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiBackgroundCallsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiBackgroundCallsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiBackgroundCallsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiBackgroundCallsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiBackgroundCallsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_background_calls_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_background_calls_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiBackgroundCallsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_func_checked_divide", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumFuncCheckedDivide();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_func_count_up", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumFuncCountUp();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_func_fibonacci", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumFuncFibonacci();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_func_new_accumulator", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumFuncNewAccumulator();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_func_summarize", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumFuncSummarize();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_method_accumulator_add", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumMethodAccumulatorAdd();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_method_accumulator_total", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumMethodAccumulatorTotal();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_constructor_accumulator_new", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumConstructorAccumulatorNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_background_calls_checksum_method_progress_report", assetId: _uniffiAssetId ) external int uniffiBackgroundCallsChecksumMethodProgressReport();
+
+@Native<Uint32 Function()>( symbol: "ffi_background_calls_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiBackgroundCallsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiBackgroundCallsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiBackgroundCallsChecksumFuncCheckedDivide() != 56231) { mismatchedChecksums.add("uniffi_background_calls_checksum_func_checked_divide"); }if (uniffiBackgroundCallsChecksumFuncCountUp() != 12117) { mismatchedChecksums.add("uniffi_background_calls_checksum_func_count_up"); }if (uniffiBackgroundCallsChecksumFuncFibonacci() != 15631) { mismatchedChecksums.add("uniffi_background_calls_checksum_func_fibonacci"); }if (uniffiBackgroundCallsChecksumFuncNewAccumulator() != 27333) { mismatchedChecksums.add("uniffi_background_calls_checksum_func_new_accumulator"); }if (uniffiBackgroundCallsChecksumFuncSummarize() != 55481) { mismatchedChecksums.add("uniffi_background_calls_checksum_func_summarize"); }if (uniffiBackgroundCallsChecksumMethodAccumulatorAdd() != 11111) { mismatchedChecksums.add("uniffi_background_calls_checksum_method_accumulator_add"); }if (uniffiBackgroundCallsChecksumMethodAccumulatorTotal() != 34993) { mismatchedChecksums.add("uniffi_background_calls_checksum_method_accumulator_total"); }if (uniffiBackgroundCallsChecksumConstructorAccumulatorNew() != 46509) { mismatchedChecksums.add("uniffi_background_calls_checksum_constructor_accumulator_new"); }if (uniffiBackgroundCallsChecksumMethodProgressReport() != 13261) { mismatchedChecksums.add("uniffi_background_calls_checksum_method_progress_report"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -0,0 +1,91 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum BackgroundError {
+    #[error("Division by zero")]
+    DivisionByZero,
+}
+
+#[derive(uniffi::Record)]
+pub struct Summary {
+    pub count: u32,
+    pub total: u64,
+    pub label: String,
+}
+
+/// Deliberately slow so that running it on the calling isolate would be noticeable.
+#[uniffi_dart::run_in_background]
+#[uniffi::export]
+pub fn fibonacci(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fibonacci(n - 1) + fibonacci(n - 2),
+    }
+}
+
+#[uniffi::export]
+pub fn checked_divide(numerator: u64, denominator: u64) -> Result<u64, BackgroundError> {
+    numerator
+        .checked_div(denominator)
+        .ok_or(BackgroundError::DivisionByZero)
+}
+
+#[uniffi::export]
+pub fn summarize(values: Vec<u64>, label: String) -> Summary {
+    Summary {
+        count: values.len() as u32,
+        total: values.iter().sum(),
+        label,
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Accumulator {
+    total: Mutex<u64>,
+}
+
+#[uniffi::export]
+impl Accumulator {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            total: Mutex::new(0),
+        })
+    }
+
+    pub fn add(&self, value: u64) -> u64 {
+        let mut total = self.total.lock().unwrap();
+        *total += value;
+        *total
+    }
+
+    pub fn total(&self) -> u64 {
+        *self.total.lock().unwrap()
+    }
+}
+
+// Returns an object, so it can't run on a helper isolate: generation warns about it being listed
+// in `run_in_background` and leaves out the background variant.
+#[uniffi::export]
+pub fn new_accumulator() -> Arc<Accumulator> {
+    Accumulator::new()
+}
+
+// Implemented in Dart; calls into it stay on the calling thread.
+#[uniffi::export(callback_interface)]
+pub trait Progress: Send + Sync {
+    fn report(&self, step: u32);
+}
+
+/// Reports each step from 1 to `steps`. Passes a callback, so generation warns about the attribute
+/// and leaves out the background variant.
+#[uniffi_dart::run_in_background]
+#[uniffi::export]
+pub fn count_up(steps: u32, progress: Box<dyn Progress>) {
+    for step in 1..=steps {
+        progress.report(step);
+    }
+}
+
+uniffi::setup_scaffolding!();
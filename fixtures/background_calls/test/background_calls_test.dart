@@ -0,0 +1,35 @@
+import 'package:test/test.dart';
+import '../background_calls.dart';
+
+void main() {
+  test('proc-macro marked function runs in background', () async {
+    expect(await fibonacciInBackground(20), fibonacci(20));
+  });
+
+  test('configured function returns through a future', () async {
+    expect(await checkedDivideInBackground(10, 2), 5);
+  });
+
+  test('errors are rethrown on the calling isolate', () async {
+    await expectLater(
+      checkedDivideInBackground(1, 0),
+      throwsA(isA<DivisionByZeroBackgroundException>()),
+    );
+  });
+
+  test('records are copied back from the helper isolate', () async {
+    final summary = await summarizeInBackground([1, 2, 3], "numbers");
+    expect(summary.count, 3);
+    expect(summary.total, 6);
+    expect(summary.label, "numbers");
+  });
+
+  test('object methods run in background', () async {
+    final accumulator = Accumulator();
+    expect(await accumulator.addInBackground(5), 5);
+    expect(await accumulator.addInBackground(7), 12);
+    expect(await accumulator.totalInBackground(), 12);
+    expect(accumulator.total(), 12);
+    accumulator.dispose();
+  });
+}
@@ -0,0 +1,34 @@
+import 'dart:isolate';
+
+import 'package:test/test.dart';
+import '../background_calls.dart';
+
+class CollectingProgress implements Progress {
+  final steps = <int>[];
+
+  @override
+  void report(int step) => steps.add(step);
+}
+
+void main() {
+  test('callbacks can be implemented in a helper isolate', () async {
+    final steps = await Isolate.run(() {
+      final progress = CollectingProgress();
+      countUp(3, progress);
+      return progress.steps;
+    });
+
+    expect(steps, [1, 2, 3]);
+  });
+
+  test('a later isolate takes over the callback registration', () async {
+    for (final count in [2, 4]) {
+      final steps = await Isolate.run(() {
+        final progress = CollectingProgress();
+        countUp(count, progress);
+        return progress.steps;
+      });
+      expect(steps, [for (var i = 1; i <= count; i++) i]);
+    }
+  });
+}
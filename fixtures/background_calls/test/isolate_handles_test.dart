@@ -0,0 +1,39 @@
+import 'dart:isolate';
+
+import 'package:test/test.dart';
+import '../background_calls.dart';
+
+void main() {
+  test('objects can be sent to another isolate by handle', () async {
+    final accumulator = Accumulator();
+    accumulator.add(1);
+
+    final handle = accumulator.toSendableHandle();
+    final total = await Isolate.run(() {
+      final shared = Accumulator.fromSendableHandle(handle);
+      shared.add(2);
+      final total = shared.total();
+      shared.dispose();
+      return total;
+    });
+
+    expect(total, 3);
+    // Both isolates referenced the same Rust object.
+    expect(accumulator.total(), 3);
+    accumulator.dispose();
+  });
+
+  test('handles can be sent back from another isolate', () async {
+    final handle = await Isolate.run(() {
+      final created = Accumulator();
+      created.add(40);
+      final handle = created.toSendableHandle();
+      created.dispose();
+      return handle;
+    });
+
+    final accumulator = Accumulator.fromSendableHandle(handle);
+    expect(accumulator.add(2), 42);
+    accumulator.dispose();
+  });
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+
+#[test]
+fn background_calls() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("background_calls")
+}
+
+#[test]
+fn background_calls_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("background_calls")
+}
+
+#[test]
+fn background_calls_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("background_calls")
+}
@@ -0,0 +1,133 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiBytesTypesRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiBytesTypesRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterUint8List { static Uint8List lift(RustBuffer value) { return FfiConverterUint8List.read(value.asUint8List()).value; } static LiftRetVal<Uint8List> read(Uint8List buf) { final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final bytes = Uint8List.view(buf.buffer, buf.offsetInBytes + 4, length); return LiftRetVal(bytes, length + 4); } static RustBuffer lower(Uint8List value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } static int allocationSize([Uint8List? value]) { if (value == null) { return 4; } return 4 + value.length; } static int write(Uint8List value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); buf.setRange(4, 4 + value.length, value); return 4 + value.length; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:bytes_types"; Uint8List getBinaryBytes() { return rustCallWithLifter( (status) => uniffiBytesTypesFnFuncGetBinaryBytes( status ), FfiConverterUint8List.lift, null ); } Uint8List getTestBytes() { return rustCallWithLifter( (status) => uniffiBytesTypesFnFuncGetTestBytes( status ), FfiConverterUint8List.lift, null ); } Uint8List takeBytes(Uint8List v,) { return rustCallWithLifter( (status) => uniffiBytesTypesFnFuncTakeBytes( FfiConverterUint8List.lower(v), status ), FfiConverterUint8List.lift, null ); } Uint8List takeBytesWithValidation(Uint8List v,) { return rustCallWithLifter( (status) => uniffiBytesTypesFnFuncTakeBytesWithValidation( FfiConverterUint8List.lower(v), status ), FfiConverterUint8List.lift, null ); } Uint8List takeEmptyBytes() { return rustCallWithLifter( (status) => uniffiBytesTypesFnFuncTakeEmptyBytes( status ), FfiConverterUint8List.lift, null ); } @Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_bytes_types_fn_func_get_binary_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiBytesTypesFnFuncGetBinaryBytes( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_bytes_types_fn_func_get_test_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiBytesTypesFnFuncGetTestBytes( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_bytes_types_fn_func_take_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiBytesTypesFnFuncTakeBytes( RustBuffer v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_bytes_types_fn_func_take_bytes_with_validation", assetId: _uniffiAssetId ) external RustBuffer uniffiBytesTypesFnFuncTakeBytesWithValidation( RustBuffer v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_bytes_types_fn_func_take_empty_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiBytesTypesFnFuncTakeEmptyBytes( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiBytesTypesRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiBytesTypesRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiBytesTypesRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiBytesTypesRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiBytesTypesRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiBytesTypesRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiBytesTypesRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiBytesTypesRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_bytes_types_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_bytes_types_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiBytesTypesRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_bytes_types_checksum_func_get_binary_bytes", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiBytesTypesChecksumFuncGetBinaryBytes();
+
+@Native<Uint16 Function()>( symbol: "uniffi_bytes_types_checksum_func_get_test_bytes", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiBytesTypesChecksumFuncGetTestBytes();
+
+@Native<Uint16 Function()>( symbol: "uniffi_bytes_types_checksum_func_take_bytes", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiBytesTypesChecksumFuncTakeBytes();
+
+@Native<Uint16 Function()>( symbol: "uniffi_bytes_types_checksum_func_take_bytes_with_validation", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiBytesTypesChecksumFuncTakeBytesWithValidation();
+
+@Native<Uint16 Function()>( symbol: "uniffi_bytes_types_checksum_func_take_empty_bytes", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiBytesTypesChecksumFuncTakeEmptyBytes();
+
+@Native<Uint32 Function()>( symbol: "ffi_bytes_types_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiBytesTypesUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiBytesTypesUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiBytesTypesChecksumFuncGetBinaryBytes() != 49935) { mismatchedChecksums.add("uniffi_bytes_types_checksum_func_get_binary_bytes"); }if (uniffiBytesTypesChecksumFuncGetTestBytes() != 4201) { mismatchedChecksums.add("uniffi_bytes_types_checksum_func_get_test_bytes"); }if (uniffiBytesTypesChecksumFuncTakeBytes() != 52073) { mismatchedChecksums.add("uniffi_bytes_types_checksum_func_take_bytes"); }if (uniffiBytesTypesChecksumFuncTakeBytesWithValidation() != 34687) { mismatchedChecksums.add("uniffi_bytes_types_checksum_func_take_bytes_with_validation"); }if (uniffiBytesTypesChecksumFuncTakeEmptyBytes() != 17363) { mismatchedChecksums.add("uniffi_bytes_types_checksum_func_take_empty_bytes"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
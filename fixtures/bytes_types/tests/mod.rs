@@ -4,3 +4,13 @@ use anyhow::Result;
 fn bytes_types() -> Result<()> {
     uniffi_dart::testing::run_test("bytes_types", "src/api.udl", None)
 }
+
+#[test]
+fn bytes_types_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("bytes_types", "src/api.udl", None)
+}
+
+#[test]
+fn bytes_types_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("bytes_types", "src/api.udl", None)
+}
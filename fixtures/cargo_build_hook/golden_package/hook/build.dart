@@ -0,0 +1,97 @@
+// Generated by uniffi-dart – do NOT edit.
+import 'dart:io';
+
+import 'package:code_assets/code_assets.dart';
+import 'package:hooks/hooks.dart';
+
+void main(List<String> args) async {
+  await build(args, (input, output) async {
+    if (!input.config.buildCodeAssets) {
+      return;
+    }
+    final code = input.config.code;
+    final crateDir = input.packageRoot.resolve('it\'s rust/');
+    final targetDir = input.outputDirectoryShared.resolve('cargo/');
+    final triple = _rustTarget(code);
+    // Override with `hooks: user_defines: cargo_build_hook: cargo_profile: dev` in the app's pubspec
+    final profile = input.userDefines['cargo_profile'] as String? ?? 'release';
+
+    final result = await Process.run('C:\\tools\\cargo.exe', [
+      'build', '--lib', '--profile', profile, '--target', triple,
+      '--features', 'a\'b,\$c',
+      '--manifest-path', crateDir.resolve('Cargo.toml').toFilePath(),
+      '--target-dir', targetDir.toFilePath(),
+    ]);
+    if (result.exitCode != 0) {
+      throw Exception('cargo build failed for $triple:\n${result.stderr}');
+    }
+
+    final profileDir = profile == 'dev' ? 'debug' : profile;
+    final library = targetDir.resolve('$triple/$profileDir/${_libraryFileName(code.targetOS)}');
+    // Dart prefixes the name with "package:<packageName>/", giving the ID the bindings use
+    output.assets.code.add(
+      CodeAsset(
+        package: input.packageName,
+        name: 'uniffi:cargo_build_hook',
+        linkMode: DynamicLoadingBundled(),
+        file: library,
+      ),
+    );
+    output.dependencies.add(crateDir.resolve('Cargo.toml'));
+    output.dependencies.add(crateDir.resolve('src/'));
+  });
+}
+
+String _libraryFileName(OS os) {
+  if (os == OS.windows) {
+    return 'cargo_build_hook.dll';
+  }
+  if (os == OS.macOS || os == OS.iOS) {
+    return 'libcargo_build_hook.dylib';
+  }
+  return 'libcargo_build_hook.so';
+}
+
+String _rustTarget(CodeConfig code) {
+  final os = code.targetOS;
+  final arch = code.targetArchitecture;
+  final String? triple;
+  if (os == OS.android) {
+    triple = {
+      Architecture.arm64: 'aarch64-linux-android',
+      Architecture.arm: 'armv7-linux-androideabi',
+      Architecture.x64: 'x86_64-linux-android',
+      Architecture.ia32: 'i686-linux-android',
+    }[arch];
+  } else if (os == OS.iOS) {
+    final simulator = code.iOS.targetSdk == IOSSdk.iPhoneSimulator;
+    triple = {
+      Architecture.arm64: simulator ? 'aarch64-apple-ios-sim' : 'aarch64-apple-ios',
+      Architecture.x64: 'x86_64-apple-ios',
+    }[arch];
+  } else if (os == OS.macOS) {
+    triple = {
+      Architecture.arm64: 'aarch64-apple-darwin',
+      Architecture.x64: 'x86_64-apple-darwin',
+    }[arch];
+  } else if (os == OS.linux) {
+    triple = {
+      Architecture.arm64: 'aarch64-unknown-linux-gnu',
+      Architecture.arm: 'armv7-unknown-linux-gnueabihf',
+      Architecture.x64: 'x86_64-unknown-linux-gnu',
+      Architecture.riscv64: 'riscv64gc-unknown-linux-gnu',
+    }[arch];
+  } else if (os == OS.windows) {
+    triple = {
+      Architecture.arm64: 'aarch64-pc-windows-msvc',
+      Architecture.x64: 'x86_64-pc-windows-msvc',
+      Architecture.ia32: 'i686-pc-windows-msvc',
+    }[arch];
+  } else {
+    triple = null;
+  }
+  if (triple == null) {
+    throw UnsupportedError('No Rust target for $os on $arch');
+  }
+  return triple;
+}
@@ -0,0 +1,8 @@
+import 'package:test/test.dart';
+import 'package:cargo_build_hook/cargo_build_hook.dart' as cargo_build_hook;
+
+void main() {
+  test('cargo_build_hook loads the native library', () {
+    cargo_build_hook.ensureInitialized();
+  });
+}
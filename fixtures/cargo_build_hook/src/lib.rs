@@ -0,0 +1,6 @@
+#[uniffi::export]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,8 @@
+use anyhow::Result;
+
+// The hook builds the crate at `cargo_crate_path`, which only exists in the snapshot, so the
+// package isn't run
+#[test]
+fn cargo_build_hook_package_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_package_snapshot("cargo_build_hook")
+}
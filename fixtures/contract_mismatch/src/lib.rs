@@ -0,0 +1,5 @@
+pub fn add(left: u32, right: u32) -> u32 {
+    left + right
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,38 @@
+import 'package:test/test.dart';
+import '../contract_mismatch.dart';
+
+void main() {
+  test('calls into a library built from another API version throw', () {
+    expect(
+      () => add(1, 2),
+      throwsA(
+        isA<UniffiApiChecksumMismatchException>().having(
+          (e) => e.mismatchedChecksums,
+          'mismatchedChecksums',
+          ['uniffi_contract_mismatch_checksum_func_add'],
+        ),
+      ),
+    );
+  });
+
+  test('the checks run again until they pass', () {
+    expect(ensureInitialized, throwsA(isA<UniffiContractMismatchException>()));
+    expect(ensureInitialized, throwsA(isA<UniffiContractMismatchException>()));
+  });
+
+  test('mismatch exceptions describe the mismatch', () {
+    expect(
+      const UniffiApiChecksumMismatchException(['a', 'b']).toString(),
+      'UniFfi::ApiChecksumMismatch: a, b',
+    );
+    const UniffiContractMismatchException versionMismatch =
+        UniffiContractVersionMismatchException(
+          bindingsVersion: 30,
+          scaffoldingVersion: 29,
+        );
+    expect(
+      versionMismatch.toString(),
+      'UniFfi::ContractVersionMismatch: bindings version 30, scaffolding version 29',
+    );
+  });
+}
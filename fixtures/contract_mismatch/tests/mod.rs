@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+#[test]
+fn contract_mismatch() -> Result<()> {
+    uniffi_dart::testing::run_test("contract_mismatch", "src/newer_api.udl", None)
+}
+
+#[test]
+fn contract_mismatch_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output(
+        "contract_mismatch",
+        "src/newer_api.udl",
+        None,
+    )
+}
+
+#[test]
+fn contract_mismatch_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("contract_mismatch", "src/newer_api.udl", None)
+}
@@ -0,0 +1 @@
+export "dart_async/uniffi_core.dart";export "dart_async/my_record.dart";export "dart_async/shared_resource_options.dart";export "dart_async/async_exception.dart";export "dart_async/my_exception.dart";export "dart_async/parser_exception.dart";export "dart_async/say_after_udl_trait.dart";export "dart_async/udl_megaphone.dart";export "dart_async/async_parser.dart";export "dart_async/fallible_megaphone.dart";export "dart_async/megaphone.dart";export "dart_async/say_after_trait.dart";
@@ -0,0 +1,3 @@
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class AsyncException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterAsyncException { static AsyncException lift( RustBuffer buffer) { return FfiConverterAsyncException.read(buffer.asUint8List()).value; } static LiftRetVal<AsyncException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return TimeoutAsyncException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( AsyncException value) { return value.lower(); } static int allocationSize(AsyncException value) { return value.allocationSize(); } static int write( AsyncException value, Uint8List buf) { return value.write(buf); } } class TimeoutAsyncException extends AsyncException { TimeoutAsyncException(); TimeoutAsyncException._(); static LiftRetVal<TimeoutAsyncException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(TimeoutAsyncException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "TimeoutAsyncException"; } } class AsyncExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterAsyncException.lift(errorBuf); } } final AsyncExceptionErrorHandler asyncExceptionErrorHandler = AsyncExceptionErrorHandler();
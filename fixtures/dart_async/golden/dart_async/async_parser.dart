@@ -0,0 +1,5 @@
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+ import "uniffi_core.dart"; import "parser_exception.dart";  abstract class AsyncParser { Future<String> asString(int delayMs,int value,);Future<int> tryFromString(int delayMs,String value,);Future<void> delay(int delayMs,);Future<void> tryDelay(String delayMs,); } class FfiConverterCallbackInterfaceAsyncParser { static final _handleMap = UniffiHandleMap<AsyncParser>(); static bool _vtableInitialized = false; static AsyncParser lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(AsyncParser value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initAsyncParserVTable(); _vtableInitialized = true; } } static LiftRetVal<AsyncParser> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(AsyncParser value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(AsyncParser value) { return 8; } } typedef UniffiCallbackInterfaceAsyncParserMethod0 = Void Function( Uint64, Int32,Int32, Pointer<NativeFunction<UniffiForeignFutureCompleteRustBuffer>>, Uint64, Pointer<UniffiForeignFuture>); typedef UniffiCallbackInterfaceAsyncParserMethod0Dart = void Function( int, int,int, Pointer<NativeFunction<UniffiForeignFutureCompleteRustBuffer>>, int, Pointer<UniffiForeignFuture>);typedef UniffiCallbackInterfaceAsyncParserMethod1 = Void Function( Uint64, Int32,RustBuffer, Pointer<NativeFunction<UniffiForeignFutureCompleteI32>>, Uint64, Pointer<UniffiForeignFuture>); typedef UniffiCallbackInterfaceAsyncParserMethod1Dart = void Function( int, int,RustBuffer, Pointer<NativeFunction<UniffiForeignFutureCompleteI32>>, int, Pointer<UniffiForeignFuture>);typedef UniffiCallbackInterfaceAsyncParserMethod2 = Void Function( Uint64, Int32, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>>, Uint64, Pointer<UniffiForeignFuture>); typedef UniffiCallbackInterfaceAsyncParserMethod2Dart = void Function( int, int, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>>, int, Pointer<UniffiForeignFuture>);typedef UniffiCallbackInterfaceAsyncParserMethod3 = Void Function( Uint64, RustBuffer, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>>, Uint64, Pointer<UniffiForeignFuture>); typedef UniffiCallbackInterfaceAsyncParserMethod3Dart = void Function( int, RustBuffer, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>>, int, Pointer<UniffiForeignFuture>);typedef UniffiCallbackInterfaceAsyncParserFree = Void Function(Uint64); typedef UniffiCallbackInterfaceAsyncParserFreeDart = void Function(int); typedef UniffiCallbackInterfaceAsyncParserClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceAsyncParserCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceAsyncParser extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod0>> asString;external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod1>> tryFromString;external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod2>> delay;external Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod3>> tryDelay; }  void asyncParserAsString( int uniffiHandle, int delayMs,int value, Pointer<NativeFunction<UniffiForeignFutureCompleteRustBuffer>> uniffiFutureCallback, int uniffiCallbackData, Pointer<UniffiForeignFuture> outReturn, ) { final obj = FfiConverterCallbackInterfaceAsyncParser._handleMap.get(uniffiHandle); final arg0 = FfiConverterInt32.lift(delayMs);final arg1 = FfiConverterInt32.lift(value); final callback = uniffiFutureCallback.asFunction<UniffiForeignFutureCompleteRustBufferDart>(); final state = UniffiForeignFutureState(); final handle = uniffiForeignFutureHandleMap.insert(state); outReturn.ref.handle = handle; outReturn.ref.free = uniffiForeignFutureFreePointer; () async { try { final result = await obj.asString(arg0,arg1,); final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultRustBuffer>(); try { resultStructPtr.ref.returnValue = FfiConverterString.lower(result); resultStructPtr.ref.callStatus.code = uniffiCallSuccess; callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } catch (e) { final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultRustBuffer>(); try { resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError; resultStructPtr.ref.callStatus.errorBuf = FfiConverterString.lower(e.toString()); callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } }(); } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod0>> asyncParserAsStringPointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserMethod0>(asyncParserAsString);void asyncParserTryFromString( int uniffiHandle, int delayMs,RustBuffer value, Pointer<NativeFunction<UniffiForeignFutureCompleteI32>> uniffiFutureCallback, int uniffiCallbackData, Pointer<UniffiForeignFuture> outReturn, ) { final obj = FfiConverterCallbackInterfaceAsyncParser._handleMap.get(uniffiHandle); final arg0 = FfiConverterInt32.lift(delayMs);final arg1 = FfiConverterString.lift(value); final callback = uniffiFutureCallback.asFunction<UniffiForeignFutureCompleteI32Dart>(); final state = UniffiForeignFutureState(); final handle = uniffiForeignFutureHandleMap.insert(state); outReturn.ref.handle = handle; outReturn.ref.free = uniffiForeignFutureFreePointer; () async { try { final result = await obj.tryFromString(arg0,arg1,); final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultI32>(); try { resultStructPtr.ref.returnValue = FfiConverterInt32.lower(result); resultStructPtr.ref.callStatus.code = uniffiCallSuccess; callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } catch (e) { final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultI32>(); try { resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError; resultStructPtr.ref.callStatus.errorBuf = FfiConverterString.lower(e.toString()); callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } }(); } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod1>> asyncParserTryFromStringPointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserMethod1>(asyncParserTryFromString);void asyncParserDelay( int uniffiHandle, int delayMs, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>> uniffiFutureCallback, int uniffiCallbackData, Pointer<UniffiForeignFuture> outReturn, ) { final obj = FfiConverterCallbackInterfaceAsyncParser._handleMap.get(uniffiHandle); final arg0 = FfiConverterInt32.lift(delayMs); final callback = uniffiFutureCallback.asFunction<UniffiForeignFutureCompleteVoidDart>(); final state = UniffiForeignFutureState(); final handle = uniffiForeignFutureHandleMap.insert(state); outReturn.ref.handle = handle; outReturn.ref.free = uniffiForeignFutureFreePointer; () async { try { final result = await obj.delay(arg0,); final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultVoid>(); try { resultStructPtr.ref.callStatus.code = uniffiCallSuccess; callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } catch (e) { final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultVoid>(); try { resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError; resultStructPtr.ref.callStatus.errorBuf = FfiConverterString.lower(e.toString()); callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } }(); } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod2>> asyncParserDelayPointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserMethod2>(asyncParserDelay);void asyncParserTryDelay( int uniffiHandle, RustBuffer delayMs, Pointer<NativeFunction<UniffiForeignFutureCompleteVoid>> uniffiFutureCallback, int uniffiCallbackData, Pointer<UniffiForeignFuture> outReturn, ) { final obj = FfiConverterCallbackInterfaceAsyncParser._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(delayMs); final callback = uniffiFutureCallback.asFunction<UniffiForeignFutureCompleteVoidDart>(); final state = UniffiForeignFutureState(); final handle = uniffiForeignFutureHandleMap.insert(state); outReturn.ref.handle = handle; outReturn.ref.free = uniffiForeignFutureFreePointer; () async { try { final result = await obj.tryDelay(arg0,); final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultVoid>(); try { resultStructPtr.ref.callStatus.code = uniffiCallSuccess; callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } catch (e) { final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultVoid>(); try { resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError; resultStructPtr.ref.callStatus.errorBuf = FfiConverterString.lower(e.toString()); callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } }(); } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserMethod3>> asyncParserTryDelayPointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserMethod3>(asyncParserTryDelay); void asyncParserFreeCallback(int handle) { FfiConverterCallbackInterfaceAsyncParser._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserFree>> asyncParserFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserFree>(asyncParserFreeCallback); int asyncParserCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceAsyncParser._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceAsyncParser._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceAsyncParserClone>> asyncParserClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceAsyncParserClone>(asyncParserCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceAsyncParser> asyncParserVTable; void initAsyncParserVTable() { if (FfiConverterCallbackInterfaceAsyncParser._vtableInitialized) { return; } asyncParserVTable = calloc<UniffiVTableCallbackInterfaceAsyncParser>(); asyncParserVTable.ref.uniffiFree = asyncParserFreePointer; asyncParserVTable.ref.uniffiClone = asyncParserClonePointer; asyncParserVTable.ref.asString = asyncParserAsStringPointer;asyncParserVTable.ref.tryFromString = asyncParserTryFromStringPointer;asyncParserVTable.ref.delay = asyncParserDelayPointer;asyncParserVTable.ref.tryDelay = asyncParserTryDelayPointer; rustCall((status) { uniffiDartAsyncFnInitCallbackVtableAsyncparser( asyncParserVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceAsyncParser._vtableInitialized = true; }
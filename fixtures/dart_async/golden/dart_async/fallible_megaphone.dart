@@ -0,0 +1,4 @@
+import "dart:ffi";
+import "dart:typed_data";
+
+ import "uniffi_core.dart"; import "my_exception.dart";  abstract class FallibleMegaphoneInterface {} final _fallibleMegaphoneFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDartAsyncFnFreeFalliblemegaphone(ptr, status)); }); class FallibleMegaphone implements FallibleMegaphoneInterface { late final Pointer<Void> _ptr; FallibleMegaphone._(this._ptr) { _fallibleMegaphoneFinalizer.attach(this, _ptr, detach: this); } static Future<FallibleMegaphone> new_() { return uniffiRustCallAsync( () => uniffiDartAsyncFnConstructorFalliblemegaphoneNew( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (int handle) => FallibleMegaphone._(Pointer<Void>.fromAddress(handle)), myExceptionErrorHandler, ); } factory FallibleMegaphone.lift(Pointer<Void> ptr) { return FallibleMegaphone._(ptr); } static Pointer<Void> lower(FallibleMegaphone value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDartAsyncFnCloneFalliblemegaphone(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory FallibleMegaphone.fromSendableHandle(int handle) { return FallibleMegaphone._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(FallibleMegaphone value) { return 8; } static LiftRetVal<FallibleMegaphone> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(FallibleMegaphone.lift(pointer), 8); } static int write(FallibleMegaphone value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _fallibleMegaphoneFinalizer.detach(this); rustCall((status) => uniffiDartAsyncFnFreeFalliblemegaphone(_ptr, status)); } }
@@ -0,0 +1,4 @@
+import "dart:ffi";
+import "dart:typed_data";
+
+ import "uniffi_core.dart"; import "my_exception.dart";  abstract class MegaphoneInterface { Future<int> fallibleMe(bool doFail);Future<String> sayAfter(int ms, String who);Future<String> sayAfterWithTokio(int ms, String who);String sayNow(String who);Future<String> silence(); } final _megaphoneFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDartAsyncFnFreeMegaphone(ptr, status)); }); class Megaphone implements MegaphoneInterface { late final Pointer<Void> _ptr; Megaphone._(this._ptr) { _megaphoneFinalizer.attach(this, _ptr, detach: this); } static Future<Megaphone> new_() { return uniffiRustCallAsync( () => uniffiDartAsyncFnConstructorMegaphoneNew( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (int handle) => Megaphone._(Pointer<Void>.fromAddress(handle)), null, ); }static Future<Megaphone> secondary() { return uniffiRustCallAsync( () => uniffiDartAsyncFnConstructorMegaphoneSecondary( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (int handle) => Megaphone._(Pointer<Void>.fromAddress(handle)), null, ); } factory Megaphone.lift(Pointer<Void> ptr) { return Megaphone._(ptr); } static Pointer<Void> lower(Megaphone value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDartAsyncFnCloneMegaphone(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Megaphone.fromSendableHandle(int handle) { return Megaphone._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Megaphone value) { return 8; } static LiftRetVal<Megaphone> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Megaphone.lift(pointer), 8); } static int write(Megaphone value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _megaphoneFinalizer.detach(this); rustCall((status) => uniffiDartAsyncFnFreeMegaphone(_ptr, status)); } @override Future<int> fallibleMe(bool doFail,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodMegaphoneFallibleMe( uniffiClonePointer(), FfiConverterBool.lower(doFail), ), ffiDartAsyncRustFuturePollU8, ffiDartAsyncRustFutureCompleteU8, ffiDartAsyncRustFutureFreeU8, FfiConverterUInt8.lift, myExceptionErrorHandler, ); } @override Future<String> sayAfter(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodMegaphoneSayAfter( uniffiClonePointer(), FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } @override Future<String> sayAfterWithTokio(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodMegaphoneSayAfterWithTokio( uniffiClonePointer(), FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } @override String sayNow(String who,) { return rustCallWithLifter( (status) => uniffiDartAsyncFnMethodMegaphoneSayNow( uniffiClonePointer(), FfiConverterString.lower(who), status ), FfiConverterString.lift, null ); } @override Future<String> silence() { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodMegaphoneSilence( uniffiClonePointer(), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } }
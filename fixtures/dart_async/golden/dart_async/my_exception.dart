@@ -0,0 +1,3 @@
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class MyException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterMyException { static MyException lift( RustBuffer buffer) { return FfiConverterMyException.read(buffer.asUint8List()).value; } static LiftRetVal<MyException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return FooMyException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( MyException value) { return value.lower(); } static int allocationSize(MyException value) { return value.allocationSize(); } static int write( MyException value, Uint8List buf) { return value.write(buf); } } class FooMyException extends MyException { FooMyException(); FooMyException._(); static LiftRetVal<FooMyException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(FooMyException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "FooMyException"; } } class MyExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterMyException.lift(errorBuf); } } final MyExceptionErrorHandler myExceptionErrorHandler = MyExceptionErrorHandler();
@@ -0,0 +1,3 @@
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  class MyRecord { final String a;final int b; MyRecord(this.a,this.b,); } class FfiConverterMyRecord { static MyRecord lift( RustBuffer buf) { return FfiConverterMyRecord.read(buf.asUint8List()).value; } static LiftRetVal<MyRecord> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final aLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final a = aLifted.value; newOffset += aLifted.bytesRead;final bLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final b = bLifted.value; newOffset += bLifted.bytesRead; return LiftRetVal(MyRecord( a,b, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( MyRecord value) { final totalLength = FfiConverterString.allocationSize(value.a) +FfiConverterUInt32.allocationSize(value.b) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( MyRecord value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.a, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt32.write(value.b, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(MyRecord value) { return FfiConverterString.allocationSize(value.a) +FfiConverterUInt32.allocationSize(value.b) + 0; } }
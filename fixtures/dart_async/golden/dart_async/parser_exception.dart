@@ -0,0 +1,3 @@
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class ParserException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterParserException { static ParserException lift( RustBuffer buffer) { return FfiConverterParserException.read(buffer.asUint8List()).value; } static LiftRetVal<ParserException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return NotAnIntParserException.read(subview);case 2: return UnexpectedExceptionParserException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( ParserException value) { return value.lower(); } static int allocationSize(ParserException value) { return value.allocationSize(); } static int write( ParserException value, Uint8List buf) { return value.write(buf); } } class NotAnIntParserException extends ParserException { NotAnIntParserException(); NotAnIntParserException._(); static LiftRetVal<NotAnIntParserException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(NotAnIntParserException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "NotAnIntParserException"; } }class UnexpectedExceptionParserException extends ParserException { UnexpectedExceptionParserException(); UnexpectedExceptionParserException._(); static LiftRetVal<UnexpectedExceptionParserException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(UnexpectedExceptionParserException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "UnexpectedExceptionParserException"; } } class ParserExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterParserException.lift(errorBuf); } } final ParserExceptionErrorHandler parserExceptionErrorHandler = ParserExceptionErrorHandler();
@@ -0,0 +1,4 @@
+import "dart:ffi";
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class SayAfterTrait { factory SayAfterTrait.lift(Pointer<Void> ptr) { final handle = ptr.address; final isForeign = (handle & 0x1) != 0; if (isForeign) { throw UnsupportedError("Foreign trait implementations are not yet supported in uniffi-dart"); } return _SayAfterTraitImpl._internal(ptr); } static Pointer<Void> lower(SayAfterTrait value) { if (value is _SayAfterTraitImpl) { return value.uniffiClonePointer(); } throw UnsupportedError("Only Rust-implemented SayAfterTrait values are supported."); } static int allocationSize(SayAfterTrait value) { if (value is _SayAfterTraitImpl) { return _SayAfterTraitImpl.allocationSize(value); } throw UnsupportedError("Only Rust-implemented SayAfterTrait values are supported."); } static LiftRetVal<SayAfterTrait> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(SayAfterTrait.lift(pointer), 8); } static int write(SayAfterTrait value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose(); Future<String> sayAfter(int ms, String who); } final class _SayAfterTraitImpl implements SayAfterTrait { _SayAfterTraitImpl._internal(this._ptr) { _finalizer.attach(this, _ptr, detach: this); } static final Finalizer<Pointer<Void>> _finalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDartAsyncFnFreeSayaftertrait(ptr, status)); }); final Pointer<Void> _ptr; static int allocationSize(_SayAfterTraitImpl _) => 8; Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDartAsyncFnCloneSayaftertrait(_ptr, status)); } @override void dispose() { _finalizer.detach(this); rustCall((status) => uniffiDartAsyncFnFreeSayaftertrait(_ptr, status)); } @override Future<String> sayAfter(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodSayaftertraitSayAfter( uniffiClonePointer(), FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } }
@@ -0,0 +1,4 @@
+import "dart:ffi";
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class SayAfterUdlTrait { factory SayAfterUdlTrait.lift(Pointer<Void> ptr) { final handle = ptr.address; final isForeign = (handle & 0x1) != 0; if (isForeign) { throw UnsupportedError("Foreign trait implementations are not yet supported in uniffi-dart"); } return _SayAfterUdlTraitImpl._internal(ptr); } static Pointer<Void> lower(SayAfterUdlTrait value) { if (value is _SayAfterUdlTraitImpl) { return value.uniffiClonePointer(); } throw UnsupportedError("Only Rust-implemented SayAfterUdlTrait values are supported."); } static int allocationSize(SayAfterUdlTrait value) { if (value is _SayAfterUdlTraitImpl) { return _SayAfterUdlTraitImpl.allocationSize(value); } throw UnsupportedError("Only Rust-implemented SayAfterUdlTrait values are supported."); } static LiftRetVal<SayAfterUdlTrait> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(SayAfterUdlTrait.lift(pointer), 8); } static int write(SayAfterUdlTrait value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose(); Future<String> sayAfter(int ms, String who); } final class _SayAfterUdlTraitImpl implements SayAfterUdlTrait { _SayAfterUdlTraitImpl._internal(this._ptr) { _finalizer.attach(this, _ptr, detach: this); } static final Finalizer<Pointer<Void>> _finalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDartAsyncFnFreeSayafterudltrait(ptr, status)); }); final Pointer<Void> _ptr; static int allocationSize(_SayAfterUdlTraitImpl _) => 8; Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDartAsyncFnCloneSayafterudltrait(_ptr, status)); } @override void dispose() { _finalizer.detach(this); rustCall((status) => uniffiDartAsyncFnFreeSayafterudltrait(_ptr, status)); } @override Future<String> sayAfter(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodSayafterudltraitSayAfter( uniffiClonePointer(), FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } }
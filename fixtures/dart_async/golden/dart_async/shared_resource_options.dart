@@ -0,0 +1,3 @@
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  class SharedResourceOptions { final int releaseAfterMs;final int timeoutMs; SharedResourceOptions(this.releaseAfterMs,this.timeoutMs,); } class FfiConverterSharedResourceOptions { static SharedResourceOptions lift( RustBuffer buf) { return FfiConverterSharedResourceOptions.read(buf.asUint8List()).value; } static LiftRetVal<SharedResourceOptions> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final releaseAfterMsLifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, newOffset)); final releaseAfterMs = releaseAfterMsLifted.value; newOffset += releaseAfterMsLifted.bytesRead;final timeoutMsLifted = FfiConverterUInt16.read(Uint8List.view(buf.buffer, newOffset)); final timeoutMs = timeoutMsLifted.value; newOffset += timeoutMsLifted.bytesRead; return LiftRetVal(SharedResourceOptions( releaseAfterMs,timeoutMs, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( SharedResourceOptions value) { final totalLength = FfiConverterUInt16.allocationSize(value.releaseAfterMs) +FfiConverterUInt16.allocationSize(value.timeoutMs) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( SharedResourceOptions value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterUInt16.write(value.releaseAfterMs, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt16.write(value.timeoutMs, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(SharedResourceOptions value) { return FfiConverterUInt16.allocationSize(value.releaseAfterMs) +FfiConverterUInt16.allocationSize(value.timeoutMs) + 0; } }
@@ -0,0 +1,4 @@
+import "dart:ffi";
+import "dart:typed_data";
+
+ import "uniffi_core.dart";  abstract class UdlMegaphoneInterface { Future<String> sayAfter(int ms, String who); } final _udlMegaphoneFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDartAsyncFnFreeUdlmegaphone(ptr, status)); }); class UdlMegaphone implements UdlMegaphoneInterface { late final Pointer<Void> _ptr; UdlMegaphone._(this._ptr) { _udlMegaphoneFinalizer.attach(this, _ptr, detach: this); } static Future<UdlMegaphone> new_() { return uniffiRustCallAsync( () => uniffiDartAsyncFnConstructorUdlmegaphoneNew( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (int handle) => UdlMegaphone._(Pointer<Void>.fromAddress(handle)), null, ); }static Future<UdlMegaphone> secondary() { return uniffiRustCallAsync( () => uniffiDartAsyncFnConstructorUdlmegaphoneSecondary( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (int handle) => UdlMegaphone._(Pointer<Void>.fromAddress(handle)), null, ); } factory UdlMegaphone.lift(Pointer<Void> ptr) { return UdlMegaphone._(ptr); } static Pointer<Void> lower(UdlMegaphone value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDartAsyncFnCloneUdlmegaphone(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory UdlMegaphone.fromSendableHandle(int handle) { return UdlMegaphone._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(UdlMegaphone value) { return 8; } static LiftRetVal<UdlMegaphone> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(UdlMegaphone.lift(pointer), 8); } static int write(UdlMegaphone value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _udlMegaphoneFinalizer.detach(this); rustCall((status) => uniffiDartAsyncFnFreeUdlmegaphone(_ptr, status)); } @override Future<String> sayAfter(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnMethodUdlmegaphoneSayAfter( uniffiClonePointer(), FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } }
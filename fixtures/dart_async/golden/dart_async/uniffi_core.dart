@@ -0,0 +1,303 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+ import "my_record.dart";import "shared_resource_options.dart";import "async_exception.dart";import "my_exception.dart";import "parser_exception.dart";import "say_after_udl_trait.dart";import "udl_megaphone.dart";import "async_parser.dart";import "fallible_megaphone.dart";import "megaphone.dart";import "say_after_trait.dart";   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiDartAsyncRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiDartAsyncRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1); } static int lower(int value) { if (value < 0 || value > 255) { throw ArgumentError("Value out of range for u8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, lower(value)); return 1; } } class FfiConverterUInt16 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2); } static int lower(int value) { if (value < 0 || value > 65535) { throw ArgumentError("Value out of range for u16: $value"); } return value; } static int allocationSize([int value = 0]) { return 2; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, lower(value)); return 2; } } class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalMegaphone { static Megaphone? lift( RustBuffer buf) { return FfiConverterOptionalMegaphone.read(buf.asUint8List()).value; } static LiftRetVal<Megaphone?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = Megaphone.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Megaphone?>(result.value, result.bytesRead + 1); } static int allocationSize([Megaphone? value]) { if (value == null) { return 1; } return Megaphone.allocationSize(value) + 1; } static RustBuffer lower( Megaphone? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalMegaphone.allocationSize(value)); FfiConverterOptionalMegaphone.write(value, buf); return toRustBuffer(buf); } static int write( Megaphone? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return Megaphone.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterSequenceSayAfterTrait { static List<SayAfterTrait> lift( RustBuffer buf) { return FfiConverterSequenceSayAfterTrait.read(buf.asUint8List()).value; } static LiftRetVal<List<SayAfterTrait>> read( Uint8List buf) { List<SayAfterTrait> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = SayAfterTrait.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<SayAfterTrait> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += SayAfterTrait.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<SayAfterTrait> value) { return value.map((l) => SayAfterTrait.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<SayAfterTrait> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } class FfiConverterSequenceSayAfterUdlTrait { static List<SayAfterUdlTrait> lift( RustBuffer buf) { return FfiConverterSequenceSayAfterUdlTrait.read(buf.asUint8List()).value; } static LiftRetVal<List<SayAfterUdlTrait>> read( Uint8List buf) { List<SayAfterUdlTrait> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = SayAfterUdlTrait.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<SayAfterUdlTrait> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += SayAfterUdlTrait.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<SayAfterUdlTrait> value) { return value.map((l) => SayAfterUdlTrait.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<SayAfterUdlTrait> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); final class UniffiForeignFutureResultRustBuffer extends Struct { external RustBuffer returnValue;external RustCallStatus callStatus; }typedef UniffiForeignFutureCompleteRustBuffer = Void Function(Uint64, UniffiForeignFutureResultRustBuffer);typedef UniffiForeignFutureCompleteRustBufferDart = void Function(int, UniffiForeignFutureResultRustBuffer);final class UniffiForeignFutureResultI32 extends Struct { @Int32() external int returnValue;external RustCallStatus callStatus; }typedef UniffiForeignFutureCompleteI32 = Void Function(Uint64, UniffiForeignFutureResultI32);typedef UniffiForeignFutureCompleteI32Dart = void Function(int, UniffiForeignFutureResultI32);final class UniffiForeignFutureResultVoid extends Struct { external RustCallStatus callStatus; }typedef UniffiForeignFutureCompleteVoid = Void Function(Uint64, UniffiForeignFutureResultVoid);typedef UniffiForeignFutureCompleteVoidDart = void Function(int, UniffiForeignFutureResultVoid); const _uniffiAssetId = "package:uniffi/uniffi:dart_async"; Future<bool> alwaysReady() { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncAlwaysReady( ), ffiDartAsyncRustFuturePollI8, ffiDartAsyncRustFutureCompleteI8, ffiDartAsyncRustFutureFreeI8, FfiConverterBool.lift, null, ); } Future<String> asStringUsingTrait(AsyncParser obj,int delayMs,int value,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncAsStringUsingTrait( FfiConverterCallbackInterfaceAsyncParser.lower(obj),FfiConverterInt32.lower(delayMs),FfiConverterInt32.lower(value), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } Future<Megaphone?> asyncMaybeNewMegaphone(bool y,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncAsyncMaybeNewMegaphone( FfiConverterBool.lower(y), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterOptionalMegaphone.lift, null, ); } Future<Megaphone> asyncNewMegaphone() { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncAsyncNewMegaphone( ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (ptr) => Megaphone.lift(Pointer<Void>.fromAddress(ptr)), null, ); } Future<void> brokenSleep(int ms,int failAfter,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncBrokenSleep( FfiConverterUInt16.lower(ms),FfiConverterUInt16.lower(failAfter), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, null, ); } Future<void> cancelDelayUsingTrait(AsyncParser obj,int delayMs,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncCancelDelayUsingTrait( FfiConverterCallbackInterfaceAsyncParser.lower(obj),FfiConverterInt32.lower(delayMs), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, null, ); } Future<void> delayUsingTrait(AsyncParser obj,int delayMs,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncDelayUsingTrait( FfiConverterCallbackInterfaceAsyncParser.lower(obj),FfiConverterInt32.lower(delayMs), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, null, ); } Future<int> fallibleMe(bool doFail,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncFallibleMe( FfiConverterBool.lower(doFail), ), ffiDartAsyncRustFuturePollU8, ffiDartAsyncRustFutureCompleteU8, ffiDartAsyncRustFutureFreeU8, FfiConverterUInt8.lift, myExceptionErrorHandler, ); } Future<Megaphone> fallibleStruct(bool doFail,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncFallibleStruct( FfiConverterBool.lower(doFail), ), ffiDartAsyncRustFuturePollU64, ffiDartAsyncRustFutureCompleteU64, ffiDartAsyncRustFutureFreeU64, (ptr) => Megaphone.lift(Pointer<Void>.fromAddress(ptr)), myExceptionErrorHandler, ); } List<SayAfterTrait> getSayAfterTraits() { return rustCallWithLifter( (status) => uniffiDartAsyncFnFuncGetSayAfterTraits( status ), FfiConverterSequenceSayAfterTrait.lift, null ); } List<SayAfterUdlTrait> getSayAfterUdlTraits() { return rustCallWithLifter( (status) => uniffiDartAsyncFnFuncGetSayAfterUdlTraits( status ), FfiConverterSequenceSayAfterUdlTrait.lift, null ); } String greet(String who,) { return rustCallWithLifter( (status) => uniffiDartAsyncFnFuncGreet( FfiConverterString.lower(who), status ), FfiConverterString.lift, null ); } Megaphone newMegaphone() { return rustCallWithLifter( (status) => uniffiDartAsyncFnFuncNewMegaphone( status ), Megaphone.lift, null ); } Future<MyRecord> newMyRecord(String a,int b,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncNewMyRecord( FfiConverterString.lower(a),FfiConverterUInt32.lower(b), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterMyRecord.lift, null, ); } Future<String> say() { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSay( ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } Future<String> sayAfter(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSayAfter( FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } Future<String> sayAfterWithMegaphone(Megaphone megaphone,int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSayAfterWithMegaphone( Megaphone.lower(megaphone),FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } Future<String> sayAfterWithTokio(int ms,String who,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSayAfterWithTokio( FfiConverterUInt16.lower(ms),FfiConverterString.lower(who), ), ffiDartAsyncRustFuturePollRustBuffer, ffiDartAsyncRustFutureCompleteRustBuffer, ffiDartAsyncRustFutureFreeRustBuffer, FfiConverterString.lift, null, ); } Future<bool> sleep(int ms,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSleep( FfiConverterUInt16.lower(ms), ), ffiDartAsyncRustFuturePollI8, ffiDartAsyncRustFutureCompleteI8, ffiDartAsyncRustFutureFreeI8, FfiConverterBool.lift, null, ); } Future<void> sleepNoReturn(int ms,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncSleepNoReturn( FfiConverterUInt16.lower(ms), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, null, ); } Future<void> tryDelayUsingTrait(AsyncParser obj,String delayMs,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncTryDelayUsingTrait( FfiConverterCallbackInterfaceAsyncParser.lower(obj),FfiConverterString.lower(delayMs), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, parserExceptionErrorHandler, ); } Future<int> tryFromStringUsingTrait(AsyncParser obj,int delayMs,String value,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncTryFromStringUsingTrait( FfiConverterCallbackInterfaceAsyncParser.lower(obj),FfiConverterInt32.lower(delayMs),FfiConverterString.lower(value), ), ffiDartAsyncRustFuturePollI32, ffiDartAsyncRustFutureCompleteI32, ffiDartAsyncRustFutureFreeI32, FfiConverterInt32.lift, parserExceptionErrorHandler, ); } Future<void> useSharedResource(SharedResourceOptions options,) { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncUseSharedResource( FfiConverterSharedResourceOptions.lower(options), ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, asyncExceptionErrorHandler, ); } Future<void> void_() { return uniffiRustCallAsync( () => uniffiDartAsyncFnFuncVoid( ), ffiDartAsyncRustFuturePollVoid, ffiDartAsyncRustFutureCompleteVoid, ffiDartAsyncRustFutureFreeVoid, (_) {}, null, ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_sayafterudltrait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneSayafterudltrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_sayafterudltrait", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeSayafterudltrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_sayafterudltrait_say_after", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodSayafterudltraitSayAfter( Pointer<Void> ptr, int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_udlmegaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneUdlmegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_udlmegaphone", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeUdlmegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_constructor_udlmegaphone_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnConstructorUdlmegaphoneNew();
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_constructor_udlmegaphone_secondary", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnConstructorUdlmegaphoneSecondary();
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_udlmegaphone_say_after", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodUdlmegaphoneSayAfter( Pointer<Void> ptr, int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_asyncparser", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneAsyncparser( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_asyncparser", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeAsyncparser( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceAsyncParser>)>( symbol: "uniffi_dart_async_fn_init_callback_vtable_asyncparser", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnInitCallbackVtableAsyncparser( Pointer<UniffiVTableCallbackInterfaceAsyncParser> vtable);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32, Int32)>( symbol: "uniffi_dart_async_fn_method_asyncparser_as_string", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodAsyncparserAsString( Pointer<Void> ptr, int delayMs, int value);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_asyncparser_try_from_string", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodAsyncparserTryFromString( Pointer<Void> ptr, int delayMs, RustBuffer value);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32)>( symbol: "uniffi_dart_async_fn_method_asyncparser_delay", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodAsyncparserDelay( Pointer<Void> ptr, int delayMs);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_asyncparser_try_delay", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodAsyncparserTryDelay( Pointer<Void> ptr, RustBuffer delayMs);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_falliblemegaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneFalliblemegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_falliblemegaphone", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeFalliblemegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_constructor_falliblemegaphone_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnConstructorFalliblemegaphoneNew();
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_megaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneMegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_megaphone", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeMegaphone( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_constructor_megaphone_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnConstructorMegaphoneNew();
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_constructor_megaphone_secondary", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnConstructorMegaphoneSecondary();
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int8)>( symbol: "uniffi_dart_async_fn_method_megaphone_fallible_me", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodMegaphoneFallibleMe( Pointer<Void> ptr, int doFail);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_megaphone_say_after", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodMegaphoneSayAfter( Pointer<Void> ptr, int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_megaphone_say_after_with_tokio", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodMegaphoneSayAfterWithTokio( Pointer<Void> ptr, int ms, RustBuffer who);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_method_megaphone_say_now", assetId: _uniffiAssetId ) external RustBuffer uniffiDartAsyncFnMethodMegaphoneSayNow( Pointer<Void> ptr, RustBuffer who, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_dart_async_fn_method_megaphone_silence", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodMegaphoneSilence( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_clone_sayaftertrait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnCloneSayaftertrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_free_sayaftertrait", assetId: _uniffiAssetId ) external void uniffiDartAsyncFnFreeSayaftertrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_method_sayaftertrait_say_after", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnMethodSayaftertraitSayAfter( Pointer<Void> ptr, int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_func_always_ready", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncAlwaysReady();
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32, Int32)>( symbol: "uniffi_dart_async_fn_func_as_string_using_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncAsStringUsingTrait( Pointer<Void> obj, int delayMs, int value);
+
+@Native<Pointer<Void> Function( Int8)>( symbol: "uniffi_dart_async_fn_func_async_maybe_new_megaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncAsyncMaybeNewMegaphone( int y);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_func_async_new_megaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncAsyncNewMegaphone();
+
+@Native<Pointer<Void> Function( Uint16, Uint16)>( symbol: "uniffi_dart_async_fn_func_broken_sleep", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncBrokenSleep( int ms, int failAfter);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32)>( symbol: "uniffi_dart_async_fn_func_cancel_delay_using_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncCancelDelayUsingTrait( Pointer<Void> obj, int delayMs);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32)>( symbol: "uniffi_dart_async_fn_func_delay_using_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncDelayUsingTrait( Pointer<Void> obj, int delayMs);
+
+@Native<Pointer<Void> Function( Int8)>( symbol: "uniffi_dart_async_fn_func_fallible_me", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncFallibleMe( int doFail);
+
+@Native<Pointer<Void> Function( Int8)>( symbol: "uniffi_dart_async_fn_func_fallible_struct", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncFallibleStruct( int doFail);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_func_get_say_after_traits", assetId: _uniffiAssetId ) external RustBuffer uniffiDartAsyncFnFuncGetSayAfterTraits( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_func_get_say_after_udl_traits", assetId: _uniffiAssetId ) external RustBuffer uniffiDartAsyncFnFuncGetSayAfterUdlTraits( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_func_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiDartAsyncFnFuncGreet( RustBuffer who, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_dart_async_fn_func_new_megaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncNewMegaphone( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Uint32)>( symbol: "uniffi_dart_async_fn_func_new_my_record", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncNewMyRecord( RustBuffer a, int b);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_func_say", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSay();
+
+@Native<Pointer<Void> Function( Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_func_say_after", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSayAfter( int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_func_say_after_with_megaphone", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSayAfterWithMegaphone( Pointer<Void> megaphone, int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Uint16, RustBuffer)>( symbol: "uniffi_dart_async_fn_func_say_after_with_tokio", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSayAfterWithTokio( int ms, RustBuffer who);
+
+@Native<Pointer<Void> Function( Uint16)>( symbol: "uniffi_dart_async_fn_func_sleep", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSleep( int ms);
+
+@Native<Pointer<Void> Function( Uint16)>( symbol: "uniffi_dart_async_fn_func_sleep_no_return", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncSleepNoReturn( int ms);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer)>( symbol: "uniffi_dart_async_fn_func_try_delay_using_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncTryDelayUsingTrait( Pointer<Void> obj, RustBuffer delayMs);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Int32, RustBuffer)>( symbol: "uniffi_dart_async_fn_func_try_from_string_using_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncTryFromStringUsingTrait( Pointer<Void> obj, int delayMs, RustBuffer value);
+
+@Native<Pointer<Void> Function( RustBuffer)>( symbol: "uniffi_dart_async_fn_func_use_shared_resource", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncUseSharedResource( RustBuffer options);
+
+@Native<Pointer<Void> Function()>( symbol: "uniffi_dart_async_fn_func_void", assetId: _uniffiAssetId ) external Pointer<Void> uniffiDartAsyncFnFuncVoid();
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiDartAsyncRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiDartAsyncRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiDartAsyncRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiDartAsyncRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiDartAsyncRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiDartAsyncRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiDartAsyncRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiDartAsyncRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_dart_async_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_dart_async_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiDartAsyncRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_always_ready", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncAlwaysReady();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_as_string_using_trait", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncAsStringUsingTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_async_maybe_new_megaphone", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncAsyncMaybeNewMegaphone();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_async_new_megaphone", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncAsyncNewMegaphone();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_broken_sleep", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncBrokenSleep();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_cancel_delay_using_trait", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncCancelDelayUsingTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_delay_using_trait", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncDelayUsingTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_fallible_me", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncFallibleMe();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_fallible_struct", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncFallibleStruct();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_get_say_after_traits", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncGetSayAfterTraits();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_get_say_after_udl_traits", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncGetSayAfterUdlTraits();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_greet", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_new_megaphone", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncNewMegaphone();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_new_my_record", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncNewMyRecord();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_say", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSay();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_say_after", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSayAfter();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_say_after_with_megaphone", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSayAfterWithMegaphone();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_say_after_with_tokio", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSayAfterWithTokio();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_sleep", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSleep();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_sleep_no_return", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncSleepNoReturn();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_try_delay_using_trait", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncTryDelayUsingTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_try_from_string_using_trait", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncTryFromStringUsingTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_use_shared_resource", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncUseSharedResource();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_func_void", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumFuncVoid();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_sayafterudltrait_say_after", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodSayafterudltraitSayAfter();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_udlmegaphone_say_after", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodUdlmegaphoneSayAfter();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_asyncparser_as_string", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodAsyncparserAsString();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_asyncparser_try_from_string", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodAsyncparserTryFromString();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_asyncparser_delay", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodAsyncparserDelay();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_asyncparser_try_delay", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodAsyncparserTryDelay();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_megaphone_fallible_me", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodMegaphoneFallibleMe();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_megaphone_say_after", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodMegaphoneSayAfter();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_megaphone_say_after_with_tokio", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodMegaphoneSayAfterWithTokio();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_megaphone_say_now", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodMegaphoneSayNow();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_megaphone_silence", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodMegaphoneSilence();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_method_sayaftertrait_say_after", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumMethodSayaftertraitSayAfter();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_constructor_udlmegaphone_new", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumConstructorUdlmegaphoneNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_constructor_udlmegaphone_secondary", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumConstructorUdlmegaphoneSecondary();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_constructor_falliblemegaphone_new", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumConstructorFalliblemegaphoneNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_constructor_megaphone_new", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumConstructorMegaphoneNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_dart_async_checksum_constructor_megaphone_secondary", assetId: _uniffiAssetId ) external int uniffiDartAsyncChecksumConstructorMegaphoneSecondary();
+
+@Native<Uint32 Function()>( symbol: "ffi_dart_async_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiDartAsyncUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiDartAsyncUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiDartAsyncChecksumFuncAlwaysReady() != 12477) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_always_ready"); }if (uniffiDartAsyncChecksumFuncAsStringUsingTrait() != 48084) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_as_string_using_trait"); }if (uniffiDartAsyncChecksumFuncAsyncMaybeNewMegaphone() != 49635) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_async_maybe_new_megaphone"); }if (uniffiDartAsyncChecksumFuncAsyncNewMegaphone() != 49052) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_async_new_megaphone"); }if (uniffiDartAsyncChecksumFuncBrokenSleep() != 11867) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_broken_sleep"); }if (uniffiDartAsyncChecksumFuncCancelDelayUsingTrait() != 21052) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_cancel_delay_using_trait"); }if (uniffiDartAsyncChecksumFuncDelayUsingTrait() != 26853) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_delay_using_trait"); }if (uniffiDartAsyncChecksumFuncFallibleMe() != 59636) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_fallible_me"); }if (uniffiDartAsyncChecksumFuncFallibleStruct() != 38576) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_fallible_struct"); }if (uniffiDartAsyncChecksumFuncGetSayAfterTraits() != 22378) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_get_say_after_traits"); }if (uniffiDartAsyncChecksumFuncGetSayAfterUdlTraits() != 7624) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_get_say_after_udl_traits"); }if (uniffiDartAsyncChecksumFuncGreet() != 62036) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_greet"); }if (uniffiDartAsyncChecksumFuncNewMegaphone() != 58535) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_new_megaphone"); }if (uniffiDartAsyncChecksumFuncNewMyRecord() != 19367) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_new_my_record"); }if (uniffiDartAsyncChecksumFuncSay() != 14792) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_say"); }if (uniffiDartAsyncChecksumFuncSayAfter() != 34720) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_say_after"); }if (uniffiDartAsyncChecksumFuncSayAfterWithMegaphone() != 48427) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_say_after_with_megaphone"); }if (uniffiDartAsyncChecksumFuncSayAfterWithTokio() != 5958) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_say_after_with_tokio"); }if (uniffiDartAsyncChecksumFuncSleep() != 53803) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_sleep"); }if (uniffiDartAsyncChecksumFuncSleepNoReturn() != 11659) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_sleep_no_return"); }if (uniffiDartAsyncChecksumFuncTryDelayUsingTrait() != 46356) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_try_delay_using_trait"); }if (uniffiDartAsyncChecksumFuncTryFromStringUsingTrait() != 39255) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_try_from_string_using_trait"); }if (uniffiDartAsyncChecksumFuncUseSharedResource() != 43450) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_use_shared_resource"); }if (uniffiDartAsyncChecksumFuncVoid() != 59141) { mismatchedChecksums.add("uniffi_dart_async_checksum_func_void"); }if (uniffiDartAsyncChecksumMethodSayafterudltraitSayAfter() != 31309) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_sayafterudltrait_say_after"); }if (uniffiDartAsyncChecksumMethodUdlmegaphoneSayAfter() != 56866) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_udlmegaphone_say_after"); }if (uniffiDartAsyncChecksumMethodAsyncparserAsString() != 359) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_asyncparser_as_string"); }if (uniffiDartAsyncChecksumMethodAsyncparserTryFromString() != 23061) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_asyncparser_try_from_string"); }if (uniffiDartAsyncChecksumMethodAsyncparserDelay() != 50970) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_asyncparser_delay"); }if (uniffiDartAsyncChecksumMethodAsyncparserTryDelay() != 66) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_asyncparser_try_delay"); }if (uniffiDartAsyncChecksumMethodMegaphoneFallibleMe() != 41932) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_megaphone_fallible_me"); }if (uniffiDartAsyncChecksumMethodMegaphoneSayAfter() != 12240) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_megaphone_say_after"); }if (uniffiDartAsyncChecksumMethodMegaphoneSayAfterWithTokio() != 23426) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_megaphone_say_after_with_tokio"); }if (uniffiDartAsyncChecksumMethodMegaphoneSayNow() != 55356) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_megaphone_say_now"); }if (uniffiDartAsyncChecksumMethodMegaphoneSilence() != 26136) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_megaphone_silence"); }if (uniffiDartAsyncChecksumMethodSayaftertraitSayAfter() != 28878) { mismatchedChecksums.add("uniffi_dart_async_checksum_method_sayaftertrait_say_after"); }if (uniffiDartAsyncChecksumConstructorUdlmegaphoneNew() != 2994) { mismatchedChecksums.add("uniffi_dart_async_checksum_constructor_udlmegaphone_new"); }if (uniffiDartAsyncChecksumConstructorUdlmegaphoneSecondary() != 8470) { mismatchedChecksums.add("uniffi_dart_async_checksum_constructor_udlmegaphone_secondary"); }if (uniffiDartAsyncChecksumConstructorFalliblemegaphoneNew() != 4763) { mismatchedChecksums.add("uniffi_dart_async_checksum_constructor_falliblemegaphone_new"); }if (uniffiDartAsyncChecksumConstructorMegaphoneNew() != 15765) { mismatchedChecksums.add("uniffi_dart_async_checksum_constructor_megaphone_new"); }if (uniffiDartAsyncChecksumConstructorMegaphoneSecondary() != 26607) { mismatchedChecksums.add("uniffi_dart_async_checksum_constructor_megaphone_secondary"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -2,5 +2,19 @@ use anyhow::Result;
 
 #[test]
 fn dart_async() -> Result<()> {
-    uniffi_dart::testing::run_test("dart_async", "src/api.udl", None)
+    uniffi_dart::testing::run_test("dart_async", "src/api.udl", Some("uniffi.toml"))
+}
+
+#[test]
+fn dart_async_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output(
+        "dart_async",
+        "src/api.udl",
+        Some("uniffi.toml"),
+    )
+}
+
+#[test]
+fn dart_async_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("dart_async", "src/api.udl", Some("uniffi.toml"))
 }
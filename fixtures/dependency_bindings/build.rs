@@ -0,0 +1,3 @@
+fn main() {
+    uniffi_dart::generate_dependency_bindings("trait_interfaces", None).unwrap();
+}
@@ -0,0 +1,189 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class FriendlyGreeterInterface { String greet(String name);Greeter toTrait(); } final _friendlyGreeterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitInterfacesFnFreeFriendlygreeter(ptr, status)); }); class FriendlyGreeter implements FriendlyGreeterInterface { late final Pointer<Void> _ptr; FriendlyGreeter._(this._ptr) { _friendlyGreeterFinalizer.attach(this, _ptr, detach: this); } FriendlyGreeter(String phrase,) : _ptr = rustCall((status) => uniffiTraitInterfacesFnConstructorFriendlygreeterNew( FfiConverterString.lower(phrase), status ), null ) { _friendlyGreeterFinalizer.attach(this, _ptr, detach: this); } factory FriendlyGreeter.lift(Pointer<Void> ptr) { return FriendlyGreeter._(ptr); } static Pointer<Void> lower(FriendlyGreeter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitInterfacesFnCloneFriendlygreeter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory FriendlyGreeter.fromSendableHandle(int handle) { return FriendlyGreeter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(FriendlyGreeter value) { return 8; } static LiftRetVal<FriendlyGreeter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(FriendlyGreeter.lift(pointer), 8); } static int write(FriendlyGreeter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _friendlyGreeterFinalizer.detach(this); rustCall((status) => uniffiTraitInterfacesFnFreeFriendlygreeter(_ptr, status)); } String debugString() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitDebug( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override String toString() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitDisplay( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override bool operator ==(Object other) { if (identical(this, other)) { return true; } if (other is! FriendlyGreeter) { return false; } return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitEqEq( uniffiClonePointer(), FriendlyGreeter.lower(other), status ), FfiConverterBool.lift, null ); }@override int get hashCode { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitHash( uniffiClonePointer(), status ), FfiConverterUInt64.lift, null ); } @override String greet(String name,) { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterGreet( uniffiClonePointer(), FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } @override Greeter toTrait() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodFriendlygreeterToTrait( uniffiClonePointer(), status ), Greeter.lift, null ); } }  abstract class Greeter { factory Greeter.lift(Pointer<Void> ptr) { final handle = ptr.address; final isForeign = (handle & 0x1) != 0; if (isForeign) { throw UnsupportedError("Foreign trait implementations are not yet supported in uniffi-dart"); } return _GreeterImpl._internal(ptr); } static Pointer<Void> lower(Greeter value) { if (value is _GreeterImpl) { return value.uniffiClonePointer(); } throw UnsupportedError("Only Rust-implemented Greeter values are supported."); } static int allocationSize(Greeter value) { if (value is _GreeterImpl) { return _GreeterImpl.allocationSize(value); } throw UnsupportedError("Only Rust-implemented Greeter values are supported."); } static LiftRetVal<Greeter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Greeter.lift(pointer), 8); } static int write(Greeter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose(); String greet(String name); } final class _GreeterImpl implements Greeter { _GreeterImpl._internal(this._ptr) { _finalizer.attach(this, _ptr, detach: this); } static final Finalizer<Pointer<Void>> _finalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitInterfacesFnFreeGreeter(ptr, status)); }); final Pointer<Void> _ptr; static int allocationSize(_GreeterImpl _) => 8; Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitInterfacesFnCloneGreeter(_ptr, status)); } @override void dispose() { _finalizer.detach(this); rustCall((status) => uniffiTraitInterfacesFnFreeGreeter(_ptr, status)); } @override String greet(String name,) { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodGreeterGreet( uniffiClonePointer(), FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } } abstract class ProcFriendlyGreeterInterface { String greet(String name);Greeter toTrait(); } final _procFriendlyGreeterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitInterfacesFnFreeProcfriendlygreeter(ptr, status)); }); class ProcFriendlyGreeter implements ProcFriendlyGreeterInterface { late final Pointer<Void> _ptr; ProcFriendlyGreeter._(this._ptr) { _procFriendlyGreeterFinalizer.attach(this, _ptr, detach: this); } ProcFriendlyGreeter(String phrase,) : _ptr = rustCall((status) => uniffiTraitInterfacesFnConstructorProcfriendlygreeterNew( FfiConverterString.lower(phrase), status ), null ) { _procFriendlyGreeterFinalizer.attach(this, _ptr, detach: this); } factory ProcFriendlyGreeter.lift(Pointer<Void> ptr) { return ProcFriendlyGreeter._(ptr); } static Pointer<Void> lower(ProcFriendlyGreeter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitInterfacesFnCloneProcfriendlygreeter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory ProcFriendlyGreeter.fromSendableHandle(int handle) { return ProcFriendlyGreeter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(ProcFriendlyGreeter value) { return 8; } static LiftRetVal<ProcFriendlyGreeter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(ProcFriendlyGreeter.lift(pointer), 8); } static int write(ProcFriendlyGreeter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _procFriendlyGreeterFinalizer.detach(this); rustCall((status) => uniffiTraitInterfacesFnFreeProcfriendlygreeter(_ptr, status)); } String debugString() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitDebug( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override String toString() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitDisplay( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override bool operator ==(Object other) { if (identical(this, other)) { return true; } if (other is! ProcFriendlyGreeter) { return false; } return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitEqEq( uniffiClonePointer(), ProcFriendlyGreeter.lower(other), status ), FfiConverterBool.lift, null ); }@override int get hashCode { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitHash( uniffiClonePointer(), status ), FfiConverterUInt64.lift, null ); } @override String greet(String name,) { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterGreet( uniffiClonePointer(), FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } @override Greeter toTrait() { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodProcfriendlygreeterToTrait( uniffiClonePointer(), status ), Greeter.lift, null ); } }  abstract class RegistryInterface { Greeter makeFriendly(String phrase);Greeter makeProc(String phrase); } final _registryFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitInterfacesFnFreeRegistry(ptr, status)); }); class Registry implements RegistryInterface { late final Pointer<Void> _ptr; Registry._(this._ptr) { _registryFinalizer.attach(this, _ptr, detach: this); } Registry() : _ptr = rustCall((status) => uniffiTraitInterfacesFnConstructorRegistryNew( status ), null ) { _registryFinalizer.attach(this, _ptr, detach: this); } factory Registry.lift(Pointer<Void> ptr) { return Registry._(ptr); } static Pointer<Void> lower(Registry value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitInterfacesFnCloneRegistry(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Registry.fromSendableHandle(int handle) { return Registry._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Registry value) { return 8; } static LiftRetVal<Registry> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Registry.lift(pointer), 8); } static int write(Registry value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _registryFinalizer.detach(this); rustCall((status) => uniffiTraitInterfacesFnFreeRegistry(_ptr, status)); } @override Greeter makeFriendly(String phrase,) { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodRegistryMakeFriendly( uniffiClonePointer(), FfiConverterString.lower(phrase), status ), Greeter.lift, null ); } @override Greeter makeProc(String phrase,) { return rustCallWithLifter( (status) => uniffiTraitInterfacesFnMethodRegistryMakeProc( uniffiClonePointer(), FfiConverterString.lower(phrase), status ), Greeter.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; int _counter = 1; int insert(T obj) { final handle = _counter; _counter += 2; _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiTraitInterfacesRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiTraitInterfacesRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:trait_interfaces"; @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_clone_friendlygreeter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnCloneFriendlygreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_free_friendlygreeter", assetId: _uniffiAssetId ) external void uniffiTraitInterfacesFnFreeFriendlygreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_constructor_friendlygreeter_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnConstructorFriendlygreeterNew( RustBuffer phrase, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodFriendlygreeterGreet( Pointer<Void> ptr, RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_to_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnMethodFriendlygreeterToTrait( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_uniffi_trait_debug", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitDebug( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_uniffi_trait_display", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitDisplay( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_uniffi_trait_eq_eq", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitEqEq( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_uniffi_trait_eq_ne", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitEqNe( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_friendlygreeter_uniffi_trait_hash", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodFriendlygreeterUniffiTraitHash( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_clone_greeter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnCloneGreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_free_greeter", assetId: _uniffiAssetId ) external void uniffiTraitInterfacesFnFreeGreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_greeter_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodGreeterGreet( Pointer<Void> ptr, RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_clone_procfriendlygreeter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnCloneProcfriendlygreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_free_procfriendlygreeter", assetId: _uniffiAssetId ) external void uniffiTraitInterfacesFnFreeProcfriendlygreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_constructor_procfriendlygreeter_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnConstructorProcfriendlygreeterNew( RustBuffer phrase, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodProcfriendlygreeterGreet( Pointer<Void> ptr, RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_to_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnMethodProcfriendlygreeterToTrait( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_uniffi_trait_debug", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitDebug( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_uniffi_trait_display", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitDisplay( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_uniffi_trait_eq_eq", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitEqEq( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_uniffi_trait_eq_ne", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitEqNe( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_procfriendlygreeter_uniffi_trait_hash", assetId: _uniffiAssetId ) external int uniffiTraitInterfacesFnMethodProcfriendlygreeterUniffiTraitHash( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_clone_registry", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnCloneRegistry( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_free_registry", assetId: _uniffiAssetId ) external void uniffiTraitInterfacesFnFreeRegistry( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_constructor_registry_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnConstructorRegistryNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_registry_make_friendly", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnMethodRegistryMakeFriendly( Pointer<Void> ptr, RustBuffer phrase, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_interfaces_fn_method_registry_make_proc", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitInterfacesFnMethodRegistryMakeProc( Pointer<Void> ptr, RustBuffer phrase, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiTraitInterfacesRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiTraitInterfacesRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiTraitInterfacesRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiTraitInterfacesRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiTraitInterfacesRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiTraitInterfacesRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiTraitInterfacesRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_interfaces_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_interfaces_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiTraitInterfacesRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_friendlygreeter_greet", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodFriendlygreeterGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_friendlygreeter_to_trait", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodFriendlygreeterToTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_greeter_greet", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodGreeterGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_procfriendlygreeter_greet", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodProcfriendlygreeterGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_procfriendlygreeter_to_trait", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodProcfriendlygreeterToTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_registry_make_friendly", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodRegistryMakeFriendly();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_method_registry_make_proc", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumMethodRegistryMakeProc();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_constructor_friendlygreeter_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumConstructorFriendlygreeterNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_constructor_procfriendlygreeter_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumConstructorProcfriendlygreeterNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_interfaces_checksum_constructor_registry_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitInterfacesChecksumConstructorRegistryNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_trait_interfaces_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiTraitInterfacesUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiTraitInterfacesUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiTraitInterfacesChecksumMethodFriendlygreeterGreet() != 34183) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_friendlygreeter_greet"); }if (uniffiTraitInterfacesChecksumMethodFriendlygreeterToTrait() != 35351) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_friendlygreeter_to_trait"); }if (uniffiTraitInterfacesChecksumMethodGreeterGreet() != 63969) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_greeter_greet"); }if (uniffiTraitInterfacesChecksumMethodProcfriendlygreeterGreet() != 64468) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_procfriendlygreeter_greet"); }if (uniffiTraitInterfacesChecksumMethodProcfriendlygreeterToTrait() != 36974) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_procfriendlygreeter_to_trait"); }if (uniffiTraitInterfacesChecksumMethodRegistryMakeFriendly() != 15720) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_registry_make_friendly"); }if (uniffiTraitInterfacesChecksumMethodRegistryMakeProc() != 11580) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_method_registry_make_proc"); }if (uniffiTraitInterfacesChecksumConstructorFriendlygreeterNew() != 50957) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_constructor_friendlygreeter_new"); }if (uniffiTraitInterfacesChecksumConstructorProcfriendlygreeterNew() != 13239) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_constructor_procfriendlygreeter_new"); }if (uniffiTraitInterfacesChecksumConstructorRegistryNew() != 19835) { mismatchedChecksums.add("uniffi_trait_interfaces_checksum_constructor_registry_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
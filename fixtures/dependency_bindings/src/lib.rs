@@ -0,0 +1,2 @@
+/// The bindings of `trait_interfaces`, generated by the build script from its cdylib.
+pub const BINDINGS: &str = include_str!(concat!(env!("OUT_DIR"), "/trait_interfaces.dart"));
@@ -0,0 +1,31 @@
+use anyhow::Result;
+use camino::Utf8PathBuf;
+use dependency_bindings::BINDINGS;
+use std::collections::BTreeMap;
+
+#[test]
+fn bindings_expose_the_dependency_api() {
+    for item in [
+        "abstract class Greeter {",
+        "class FriendlyGreeter implements FriendlyGreeterInterface {",
+        "class ProcFriendlyGreeter implements ProcFriendlyGreeterInterface {",
+        "class Registry implements RegistryInterface {",
+        "Greeter makeFriendly(String phrase,) {",
+        "Greeter makeProc(String phrase,) {",
+        "String greet(String name,) {",
+    ] {
+        assert!(
+            BINDINGS.contains(item),
+            "`{item}` missing from the bindings"
+        );
+    }
+}
+
+#[test]
+fn bindings_match_snapshot() -> Result<()> {
+    let generated = BTreeMap::from([(
+        Utf8PathBuf::from("trait_interfaces.dart"),
+        BINDINGS.to_owned(),
+    )]);
+    uniffi_dart::testing::assert_matches_golden("dependency_bindings", &generated)
+}
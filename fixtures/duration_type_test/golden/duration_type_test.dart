@@ -0,0 +1,125 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiDurationTypeTestRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiDurationTypeTestRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterDuration { static Duration lift( RustBuffer buf) { return FfiConverterDuration.read(buf.asUint8List()).value; } static RustBuffer lower( Duration value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } static LiftRetVal<Duration> read( Uint8List buf) { final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12); final seconds = bytes.getUint64(0); final micros = (bytes.getUint32(8) ~/ 1000); return LiftRetVal(Duration(seconds: seconds, microseconds: micros), 12); } static int allocationSize([Duration value = const Duration()]) { return 12; } static int write( Duration value, Uint8List buf) { final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12); bytes.setUint64(0, value.inSeconds); final ms = (value.inMicroseconds - (value.inSeconds * 1000000)) * 1000; bytes.setUint32(8, ms.toInt()); return 12; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:duration_type_test"; int getNanos(Duration duration,) { return rustCallWithLifter( (status) => uniffiDurationTypeTestFnFuncGetNanos( FfiConverterDuration.lower(duration), status ), FfiConverterUInt32.lift, null ); } int getSeconds(Duration duration,) { return rustCallWithLifter( (status) => uniffiDurationTypeTestFnFuncGetSeconds( FfiConverterDuration.lower(duration), status ), FfiConverterUInt64.lift, null ); } Duration makeDuration(int seconds,int nanos,) { return rustCallWithLifter( (status) => uniffiDurationTypeTestFnFuncMakeDuration( FfiConverterUInt64.lower(seconds),FfiConverterUInt32.lower(nanos), status ), FfiConverterDuration.lift, null ); } @Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_duration_type_test_fn_func_get_nanos", assetId: _uniffiAssetId ) external int uniffiDurationTypeTestFnFuncGetNanos( RustBuffer duration, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_duration_type_test_fn_func_get_seconds", assetId: _uniffiAssetId ) external int uniffiDurationTypeTestFnFuncGetSeconds( RustBuffer duration, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_duration_type_test_fn_func_make_duration", assetId: _uniffiAssetId ) external RustBuffer uniffiDurationTypeTestFnFuncMakeDuration( int seconds, int nanos, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiDurationTypeTestRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiDurationTypeTestRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiDurationTypeTestRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiDurationTypeTestRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiDurationTypeTestRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiDurationTypeTestRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiDurationTypeTestRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_duration_type_test_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_duration_type_test_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiDurationTypeTestRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_duration_type_test_checksum_func_get_nanos", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDurationTypeTestChecksumFuncGetNanos();
+
+@Native<Uint16 Function()>( symbol: "uniffi_duration_type_test_checksum_func_get_seconds", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDurationTypeTestChecksumFuncGetSeconds();
+
+@Native<Uint16 Function()>( symbol: "uniffi_duration_type_test_checksum_func_make_duration", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDurationTypeTestChecksumFuncMakeDuration();
+
+@Native<Uint32 Function()>( symbol: "ffi_duration_type_test_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiDurationTypeTestUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiDurationTypeTestUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiDurationTypeTestChecksumFuncGetNanos() != 44543) { mismatchedChecksums.add("uniffi_duration_type_test_checksum_func_get_nanos"); }if (uniffiDurationTypeTestChecksumFuncGetSeconds() != 4024) { mismatchedChecksums.add("uniffi_duration_type_test_checksum_func_get_seconds"); }if (uniffiDurationTypeTestChecksumFuncMakeDuration() != 1174) { mismatchedChecksums.add("uniffi_duration_type_test_checksum_func_make_duration"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
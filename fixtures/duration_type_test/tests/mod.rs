@@ -4,3 +4,13 @@ use anyhow::Result;
 fn duration_type_test() -> Result<()> {
     uniffi_dart::testing::run_test("duration_type_test", "src/api.udl", None)
 }
+
+#[test]
+fn duration_type_test_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("duration_type_test", "src/api.udl", None)
+}
+
+#[test]
+fn duration_type_test_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("duration_type_test", "src/api.udl", None)
+}
@@ -0,0 +1,138 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:io";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Point { final double x;final double y; Point(this.x,this.y,); } class FfiConverterPoint { static Point lift( RustBuffer buf) { return FfiConverterPoint.read(buf.asUint8List()).value; } static LiftRetVal<Point> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final xLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final x = xLifted.value; newOffset += xLifted.bytesRead;final yLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final y = yLifted.value; newOffset += yLifted.bytesRead; return LiftRetVal(Point( x,y, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Point value) { final totalLength = FfiConverterDouble64.allocationSize(value.x) +FfiConverterDouble64.allocationSize(value.y) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Point value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterDouble64.write(value.x, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterDouble64.write(value.y, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Point value) { return FfiConverterDouble64.allocationSize(value.x) +FfiConverterDouble64.allocationSize(value.y) + 0; } } abstract class CounterInterface { int increment(); } final _counterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiDynamicLibraryFnFreeCounter(ptr, status)); }); class Counter implements CounterInterface { late final Pointer<Void> _ptr; Counter._(this._ptr) { _counterFinalizer.attach(this, _ptr, detach: this); } Counter() : _ptr = rustCall((status) => uniffiDynamicLibraryFnConstructorCounterNew( status ), null ) { _counterFinalizer.attach(this, _ptr, detach: this); } factory Counter.lift(Pointer<Void> ptr) { return Counter._(ptr); } static Pointer<Void> lower(Counter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiDynamicLibraryFnCloneCounter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Counter.fromSendableHandle(int handle) { return Counter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Counter value) { return 8; } static LiftRetVal<Counter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Counter.lift(pointer), 8); } static int write(Counter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _counterFinalizer.detach(this); rustCall((status) => uniffiDynamicLibraryFnFreeCounter(_ptr, status)); } @override int increment() { return rustCallWithLifter( (status) => uniffiDynamicLibraryFnMethodCounterIncrement( uniffiClonePointer(), status ), FfiConverterUInt32.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiDynamicLibraryRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiDynamicLibraryRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree);  final DynamicLibrary _uniffiLibrary = _uniffiOpenLibrary(); DynamicLibrary _uniffiOpenLibrary() { final String fileName; if (Platform.isWindows) { fileName = "dynamic_library.dll"; } else if (Platform.isMacOS || Platform.isIOS) { fileName = "libdynamic_library.dylib"; } else { fileName = "libdynamic_library.so"; } final path = Platform.environment["UNIFFI_DYNAMIC_LIBRARY_LIBRARY_PATH"]; if (path != null && path.isNotEmpty) { return DynamicLibrary.open(path); } const searchPaths = <String>["."]; for (final dir in searchPaths) { final candidate = File(dir + Platform.pathSeparator + fileName); if (candidate.existsSync()) { return DynamicLibrary.open(candidate.path); } } return DynamicLibrary.open(fileName); } int add(int a,int b,) { return rustCallWithLifter( (status) => uniffiDynamicLibraryFnFuncAdd( FfiConverterUInt32.lower(a),FfiConverterUInt32.lower(b), status ), FfiConverterUInt32.lift, null ); } String greet(String name,) { return rustCallWithLifter( (status) => uniffiDynamicLibraryFnFuncGreet( FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } Point midpoint(Point a,Point b,) { return rustCallWithLifter( (status) => uniffiDynamicLibraryFnFuncMidpoint( FfiConverterPoint.lower(a),FfiConverterPoint.lower(b), status ), FfiConverterPoint.lift, null ); } final Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>) uniffiDynamicLibraryFnCloneCounter = _uniffiLibrary.lookupFunction< Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>), Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_clone_counter");
+
+final void Function( Pointer<Void>, Pointer<RustCallStatus>) uniffiDynamicLibraryFnFreeCounter = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<RustCallStatus>), void Function( Pointer<Void>, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_free_counter");
+
+final Pointer<Void> Function( Pointer<RustCallStatus>) uniffiDynamicLibraryFnConstructorCounterNew = _uniffiLibrary.lookupFunction< Pointer<Void> Function( Pointer<RustCallStatus>), Pointer<Void> Function( Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_constructor_counter_new");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) uniffiDynamicLibraryFnMethodCounterIncrement = _uniffiLibrary.lookupFunction< Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_method_counter_increment");
+
+final int Function( int, int, Pointer<RustCallStatus>) uniffiDynamicLibraryFnFuncAdd = _uniffiLibrary.lookupFunction< Uint32 Function( Uint32, Uint32, Pointer<RustCallStatus>), int Function( int, int, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_func_add", isLeaf: true);
+
+final RustBuffer Function( RustBuffer, Pointer<RustCallStatus>) uniffiDynamicLibraryFnFuncGreet = _uniffiLibrary.lookupFunction< RustBuffer Function( RustBuffer, Pointer<RustCallStatus>), RustBuffer Function( RustBuffer, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_func_greet");
+
+final RustBuffer Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>) uniffiDynamicLibraryFnFuncMidpoint = _uniffiLibrary.lookupFunction< RustBuffer Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>), RustBuffer Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>) >("uniffi_dynamic_library_fn_func_midpoint");
+
+final RustBuffer Function( int, Pointer<RustCallStatus>) ffiDynamicLibraryRustbufferAlloc = _uniffiLibrary.lookupFunction< RustBuffer Function( Uint64, Pointer<RustCallStatus>), RustBuffer Function( int, Pointer<RustCallStatus>) >("ffi_dynamic_library_rustbuffer_alloc");
+
+final RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>) ffiDynamicLibraryRustbufferFromBytes = _uniffiLibrary.lookupFunction< RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>), RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>) >("ffi_dynamic_library_rustbuffer_from_bytes");
+
+final void Function( RustBuffer, Pointer<RustCallStatus>) ffiDynamicLibraryRustbufferFree = _uniffiLibrary.lookupFunction< Void Function( RustBuffer, Pointer<RustCallStatus>), void Function( RustBuffer, Pointer<RustCallStatus>) >("ffi_dynamic_library_rustbuffer_free");
+
+final RustBuffer Function( RustBuffer, int, Pointer<RustCallStatus>) ffiDynamicLibraryRustbufferReserve = _uniffiLibrary.lookupFunction< RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>), RustBuffer Function( RustBuffer, int, Pointer<RustCallStatus>) >("ffi_dynamic_library_rustbuffer_reserve");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollU8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_u8");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelU8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_u8");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeU8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_u8");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteU8 = _uniffiLibrary.lookupFunction< Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_u8");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollI8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_i8");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelI8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_i8");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeI8 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_i8");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteI8 = _uniffiLibrary.lookupFunction< Int8 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_i8");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollU16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_u16");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelU16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_u16");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeU16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_u16");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteU16 = _uniffiLibrary.lookupFunction< Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_u16");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollI16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_i16");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelI16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_i16");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeI16 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_i16");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteI16 = _uniffiLibrary.lookupFunction< Int16 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_i16");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollU32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_u32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelU32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_u32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeU32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_u32");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteU32 = _uniffiLibrary.lookupFunction< Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_u32");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollI32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_i32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelI32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_i32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeI32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_i32");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteI32 = _uniffiLibrary.lookupFunction< Int32 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_i32");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollU64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_u64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelU64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_u64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeU64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_u64");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteU64 = _uniffiLibrary.lookupFunction< Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_u64");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollI64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_i64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelI64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_i64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeI64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_i64");
+
+final int Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteI64 = _uniffiLibrary.lookupFunction< Int64 Function( Pointer<Void>, Pointer<RustCallStatus>), int Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_i64");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollF32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_f32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelF32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_f32");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeF32 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_f32");
+
+final double Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteF32 = _uniffiLibrary.lookupFunction< Float Function( Pointer<Void>, Pointer<RustCallStatus>), double Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_f32");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollF64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_f64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelF64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_f64");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeF64 = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_f64");
+
+final double Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteF64 = _uniffiLibrary.lookupFunction< Double Function( Pointer<Void>, Pointer<RustCallStatus>), double Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_f64");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollRustBuffer = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_rust_buffer");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelRustBuffer = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_rust_buffer");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeRustBuffer = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_rust_buffer");
+
+final RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteRustBuffer = _uniffiLibrary.lookupFunction< RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>), RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_rust_buffer");
+
+final void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) ffiDynamicLibraryRustFuturePollVoid = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>), void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) >("ffi_dynamic_library_rust_future_poll_void");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureCancelVoid = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_cancel_void");
+
+final void Function( Pointer<Void>) ffiDynamicLibraryRustFutureFreeVoid = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>), void Function( Pointer<Void>) >("ffi_dynamic_library_rust_future_free_void");
+
+final void Function( Pointer<Void>, Pointer<RustCallStatus>) ffiDynamicLibraryRustFutureCompleteVoid = _uniffiLibrary.lookupFunction< Void Function( Pointer<Void>, Pointer<RustCallStatus>), void Function( Pointer<Void>, Pointer<RustCallStatus>) >("ffi_dynamic_library_rust_future_complete_void");
+
+final int Function() uniffiDynamicLibraryChecksumFuncAdd = _uniffiLibrary.lookupFunction< Uint16 Function(), int Function() >("uniffi_dynamic_library_checksum_func_add", isLeaf: true);
+
+final int Function() uniffiDynamicLibraryChecksumFuncGreet = _uniffiLibrary.lookupFunction< Uint16 Function(), int Function() >("uniffi_dynamic_library_checksum_func_greet", isLeaf: true);
+
+final int Function() uniffiDynamicLibraryChecksumFuncMidpoint = _uniffiLibrary.lookupFunction< Uint16 Function(), int Function() >("uniffi_dynamic_library_checksum_func_midpoint", isLeaf: true);
+
+final int Function() uniffiDynamicLibraryChecksumMethodCounterIncrement = _uniffiLibrary.lookupFunction< Uint16 Function(), int Function() >("uniffi_dynamic_library_checksum_method_counter_increment", isLeaf: true);
+
+final int Function() uniffiDynamicLibraryChecksumConstructorCounterNew = _uniffiLibrary.lookupFunction< Uint16 Function(), int Function() >("uniffi_dynamic_library_checksum_constructor_counter_new", isLeaf: true);
+
+final int Function() ffiDynamicLibraryUniffiContractVersion = _uniffiLibrary.lookupFunction< Uint32 Function(), int Function() >("ffi_dynamic_library_uniffi_contract_version", isLeaf: true);
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiDynamicLibraryUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiDynamicLibraryChecksumFuncAdd() != 14544) { mismatchedChecksums.add("uniffi_dynamic_library_checksum_func_add"); }if (uniffiDynamicLibraryChecksumFuncGreet() != 46773) { mismatchedChecksums.add("uniffi_dynamic_library_checksum_func_greet"); }if (uniffiDynamicLibraryChecksumFuncMidpoint() != 10661) { mismatchedChecksums.add("uniffi_dynamic_library_checksum_func_midpoint"); }if (uniffiDynamicLibraryChecksumMethodCounterIncrement() != 64590) { mismatchedChecksums.add("uniffi_dynamic_library_checksum_method_counter_increment"); }if (uniffiDynamicLibraryChecksumConstructorCounterNew() != 28201) { mismatchedChecksums.add("uniffi_dynamic_library_checksum_constructor_counter_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
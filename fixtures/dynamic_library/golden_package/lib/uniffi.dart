@@ -0,0 +1 @@
+export 'dynamic_library.dart';
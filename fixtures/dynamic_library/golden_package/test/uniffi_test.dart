@@ -0,0 +1,8 @@
+import 'package:test/test.dart';
+import 'package:uniffi/dynamic_library.dart' as dynamic_library;
+
+void main() {
+  test('dynamic_library loads the native library', () {
+    dynamic_library.ensureInitialized();
+  });
+}
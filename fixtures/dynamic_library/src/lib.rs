@@ -0,0 +1,48 @@
+use std::sync::Mutex;
+
+#[uniffi::export]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[uniffi::export]
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+#[derive(uniffi::Record)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[uniffi::export]
+pub fn midpoint(a: Point, b: Point) -> Point {
+    Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {
+            value: Mutex::new(0),
+        }
+    }
+
+    pub fn increment(&self) -> u32 {
+        let mut value = self.value.lock().unwrap();
+        *value += 1;
+        *value
+    }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,26 @@
+import 'package:test/test.dart';
+import '../dynamic_library.dart';
+
+void main() {
+  test('functions are looked up in the opened library', () {
+    expect(add(2, 3), 5);
+    expect(greet('Dart'), 'Hello, Dart!');
+  });
+
+  test('records cross the boundary', () {
+    final point = midpoint(Point(x: 0, y: 0), Point(x: 2, y: 4));
+    expect(point.x, 1);
+    expect(point.y, 2);
+  });
+
+  test('objects keep their state', () {
+    final counter = Counter();
+    expect(counter.increment(), 1);
+    expect(counter.increment(), 2);
+  });
+
+  test('contract checks pass on first use and can be rerun', () {
+    expect(add(1, 1), 2);
+    expect(() => ensureInitialized(), returnsNormally);
+  });
+}
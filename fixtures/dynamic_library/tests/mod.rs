@@ -0,0 +1,21 @@
+use anyhow::Result;
+
+#[test]
+fn dynamic_library() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("dynamic_library")
+}
+
+#[test]
+fn dynamic_library_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("dynamic_library")
+}
+
+#[test]
+fn dynamic_library_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("dynamic_library")
+}
+
+#[test]
+fn dynamic_library_package_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_package_snapshot("dynamic_library")
+}
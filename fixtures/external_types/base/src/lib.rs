@@ -0,0 +1,47 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, Copy, uniffi::Enum)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(uniffi::Object)]
+pub struct Odometer {
+    distance: Mutex<f64>,
+}
+
+#[uniffi::export]
+impl Odometer {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            distance: Mutex::new(0.0),
+        })
+    }
+
+    pub fn distance(&self) -> f64 {
+        *self.distance.lock().unwrap()
+    }
+}
+
+impl Odometer {
+    pub fn record(&self, distance: f64) {
+        *self.distance.lock().unwrap() += distance;
+    }
+}
+
+#[uniffi::export]
+pub fn origin() -> Point {
+    Point { x: 0.0, y: 0.0 }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,125 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+ import "external_types_base.dart" as external_types_base;  class Route { final external_types_base.Point start;final List<external_types_base.Direction> steps; Route(this.start,this.steps,); } class FfiConverterRoute { static Route lift( RustBuffer buf) { return FfiConverterRoute.read(buf.asUint8List()).value; } static LiftRetVal<Route> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final startLifted = external_types_base.FfiConverterPoint.read(Uint8List.view(buf.buffer, newOffset)); final start = startLifted.value; newOffset += startLifted.bytesRead;final stepsLifted = FfiConverterSequenceDirection.read(Uint8List.view(buf.buffer, newOffset)); final steps = stepsLifted.value; newOffset += stepsLifted.bytesRead; return LiftRetVal(Route( start,steps, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Route value) { final totalLength = external_types_base.FfiConverterPoint.allocationSize(value.start) +FfiConverterSequenceDirection.allocationSize(value.steps) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Route value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += external_types_base.FfiConverterPoint.write(value.start, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterSequenceDirection.write(value.steps, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Route value) { return external_types_base.FfiConverterPoint.allocationSize(value.start) +FfiConverterSequenceDirection.allocationSize(value.steps) + 0; } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiExternalTypesRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiExternalTypesRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalDirection { static external_types_base.Direction? lift( RustBuffer buf) { return FfiConverterOptionalDirection.read(buf.asUint8List()).value; } static LiftRetVal<external_types_base.Direction?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = external_types_base.FfiConverterDirection.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<external_types_base.Direction?>(result.value, result.bytesRead + 1); } static int allocationSize([external_types_base.Direction? value]) { if (value == null) { return 1; } return external_types_base.FfiConverterDirection.allocationSize(value) + 1; } static RustBuffer lower( external_types_base.Direction? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalDirection.allocationSize(value)); FfiConverterOptionalDirection.write(value, buf); return toRustBuffer(buf); } static int write( external_types_base.Direction? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return external_types_base.FfiConverterDirection.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterSequenceDirection { static List<external_types_base.Direction> lift( RustBuffer buf) { return FfiConverterSequenceDirection.read(buf.asUint8List()).value; } static LiftRetVal<List<external_types_base.Direction>> read( Uint8List buf) { List<external_types_base.Direction> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = external_types_base.FfiConverterDirection.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<external_types_base.Direction> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += external_types_base.FfiConverterDirection.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<external_types_base.Direction> value) { return value.map((l) => external_types_base.FfiConverterDirection.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<external_types_base.Direction> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:external_types"; external_types_base.Direction? lastDirection(Route route,) { return rustCallWithLifter( (status) => uniffiExternalTypesFnFuncLastDirection( FfiConverterRoute.lower(route), status ), FfiConverterOptionalDirection.lift, null ); } external_types_base.Point step(external_types_base.Point point,external_types_base.Direction direction,double distance,) { return rustCallWithLifter( (status) => uniffiExternalTypesFnFuncStep( external_types_base.FfiConverterPoint.lower(point),external_types_base.FfiConverterDirection.lower(direction),distance, status ), external_types_base.FfiConverterPoint.lift, null ); } external_types_base.Point walk(Route route,external_types_base.Odometer odometer,) { return rustCallWithLifter( (status) => uniffiExternalTypesFnFuncWalk( FfiConverterRoute.lower(route),external_types_base.Odometer.lower(odometer), status ), external_types_base.FfiConverterPoint.lift, null ); } @Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_fn_func_last_direction", assetId: _uniffiAssetId ) external RustBuffer uniffiExternalTypesFnFuncLastDirection( RustBuffer route, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<external_types_base.RustBuffer Function( external_types_base.RustBuffer, external_types_base.RustBuffer, Double, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_fn_func_step", assetId: _uniffiAssetId ) external external_types_base.RustBuffer uniffiExternalTypesFnFuncStep( external_types_base.RustBuffer point, external_types_base.RustBuffer direction, double distance, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<external_types_base.RustBuffer Function( RustBuffer, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_fn_func_walk", assetId: _uniffiAssetId ) external external_types_base.RustBuffer uniffiExternalTypesFnFuncWalk( RustBuffer route, Pointer<Void> odometer, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiExternalTypesRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiExternalTypesRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiExternalTypesRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiExternalTypesRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiExternalTypesRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_checksum_func_last_direction", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesChecksumFuncLastDirection();
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_checksum_func_step", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesChecksumFuncStep();
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_checksum_func_walk", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesChecksumFuncWalk();
+
+@Native<Uint32 Function()>( symbol: "ffi_external_types_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiExternalTypesUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiExternalTypesUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiExternalTypesChecksumFuncLastDirection() != 31155) { mismatchedChecksums.add("uniffi_external_types_checksum_func_last_direction"); }if (uniffiExternalTypesChecksumFuncStep() != 3931) { mismatchedChecksums.add("uniffi_external_types_checksum_func_step"); }if (uniffiExternalTypesChecksumFuncWalk() != 30140) { mismatchedChecksums.add("uniffi_external_types_checksum_func_walk"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
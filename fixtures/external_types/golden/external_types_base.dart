@@ -0,0 +1,129 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Point { final double x;final double y; Point(this.x,this.y,); } class FfiConverterPoint { static Point lift( RustBuffer buf) { return FfiConverterPoint.read(buf.asUint8List()).value; } static LiftRetVal<Point> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final xLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final x = xLifted.value; newOffset += xLifted.bytesRead;final yLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final y = yLifted.value; newOffset += yLifted.bytesRead; return LiftRetVal(Point( x,y, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Point value) { final totalLength = FfiConverterDouble64.allocationSize(value.x) +FfiConverterDouble64.allocationSize(value.y) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Point value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterDouble64.write(value.x, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterDouble64.write(value.y, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Point value) { return FfiConverterDouble64.allocationSize(value.x) +FfiConverterDouble64.allocationSize(value.y) + 0; } } enum Direction { north,east,south,west, ; } class FfiConverterDirection { static LiftRetVal<Direction> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( Direction.north, 4, );case 2: return LiftRetVal( Direction.east, 4, );case 3: return LiftRetVal( Direction.south, 4, );case 4: return LiftRetVal( Direction.west, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static Direction lift( RustBuffer buffer) { return FfiConverterDirection.read(buffer.asUint8List()).value; } static RustBuffer lower( Direction input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(Direction value) { return 4; } static int write( Direction value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  abstract class OdometerInterface { double distance(); } final _odometerFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiExternalTypesBaseFnFreeOdometer(ptr, status)); }); class Odometer implements OdometerInterface { late final Pointer<Void> _ptr; Odometer._(this._ptr) { _odometerFinalizer.attach(this, _ptr, detach: this); } Odometer() : _ptr = rustCall((status) => uniffiExternalTypesBaseFnConstructorOdometerNew( status ), null ) { _odometerFinalizer.attach(this, _ptr, detach: this); } factory Odometer.lift(Pointer<Void> ptr) { return Odometer._(ptr); } static Pointer<Void> lower(Odometer value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiExternalTypesBaseFnCloneOdometer(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Odometer.fromSendableHandle(int handle) { return Odometer._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Odometer value) { return 8; } static LiftRetVal<Odometer> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Odometer.lift(pointer), 8); } static int write(Odometer value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _odometerFinalizer.detach(this); rustCall((status) => uniffiExternalTypesBaseFnFreeOdometer(_ptr, status)); } @override double distance() { return rustCallWithLifter( (status) => uniffiExternalTypesBaseFnMethodOdometerDistance( uniffiClonePointer(), status ), FfiConverterDouble64.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiExternalTypesBaseRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiExternalTypesBaseRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:external_types"; Point origin() { return rustCallWithLifter( (status) => uniffiExternalTypesBaseFnFuncOrigin( status ), FfiConverterPoint.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_base_fn_clone_odometer", assetId: _uniffiAssetId ) external Pointer<Void> uniffiExternalTypesBaseFnCloneOdometer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_base_fn_free_odometer", assetId: _uniffiAssetId ) external void uniffiExternalTypesBaseFnFreeOdometer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_base_fn_constructor_odometer_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiExternalTypesBaseFnConstructorOdometerNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_base_fn_method_odometer_distance", assetId: _uniffiAssetId ) external double uniffiExternalTypesBaseFnMethodOdometerDistance( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_external_types_base_fn_func_origin", assetId: _uniffiAssetId ) external RustBuffer uniffiExternalTypesBaseFnFuncOrigin( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesBaseRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesBaseRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesBaseRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiExternalTypesBaseRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiExternalTypesBaseRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiExternalTypesBaseRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiExternalTypesBaseRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_external_types_base_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_external_types_base_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiExternalTypesBaseRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_base_checksum_func_origin", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesBaseChecksumFuncOrigin();
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_base_checksum_method_odometer_distance", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesBaseChecksumMethodOdometerDistance();
+
+@Native<Uint16 Function()>( symbol: "uniffi_external_types_base_checksum_constructor_odometer_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiExternalTypesBaseChecksumConstructorOdometerNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_external_types_base_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiExternalTypesBaseUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiExternalTypesBaseUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiExternalTypesBaseChecksumFuncOrigin() != 15143) { mismatchedChecksums.add("uniffi_external_types_base_checksum_func_origin"); }if (uniffiExternalTypesBaseChecksumMethodOdometerDistance() != 9039) { mismatchedChecksums.add("uniffi_external_types_base_checksum_method_odometer_distance"); }if (uniffiExternalTypesBaseChecksumConstructorOdometerNew() != 41627) { mismatchedChecksums.add("uniffi_external_types_base_checksum_constructor_odometer_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use external_types_base::{Direction, Odometer, Point};
+
+// Every type below but `Route` is defined in `external_types_base`, whose bindings this
+// component's bindings import.
+#[derive(uniffi::Record)]
+pub struct Route {
+    pub start: Point,
+    pub steps: Vec<Direction>,
+}
+
+#[uniffi::export]
+pub fn step(point: Point, direction: Direction, distance: f64) -> Point {
+    match direction {
+        Direction::North => Point {
+            y: point.y + distance,
+            ..point
+        },
+        Direction::East => Point {
+            x: point.x + distance,
+            ..point
+        },
+        Direction::South => Point {
+            y: point.y - distance,
+            ..point
+        },
+        Direction::West => Point {
+            x: point.x - distance,
+            ..point
+        },
+    }
+}
+
+/// Walks `route` one unit per step, recording the distance on `odometer`.
+#[uniffi::export]
+pub fn walk(route: Route, odometer: Arc<Odometer>) -> Point {
+    route.steps.iter().fold(route.start, |point, direction| {
+        odometer.record(1.0);
+        step(point, *direction, 1.0)
+    })
+}
+
+#[uniffi::export]
+pub fn last_direction(route: Route) -> Option<Direction> {
+    route.steps.last().copied()
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,26 @@
+import 'package:test/test.dart';
+import '../external_types.dart';
+import '../external_types_base.dart';
+
+void main() {
+  test('records and enums of another crate pass through functions', () {
+    final moved = step(origin(), Direction.north, 2.5);
+    expect(moved.x, 0.0);
+    expect(moved.y, 2.5);
+  });
+
+  test('external types can be nested in local records', () {
+    final route = Route(Point(1.0, 1.0), [Direction.east, Direction.east, Direction.south]);
+    expect(lastDirection(route), Direction.south);
+    expect(lastDirection(Route(origin(), [])), isNull);
+  });
+
+  test('objects of another crate can be passed', () {
+    final odometer = Odometer();
+    final end = walk(Route(origin(), [Direction.west, Direction.north]), odometer);
+    expect(end.x, -1.0);
+    expect(end.y, 1.0);
+    expect(odometer.distance(), 2.0);
+    odometer.dispose();
+  });
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+
+#[test]
+fn external_types() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("external_types")
+}
+
+#[test]
+fn external_types_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("external_types")
+}
+
+#[test]
+fn external_types_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("external_types")
+}
+
+#[test]
+fn external_types_import_the_public_library_of_their_package() -> Result<()> {
+    let sources = uniffi_dart::testing::written_sources_library_mode(
+        "external_types",
+        Some(&["external_types"]),
+        Some("packages.toml"),
+    )?
+    .into_iter()
+    .map(|(path, source)| (path.to_string(), source))
+    .collect::<BTreeMap<_, _>>();
+
+    // `external_types_base` is published by another package, so it isn't generated here
+    let paths = sources.keys().map(String::as_str).collect::<Vec<_>>();
+    if paths != ["external_types.dart", "src/external_types.dart"] {
+        bail!("unexpected files: {paths:?}");
+    }
+
+    let bindings = &sources["src/external_types.dart"];
+    let imports = bindings
+        .split(';')
+        .map(str::trim)
+        .filter(|statement| statement.starts_with("import") && statement.contains("base"))
+        .collect::<Vec<_>>();
+    if imports
+        != ["import \"package:base_bindings/external_types_base.dart\" as external_types_base"]
+    {
+        bail!("unexpected imports of `external_types_base`: {imports:?}");
+    }
+    if !bindings.contains("external_types_base.Point step(external_types_base.Point point") {
+        bail!("external types aren't referenced through the import prefix");
+    }
+    Ok(())
+}
+
+#[test]
+fn external_types_are_written_next_to_the_cdylib_by_default() -> Result<()> {
+    let sources = uniffi_dart::testing::written_sources_next_to_cdylib("external_types")?;
+    let paths = sources.keys().map(|path| path.as_str()).collect::<Vec<_>>();
+    if paths != ["external_types.dart", "external_types_base.dart"] {
+        bail!("unexpected files: {paths:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn external_types_reject_unknown_crates_in_the_filter() -> Result<()> {
+    let result = uniffi_dart::testing::written_sources_library_mode(
+        "external_types",
+        Some(&["missing"]),
+        None,
+    );
+    match result {
+        Ok(_) => bail!("generation succeeded for a crate that isn't linked in"),
+        Err(error) if error.to_string().contains("crate `missing` isn't part of") => Ok(()),
+        Err(error) => Err(error),
+    }
+}
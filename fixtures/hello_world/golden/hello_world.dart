@@ -0,0 +1,157 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class WorldState { final int inhabitants;final String? name; WorldState(this.inhabitants,this.name,); } class FfiConverterWorldState { static WorldState lift( RustBuffer buf) { return FfiConverterWorldState.read(buf.asUint8List()).value; } static LiftRetVal<WorldState> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final inhabitantsLifted = FfiConverterUInt8.read(Uint8List.view(buf.buffer, newOffset)); final inhabitants = inhabitantsLifted.value; newOffset += inhabitantsLifted.bytesRead;final nameLifted = FfiConverterOptionalString.read(Uint8List.view(buf.buffer, newOffset)); final name = nameLifted.value; newOffset += nameLifted.bytesRead; return LiftRetVal(WorldState( inhabitants,name, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( WorldState value) { final totalLength = FfiConverterUInt8.allocationSize(value.inhabitants) +FfiConverterOptionalString.allocationSize(value.name) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( WorldState value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterUInt8.write(value.inhabitants, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterOptionalString.write(value.name, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(WorldState value) { return FfiConverterUInt8.allocationSize(value.inhabitants) +FfiConverterOptionalString.allocationSize(value.name) + 0; } } abstract class WorldInterface { WorldInterface incInhabitants();bool isThere();String? name();String? prefixedName(String? inp);WorldInterface setName(String? inp);WorldState state(); } final _worldFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiHelloWorldFnFreeWorld(ptr, status)); }); class World implements WorldInterface { late final Pointer<Void> _ptr; World._(this._ptr) { _worldFinalizer.attach(this, _ptr, detach: this); } factory World.lift(Pointer<Void> ptr) { return World._(ptr); } static Pointer<Void> lower(World value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiHelloWorldFnCloneWorld(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory World.fromSendableHandle(int handle) { return World._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(World value) { return 8; } static LiftRetVal<World> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(World.lift(pointer), 8); } static int write(World value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _worldFinalizer.detach(this); rustCall((status) => uniffiHelloWorldFnFreeWorld(_ptr, status)); } @override World incInhabitants() { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldIncInhabitants( uniffiClonePointer(), status ), World.lift, null ); } @override bool isThere() { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldIsThere( uniffiClonePointer(), status ), FfiConverterBool.lift, null ); } @override String? name() { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldName( uniffiClonePointer(), status ), FfiConverterOptionalString.lift, null ); } @override String? prefixedName(String? inp,) { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldPrefixedName( uniffiClonePointer(), FfiConverterOptionalString.lower(inp), status ), FfiConverterOptionalString.lift, null ); } @override World setName(String? inp,) { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldSetName( uniffiClonePointer(), FfiConverterOptionalString.lower(inp), status ), World.lift, null ); } @override WorldState state() { return rustCallWithLifter( (status) => uniffiHelloWorldFnMethodWorldState( uniffiClonePointer(), status ), FfiConverterWorldState.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiHelloWorldRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiHelloWorldRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1); } static int lower(int value) { if (value < 0 || value > 255) { throw ArgumentError("Value out of range for u8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, lower(value)); return 1; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalString { static String? lift( RustBuffer buf) { return FfiConverterOptionalString.read(buf.asUint8List()).value; } static LiftRetVal<String?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<String?>(result.value, result.bytesRead + 1); } static int allocationSize([String? value]) { if (value == null) { return 1; } return FfiConverterString.allocationSize(value) + 1; } static RustBuffer lower( String? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalString.allocationSize(value)); FfiConverterOptionalString.write(value, buf); return toRustBuffer(buf); } static int write( String? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:hello_world"; String hello(String input,) { return rustCallWithLifter( (status) => uniffiHelloWorldFnFuncHello( FfiConverterString.lower(input), status ), FfiConverterString.lift, null ); } String helloWorld() { return rustCallWithLifter( (status) => uniffiHelloWorldFnFuncHelloWorld( status ), FfiConverterString.lift, null ); } World newWorld() { return rustCallWithLifter( (status) => uniffiHelloWorldFnFuncNewWorld( status ), World.lift, null ); } World newWorldWithName(String name,) { return rustCallWithLifter( (status) => uniffiHelloWorldFnFuncNewWorldWithName( FfiConverterString.lower(name), status ), World.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_clone_world", assetId: _uniffiAssetId ) external Pointer<Void> uniffiHelloWorldFnCloneWorld( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_free_world", assetId: _uniffiAssetId ) external void uniffiHelloWorldFnFreeWorld( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_inc_inhabitants", assetId: _uniffiAssetId ) external Pointer<Void> uniffiHelloWorldFnMethodWorldIncInhabitants( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_is_there", assetId: _uniffiAssetId ) external int uniffiHelloWorldFnMethodWorldIsThere( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_name", assetId: _uniffiAssetId ) external RustBuffer uniffiHelloWorldFnMethodWorldName( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_prefixed_name", assetId: _uniffiAssetId ) external RustBuffer uniffiHelloWorldFnMethodWorldPrefixedName( Pointer<Void> ptr, RustBuffer inp, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_set_name", assetId: _uniffiAssetId ) external Pointer<Void> uniffiHelloWorldFnMethodWorldSetName( Pointer<Void> ptr, RustBuffer inp, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_method_world_state", assetId: _uniffiAssetId ) external RustBuffer uniffiHelloWorldFnMethodWorldState( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_func_hello", assetId: _uniffiAssetId ) external RustBuffer uniffiHelloWorldFnFuncHello( RustBuffer input, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_func_hello_world", assetId: _uniffiAssetId ) external RustBuffer uniffiHelloWorldFnFuncHelloWorld( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_func_new_world", assetId: _uniffiAssetId ) external Pointer<Void> uniffiHelloWorldFnFuncNewWorld( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_hello_world_fn_func_new_world_with_name", assetId: _uniffiAssetId ) external Pointer<Void> uniffiHelloWorldFnFuncNewWorldWithName( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiHelloWorldRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiHelloWorldRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiHelloWorldRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiHelloWorldRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiHelloWorldRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiHelloWorldRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiHelloWorldRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiHelloWorldRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_hello_world_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_hello_world_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiHelloWorldRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_func_hello", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumFuncHello();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_func_hello_world", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumFuncHelloWorld();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_func_new_world", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumFuncNewWorld();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_func_new_world_with_name", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumFuncNewWorldWithName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_inc_inhabitants", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldIncInhabitants();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_is_there", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldIsThere();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_name", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_prefixed_name", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldPrefixedName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_set_name", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldSetName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_hello_world_checksum_method_world_state", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiHelloWorldChecksumMethodWorldState();
+
+@Native<Uint32 Function()>( symbol: "ffi_hello_world_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiHelloWorldUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiHelloWorldUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiHelloWorldChecksumFuncHello() != 59859) { mismatchedChecksums.add("uniffi_hello_world_checksum_func_hello"); }if (uniffiHelloWorldChecksumFuncHelloWorld() != 64773) { mismatchedChecksums.add("uniffi_hello_world_checksum_func_hello_world"); }if (uniffiHelloWorldChecksumFuncNewWorld() != 7174) { mismatchedChecksums.add("uniffi_hello_world_checksum_func_new_world"); }if (uniffiHelloWorldChecksumFuncNewWorldWithName() != 26270) { mismatchedChecksums.add("uniffi_hello_world_checksum_func_new_world_with_name"); }if (uniffiHelloWorldChecksumMethodWorldIncInhabitants() != 24860) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_inc_inhabitants"); }if (uniffiHelloWorldChecksumMethodWorldIsThere() != 25296) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_is_there"); }if (uniffiHelloWorldChecksumMethodWorldName() != 23263) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_name"); }if (uniffiHelloWorldChecksumMethodWorldPrefixedName() != 33696) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_prefixed_name"); }if (uniffiHelloWorldChecksumMethodWorldSetName() != 7167) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_set_name"); }if (uniffiHelloWorldChecksumMethodWorldState() != 16315) { mismatchedChecksums.add("uniffi_hello_world_checksum_method_world_state"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -1,6 +1,34 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 #[test]
 fn hello_world() -> Result<()> {
     uniffi_dart::testing::run_test("hello_world", "src/api.udl", None)
 }
+
+#[test]
+fn hello_world_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("hello_world", "src/api.udl", None)
+}
+
+#[test]
+fn hello_world_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("hello_world", "src/api.udl", None)
+}
+
+#[test]
+fn hello_world_generates_sources_in_memory() -> Result<()> {
+    let sources = uniffi_dart::testing::generated_sources("hello_world", "src/api.udl", None)?;
+    let paths = sources.keys().map(|path| path.as_str()).collect::<Vec<_>>();
+    if paths != ["hello_world.dart"] {
+        bail!("unexpected files: {paths:?}");
+    }
+
+    // The UDL is empty: everything comes from the proc-macro metadata of the cdylib
+    let bindings = sources.into_values().collect::<String>();
+    for expected in ["String helloWorld()", "World newWorldWithName(String name"] {
+        if !bindings.contains(expected) {
+            bail!("`{expected}` isn't generated");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,149 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+ import "leaf_calls_listener.dart" as leaf_calls_listener;  abstract class CounterInterface { int get_();int increment(); } final _counterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiLeafCallsFnFreeCounter(ptr, status)); }); class Counter implements CounterInterface { late final Pointer<Void> _ptr; Counter._(this._ptr) { _counterFinalizer.attach(this, _ptr, detach: this); } Counter() : _ptr = rustCall((status) => uniffiLeafCallsFnConstructorCounterNew( status ), null ) { _counterFinalizer.attach(this, _ptr, detach: this); } factory Counter.lift(Pointer<Void> ptr) { return Counter._(ptr); } static Pointer<Void> lower(Counter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiLeafCallsFnCloneCounter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Counter.fromSendableHandle(int handle) { return Counter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Counter value) { return 8; } static LiftRetVal<Counter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Counter.lift(pointer), 8); } static int write(Counter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _counterFinalizer.detach(this); rustCall((status) => uniffiLeafCallsFnFreeCounter(_ptr, status)); } @override int get_() { return rustCallWithLifter( (status) => uniffiLeafCallsFnMethodCounterGet( uniffiClonePointer(), status ), FfiConverterUInt32.lift, null ); } @override int increment() { return rustCallWithLifter( (status) => uniffiLeafCallsFnMethodCounterIncrement( uniffiClonePointer(), status ), FfiConverterUInt32.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiLeafCallsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiLeafCallsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:leaf_calls"; int add(int a,int b,) { return rustCallWithLifter( (status) => uniffiLeafCallsFnFuncAdd( FfiConverterUInt32.lower(a),FfiConverterUInt32.lower(b), status ), FfiConverterUInt32.lift, null ); } void fill(leaf_calls_listener.Consumer consumer,int value,) { return rustCall((status) { uniffiLeafCallsFnFuncFill( leaf_calls_listener.FfiConverterCallbackInterfaceConsumer.lower(consumer),FfiConverterUInt32.lower(value), status ); }, null); } String greet(String name,) { return rustCallWithLifter( (status) => uniffiLeafCallsFnFuncGreet( FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } void noop() { return rustCall((status) { uniffiLeafCallsFnFuncNoop( status ); }, null); } void notify(leaf_calls_listener.Listener listener,int value,) { return rustCall((status) { uniffiLeafCallsFnFuncNotify( leaf_calls_listener.FfiConverterCallbackInterfaceListener.lower(listener).address,FfiConverterUInt32.lower(value), status ); }, null); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_clone_counter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiLeafCallsFnCloneCounter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_free_counter", assetId: _uniffiAssetId ) external void uniffiLeafCallsFnFreeCounter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_constructor_counter_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiLeafCallsFnConstructorCounterNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_method_counter_get", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiLeafCallsFnMethodCounterGet( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_method_counter_increment", assetId: _uniffiAssetId ) external int uniffiLeafCallsFnMethodCounterIncrement( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Uint32, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_func_add", assetId: _uniffiAssetId ) external int uniffiLeafCallsFnFuncAdd( int a, int b, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_func_fill", assetId: _uniffiAssetId ) external void uniffiLeafCallsFnFuncFill( Pointer<Void> consumer, int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_func_greet", assetId: _uniffiAssetId, isLeaf: true ) external RustBuffer uniffiLeafCallsFnFuncGreet( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_func_noop", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiLeafCallsFnFuncNoop( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Uint64, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_fn_func_notify", assetId: _uniffiAssetId ) external void uniffiLeafCallsFnFuncNotify( int listener, int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiLeafCallsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiLeafCallsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiLeafCallsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiLeafCallsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiLeafCallsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_func_add", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumFuncAdd();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_func_fill", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumFuncFill();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_func_greet", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumFuncGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_func_noop", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumFuncNoop();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_func_notify", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumFuncNotify();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_method_counter_get", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumMethodCounterGet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_method_counter_increment", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumMethodCounterIncrement();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_checksum_constructor_counter_new", assetId: _uniffiAssetId ) external int uniffiLeafCallsChecksumConstructorCounterNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_leaf_calls_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiLeafCallsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiLeafCallsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiLeafCallsChecksumFuncAdd() != 42418) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_func_add"); }if (uniffiLeafCallsChecksumFuncFill() != 43439) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_func_fill"); }if (uniffiLeafCallsChecksumFuncGreet() != 29768) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_func_greet"); }if (uniffiLeafCallsChecksumFuncNoop() != 54021) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_func_noop"); }if (uniffiLeafCallsChecksumFuncNotify() != 60610) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_func_notify"); }if (uniffiLeafCallsChecksumMethodCounterGet() != 36091) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_method_counter_get"); }if (uniffiLeafCallsChecksumMethodCounterIncrement() != 57554) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_method_counter_increment"); }if (uniffiLeafCallsChecksumConstructorCounterNew() != 9205) { mismatchedChecksums.add("uniffi_leaf_calls_checksum_constructor_counter_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
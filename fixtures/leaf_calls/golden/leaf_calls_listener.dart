@@ -0,0 +1,127 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class Consumer { void accept(int value,); } class FfiConverterCallbackInterfaceConsumer { static final _handleMap = UniffiHandleMap<Consumer>(); static bool _vtableInitialized = false; static Consumer lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Consumer value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initConsumerVTable(); _vtableInitialized = true; } } static LiftRetVal<Consumer> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Consumer value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Consumer value) { return 8; } } typedef UniffiCallbackInterfaceConsumerMethod0 = Void Function( Uint64, Uint32, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceConsumerMethod0Dart = void Function( int, int, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceConsumerFree = Void Function(Uint64); typedef UniffiCallbackInterfaceConsumerFreeDart = void Function(int); typedef UniffiCallbackInterfaceConsumerClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceConsumerCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceConsumer extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceConsumerFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceConsumerClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceConsumerMethod0>> accept; }  void consumerAccept(int uniffiHandle, int value, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceConsumer._handleMap.get(uniffiHandle); final arg0 = FfiConverterUInt32.lift(value); obj.accept(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceConsumerMethod0>> consumerAcceptPointer = Pointer.fromFunction<UniffiCallbackInterfaceConsumerMethod0>(consumerAccept); void consumerFreeCallback(int handle) { FfiConverterCallbackInterfaceConsumer._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceConsumerFree>> consumerFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceConsumerFree>(consumerFreeCallback); int consumerCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceConsumer._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceConsumer._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceConsumerClone>> consumerClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceConsumerClone>(consumerCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceConsumer> consumerVTable; void initConsumerVTable() { if (FfiConverterCallbackInterfaceConsumer._vtableInitialized) { return; } consumerVTable = calloc<UniffiVTableCallbackInterfaceConsumer>(); consumerVTable.ref.uniffiFree = consumerFreePointer; consumerVTable.ref.uniffiClone = consumerClonePointer; consumerVTable.ref.accept = consumerAcceptPointer; rustCall((status) { uniffiLeafCallsListenerFnInitCallbackVtableConsumer( consumerVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceConsumer._vtableInitialized = true; } abstract class Listener { void onValue(int value,); } class FfiConverterCallbackInterfaceListener { static final _handleMap = UniffiHandleMap<Listener>(); static bool _vtableInitialized = false; static Listener lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Listener value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initListenerVTable(); _vtableInitialized = true; } } static LiftRetVal<Listener> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Listener value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Listener value) { return 8; } } typedef UniffiCallbackInterfaceListenerMethod0 = Void Function( Uint64, Uint32, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceListenerMethod0Dart = void Function( int, int, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceListenerFree = Void Function(Uint64); typedef UniffiCallbackInterfaceListenerFreeDart = void Function(int); typedef UniffiCallbackInterfaceListenerClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceListenerCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceListener extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceListenerFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceListenerClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceListenerMethod0>> onValue; }  void listenerOnValue(int uniffiHandle, int value, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceListener._handleMap.get(uniffiHandle); final arg0 = FfiConverterUInt32.lift(value); obj.onValue(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceListenerMethod0>> listenerOnValuePointer = Pointer.fromFunction<UniffiCallbackInterfaceListenerMethod0>(listenerOnValue); void listenerFreeCallback(int handle) { FfiConverterCallbackInterfaceListener._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceListenerFree>> listenerFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceListenerFree>(listenerFreeCallback); int listenerCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceListener._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceListener._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceListenerClone>> listenerClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceListenerClone>(listenerCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceListener> listenerVTable; void initListenerVTable() { if (FfiConverterCallbackInterfaceListener._vtableInitialized) { return; } listenerVTable = calloc<UniffiVTableCallbackInterfaceListener>(); listenerVTable.ref.uniffiFree = listenerFreePointer; listenerVTable.ref.uniffiClone = listenerClonePointer; listenerVTable.ref.onValue = listenerOnValuePointer; rustCall((status) { uniffiLeafCallsListenerFnInitCallbackVtableListener( listenerVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceListener._vtableInitialized = true; }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiLeafCallsListenerRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiLeafCallsListenerRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:leaf_calls"; @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_listener_fn_clone_consumer", assetId: _uniffiAssetId ) external Pointer<Void> uniffiLeafCallsListenerFnCloneConsumer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_listener_fn_free_consumer", assetId: _uniffiAssetId ) external void uniffiLeafCallsListenerFnFreeConsumer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceConsumer>)>( symbol: "uniffi_leaf_calls_listener_fn_init_callback_vtable_consumer", assetId: _uniffiAssetId ) external void uniffiLeafCallsListenerFnInitCallbackVtableConsumer( Pointer<UniffiVTableCallbackInterfaceConsumer> vtable);
+
+@Native<Void Function( Pointer<Void>, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_leaf_calls_listener_fn_method_consumer_accept", assetId: _uniffiAssetId ) external void uniffiLeafCallsListenerFnMethodConsumerAccept( Pointer<Void> ptr, int value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceListener>)>( symbol: "uniffi_leaf_calls_listener_fn_init_callback_vtable_listener", assetId: _uniffiAssetId ) external void uniffiLeafCallsListenerFnInitCallbackVtableListener( Pointer<UniffiVTableCallbackInterfaceListener> vtable);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsListenerRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsListenerRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsListenerRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiLeafCallsListenerRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiLeafCallsListenerRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiLeafCallsListenerRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_leaf_calls_listener_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_leaf_calls_listener_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiLeafCallsListenerRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_listener_checksum_method_consumer_accept", assetId: _uniffiAssetId ) external int uniffiLeafCallsListenerChecksumMethodConsumerAccept();
+
+@Native<Uint16 Function()>( symbol: "uniffi_leaf_calls_listener_checksum_method_listener_on_value", assetId: _uniffiAssetId ) external int uniffiLeafCallsListenerChecksumMethodListenerOnValue();
+
+@Native<Uint32 Function()>( symbol: "ffi_leaf_calls_listener_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiLeafCallsListenerUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiLeafCallsListenerUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiLeafCallsListenerChecksumMethodConsumerAccept() != 24062) { mismatchedChecksums.add("uniffi_leaf_calls_listener_checksum_method_consumer_accept"); }if (uniffiLeafCallsListenerChecksumMethodListenerOnValue() != 30875) { mismatchedChecksums.add("uniffi_leaf_calls_listener_checksum_method_listener_on_value"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
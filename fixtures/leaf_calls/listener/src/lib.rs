@@ -0,0 +1,13 @@
+// Defined apart from the functions taking them, which have no callbacks of their own
+
+#[uniffi::export(callback_interface)]
+pub trait Listener: Send + Sync {
+    fn on_value(&self, value: u32);
+}
+
+#[uniffi::export(with_foreign)]
+pub trait Consumer: Send + Sync {
+    fn accept(&self, value: u32);
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,55 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use leaf_calls_listener::{Consumer, Listener};
+
+// Primitive-only, so a leaf call by default, but not listed in `leaf_functions`.
+#[uniffi::export]
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+#[uniffi::export]
+pub fn noop() {}
+
+// Takes a `RustBuffer`, so only a leaf call because it's listed.
+#[uniffi::export]
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+// Only passes primitives across the boundary, but calls back into Dart, so never a leaf call.
+#[uniffi::export]
+pub fn notify(listener: Box<dyn Listener>, value: u32) {
+    listener.on_value(value);
+}
+
+#[uniffi::export]
+pub fn fill(consumer: Arc<dyn Consumer>, value: u32) {
+    consumer.accept(value);
+}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: AtomicU32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {
+            value: AtomicU32::new(0),
+        }
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    pub fn increment(&self) -> u32 {
+        self.value.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+uniffi::setup_scaffolding!();
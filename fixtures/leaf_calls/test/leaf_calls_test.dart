@@ -0,0 +1,42 @@
+import 'package:test/test.dart';
+import '../leaf_calls.dart';
+import '../leaf_calls_listener.dart';
+
+class RecordingListener implements Listener {
+  final values = <int>[];
+
+  @override
+  void onValue(int value) => values.add(value);
+}
+
+class RecordingConsumer implements Consumer {
+  final values = <int>[];
+
+  @override
+  void accept(int value) => values.add(value);
+}
+
+void main() {
+  test('leaf and regular calls return the same results', () {
+    expect(add(2, 3), 5);
+    noop();
+    expect(greet('Dart'), 'Hello, Dart!');
+  });
+
+  test('leaf methods see the object state', () {
+    final counter = Counter();
+    expect(counter.get(), 0);
+    expect(counter.increment(), 1);
+    expect(counter.get(), 1);
+  });
+
+  test('calls passing callbacks reach Dart', () {
+    final listener = RecordingListener();
+    notify(listener, 7);
+    expect(listener.values, [7]);
+
+    final consumer = RecordingConsumer();
+    fill(consumer, 9);
+    expect(consumer.values, [9]);
+  });
+}
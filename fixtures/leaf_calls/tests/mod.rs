@@ -0,0 +1,90 @@
+use anyhow::{bail, Result};
+
+#[test]
+fn leaf_calls() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("leaf_calls")
+}
+
+#[test]
+fn leaf_calls_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("leaf_calls")
+}
+
+#[test]
+fn leaf_calls_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("leaf_calls")
+}
+
+/// The `@Native` annotation of the FFI function exported as `symbol`.
+fn native_annotation(sources: &str, symbol: &str) -> Result<String> {
+    let Some(symbol_start) = sources.find(&format!("symbol: \"{symbol}\"")) else {
+        bail!("no FFI declaration for `{symbol}`");
+    };
+    let start = sources[..symbol_start].rfind("@Native").unwrap_or(0);
+    let end = symbol_start + sources[symbol_start..].find(')').unwrap_or(0);
+    Ok(sources[start..=end].to_owned())
+}
+
+#[test]
+fn leaf_calls_follow_config() -> Result<()> {
+    let sources = uniffi_dart::testing::generated_sources_library_mode("leaf_calls")?
+        .into_values()
+        .collect::<String>();
+
+    // Listed in `leaf_functions`, whatever they pass across the boundary
+    for symbol in [
+        "uniffi_leaf_calls_fn_func_noop",
+        "uniffi_leaf_calls_fn_func_greet",
+        "uniffi_leaf_calls_fn_method_counter_get",
+    ] {
+        let annotation = native_annotation(&sources, symbol)?;
+        if !annotation.contains("isLeaf: true") {
+            bail!("`{symbol}` should be a leaf call: {annotation}");
+        }
+    }
+
+    // `leaf_calls = false` turns off detection of primitive-only functions, and calls passing
+    // callbacks are left out of `leaf_functions`
+    for symbol in [
+        "uniffi_leaf_calls_fn_func_add",
+        "uniffi_leaf_calls_fn_func_notify",
+        "uniffi_leaf_calls_fn_func_fill",
+        "uniffi_leaf_calls_fn_method_counter_increment",
+        "uniffi_leaf_calls_checksum_func_add",
+        "ffi_leaf_calls_uniffi_contract_version",
+    ] {
+        let annotation = native_annotation(&sources, symbol)?;
+        if annotation.contains("isLeaf") {
+            bail!("`{symbol}` shouldn't be a leaf call: {annotation}");
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn leaf_calls_never_pass_callbacks() -> Result<()> {
+    let sources = uniffi_dart::testing::written_sources_library_mode(
+        "leaf_calls",
+        Some(&["leaf_calls"]),
+        Some("detect.toml"),
+    )?
+    .into_values()
+    .collect::<String>();
+
+    let annotation = native_annotation(&sources, "uniffi_leaf_calls_fn_func_add")?;
+    if !annotation.contains("isLeaf: true") {
+        bail!("primitive-only functions should be detected as leaf calls: {annotation}");
+    }
+
+    // Their callbacks are defined in another crate, so only lowered to primitives here
+    for symbol in [
+        "uniffi_leaf_calls_fn_func_notify",
+        "uniffi_leaf_calls_fn_func_fill",
+    ] {
+        let annotation = native_annotation(&sources, symbol)?;
+        if annotation.contains("isLeaf") {
+            bail!("`{symbol}` calls back into Dart, so it can't be a leaf call: {annotation}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,151 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class DartLogRecord { final DartLogLevel level;final String target;final String message;final Map<String, String> fields; DartLogRecord(this.level,this.target,this.message,this.fields,); } class FfiConverterDartLogRecord { static DartLogRecord lift( RustBuffer buf) { return FfiConverterDartLogRecord.read(buf.asUint8List()).value; } static LiftRetVal<DartLogRecord> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final levelLifted = FfiConverterDartLogLevel.read(Uint8List.view(buf.buffer, newOffset)); final level = levelLifted.value; newOffset += levelLifted.bytesRead;final targetLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final target = targetLifted.value; newOffset += targetLifted.bytesRead;final messageLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final message = messageLifted.value; newOffset += messageLifted.bytesRead;final fieldsLifted = FfiConverterMapStringToString.read(Uint8List.view(buf.buffer, newOffset)); final fields = fieldsLifted.value; newOffset += fieldsLifted.bytesRead; return LiftRetVal(DartLogRecord( level,target,message,fields, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( DartLogRecord value) { final totalLength = FfiConverterDartLogLevel.allocationSize(value.level) +FfiConverterString.allocationSize(value.target) +FfiConverterString.allocationSize(value.message) +FfiConverterMapStringToString.allocationSize(value.fields) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( DartLogRecord value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterDartLogLevel.write(value.level, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterString.write(value.target, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterString.write(value.message, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterMapStringToString.write(value.fields, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(DartLogRecord value) { return FfiConverterDartLogLevel.allocationSize(value.level) +FfiConverterString.allocationSize(value.target) +FfiConverterString.allocationSize(value.message) +FfiConverterMapStringToString.allocationSize(value.fields) + 0; } } abstract class DartLogException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterDartLogException { static DartLogException lift( RustBuffer buffer) { return FfiConverterDartLogException.read(buffer.asUint8List()).value; } static LiftRetVal<DartLogException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return LoggerAlreadyInstalledDartLogException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( DartLogException value) { return value.lower(); } static int allocationSize(DartLogException value) { return value.allocationSize(); } static int write( DartLogException value, Uint8List buf) { return value.write(buf); } } class LoggerAlreadyInstalledDartLogException extends DartLogException { LoggerAlreadyInstalledDartLogException(); LoggerAlreadyInstalledDartLogException._(); static LiftRetVal<LoggerAlreadyInstalledDartLogException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(LoggerAlreadyInstalledDartLogException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "LoggerAlreadyInstalledDartLogException"; } } class DartLogExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterDartLogException.lift(errorBuf); } } final DartLogExceptionErrorHandler dartLogExceptionErrorHandler = DartLogExceptionErrorHandler(); enum DartLogLevel { error,warn,info,debug,trace, ; } class FfiConverterDartLogLevel { static LiftRetVal<DartLogLevel> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( DartLogLevel.error, 4, );case 2: return LiftRetVal( DartLogLevel.warn, 4, );case 3: return LiftRetVal( DartLogLevel.info, 4, );case 4: return LiftRetVal( DartLogLevel.debug, 4, );case 5: return LiftRetVal( DartLogLevel.trace, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static DartLogLevel lift( RustBuffer buffer) { return FfiConverterDartLogLevel.read(buffer.asUint8List()).value; } static RustBuffer lower( DartLogLevel input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(DartLogLevel value) { return 4; } static int write( DartLogLevel value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  abstract class DartLogSink { void log(DartLogRecord record,); } class FfiConverterCallbackInterfaceDartLogSink { static final _handleMap = UniffiHandleMap<DartLogSink>(uniffiDartLoggingDartlogsinkNewHandle); static bool _vtableInitialized = false; static DartLogSink lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(DartLogSink value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initDartLogSinkVTable(); _vtableInitialized = true; } } static LiftRetVal<DartLogSink> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(DartLogSink value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(DartLogSink value) { return 8; } } typedef UniffiCallbackInterfaceDartLogSinkMethod0 = Void Function( Uint64, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceDartLogSinkMethod0Dart = void Function( int, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceDartLogSinkFree = Void Function(Uint64); typedef UniffiCallbackInterfaceDartLogSinkFreeDart = void Function(int); typedef UniffiCallbackInterfaceDartLogSinkClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceDartLogSinkCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceDartLogSink extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkMethod0>> log; }  void dartLogSinkLog(int uniffiHandle, RustBuffer record, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceDartLogSink._handleMap.get(uniffiHandle); final arg0 = FfiConverterDartLogRecord.lift(record); obj.log(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkMethod0>> dartLogSinkLogPointer = Pointer.fromFunction<UniffiCallbackInterfaceDartLogSinkMethod0>(dartLogSinkLog);
+
+void dartLogSinkLogQueued(int uniffiHandle, RustBuffer record, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final obj = FfiConverterCallbackInterfaceDartLogSink._handleMap.get(uniffiHandle); final arg0 = FfiConverterDartLogRecord.lift(record); obj.log(arg0,); } final _dartLogSinkLogQueuedCallable = NativeCallable<UniffiCallbackInterfaceDartLogSinkMethod0>.listener(dartLogSinkLogQueued) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkMethod0>> dartLogSinkLogListenerPointer = _dartLogSinkLogQueuedCallable.nativeFunction; void dartLogSinkFreeCallback(int handle) { FfiConverterCallbackInterfaceDartLogSink._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkFree>> dartLogSinkFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceDartLogSinkFree>(dartLogSinkFreeCallback); final _dartLogSinkFreeCallbackCallable = NativeCallable<UniffiCallbackInterfaceDartLogSinkFree>.listener(dartLogSinkFreeCallback) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceDartLogSinkFree>> dartLogSinkFreeListenerPointer = _dartLogSinkFreeCallbackCallable.nativeFunction;  late final Pointer<UniffiVTableCallbackInterfaceDartLogSink> dartLogSinkVTable; void initDartLogSinkVTable() { if (FfiConverterCallbackInterfaceDartLogSink._vtableInitialized) { return; } dartLogSinkVTable = calloc<UniffiVTableCallbackInterfaceDartLogSink>(); final direct = calloc<Pointer<Void>>(2); final listeners = calloc<Pointer<Void>>(2); try { direct[0] = dartLogSinkFreePointer.cast(); listeners[0] = dartLogSinkFreeListenerPointer.cast();direct[1] = dartLogSinkLogPointer.cast(); listeners[1] = dartLogSinkLogListenerPointer.cast(); final registered = uniffiDartLoggingDartlogsinkRegister( NativeApi.initializeApiDLData, direct, listeners, dartLogSinkVTable.cast(), ); if (registered != 0) { throw UnsupportedError( "DartLogSink can't be called from other threads on this Dart version", ); } } finally { calloc.free(direct); calloc.free(listeners); } rustCall((status) { uniffiLoggingFnInitCallbackVtableDartlogsink( dartLogSinkVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceDartLogSink._vtableInitialized = true; }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiLoggingRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiLoggingRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterMapStringToString { static Map<String, String> lift(RustBuffer buf) { return FfiConverterMapStringToString.read(buf.asUint8List()).value; } static LiftRetVal<Map<String, String>> read(Uint8List buf) { final map = <String, String>{}; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final k = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += k.bytesRead; final v = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += v.bytesRead; map[k.value] = v.value; } return LiftRetVal(map, offset - buf.offsetInBytes); } static int write(Map<String, String> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (final entry in value.entries) { offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset)); offset += FfiConverterString.write(entry.value, Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(Map<String, String> value) { return value.entries .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterString.allocationSize(e.value)) .fold(4, (a, b) => a + b); } static RustBuffer lower(Map<String, String> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:logging"; void installDartLogSink(DartLogSink sink,DartLogLevel maxLevel,) { return rustCall((status) { uniffiLoggingFnFuncInstallDartLogSink( FfiConverterCallbackInterfaceDartLogSink.lower(sink),FfiConverterDartLogLevel.lower(maxLevel), status ); }, dartLogExceptionErrorHandler); } void logFromThread(String message,) { return rustCall((status) { uniffiLoggingFnFuncLogFromThread( FfiConverterString.lower(message), status ); }, null); } void logMessage(DartLogLevel level,String message,) { return rustCall((status) { uniffiLoggingFnFuncLogMessage( FfiConverterDartLogLevel.lower(level),FfiConverterString.lower(message), status ); }, null); } void logWithFields(String user,int attempts,) { return rustCall((status) { uniffiLoggingFnFuncLogWithFields( FfiConverterString.lower(user),FfiConverterUInt32.lower(attempts), status ); }, null); } void uninstallDartLogSink() { return rustCall((status) { uniffiLoggingFnFuncUninstallDartLogSink( status ); }, null); }  class _UniffiDartLogSinkForwarder implements DartLogSink { final StreamController<DartLogRecord> _controller; _UniffiDartLogSinkForwarder(this._controller); @override void log(DartLogRecord record) { if (!_controller.isClosed) { _controller.add(record); } } } _UniffiDartLogSinkForwarder? _uniffiActiveLogForwarder; Stream<DartLogRecord> rustLogRecords({DartLogLevel maxLevel = DartLogLevel.info}) { late final StreamController<DartLogRecord> controller; late final _UniffiDartLogSinkForwarder forwarder; controller = StreamController<DartLogRecord>( onListen: () { forwarder = _UniffiDartLogSinkForwarder(controller); try { installDartLogSink(forwarder, maxLevel); _uniffiActiveLogForwarder = forwarder; } catch (error, stackTrace) { controller.addError(error, stackTrace); controller.close(); } }, onCancel: () { if (identical(_uniffiActiveLogForwarder, forwarder)) { _uniffiActiveLogForwarder = null; uninstallDartLogSink(); } }, ); return controller.stream; } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_clone_dartlogsink", assetId: _uniffiAssetId ) external Pointer<Void> uniffiLoggingFnCloneDartlogsink( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_free_dartlogsink", assetId: _uniffiAssetId ) external void uniffiLoggingFnFreeDartlogsink( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceDartLogSink>)>( symbol: "uniffi_logging_fn_init_callback_vtable_dartlogsink", assetId: _uniffiAssetId ) external void uniffiLoggingFnInitCallbackVtableDartlogsink( Pointer<UniffiVTableCallbackInterfaceDartLogSink> vtable);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_method_dartlogsink_log", assetId: _uniffiAssetId ) external void uniffiLoggingFnMethodDartlogsinkLog( Pointer<Void> ptr, RustBuffer record, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_func_install_dart_log_sink", assetId: _uniffiAssetId ) external void uniffiLoggingFnFuncInstallDartLogSink( Pointer<Void> sink, RustBuffer maxLevel, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_func_log_from_thread", assetId: _uniffiAssetId ) external void uniffiLoggingFnFuncLogFromThread( RustBuffer message, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_func_log_message", assetId: _uniffiAssetId ) external void uniffiLoggingFnFuncLogMessage( RustBuffer level, RustBuffer message, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_func_log_with_fields", assetId: _uniffiAssetId ) external void uniffiLoggingFnFuncLogWithFields( RustBuffer user, int attempts, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<RustCallStatus>)>( symbol: "uniffi_logging_fn_func_uninstall_dart_log_sink", assetId: _uniffiAssetId ) external void uniffiLoggingFnFuncUninstallDartLogSink( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiLoggingRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiLoggingRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiLoggingRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiLoggingRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiLoggingRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiLoggingRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiLoggingRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiLoggingRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_logging_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiLoggingRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_logging_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_logging_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiLoggingRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_func_install_dart_log_sink", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumFuncInstallDartLogSink();
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_func_log_from_thread", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumFuncLogFromThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_func_log_message", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumFuncLogMessage();
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_func_log_with_fields", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumFuncLogWithFields();
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_func_uninstall_dart_log_sink", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumFuncUninstallDartLogSink();
+
+@Native<Uint16 Function()>( symbol: "uniffi_logging_checksum_method_dartlogsink_log", assetId: _uniffiAssetId ) external int uniffiLoggingChecksumMethodDartlogsinkLog();
+
+@Native<Uint32 Function()>( symbol: "ffi_logging_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiLoggingUniffiContractVersion();
+
+@Native<Int8 Function(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>)>( symbol: "uniffi_dart_logging_dartlogsink_register", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartLoggingDartlogsinkRegister(Pointer<Void> api, Pointer<Pointer<Void>> direct, Pointer<Pointer<Void>> listeners, Pointer<Pointer<Void>> vtable);
+
+@Native<Uint64 Function()>( symbol: "uniffi_dart_logging_dartlogsink_new_handle", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartLoggingDartlogsinkNewHandle();
+
+@Native<Void Function(Pointer<Void>)>( symbol: "uniffi_dart_logging_dartlogsink_signal", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiDartLoggingDartlogsinkSignal(Pointer<Void> waiter);
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiLoggingUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiLoggingChecksumFuncInstallDartLogSink() != 49836) { mismatchedChecksums.add("uniffi_logging_checksum_func_install_dart_log_sink"); }if (uniffiLoggingChecksumFuncLogFromThread() != 17720) { mismatchedChecksums.add("uniffi_logging_checksum_func_log_from_thread"); }if (uniffiLoggingChecksumFuncLogMessage() != 60315) { mismatchedChecksums.add("uniffi_logging_checksum_func_log_message"); }if (uniffiLoggingChecksumFuncLogWithFields() != 6209) { mismatchedChecksums.add("uniffi_logging_checksum_func_log_with_fields"); }if (uniffiLoggingChecksumFuncUninstallDartLogSink() != 41757) { mismatchedChecksums.add("uniffi_logging_checksum_func_uninstall_dart_log_sink"); }if (uniffiLoggingChecksumMethodDartlogsinkLog() != 37016) { mismatchedChecksums.add("uniffi_logging_checksum_method_dartlogsink_log"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
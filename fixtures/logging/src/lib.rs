@@ -0,0 +1,29 @@
+use std::thread;
+
+uniffi_dart::export_logging!();
+
+#[uniffi::export]
+pub fn log_message(level: DartLogLevel, message: String) {
+    let level = match level {
+        DartLogLevel::Error => log::Level::Error,
+        DartLogLevel::Warn => log::Level::Warn,
+        DartLogLevel::Info => log::Level::Info,
+        DartLogLevel::Debug => log::Level::Debug,
+        DartLogLevel::Trace => log::Level::Trace,
+    };
+    log::log!(level, "{message}");
+}
+
+#[uniffi::export]
+pub fn log_with_fields(user: String, attempts: u32) {
+    log::info!(target: "fixture::auth", user = user.as_str(), attempts; "user logged in");
+}
+
+#[uniffi::export]
+pub fn log_from_thread(message: String) {
+    thread::spawn(move || log::warn!("{message}"))
+        .join()
+        .unwrap();
+}
+
+uniffi::setup_scaffolding!();
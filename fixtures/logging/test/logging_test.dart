@@ -0,0 +1,57 @@
+import 'dart:async';
+
+import 'package:test/test.dart';
+import '../logging.dart';
+
+void main() {
+  test('rust log records are forwarded to a dart stream', () async {
+    final received = rustLogRecords(maxLevel: DartLogLevel.debug)
+        .take(3)
+        .toList()
+        .timeout(Duration(seconds: 5));
+
+    // Above the requested level, so it must not show up.
+    logMessage(DartLogLevel.trace, "filtered out");
+    logMessage(DartLogLevel.info, "hello from rust");
+    logWithFields("alice", 3);
+    logFromThread("from a worker thread");
+
+    final records = await received;
+    expect(records.map((r) => r.message).toList(), [
+      "hello from rust",
+      "user logged in",
+      "from a worker thread",
+    ]);
+
+    expect(records[0].level, DartLogLevel.info);
+    expect(records[0].target, "logging");
+
+    expect(records[1].target, "fixture::auth");
+    expect(records[1].fields, {"user": "alice", "attempts": "3"});
+
+    expect(records[2].level, DartLogLevel.warn);
+  });
+
+  test('records stop after the stream is cancelled', () async {
+    final records = <DartLogRecord>[];
+    final subscription =
+        rustLogRecords(maxLevel: DartLogLevel.trace).listen(records.add);
+    await subscription.cancel();
+
+    logMessage(DartLogLevel.error, "nobody is listening");
+    await Future.delayed(Duration(milliseconds: 100));
+    expect(records, isEmpty);
+  });
+
+  test('cancelling an older stream keeps a newer one forwarding', () async {
+    final older =
+        rustLogRecords(maxLevel: DartLogLevel.trace).listen((_) {});
+    final received = rustLogRecords(maxLevel: DartLogLevel.trace)
+        .first
+        .timeout(Duration(seconds: 5));
+    await older.cancel();
+
+    logMessage(DartLogLevel.info, "still forwarded");
+    expect((await received).message, "still forwarded");
+  });
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use logging::{DartLogError, DartLogLevel, DartLogRecord, DartLogSink};
+
+#[test]
+fn logging() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("logging")
+}
+
+#[test]
+fn logging_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("logging")
+}
+
+#[test]
+fn logging_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("logging")
+}
+
+struct IgnoredSink;
+
+impl DartLogSink for IgnoredSink {
+    fn log(&self, _record: DartLogRecord) {}
+}
+
+struct OtherLogger;
+
+impl log::Log for OtherLogger {
+    fn enabled(&self, _metadata: &log::Metadata<'_>) -> bool {
+        false
+    }
+
+    fn log(&self, _record: &log::Record<'_>) {}
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn installing_over_another_logger_fails() -> Result<()> {
+    log::set_logger(&OtherLogger).unwrap();
+    match logging::install_dart_log_sink(Arc::new(IgnoredSink), DartLogLevel::Info) {
+        Err(DartLogError::LoggerAlreadyInstalled) => Ok(()),
+        Ok(()) => bail!("the Dart log sink replaced the application's logger"),
+    }
+}
@@ -0,0 +1,141 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class MetadataStruct { final String name;final int version;final List<String> features; MetadataStruct(this.name,this.version,this.features,); } class FfiConverterMetadataStruct { static MetadataStruct lift( RustBuffer buf) { return FfiConverterMetadataStruct.read(buf.asUint8List()).value; } static LiftRetVal<MetadataStruct> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final nameLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final name = nameLifted.value; newOffset += nameLifted.bytesRead;final versionLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final version = versionLifted.value; newOffset += versionLifted.bytesRead;final featuresLifted = FfiConverterSequenceString.read(Uint8List.view(buf.buffer, newOffset)); final features = featuresLifted.value; newOffset += featuresLifted.bytesRead; return LiftRetVal(MetadataStruct( name,version,features, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( MetadataStruct value) { final totalLength = FfiConverterString.allocationSize(value.name) +FfiConverterUInt32.allocationSize(value.version) +FfiConverterSequenceString.allocationSize(value.features) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( MetadataStruct value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.name, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt32.write(value.version, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterSequenceString.write(value.features, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(MetadataStruct value) { return FfiConverterString.allocationSize(value.name) +FfiConverterUInt32.allocationSize(value.version) +FfiConverterSequenceString.allocationSize(value.features) + 0; } } enum MetadataType { basic,advanced,experimental, ; } class FfiConverterMetadataType { static LiftRetVal<MetadataType> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( MetadataType.basic, 4, );case 2: return LiftRetVal( MetadataType.advanced, 4, );case 3: return LiftRetVal( MetadataType.experimental, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static MetadataType lift( RustBuffer buffer) { return FfiConverterMetadataType.read(buffer.asUint8List()).value; } static RustBuffer lower( MetadataType input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(MetadataType value) { return 4; } static int write( MetadataType value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  abstract class MetadataObjectInterface { String getName();int getVersion();void setVersion(int version); } final _metadataObjectFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiMetadataFnFreeMetadataobject(ptr, status)); }); class MetadataObject implements MetadataObjectInterface { late final Pointer<Void> _ptr; MetadataObject._(this._ptr) { _metadataObjectFinalizer.attach(this, _ptr, detach: this); } MetadataObject(String name,) : _ptr = rustCall((status) => uniffiMetadataFnConstructorMetadataobjectNew( FfiConverterString.lower(name), status ), null ) { _metadataObjectFinalizer.attach(this, _ptr, detach: this); } factory MetadataObject.lift(Pointer<Void> ptr) { return MetadataObject._(ptr); } static Pointer<Void> lower(MetadataObject value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiMetadataFnCloneMetadataobject(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory MetadataObject.fromSendableHandle(int handle) { return MetadataObject._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(MetadataObject value) { return 8; } static LiftRetVal<MetadataObject> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(MetadataObject.lift(pointer), 8); } static int write(MetadataObject value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _metadataObjectFinalizer.detach(this); rustCall((status) => uniffiMetadataFnFreeMetadataobject(_ptr, status)); } @override String getName() { return rustCallWithLifter( (status) => uniffiMetadataFnMethodMetadataobjectGetName( uniffiClonePointer(), status ), FfiConverterString.lift, null ); } @override int getVersion() { return rustCallWithLifter( (status) => uniffiMetadataFnMethodMetadataobjectGetVersion( uniffiClonePointer(), status ), FfiConverterUInt32.lift, null ); } @override void setVersion(int version,) { return rustCall((status) { uniffiMetadataFnMethodMetadataobjectSetVersion( uniffiClonePointer(), FfiConverterUInt32.lower(version), status ); }, null); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiMetadataRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiMetadataRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterSequenceString { static List<String> lift( RustBuffer buf) { return FfiConverterSequenceString.read(buf.asUint8List()).value; } static LiftRetVal<List<String>> read( Uint8List buf) { List<String> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<String> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<String> value) { return value.map((l) => FfiConverterString.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<String> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:metadata"; MetadataStruct getMetadataStruct() { return rustCallWithLifter( (status) => uniffiMetadataFnFuncGetMetadataStruct( status ), FfiConverterMetadataStruct.lift, null ); } void testMetadata() { return rustCall((status) { uniffiMetadataFnFuncTestMetadata( status ); }, null); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_clone_metadataobject", assetId: _uniffiAssetId ) external Pointer<Void> uniffiMetadataFnCloneMetadataobject( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_free_metadataobject", assetId: _uniffiAssetId ) external void uniffiMetadataFnFreeMetadataobject( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_constructor_metadataobject_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiMetadataFnConstructorMetadataobjectNew( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_method_metadataobject_get_name", assetId: _uniffiAssetId ) external RustBuffer uniffiMetadataFnMethodMetadataobjectGetName( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_method_metadataobject_get_version", assetId: _uniffiAssetId ) external int uniffiMetadataFnMethodMetadataobjectGetVersion( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_method_metadataobject_set_version", assetId: _uniffiAssetId ) external void uniffiMetadataFnMethodMetadataobjectSetVersion( Pointer<Void> ptr, int version, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_func_get_metadata_struct", assetId: _uniffiAssetId ) external RustBuffer uniffiMetadataFnFuncGetMetadataStruct( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<RustCallStatus>)>( symbol: "uniffi_metadata_fn_func_test_metadata", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiMetadataFnFuncTestMetadata( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiMetadataRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiMetadataRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiMetadataRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiMetadataRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiMetadataRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiMetadataRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiMetadataRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiMetadataRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_metadata_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiMetadataRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_metadata_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_metadata_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiMetadataRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_func_get_metadata_struct", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumFuncGetMetadataStruct();
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_func_test_metadata", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumFuncTestMetadata();
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_method_metadataobject_get_name", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumMethodMetadataobjectGetName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_method_metadataobject_get_version", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumMethodMetadataobjectGetVersion();
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_method_metadataobject_set_version", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumMethodMetadataobjectSetVersion();
+
+@Native<Uint16 Function()>( symbol: "uniffi_metadata_checksum_constructor_metadataobject_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiMetadataChecksumConstructorMetadataobjectNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_metadata_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiMetadataUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiMetadataUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiMetadataChecksumFuncGetMetadataStruct() != 59059) { mismatchedChecksums.add("uniffi_metadata_checksum_func_get_metadata_struct"); }if (uniffiMetadataChecksumFuncTestMetadata() != 33011) { mismatchedChecksums.add("uniffi_metadata_checksum_func_test_metadata"); }if (uniffiMetadataChecksumMethodMetadataobjectGetName() != 1220) { mismatchedChecksums.add("uniffi_metadata_checksum_method_metadataobject_get_name"); }if (uniffiMetadataChecksumMethodMetadataobjectGetVersion() != 58441) { mismatchedChecksums.add("uniffi_metadata_checksum_method_metadataobject_get_version"); }if (uniffiMetadataChecksumMethodMetadataobjectSetVersion() != 33556) { mismatchedChecksums.add("uniffi_metadata_checksum_method_metadataobject_set_version"); }if (uniffiMetadataChecksumConstructorMetadataobjectNew() != 11753) { mismatchedChecksums.add("uniffi_metadata_checksum_constructor_metadataobject_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -4,4 +4,14 @@ mod tests {
     fn test_metadata() {
         uniffi_dart::testing::run_test("metadata", "src/api.udl", None).unwrap();
     }
+
+    #[test]
+    fn test_metadata_output_is_deterministic() {
+        uniffi_dart::testing::assert_deterministic_output("metadata", "src/api.udl", None).unwrap();
+    }
+
+    #[test]
+    fn test_metadata_matches_snapshot() {
+        uniffi_dart::testing::assert_snapshot("metadata", "src/api.udl", None).unwrap();
+    }
 }
@@ -0,0 +1,174 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:isolate";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class FooError { final String message; FooError(this.message,); } class FfiConverterFooError { static FooError lift( RustBuffer buf) { return FfiConverterFooError.read(buf.asUint8List()).value; } static LiftRetVal<FooError> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final messageLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final message = messageLifted.value; newOffset += messageLifted.bytesRead; return LiftRetVal(FooError( message, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( FooError value) { final totalLength = FfiConverterString.allocationSize(value.message) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( FooError value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.message, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(FooError value) { return FfiConverterString.allocationSize(value.message) + 0; } } class FooException { final int code; FooException(this.code,); } class FfiConverterFooException { static FooException lift( RustBuffer buf) { return FfiConverterFooException.read(buf.asUint8List()).value; } static LiftRetVal<FooException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final codeLifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, newOffset)); final code = codeLifted.value; newOffset += codeLifted.bytesRead; return LiftRetVal(FooException( code, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( FooException value) { final totalLength = FfiConverterInt32.allocationSize(value.code) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( FooException value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterInt32.write(value.code, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(FooException value) { return FfiConverterInt32.allocationSize(value.code) + 0; } } class List_ { final List<String> items; List_(this.items,); } class FfiConverterList_ { static List_ lift( RustBuffer buf) { return FfiConverterList_.read(buf.asUint8List()).value; } static LiftRetVal<List_> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final itemsLifted = FfiConverterSequenceString.read(Uint8List.view(buf.buffer, newOffset)); final items = itemsLifted.value; newOffset += itemsLifted.bytesRead; return LiftRetVal(List_( items, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( List_ value) { final totalLength = FfiConverterSequenceString.allocationSize(value.items) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( List_ value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterSequenceString.write(value.items, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(List_ value) { return FfiConverterSequenceString.allocationSize(value.items) + 0; } } class ParseExceptionErrorHandler { final String reason; ParseExceptionErrorHandler(this.reason,); } class FfiConverterParseExceptionErrorHandler { static ParseExceptionErrorHandler lift( RustBuffer buf) { return FfiConverterParseExceptionErrorHandler.read(buf.asUint8List()).value; } static LiftRetVal<ParseExceptionErrorHandler> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final reasonLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final reason = reasonLifted.value; newOffset += reasonLifted.bytesRead; return LiftRetVal(ParseExceptionErrorHandler( reason, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( ParseExceptionErrorHandler value) { final totalLength = FfiConverterString.allocationSize(value.reason) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( ParseExceptionErrorHandler value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.reason, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(ParseExceptionErrorHandler value) { return FfiConverterString.allocationSize(value.reason) + 0; } } abstract class Chunk { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterChunk { static Chunk lift( RustBuffer buffer) { return FfiConverterChunk.read(buffer.asUint8List()).value; } static LiftRetVal<Chunk> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return DataChunk.read(subview);case 2: return EmptyChunk.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( Chunk value) { return value.lower(); } static int allocationSize(Chunk value) { return value.allocationSize(); } static int write( Chunk value, Uint8List buf) { return value.write(buf); } } class DataChunk extends Chunk { final int write_;final int newOffset_; DataChunk({ required this.write_,required this.newOffset_, }); DataChunk._(this.write_,this.newOffset_,); static LiftRetVal<DataChunk> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final write_Lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final write_ = write_Lifted.value; newOffset += write_Lifted.bytesRead;final newOffset_Lifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final newOffset_ = newOffset_Lifted.value; newOffset += newOffset_Lifted.bytesRead; return LiftRetVal(DataChunk._( write_,newOffset_, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterUInt32.allocationSize(write_) +FfiConverterUInt32.allocationSize(newOffset_) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterUInt32.write(write_, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt32.write(newOffset_, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }class EmptyChunk extends Chunk { EmptyChunk(); EmptyChunk._(); static LiftRetVal<EmptyChunk> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(EmptyChunk._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; return newOffset; } }  enum Ordering { index_,name_,values_, ; } class FfiConverterOrdering { static LiftRetVal<Ordering> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( Ordering.index_, 4, );case 2: return LiftRetVal( Ordering.name_, 4, );case 3: return LiftRetVal( Ordering.values_, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static Ordering lift( RustBuffer buffer) { return FfiConverterOrdering.read(buffer.asUint8List()).value; } static RustBuffer lower( Ordering input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(Ordering value) { return 4; } static int write( Ordering value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  enum ParseException implements Exception { empty, ; } class FfiConverterParseException { static LiftRetVal<ParseException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( ParseException.empty, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static ParseException lift( RustBuffer buffer) { return FfiConverterParseException.read(buffer.asUint8List()).value; } static RustBuffer lower( ParseException input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(ParseException value) { return 4; } static int write( ParseException value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } } class ParseExceptionErrorHandler_ extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterParseException.lift(errorBuf); } } final ParseExceptionErrorHandler_ parseExceptionErrorHandler_ = ParseExceptionErrorHandler_(); abstract class CounterInterface { void increment();int read_(); } final _counterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiNameCollisionsFnFreeCounter(ptr, status)); }); class Counter implements CounterInterface { late final Pointer<Void> _ptr; Counter._(this._ptr) { _counterFinalizer.attach(this, _ptr, detach: this); } Counter() : _ptr = rustCall((status) => uniffiNameCollisionsFnConstructorCounterNew( status ), null ) { _counterFinalizer.attach(this, _ptr, detach: this); } factory Counter.lift(Pointer<Void> ptr) { return Counter._(ptr); } static Pointer<Void> lower(Counter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiNameCollisionsFnCloneCounter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Counter.fromSendableHandle(int handle) { return Counter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Counter value) { return 8; } static LiftRetVal<Counter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Counter.lift(pointer), 8); } static int write(Counter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _counterFinalizer.detach(this); rustCall((status) => uniffiNameCollisionsFnFreeCounter(_ptr, status)); } @override void increment() { return rustCall((status) { uniffiNameCollisionsFnMethodCounterIncrement( uniffiClonePointer(), status ); }, null); } @override int read_() { return rustCallWithLifter( (status) => uniffiNameCollisionsFnMethodCounterRead( uniffiClonePointer(), status ), FfiConverterUInt32.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiNameCollisionsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiNameCollisionsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterSequenceUInt32 { static List<int> lift( RustBuffer buf) { return FfiConverterSequenceUInt32.read(buf.asUint8List()).value; } static LiftRetVal<List<int>> read( Uint8List buf) { List<int> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = FfiConverterUInt32.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<int> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += FfiConverterUInt32.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<int> value) { return value.map((l) => FfiConverterUInt32.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<int> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } class FfiConverterSequenceString { static List<String> lift( RustBuffer buf) { return FfiConverterSequenceString.read(buf.asUint8List()).value; } static LiftRetVal<List<String>> read( Uint8List buf) { List<String> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<String> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<String> value) { return value.map((l) => FfiConverterString.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<String> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:name_collisions"; int chunkSize(Chunk chunk,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncChunkSize( FfiConverterChunk.lower(chunk), status ), FfiConverterUInt32.lift, null ); } FooError fooError(String message,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncFooError( FfiConverterString.lower(message), status ), FfiConverterFooError.lift, null ); } FooException fooException(int code,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncFooException( FfiConverterInt32.lower(code), status ), FfiConverterFooException.lift, null ); } String initialize_() { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncInitialize( status ), FfiConverterString.lift, null ); } List_ list(List<String> items,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncList( FfiConverterSequenceString.lower(items), status ), FfiConverterList_.lift, null ); } int listLen(List_ list,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncListLen( FfiConverterList_.lower(list), status ), FfiConverterUInt32.lift, null ); } Ordering ordering(int index,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncOrdering( FfiConverterUInt32.lower(index), status ), FfiConverterOrdering.lift, null ); } int parse(String input,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncParse( FfiConverterString.lower(input), status ), FfiConverterUInt32.lift, parseExceptionErrorHandler_ ); } ParseExceptionErrorHandler parseExceptionErrorHandler() { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncParseExceptionErrorHandler( status ), FfiConverterParseExceptionErrorHandler.lift, null ); } int total(List<int> values,) { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncTotal( FfiConverterSequenceUInt32.lower(values), status ), FfiConverterUInt32.lift, null ); }  Future<int> totalInBackground_(List<int> values,) { return Isolate.run(() => total(values,)); }String totalInBackground() { return rustCallWithLifter( (status) => uniffiNameCollisionsFnFuncTotalInBackground( status ), FfiConverterString.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_clone_counter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiNameCollisionsFnCloneCounter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_free_counter", assetId: _uniffiAssetId ) external void uniffiNameCollisionsFnFreeCounter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_constructor_counter_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiNameCollisionsFnConstructorCounterNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_method_counter_increment", assetId: _uniffiAssetId ) external void uniffiNameCollisionsFnMethodCounterIncrement( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_method_counter_read", assetId: _uniffiAssetId ) external int uniffiNameCollisionsFnMethodCounterRead( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_chunk_size", assetId: _uniffiAssetId ) external int uniffiNameCollisionsFnFuncChunkSize( RustBuffer chunk, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_foo_error", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncFooError( RustBuffer message, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_foo_exception", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncFooException( int code, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_initialize", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncInitialize( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_list", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncList( RustBuffer items, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_list_len", assetId: _uniffiAssetId ) external int uniffiNameCollisionsFnFuncListLen( RustBuffer list, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_ordering", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncOrdering( int index, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_parse", assetId: _uniffiAssetId ) external int uniffiNameCollisionsFnFuncParse( RustBuffer input, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_parse_exception_error_handler", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncParseExceptionErrorHandler( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_total", assetId: _uniffiAssetId ) external int uniffiNameCollisionsFnFuncTotal( RustBuffer values, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_name_collisions_fn_func_total_in_background", assetId: _uniffiAssetId ) external RustBuffer uniffiNameCollisionsFnFuncTotalInBackground( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiNameCollisionsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiNameCollisionsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiNameCollisionsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiNameCollisionsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiNameCollisionsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiNameCollisionsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiNameCollisionsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_name_collisions_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_name_collisions_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiNameCollisionsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_chunk_size", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncChunkSize();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_foo_error", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncFooError();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_foo_exception", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncFooException();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_initialize", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncInitialize();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_list", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncList();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_list_len", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncListLen();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_ordering", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncOrdering();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_parse", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncParse();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_parse_exception_error_handler", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncParseExceptionErrorHandler();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_total", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncTotal();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_func_total_in_background", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumFuncTotalInBackground();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_method_counter_increment", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumMethodCounterIncrement();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_method_counter_read", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumMethodCounterRead();
+
+@Native<Uint16 Function()>( symbol: "uniffi_name_collisions_checksum_constructor_counter_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiNameCollisionsChecksumConstructorCounterNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_name_collisions_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiNameCollisionsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiNameCollisionsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiNameCollisionsChecksumFuncChunkSize() != 63003) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_chunk_size"); }if (uniffiNameCollisionsChecksumFuncFooError() != 51660) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_foo_error"); }if (uniffiNameCollisionsChecksumFuncFooException() != 3927) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_foo_exception"); }if (uniffiNameCollisionsChecksumFuncInitialize() != 10919) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_initialize"); }if (uniffiNameCollisionsChecksumFuncList() != 7249) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_list"); }if (uniffiNameCollisionsChecksumFuncListLen() != 29716) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_list_len"); }if (uniffiNameCollisionsChecksumFuncOrdering() != 22326) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_ordering"); }if (uniffiNameCollisionsChecksumFuncParse() != 48703) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_parse"); }if (uniffiNameCollisionsChecksumFuncParseExceptionErrorHandler() != 22219) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_parse_exception_error_handler"); }if (uniffiNameCollisionsChecksumFuncTotal() != 22549) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_total"); }if (uniffiNameCollisionsChecksumFuncTotalInBackground() != 51579) { mismatchedChecksums.add("uniffi_name_collisions_checksum_func_total_in_background"); }if (uniffiNameCollisionsChecksumMethodCounterIncrement() != 18970) { mismatchedChecksums.add("uniffi_name_collisions_checksum_method_counter_increment"); }if (uniffiNameCollisionsChecksumMethodCounterRead() != 44573) { mismatchedChecksums.add("uniffi_name_collisions_checksum_method_counter_read"); }if (uniffiNameCollisionsChecksumConstructorCounterNew() != 32094) { mismatchedChecksums.add("uniffi_name_collisions_checksum_constructor_counter_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
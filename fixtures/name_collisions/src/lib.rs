@@ -0,0 +1,111 @@
+use std::sync::atomic::{AtomicU32, Ordering as AtomicOrdering};
+
+pub struct FooError {
+    message: String,
+}
+
+pub struct FooException {
+    code: i32,
+}
+
+pub struct List {
+    items: Vec<String>,
+}
+
+pub enum Ordering {
+    Index,
+    Name,
+    Values,
+}
+
+fn foo_error(message: String) -> FooError {
+    FooError { message }
+}
+
+fn foo_exception(code: i32) -> FooException {
+    FooException { code }
+}
+
+fn list(items: Vec<String>) -> List {
+    List { items }
+}
+
+fn list_len(list: List) -> u32 {
+    list.items.len() as u32
+}
+
+fn ordering(index: u32) -> Ordering {
+    match index {
+        0 => Ordering::Index,
+        1 => Ordering::Name,
+        _ => Ordering::Values,
+    }
+}
+
+fn initialize() -> String {
+    "initialized".to_string()
+}
+
+fn total(values: Vec<u32>) -> u32 {
+    values.iter().sum()
+}
+
+fn total_in_background() -> String {
+    "a function of its own".to_string()
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("empty input")]
+    Empty,
+}
+
+pub struct ParseExceptionErrorHandler {
+    reason: String,
+}
+
+pub enum Chunk {
+    Data { write: u32, new_offset: u32 },
+    Empty,
+}
+
+fn parse(input: String) -> Result<u32, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    Ok(input.len() as u32)
+}
+
+fn parse_exception_error_handler() -> ParseExceptionErrorHandler {
+    ParseExceptionErrorHandler {
+        reason: "a record of its own".to_string(),
+    }
+}
+
+fn chunk_size(chunk: Chunk) -> u32 {
+    match chunk {
+        Chunk::Data { write, new_offset } => write + new_offset,
+        Chunk::Empty => 0,
+    }
+}
+
+#[derive(Default)]
+pub struct Counter {
+    count: AtomicU32,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn read(&self) -> u32 {
+        self.count.load(AtomicOrdering::SeqCst)
+    }
+
+    fn increment(&self) {
+        self.count.fetch_add(1, AtomicOrdering::SeqCst);
+    }
+}
+
+uniffi::include_scaffolding!("api");
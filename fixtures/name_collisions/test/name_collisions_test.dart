@@ -0,0 +1,48 @@
+import 'package:test/test.dart';
+import '../name_collisions.dart';
+
+void main() {
+  test('records whose names collide keep distinct classes', () {
+    final FooError error = fooError('boom');
+    final FooException exception = fooException(7);
+    expect(error.message, 'boom');
+    expect(exception.code, 7);
+  });
+
+  test('types shadowing dart:core are renamed', () {
+    final List_ list = list(['a', 'b']);
+    expect(list.items, ['a', 'b']);
+    expect(listLen(list), 2);
+  });
+
+  test('enum variants clashing with enum members are renamed', () {
+    expect(ordering(0), Ordering.index_);
+    expect(ordering(1), Ordering.name_);
+    expect(ordering(2), Ordering.values_);
+    expect(Ordering.values_.index, 2);
+  });
+
+  test('functions and methods clashing with generated code are renamed', () {
+    expect(initialize_(), 'initialized');
+
+    final counter = Counter();
+    counter.increment();
+    expect(counter.read_(), 1);
+  });
+
+  test('background variants clashing with functions are renamed', () async {
+    expect(totalInBackground(), 'a function of its own');
+    expect(await totalInBackground_([1, 2, 3]), 6);
+  });
+
+  test('error handlers clashing with functions and types are renamed', () {
+    expect(parseExceptionErrorHandler().reason, 'a record of its own');
+    expect(parse('abc'), 3);
+    expect(() => parse(''), throwsA(isA<ParseException>()));
+  });
+
+  test('variant fields clashing with generated code are renamed', () {
+    expect(chunkSize(DataChunk(write_: 2, newOffset_: 3)), 5);
+    expect(chunkSize(EmptyChunk()), 0);
+  });
+}
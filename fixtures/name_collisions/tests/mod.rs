@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+#[test]
+fn name_collisions() -> Result<()> {
+    uniffi_dart::testing::run_test("name_collisions", "src/api.udl", Some("uniffi.toml"))
+}
+
+#[test]
+fn name_collisions_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output(
+        "name_collisions",
+        "src/api.udl",
+        Some("uniffi.toml"),
+    )
+}
+
+#[test]
+fn name_collisions_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("name_collisions", "src/api.udl", Some("uniffi.toml"))
+}
@@ -0,0 +1,239 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Externals { final One? one;final MaybeBool? bool; Externals(this.one,this.bool,); } class FfiConverterExternals { static Externals lift( RustBuffer buf) { return FfiConverterExternals.read(buf.asUint8List()).value; } static LiftRetVal<Externals> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final oneLifted = FfiConverterOptionalOne.read(Uint8List.view(buf.buffer, newOffset)); final one = oneLifted.value; newOffset += oneLifted.bytesRead;final boolLifted = FfiConverterOptionalMaybeBool.read(Uint8List.view(buf.buffer, newOffset)); final bool = boolLifted.value; newOffset += boolLifted.bytesRead; return LiftRetVal(Externals( one,bool, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Externals value) { final totalLength = FfiConverterOptionalOne.allocationSize(value.one) +FfiConverterOptionalMaybeBool.allocationSize(value.bool) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Externals value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterOptionalOne.write(value.one, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterOptionalMaybeBool.write(value.bool, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Externals value) { return FfiConverterOptionalOne.allocationSize(value.one) +FfiConverterOptionalMaybeBool.allocationSize(value.bool) + 0; } } class One { final int inner; One(this.inner,); } class FfiConverterOne { static One lift( RustBuffer buf) { return FfiConverterOne.read(buf.asUint8List()).value; } static LiftRetVal<One> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final innerLifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, newOffset)); final inner = innerLifted.value; newOffset += innerLifted.bytesRead; return LiftRetVal(One( inner, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( One value) { final totalLength = FfiConverterInt32.allocationSize(value.inner) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( One value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterInt32.write(value.inner, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(One value) { return FfiConverterInt32.allocationSize(value.inner) + 0; } } class RecordWithBytes { final Uint8List someBytes; RecordWithBytes(this.someBytes,); } class FfiConverterRecordWithBytes { static RecordWithBytes lift( RustBuffer buf) { return FfiConverterRecordWithBytes.read(buf.asUint8List()).value; } static LiftRetVal<RecordWithBytes> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final someBytesLifted = FfiConverterUint8List.read(Uint8List.view(buf.buffer, newOffset)); final someBytes = someBytesLifted.value; newOffset += someBytesLifted.bytesRead; return LiftRetVal(RecordWithBytes( someBytes, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( RecordWithBytes value) { final totalLength = FfiConverterUint8List.allocationSize(value.someBytes) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( RecordWithBytes value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterUint8List.write(value.someBytes, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(RecordWithBytes value) { return FfiConverterUint8List.allocationSize(value.someBytes) + 0; } } class Two { final String a; Two(this.a,); } class FfiConverterTwo { static Two lift( RustBuffer buf) { return FfiConverterTwo.read(buf.asUint8List()).value; } static LiftRetVal<Two> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final aLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final a = aLifted.value; newOffset += aLifted.bytesRead; return LiftRetVal(Two( a, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Two value) { final totalLength = FfiConverterString.allocationSize(value.a) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Two value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.a, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Two value) { return FfiConverterString.allocationSize(value.a) + 0; } } class Zero { final String inner; Zero(this.inner,); } class FfiConverterZero { static Zero lift( RustBuffer buf) { return FfiConverterZero.read(buf.asUint8List()).value; } static LiftRetVal<Zero> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final innerLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final inner = innerLifted.value; newOffset += innerLifted.bytesRead; return LiftRetVal(Zero( inner, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Zero value) { final totalLength = FfiConverterString.allocationSize(value.inner) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Zero value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.inner, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Zero value) { return FfiConverterString.allocationSize(value.inner) + 0; } } abstract class BasicException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterBasicException { static BasicException lift( RustBuffer buffer) { return FfiConverterBasicException.read(buffer.asUint8List()).value; } static LiftRetVal<BasicException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return InvalidInputBasicException.read(subview);case 2: return OsExceptionBasicException.read(subview);case 3: return UnexpectedExceptionBasicException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( BasicException value) { return value.lower(); } static int allocationSize(BasicException value) { return value.allocationSize(); } static int write( BasicException value, Uint8List buf) { return value.write(buf); } } class InvalidInputBasicException extends BasicException { InvalidInputBasicException(); InvalidInputBasicException._(); static LiftRetVal<InvalidInputBasicException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(InvalidInputBasicException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "InvalidInputBasicException"; } }class OsExceptionBasicException extends BasicException { OsExceptionBasicException(); OsExceptionBasicException._(); static LiftRetVal<OsExceptionBasicException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(OsExceptionBasicException._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; return newOffset; } @override String toString() { return "OsExceptionBasicException"; } }class UnexpectedExceptionBasicException extends BasicException { final String reason; UnexpectedExceptionBasicException(this.reason,); UnexpectedExceptionBasicException._(this.reason,); static LiftRetVal<UnexpectedExceptionBasicException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final reasonLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final reason = reasonLifted.value; newOffset += reasonLifted.bytesRead; return LiftRetVal(UnexpectedExceptionBasicException._( reason, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterString.allocationSize(reason) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterString.write(reason, Uint8List.view(buf.buffer, newOffset)); return newOffset; } @override String toString() { return "UnexpectedExceptionBasicException($reason)"; } } class BasicExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterBasicException.lift(errorBuf); } } final BasicExceptionErrorHandler basicExceptionErrorHandler = BasicExceptionErrorHandler(); enum MaybeBool { true_,false_,uncertain, ; } class FfiConverterMaybeBool { static LiftRetVal<MaybeBool> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( MaybeBool.true_, 4, );case 2: return LiftRetVal( MaybeBool.false_, 4, );case 3: return LiftRetVal( MaybeBool.uncertain, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static MaybeBool lift( RustBuffer buffer) { return FfiConverterMaybeBool.read(buffer.asUint8List()).value; } static RustBuffer lower( MaybeBool input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(MaybeBool value) { return 4; } static int write( MaybeBool value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  abstract class MixedEnum { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterMixedEnum { static MixedEnum lift( RustBuffer buffer) { return FfiConverterMixedEnum.read(buffer.asUint8List()).value; } static LiftRetVal<MixedEnum> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return NoneMixedEnum.read(subview);case 2: return StringMixedEnum.read(subview);case 3: return IntMixedEnum.read(subview);case 4: return BothMixedEnum.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( MixedEnum value) { return value.lower(); } static int allocationSize(MixedEnum value) { return value.allocationSize(); } static int write( MixedEnum value, Uint8List buf) { return value.write(buf); } } class NoneMixedEnum extends MixedEnum { NoneMixedEnum(); NoneMixedEnum._(); static LiftRetVal<NoneMixedEnum> read( Uint8List buf) { int newOffset = buf.offsetInBytes; return LiftRetVal(NoneMixedEnum._( ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; return newOffset; } }class StringMixedEnum extends MixedEnum { final String v0; StringMixedEnum(this.v0,); StringMixedEnum._(this.v0,); static LiftRetVal<StringMixedEnum> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final v0Lifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final v0 = v0Lifted.value; newOffset += v0Lifted.bytesRead; return LiftRetVal(StringMixedEnum._( v0, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterString.allocationSize(v0) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterString.write(v0, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }class IntMixedEnum extends MixedEnum { final int v0; IntMixedEnum(this.v0,); IntMixedEnum._(this.v0,); static LiftRetVal<IntMixedEnum> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final v0Lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, newOffset)); final v0 = v0Lifted.value; newOffset += v0Lifted.bytesRead; return LiftRetVal(IntMixedEnum._( v0, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterInt64.allocationSize(v0) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 3); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterInt64.write(v0, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }class BothMixedEnum extends MixedEnum { final String v0;final int v1; BothMixedEnum({ required this.v0,required this.v1, }); BothMixedEnum._(this.v0,this.v1,); static LiftRetVal<BothMixedEnum> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final v0Lifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final v0 = v0Lifted.value; newOffset += v0Lifted.bytesRead;final v1Lifted = FfiConverterInt64.read(Uint8List.view(buf.buffer, newOffset)); final v1 = v1Lifted.value; newOffset += v1Lifted.bytesRead; return LiftRetVal(BothMixedEnum._( v0,v1, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterString.allocationSize(v0) +FfiConverterInt64.allocationSize(v1) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 4); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterString.write(v0, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterInt64.write(v1, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }  abstract class Object_Interface { Trait getTrait(Trait? inc);TraitWithForeign getTraitWithForeign(TraitWithForeign? inc);MaybeBool isHeavy(); } final _objectFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiProcMacroUniffiFnFreeObject(ptr, status)); }); class Object_ implements Object_Interface { late final Pointer<Void> _ptr; Object_._(this._ptr) { _objectFinalizer.attach(this, _ptr, detach: this); } Object_() : _ptr = rustCall((status) => uniffiProcMacroUniffiFnConstructorObjectNew( status ), null ) { _objectFinalizer.attach(this, _ptr, detach: this); } factory Object_.lift(Pointer<Void> ptr) { return Object_._(ptr); } static Pointer<Void> lower(Object_ value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiProcMacroUniffiFnCloneObject(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Object_.fromSendableHandle(int handle) { return Object_._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Object_ value) { return 8; } static LiftRetVal<Object_> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Object_.lift(pointer), 8); } static int write(Object_ value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _objectFinalizer.detach(this); rustCall((status) => uniffiProcMacroUniffiFnFreeObject(_ptr, status)); } @override Trait getTrait(Trait? inc,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnMethodObjectGetTrait( uniffiClonePointer(), FfiConverterOptionalTrait.lower(inc), status ), Trait.lift, null ); } @override TraitWithForeign getTraitWithForeign(TraitWithForeign? inc,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnMethodObjectGetTraitWithForeign( uniffiClonePointer(), FfiConverterOptionalTraitWithForeign.lower(inc), status ), FfiConverterCallbackInterfaceTraitWithForeign.lift, null ); } @override MaybeBool isHeavy() { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnMethodObjectIsHeavy( uniffiClonePointer(), status ), FfiConverterMaybeBool.lift, null ); } }  abstract class OtherCallbackInterface { int multiply(int a,int b,); } class FfiConverterCallbackInterfaceOtherCallbackInterface { static final _handleMap = UniffiHandleMap<OtherCallbackInterface>(); static bool _vtableInitialized = false; static OtherCallbackInterface lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(OtherCallbackInterface value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initOtherCallbackInterfaceVTable(); _vtableInitialized = true; } } static LiftRetVal<OtherCallbackInterface> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(OtherCallbackInterface value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(OtherCallbackInterface value) { return 8; } } typedef UniffiCallbackInterfaceOtherCallbackInterfaceMethod0 = Void Function( Uint64, Int32,Int32, Pointer<Int32>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceOtherCallbackInterfaceMethod0Dart = void Function( int, int,int, Pointer<Int32>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceOtherCallbackInterfaceFree = Void Function(Uint64); typedef UniffiCallbackInterfaceOtherCallbackInterfaceFreeDart = void Function(int); typedef UniffiCallbackInterfaceOtherCallbackInterfaceClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceOtherCallbackInterfaceCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceOtherCallbackInterface extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceMethod0>> multiply; }  void otherCallbackInterfaceMultiply(int uniffiHandle, int a,int b, Pointer<Int32> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceOtherCallbackInterface._handleMap.get(uniffiHandle); final arg0 = FfiConverterInt32.lift(a);final arg1 = FfiConverterInt32.lift(b); final result = obj.multiply(arg0,arg1,); outReturn.value = FfiConverterInt32.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceMethod0>> otherCallbackInterfaceMultiplyPointer = Pointer.fromFunction<UniffiCallbackInterfaceOtherCallbackInterfaceMethod0>(otherCallbackInterfaceMultiply); void otherCallbackInterfaceFreeCallback(int handle) { FfiConverterCallbackInterfaceOtherCallbackInterface._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceFree>> otherCallbackInterfaceFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceOtherCallbackInterfaceFree>(otherCallbackInterfaceFreeCallback); int otherCallbackInterfaceCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceOtherCallbackInterface._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceOtherCallbackInterface._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceOtherCallbackInterfaceClone>> otherCallbackInterfaceClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceOtherCallbackInterfaceClone>(otherCallbackInterfaceCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceOtherCallbackInterface> otherCallbackInterfaceVTable; void initOtherCallbackInterfaceVTable() { if (FfiConverterCallbackInterfaceOtherCallbackInterface._vtableInitialized) { return; } otherCallbackInterfaceVTable = calloc<UniffiVTableCallbackInterfaceOtherCallbackInterface>(); otherCallbackInterfaceVTable.ref.uniffiFree = otherCallbackInterfaceFreePointer; otherCallbackInterfaceVTable.ref.uniffiClone = otherCallbackInterfaceClonePointer; otherCallbackInterfaceVTable.ref.multiply = otherCallbackInterfaceMultiplyPointer; rustCall((status) { uniffiProcMacroUniffiFnInitCallbackVtableOthercallbackinterface( otherCallbackInterfaceVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceOtherCallbackInterface._vtableInitialized = true; } abstract class TestCallbackInterface { void doNothing();int add(int a,int b,);int optional(int? a,);Uint8List withBytes(RecordWithBytes rwb,);int tryParseInt(String value,);int callbackHandler(Object_ o,);OtherCallbackInterface getOtherCallbackInterface(); } class FfiConverterCallbackInterfaceTestCallbackInterface { static final _handleMap = UniffiHandleMap<TestCallbackInterface>(); static bool _vtableInitialized = false; static TestCallbackInterface lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(TestCallbackInterface value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initTestCallbackInterfaceVTable(); _vtableInitialized = true; } } static LiftRetVal<TestCallbackInterface> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(TestCallbackInterface value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(TestCallbackInterface value) { return 8; } } typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod0 = Void Function( Uint64, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod0Dart = void Function( int, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod1 = Void Function( Uint64, Int32,Int32, Pointer<Int32>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod1Dart = void Function( int, int,int, Pointer<Int32>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod2 = Void Function( Uint64, RustBuffer, Pointer<Int32>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod2Dart = void Function( int, RustBuffer, Pointer<Int32>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod3 = Void Function( Uint64, RustBuffer, Pointer<RustBuffer>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod3Dart = void Function( int, RustBuffer, Pointer<RustBuffer>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod4 = Void Function( Uint64, RustBuffer, Pointer<Int32>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod4Dart = void Function( int, RustBuffer, Pointer<Int32>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod5 = Void Function( Uint64, Pointer<Void>, Pointer<Int32>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod5Dart = void Function( int, Pointer<Void>, Pointer<Int32>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod6 = Void Function( Uint64, Pointer<Pointer<Void>>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTestCallbackInterfaceMethod6Dart = void Function( int, Pointer<Pointer<Void>>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTestCallbackInterfaceFree = Void Function(Uint64); typedef UniffiCallbackInterfaceTestCallbackInterfaceFreeDart = void Function(int); typedef UniffiCallbackInterfaceTestCallbackInterfaceClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceTestCallbackInterfaceCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceTestCallbackInterface extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod0>> doNothing;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod1>> add;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod2>> optional;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod3>> withBytes;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod4>> tryParseInt;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod5>> callbackHandler;external Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod6>> getOtherCallbackInterface; }  void testCallbackInterfaceDoNothing(int uniffiHandle, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); obj.doNothing(); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod0>> testCallbackInterfaceDoNothingPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod0>(testCallbackInterfaceDoNothing);void testCallbackInterfaceAdd(int uniffiHandle, int a,int b, Pointer<Int32> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final arg0 = FfiConverterInt32.lift(a);final arg1 = FfiConverterInt32.lift(b); final result = obj.add(arg0,arg1,); outReturn.value = FfiConverterInt32.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod1>> testCallbackInterfaceAddPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod1>(testCallbackInterfaceAdd);void testCallbackInterfaceOptional(int uniffiHandle, RustBuffer a, Pointer<Int32> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final arg0 = FfiConverterOptionalInt32.lift(a); final result = obj.optional(arg0,); outReturn.value = FfiConverterInt32.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod2>> testCallbackInterfaceOptionalPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod2>(testCallbackInterfaceOptional);void testCallbackInterfaceWithBytes(int uniffiHandle, RustBuffer rwb, Pointer<RustBuffer> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final arg0 = FfiConverterRecordWithBytes.lift(rwb); final result = obj.withBytes(arg0,); outReturn.ref = FfiConverterUint8List.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod3>> testCallbackInterfaceWithBytesPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod3>(testCallbackInterfaceWithBytes);void testCallbackInterfaceTryParseInt(int uniffiHandle, RustBuffer value, Pointer<Int32> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(value); final result = obj.tryParseInt(arg0,); outReturn.value = FfiConverterInt32.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod4>> testCallbackInterfaceTryParseIntPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod4>(testCallbackInterfaceTryParseInt);void testCallbackInterfaceCallbackHandler(int uniffiHandle, Pointer<Void> o, Pointer<Int32> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final arg0 = Object_.lift(o); final result = obj.callbackHandler(arg0,); outReturn.value = FfiConverterInt32.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod5>> testCallbackInterfaceCallbackHandlerPointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod5>(testCallbackInterfaceCallbackHandler);void testCallbackInterfaceGetOtherCallbackInterface(int uniffiHandle, Pointer<Pointer<Void>> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(uniffiHandle); final result = obj.getOtherCallbackInterface(); outReturn.value = FfiConverterCallbackInterfaceOtherCallbackInterface.lower(result); } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod6>> testCallbackInterfaceGetOtherCallbackInterfacePointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceMethod6>(testCallbackInterfaceGetOtherCallbackInterface); void testCallbackInterfaceFreeCallback(int handle) { FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceFree>> testCallbackInterfaceFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceFree>(testCallbackInterfaceFreeCallback); int testCallbackInterfaceCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceTestCallbackInterface._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceTestCallbackInterfaceClone>> testCallbackInterfaceClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceTestCallbackInterfaceClone>(testCallbackInterfaceCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceTestCallbackInterface> testCallbackInterfaceVTable; void initTestCallbackInterfaceVTable() { if (FfiConverterCallbackInterfaceTestCallbackInterface._vtableInitialized) { return; } testCallbackInterfaceVTable = calloc<UniffiVTableCallbackInterfaceTestCallbackInterface>(); testCallbackInterfaceVTable.ref.uniffiFree = testCallbackInterfaceFreePointer; testCallbackInterfaceVTable.ref.uniffiClone = testCallbackInterfaceClonePointer; testCallbackInterfaceVTable.ref.doNothing = testCallbackInterfaceDoNothingPointer;testCallbackInterfaceVTable.ref.add = testCallbackInterfaceAddPointer;testCallbackInterfaceVTable.ref.optional = testCallbackInterfaceOptionalPointer;testCallbackInterfaceVTable.ref.withBytes = testCallbackInterfaceWithBytesPointer;testCallbackInterfaceVTable.ref.tryParseInt = testCallbackInterfaceTryParseIntPointer;testCallbackInterfaceVTable.ref.callbackHandler = testCallbackInterfaceCallbackHandlerPointer;testCallbackInterfaceVTable.ref.getOtherCallbackInterface = testCallbackInterfaceGetOtherCallbackInterfacePointer; rustCall((status) { uniffiProcMacroUniffiFnInitCallbackVtableTestcallbackinterface( testCallbackInterfaceVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceTestCallbackInterface._vtableInitialized = true; } abstract class Trait { factory Trait.lift(Pointer<Void> ptr) { final handle = ptr.address; final isForeign = (handle & 0x1) != 0; if (isForeign) { throw UnsupportedError("Foreign trait implementations are not yet supported in uniffi-dart"); } return _TraitImpl._internal(ptr); } static Pointer<Void> lower(Trait value) { if (value is _TraitImpl) { return value.uniffiClonePointer(); } throw UnsupportedError("Only Rust-implemented Trait values are supported."); } static int allocationSize(Trait value) { if (value is _TraitImpl) { return _TraitImpl.allocationSize(value); } throw UnsupportedError("Only Rust-implemented Trait values are supported."); } static LiftRetVal<Trait> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Trait.lift(pointer), 8); } static int write(Trait value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose(); String concatStrings(String a, String b); } final class _TraitImpl implements Trait { _TraitImpl._internal(this._ptr) { _finalizer.attach(this, _ptr, detach: this); } static final Finalizer<Pointer<Void>> _finalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiProcMacroUniffiFnFreeTrait(ptr, status)); }); final Pointer<Void> _ptr; static int allocationSize(_TraitImpl _) => 8; Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiProcMacroUniffiFnCloneTrait(_ptr, status)); } @override void dispose() { _finalizer.detach(this); rustCall((status) => uniffiProcMacroUniffiFnFreeTrait(_ptr, status)); } @override String concatStrings(String a,String b,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnMethodTraitConcatStrings( uniffiClonePointer(), FfiConverterString.lower(a),FfiConverterString.lower(b), status ), FfiConverterString.lift, null ); } } abstract class TraitWithForeign { String name(); } class FfiConverterCallbackInterfaceTraitWithForeign { static final _handleMap = UniffiHandleMap<TraitWithForeign>(); static bool _vtableInitialized = false; static TraitWithForeign lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(TraitWithForeign value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initTraitWithForeignVTable(); _vtableInitialized = true; } } static LiftRetVal<TraitWithForeign> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(TraitWithForeign value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(TraitWithForeign value) { return 8; } } typedef UniffiCallbackInterfaceTraitWithForeignMethod0 = Void Function( Uint64, Pointer<RustBuffer>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceTraitWithForeignMethod0Dart = void Function( int, Pointer<RustBuffer>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceTraitWithForeignFree = Void Function(Uint64); typedef UniffiCallbackInterfaceTraitWithForeignFreeDart = void Function(int); typedef UniffiCallbackInterfaceTraitWithForeignClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceTraitWithForeignCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceTraitWithForeign extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignMethod0>> name; }  void traitWithForeignName(int uniffiHandle, Pointer<RustBuffer> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceTraitWithForeign._handleMap.get(uniffiHandle); final result = obj.name(); outReturn.ref = FfiConverterString.lower(result); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignMethod0>> traitWithForeignNamePointer = Pointer.fromFunction<UniffiCallbackInterfaceTraitWithForeignMethod0>(traitWithForeignName); void traitWithForeignFreeCallback(int handle) { FfiConverterCallbackInterfaceTraitWithForeign._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignFree>> traitWithForeignFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceTraitWithForeignFree>(traitWithForeignFreeCallback); int traitWithForeignCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfaceTraitWithForeign._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfaceTraitWithForeign._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfaceTraitWithForeignClone>> traitWithForeignClonePointer = Pointer.fromFunction<UniffiCallbackInterfaceTraitWithForeignClone>(traitWithForeignCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfaceTraitWithForeign> traitWithForeignVTable; void initTraitWithForeignVTable() { if (FfiConverterCallbackInterfaceTraitWithForeign._vtableInitialized) { return; } traitWithForeignVTable = calloc<UniffiVTableCallbackInterfaceTraitWithForeign>(); traitWithForeignVTable.ref.uniffiFree = traitWithForeignFreePointer; traitWithForeignVTable.ref.uniffiClone = traitWithForeignClonePointer; traitWithForeignVTable.ref.name = traitWithForeignNamePointer; rustCall((status) { uniffiProcMacroUniffiFnInitCallbackVtableTraitwithforeign( traitWithForeignVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceTraitWithForeign._vtableInitialized = true; }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiProcMacroUniffiRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiProcMacroUniffiRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1); } static int lower(int value) { if (value < -128 || value > 127) { throw ArgumentError("Value out of range for i8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, lower(value)); return 1; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8); } static int lower(int value) { if (value < -9223372036854775808 || value > 9223372036854775807) { throw ArgumentError("Value out of range for i64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, lower(value)); return 8; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterUint8List { static Uint8List lift(RustBuffer value) { return FfiConverterUint8List.read(value.asUint8List()).value; } static LiftRetVal<Uint8List> read(Uint8List buf) { final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final bytes = Uint8List.view(buf.buffer, buf.offsetInBytes + 4, length); return LiftRetVal(bytes, length + 4); } static RustBuffer lower(Uint8List value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } static int allocationSize([Uint8List? value]) { if (value == null) { return 4; } return 4 + value.length; } static int write(Uint8List value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); buf.setRange(4, 4 + value.length, value); return 4 + value.length; } } class FfiConverterOptionalInt32 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt32.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt32.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value)); FfiConverterOptionalInt32.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalObject_ { static Object_? lift( RustBuffer buf) { return FfiConverterOptionalObject_.read(buf.asUint8List()).value; } static LiftRetVal<Object_?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = Object_.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Object_?>(result.value, result.bytesRead + 1); } static int allocationSize([Object_? value]) { if (value == null) { return 1; } return Object_.allocationSize(value) + 1; } static RustBuffer lower( Object_? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalObject_.allocationSize(value)); FfiConverterOptionalObject_.write(value, buf); return toRustBuffer(buf); } static int write( Object_? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return Object_.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalTrait { static Trait? lift( RustBuffer buf) { return FfiConverterOptionalTrait.read(buf.asUint8List()).value; } static LiftRetVal<Trait?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = Trait.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Trait?>(result.value, result.bytesRead + 1); } static int allocationSize([Trait? value]) { if (value == null) { return 1; } return Trait.allocationSize(value) + 1; } static RustBuffer lower( Trait? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalTrait.allocationSize(value)); FfiConverterOptionalTrait.write(value, buf); return toRustBuffer(buf); } static int write( Trait? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return Trait.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalTraitWithForeign { static TraitWithForeign? lift( RustBuffer buf) { return FfiConverterOptionalTraitWithForeign.read(buf.asUint8List()).value; } static LiftRetVal<TraitWithForeign?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterCallbackInterfaceTraitWithForeign.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<TraitWithForeign?>(result.value, result.bytesRead + 1); } static int allocationSize([TraitWithForeign? value]) { if (value == null) { return 1; } return FfiConverterCallbackInterfaceTraitWithForeign.allocationSize(value) + 1; } static RustBuffer lower( TraitWithForeign? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalTraitWithForeign.allocationSize(value)); FfiConverterOptionalTraitWithForeign.write(value, buf); return toRustBuffer(buf); } static int write( TraitWithForeign? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterCallbackInterfaceTraitWithForeign.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalExternals { static Externals? lift( RustBuffer buf) { return FfiConverterOptionalExternals.read(buf.asUint8List()).value; } static LiftRetVal<Externals?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterExternals.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Externals?>(result.value, result.bytesRead + 1); } static int allocationSize([Externals? value]) { if (value == null) { return 1; } return FfiConverterExternals.allocationSize(value) + 1; } static RustBuffer lower( Externals? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalExternals.allocationSize(value)); FfiConverterOptionalExternals.write(value, buf); return toRustBuffer(buf); } static int write( Externals? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterExternals.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalOne { static One? lift( RustBuffer buf) { return FfiConverterOptionalOne.read(buf.asUint8List()).value; } static LiftRetVal<One?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterOne.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<One?>(result.value, result.bytesRead + 1); } static int allocationSize([One? value]) { if (value == null) { return 1; } return FfiConverterOne.allocationSize(value) + 1; } static RustBuffer lower( One? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalOne.allocationSize(value)); FfiConverterOptionalOne.write(value, buf); return toRustBuffer(buf); } static int write( One? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterOne.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalMaybeBool { static MaybeBool? lift( RustBuffer buf) { return FfiConverterOptionalMaybeBool.read(buf.asUint8List()).value; } static LiftRetVal<MaybeBool?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterMaybeBool.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<MaybeBool?>(result.value, result.bytesRead + 1); } static int allocationSize([MaybeBool? value]) { if (value == null) { return 1; } return FfiConverterMaybeBool.allocationSize(value) + 1; } static RustBuffer lower( MaybeBool? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalMaybeBool.allocationSize(value)); FfiConverterOptionalMaybeBool.write(value, buf); return toRustBuffer(buf); } static int write( MaybeBool? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterMaybeBool.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterMapInt8ToUInt64 { static Map<int, int> lift(RustBuffer buf) { return FfiConverterMapInt8ToUInt64.read(buf.asUint8List()).value; } static LiftRetVal<Map<int, int>> read(Uint8List buf) { final map = <int, int>{}; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final k = FfiConverterInt8.read(Uint8List.view(buf.buffer, offset)); offset += k.bytesRead; final v = FfiConverterUInt64.read(Uint8List.view(buf.buffer, offset)); offset += v.bytesRead; map[k.value] = v.value; } return LiftRetVal(map, offset - buf.offsetInBytes); } static int write(Map<int, int> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (final entry in value.entries) { offset += FfiConverterInt8.write(entry.key, Uint8List.view(buf.buffer, offset)); offset += FfiConverterUInt64.write(entry.value, Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(Map<int, int> value) { return value.entries .map((e) => FfiConverterInt8.allocationSize(e.key) + FfiConverterUInt64.allocationSize(e.value)) .fold(4, (a, b) => a + b); } static RustBuffer lower(Map<int, int> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:proc_macro_uniffi"; void alwaysFails() { return rustCall((status) { uniffiProcMacroUniffiFnFuncAlwaysFails( status ); }, basicExceptionErrorHandler); } MaybeBool getBool(MaybeBool? b,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetBool( FfiConverterOptionalMaybeBool.lower(b), status ), FfiConverterMaybeBool.lift, null ); } Externals getExternals(Externals? e,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetExternals( FfiConverterOptionalExternals.lower(e), status ), FfiConverterExternals.lift, null ); } Object_ getObject(Object_? o,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetObject( FfiConverterOptionalObject_.lower(o), status ), Object_.lift, null ); } One getOne(One? one,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetOne( FfiConverterOptionalOne.lower(one), status ), FfiConverterOne.lift, null ); } Trait getTrait(Trait? o,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetTrait( FfiConverterOptionalTrait.lower(o), status ), Trait.lift, null ); } TraitWithForeign getTraitWithForeign(TraitWithForeign? o,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncGetTraitWithForeign( FfiConverterOptionalTraitWithForeign.lower(o), status ), FfiConverterCallbackInterfaceTraitWithForeign.lift, null ); } Map<int, int> makeHashmap(int k,int v,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncMakeHashmap( FfiConverterInt8.lower(k),FfiConverterUInt64.lower(v), status ), FfiConverterMapInt8ToUInt64.lift, null ); } One makeOne(int inner,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncMakeOne( FfiConverterInt32.lower(inner), status ), FfiConverterOne.lift, null ); } Zero makeZero() { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncMakeZero( status ), FfiConverterZero.lift, null ); } String takeTwo(Two two,) { return rustCallWithLifter( (status) => uniffiProcMacroUniffiFnFuncTakeTwo( FfiConverterTwo.lower(two), status ), FfiConverterString.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_clone_object", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnCloneObject( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_free_object", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFreeObject( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_constructor_object_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnConstructorObjectNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_object_get_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnMethodObjectGetTrait( Pointer<Void> ptr, RustBuffer inc, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_object_get_trait_with_foreign", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnMethodObjectGetTraitWithForeign( Pointer<Void> ptr, RustBuffer inc, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_object_is_heavy", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnMethodObjectIsHeavy( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_clone_othercallbackinterface", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnCloneOthercallbackinterface( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_free_othercallbackinterface", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFreeOthercallbackinterface( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceOtherCallbackInterface>)>( symbol: "uniffi_proc_macro_uniffi_fn_init_callback_vtable_othercallbackinterface", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnInitCallbackVtableOthercallbackinterface( Pointer<UniffiVTableCallbackInterfaceOtherCallbackInterface> vtable);
+
+@Native<Int32 Function( Pointer<Void>, Int32, Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_othercallbackinterface_multiply", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiFnMethodOthercallbackinterfaceMultiply( Pointer<Void> ptr, int a, int b, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_clone_testcallbackinterface", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnCloneTestcallbackinterface( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_free_testcallbackinterface", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFreeTestcallbackinterface( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceTestCallbackInterface>)>( symbol: "uniffi_proc_macro_uniffi_fn_init_callback_vtable_testcallbackinterface", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnInitCallbackVtableTestcallbackinterface( Pointer<UniffiVTableCallbackInterfaceTestCallbackInterface> vtable);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_do_nothing", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnMethodTestcallbackinterfaceDoNothing( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Pointer<Void>, Int32, Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_add", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiFnMethodTestcallbackinterfaceAdd( Pointer<Void> ptr, int a, int b, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_optional", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiFnMethodTestcallbackinterfaceOptional( Pointer<Void> ptr, RustBuffer a, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_with_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnMethodTestcallbackinterfaceWithBytes( Pointer<Void> ptr, RustBuffer rwb, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_try_parse_int", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiFnMethodTestcallbackinterfaceTryParseInt( Pointer<Void> ptr, RustBuffer value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_callback_handler", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiFnMethodTestcallbackinterfaceCallbackHandler( Pointer<Void> ptr, Pointer<Void> o, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_testcallbackinterface_get_other_callback_interface", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnMethodTestcallbackinterfaceGetOtherCallbackInterface( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_clone_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnCloneTrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_free_trait", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFreeTrait( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_trait_concat_strings", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnMethodTraitConcatStrings( Pointer<Void> ptr, RustBuffer a, RustBuffer b, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_clone_traitwithforeign", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnCloneTraitwithforeign( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_free_traitwithforeign", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFreeTraitwithforeign( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceTraitWithForeign>)>( symbol: "uniffi_proc_macro_uniffi_fn_init_callback_vtable_traitwithforeign", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnInitCallbackVtableTraitwithforeign( Pointer<UniffiVTableCallbackInterfaceTraitWithForeign> vtable);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_method_traitwithforeign_name", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnMethodTraitwithforeignName( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_always_fails", assetId: _uniffiAssetId ) external void uniffiProcMacroUniffiFnFuncAlwaysFails( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_bool", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncGetBool( RustBuffer b, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_externals", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncGetExternals( RustBuffer e, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_object", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnFuncGetObject( RustBuffer o, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_one", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncGetOne( RustBuffer one, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_trait", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnFuncGetTrait( RustBuffer o, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_get_trait_with_foreign", assetId: _uniffiAssetId ) external Pointer<Void> uniffiProcMacroUniffiFnFuncGetTraitWithForeign( RustBuffer o, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int8, Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_make_hashmap", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncMakeHashmap( int k, int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_make_one", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncMakeOne( int inner, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_make_zero", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncMakeZero( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_proc_macro_uniffi_fn_func_take_two", assetId: _uniffiAssetId ) external RustBuffer uniffiProcMacroUniffiFnFuncTakeTwo( RustBuffer two, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiProcMacroUniffiRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiProcMacroUniffiRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiProcMacroUniffiRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiProcMacroUniffiRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiProcMacroUniffiRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiProcMacroUniffiRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_proc_macro_uniffi_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_proc_macro_uniffi_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiProcMacroUniffiRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_always_fails", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncAlwaysFails();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_bool", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetBool();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_externals", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetExternals();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_object", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetObject();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_one", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetOne();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_trait", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_get_trait_with_foreign", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncGetTraitWithForeign();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_make_hashmap", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncMakeHashmap();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_make_one", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncMakeOne();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_make_zero", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncMakeZero();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_func_take_two", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumFuncTakeTwo();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_object_get_trait", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodObjectGetTrait();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_object_get_trait_with_foreign", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodObjectGetTraitWithForeign();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_object_is_heavy", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodObjectIsHeavy();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_othercallbackinterface_multiply", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodOthercallbackinterfaceMultiply();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_do_nothing", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceDoNothing();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_add", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceAdd();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_optional", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceOptional();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_with_bytes", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceWithBytes();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_try_parse_int", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceTryParseInt();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_callback_handler", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceCallbackHandler();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_get_other_callback_interface", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceGetOtherCallbackInterface();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_trait_concat_strings", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTraitConcatStrings();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_method_traitwithforeign_name", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumMethodTraitwithforeignName();
+
+@Native<Uint16 Function()>( symbol: "uniffi_proc_macro_uniffi_checksum_constructor_object_new", assetId: _uniffiAssetId ) external int uniffiProcMacroUniffiChecksumConstructorObjectNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_proc_macro_uniffi_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiProcMacroUniffiUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiProcMacroUniffiUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiProcMacroUniffiChecksumFuncAlwaysFails() != 10479) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_always_fails"); }if (uniffiProcMacroUniffiChecksumFuncGetBool() != 29769) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_bool"); }if (uniffiProcMacroUniffiChecksumFuncGetExternals() != 60618) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_externals"); }if (uniffiProcMacroUniffiChecksumFuncGetObject() != 13842) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_object"); }if (uniffiProcMacroUniffiChecksumFuncGetOne() != 51962) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_one"); }if (uniffiProcMacroUniffiChecksumFuncGetTrait() != 38414) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_trait"); }if (uniffiProcMacroUniffiChecksumFuncGetTraitWithForeign() != 45455) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_get_trait_with_foreign"); }if (uniffiProcMacroUniffiChecksumFuncMakeHashmap() != 6718) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_make_hashmap"); }if (uniffiProcMacroUniffiChecksumFuncMakeOne() != 28355) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_make_one"); }if (uniffiProcMacroUniffiChecksumFuncMakeZero() != 23234) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_make_zero"); }if (uniffiProcMacroUniffiChecksumFuncTakeTwo() != 270) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_func_take_two"); }if (uniffiProcMacroUniffiChecksumMethodObjectGetTrait() != 38145) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_object_get_trait"); }if (uniffiProcMacroUniffiChecksumMethodObjectGetTraitWithForeign() != 4643) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_object_get_trait_with_foreign"); }if (uniffiProcMacroUniffiChecksumMethodObjectIsHeavy() != 4427) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_object_is_heavy"); }if (uniffiProcMacroUniffiChecksumMethodOthercallbackinterfaceMultiply() != 41140) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_othercallbackinterface_multiply"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceDoNothing() != 12835) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_do_nothing"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceAdd() != 62076) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_add"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceOptional() != 32269) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_optional"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceWithBytes() != 63234) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_with_bytes"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceTryParseInt() != 43979) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_try_parse_int"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceCallbackHandler() != 31648) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_callback_handler"); }if (uniffiProcMacroUniffiChecksumMethodTestcallbackinterfaceGetOtherCallbackInterface() != 45476) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_testcallbackinterface_get_other_callback_interface"); }if (uniffiProcMacroUniffiChecksumMethodTraitConcatStrings() != 26660) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_trait_concat_strings"); }if (uniffiProcMacroUniffiChecksumMethodTraitwithforeignName() != 63260) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_method_traitwithforeign_name"); }if (uniffiProcMacroUniffiChecksumConstructorObjectNew() != 23302) { mismatchedChecksums.add("uniffi_proc_macro_uniffi_checksum_constructor_object_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -9,7 +9,7 @@ pub trait TestCallbackInterface: Send + Sync {
     fn with_bytes(&self, rwb: RecordWithBytes) -> Vec<u8>;
     fn try_parse_int(&self, value: String) -> Result<i32, BasicError>;
     fn callback_handler(&self, o: Arc<Object>) -> i32;
-    fn get_other_callback_interface(&self) -> Box<dyn OtherCallbackInterface>;
+    fn get_other_callback_interface(&self) -> Arc<dyn OtherCallbackInterface>;
 }
 
 #[uniffi::export(with_foreign)]
@@ -1,7 +1,6 @@
 use std::{collections::HashMap, sync::Arc};
 
 mod callback_interface;
-use callback_interface::TestCallbackInterface;
 
 #[derive(uniffi::Record)]
 pub struct One {
@@ -115,7 +114,7 @@ pub fn always_fails() -> Result<(), BasicError> {
     Err(BasicError::OsError)
 }
 
-// Type that's defined in the UDL and not wrapped with #[uniffi::export]
+#[derive(uniffi::Record)]
 pub struct Zero {
     inner: String,
 }
@@ -127,35 +126,41 @@ fn make_zero() -> Zero {
     }
 }
 
-// UDL functions that reference proc-macro types
+#[uniffi::export]
 fn get_one(one: Option<One>) -> One {
     one.unwrap_or(One { inner: 0 })
 }
 
+#[uniffi::export]
 fn get_bool(b: Option<MaybeBool>) -> MaybeBool {
     b.unwrap_or(MaybeBool::Uncertain)
 }
 
+#[uniffi::export]
 fn get_object(o: Option<Arc<Object>>) -> Arc<Object> {
     o.unwrap_or_else(Object::new)
 }
 
+#[uniffi::export]
 fn get_trait(o: Option<Arc<dyn Trait>>) -> Arc<dyn Trait> {
     o.unwrap_or_else(|| Arc::new(TraitImpl {}))
 }
 
+#[uniffi::export]
 fn get_trait_with_foreign(o: Option<Arc<dyn TraitWithForeign>>) -> Arc<dyn TraitWithForeign> {
     o.unwrap_or_else(|| Arc::new(RustTraitImpl {}))
 }
 
-#[derive(Default)]
+#[derive(uniffi::Record, Default)]
 struct Externals {
     one: Option<One>,
     bool: Option<MaybeBool>,
 }
 
+#[uniffi::export]
 fn get_externals(e: Option<Externals>) -> Externals {
     e.unwrap_or_default()
 }
 
-uniffi::include_scaffolding!("api");
+// Namespace different from crate name.
+uniffi::setup_scaffolding!("proc_macro");
@@ -1,73 +1,53 @@
 import 'package:test/test.dart';
 import '../proc_macro.dart';
 
+class DartTraitWithForeign implements TraitWithForeign {
+  @override
+  String name() => 'DartTraitWithForeign';
+}
+
 void main() {
   group('ProcMacro', () {
-    test('UDL and proc-macro interoperability', () {
-      // This massive test will fail until comprehensive proc-macro support is implemented
-      // Expected functionality:
-      // - UDL types used in proc-macros (Zero -> make_zero())
-      // - Proc-macro types used in UDL (One, MaybeBool, Object, etc.)
-      // - Traits with foreign implementations
-      // - Records, enums, objects, errors all working together
-
-      // Basic record operations
-      // final one = makeOne(42);
-      // expect(one.inner, equals(42));
-
-      // final two = Two(a: "hello");
-      // expect(takeTwo(two), equals("hello"));
-
-      // HashMap operations
-      // final map = makeHashmap(1, 100);
-      // expect(map[1], equals(100));
-
-      // Enum operations
-      // final obj = Object();
-      // expect(obj.isHeavy(), equals(MaybeBool.uncertain));
-
-      // Error handling
-      // expect(() => alwaysFails(), throwsA(isA<BasicError>()));
-
-      // UDL-defined functions using proc-macro types
-      // final retrieved = getOne(null);
-      // expect(retrieved.inner, equals(0));
+    test('records pass through functions', () {
+      expect(makeOne(42).inner, 42);
+      expect(takeTwo(Two('hello')), 'hello');
+      expect(makeZero().inner, 'ZERO');
+      expect(getOne(null).inner, 0);
+      expect(getOne(makeOne(7)).inner, 7);
+    });
 
-      // final bool = getBool(null);
-      // expect(bool, equals(MaybeBool.uncertain));
+    test('maps', () {
+      expect(makeHashmap(1, 100), {1: 100});
+    });
 
-      // Skip test until comprehensive proc-macro support is implemented
-      skip(
-        'Blocked by comprehensive proc-macro support: '
-        '#[derive(uniffi::Record)], #[derive(uniffi::Object)], #[derive(uniffi::Enum)], '
-        '#[derive(uniffi::Error)], #[uniffi::export], #[uniffi::export(with_foreign)], '
-        'trait definitions, HashMap support, and UDL/proc-macro interoperability',
-      );
+    test('enums and objects', () {
+      final obj = Object_();
+      expect(obj.isHeavy(), MaybeBool.uncertain);
+      expect(getBool(null), MaybeBool.uncertain);
+      expect(getBool(MaybeBool.true_), MaybeBool.true_);
+      expect(getObject(obj).isHeavy(), MaybeBool.uncertain);
     });
 
-    test('callback interfaces', () {
-      // This test will fail until callback interface support is implemented
-      // Expected functionality:
-      // - Callback traits with #[uniffi::export(with_foreign)]
-      // - Complex callback patterns with multiple traits
-      // - Error handling in callbacks
+    test('records with optional fields', () {
+      final externals = getExternals(null);
+      expect(externals.one, isNull);
+      expect(externals.bool, isNull);
+    });
 
-      // Skip test until callback interface support is implemented
-      skip(
-        'Blocked by callback interface support: #[uniffi::export(with_foreign)] traits',
-      );
+    test('errors', () {
+      expect(() => alwaysFails(), throwsA(isA<OsExceptionBasicException>()));
     });
 
     test('trait objects', () {
-      // This test will fail until trait object support is implemented
-      // Expected functionality:
-      // - Trait definitions with #[uniffi::export]
-      // - Trait implementations
-      // - Trait objects as parameters and return values
+      expect(getTrait(null).concatStrings('foo', 'bar'), 'foobar');
+      expect(Object_().getTrait(null).concatStrings('a', 'b'), 'ab');
+    });
 
-      // Skip test until trait object support is implemented
-      skip(
-        'Blocked by trait object support: #[uniffi::export] traits and Arc<dyn Trait>',
+    test('traits with foreign implementations', () {
+      expect(getTraitWithForeign(null).name(), 'RustTraitImpl');
+      expect(
+        getTraitWithForeign(DartTraitWithForeign()).name(),
+        'DartTraitWithForeign',
       );
     });
   });
@@ -2,5 +2,15 @@ use anyhow::Result;
 
 #[test]
 fn proc_macro() -> Result<()> {
-    uniffi_dart::testing::run_test("proc_macro", "src/api.udl", None)
+    uniffi_dart::testing::run_test_library_mode("proc_macro_uniffi")
+}
+
+#[test]
+fn proc_macro_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("proc_macro_uniffi")
+}
+
+#[test]
+fn proc_macro_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("proc_macro_uniffi")
 }
@@ -0,0 +1,141 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Vec2 { final int dx;final int dy; Vec2(this.dx,this.dy,); } class FfiConverterVec2 { static Vec2 lift( RustBuffer buf) { return FfiConverterVec2.read(buf.asUint8List()).value; } static LiftRetVal<Vec2> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final dxLifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, newOffset)); final dx = dxLifted.value; newOffset += dxLifted.bytesRead;final dyLifted = FfiConverterInt32.read(Uint8List.view(buf.buffer, newOffset)); final dy = dyLifted.value; newOffset += dyLifted.bytesRead; return LiftRetVal(Vec2( dx,dy, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Vec2 value) { final totalLength = FfiConverterInt32.allocationSize(value.dx) +FfiConverterInt32.allocationSize(value.dy) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Vec2 value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterInt32.write(value.dx, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterInt32.write(value.dy, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Vec2 value) { return FfiConverterInt32.allocationSize(value.dx) +FfiConverterInt32.allocationSize(value.dy) + 0; } } enum Color { crimson,green, ; } class FfiConverterColor { static LiftRetVal<Color> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( Color.crimson, 4, );case 2: return LiftRetVal( Color.green, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static Color lift( RustBuffer buffer) { return FfiConverterColor.read(buffer.asUint8List()).value; } static RustBuffer lower( Color input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(Color value) { return 4; } static int write( Color value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }  enum ParseError implements Exception { empty,invalid, ; } class FfiConverterParseError { static LiftRetVal<ParseError> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( ParseError.empty, 4, );case 2: return LiftRetVal( ParseError.invalid, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static ParseError lift( RustBuffer buffer) { return FfiConverterParseError.read(buffer.asUint8List()).value; } static RustBuffer lower( ParseError input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(ParseError value) { return 4; } static int write( ParseError value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } } class ParseErrorErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterParseError.lift(errorBuf); } } final ParseErrorErrorHandler parseErrorErrorHandler = ParseErrorErrorHandler(); abstract class Shape { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterShape { static Shape lift( RustBuffer buffer) { return FfiConverterShape.read(buffer.asUint8List()).value; } static LiftRetVal<Shape> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return RoundShape.read(subview);case 2: return SquareShape.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( Shape value) { return value.lower(); } static int allocationSize(Shape value) { return value.allocationSize(); } static int write( Shape value, Uint8List buf) { return value.write(buf); } } class RoundShape extends Shape { final double radius; RoundShape(this.radius,); RoundShape._(this.radius,); static LiftRetVal<RoundShape> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final radiusLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final radius = radiusLifted.value; newOffset += radiusLifted.bytesRead; return LiftRetVal(RoundShape._( radius, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterDouble64.allocationSize(radius) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterDouble64.write(radius, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }class SquareShape extends Shape { final double side; SquareShape(this.side,); SquareShape._(this.side,); static LiftRetVal<SquareShape> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final sideLifted = FfiConverterDouble64.read(Uint8List.view(buf.buffer, newOffset)); final side = sideLifted.value; newOffset += sideLifted.bytesRead; return LiftRetVal(SquareShape._( side, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterDouble64.allocationSize(side) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 2); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterDouble64.write(side, Uint8List.view(buf.buffer, newOffset)); return newOffset; } }  abstract class GreeterInterface { String sayHello(); } final _greeterFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiRenamesFnFreeGreeter(ptr, status)); }); class Greeter implements GreeterInterface { late final Pointer<Void> _ptr; Greeter._(this._ptr) { _greeterFinalizer.attach(this, _ptr, detach: this); } Greeter(String name,) : _ptr = rustCall((status) => uniffiRenamesFnConstructorGreeterNew( FfiConverterString.lower(name), status ), null ) { _greeterFinalizer.attach(this, _ptr, detach: this); } factory Greeter.lift(Pointer<Void> ptr) { return Greeter._(ptr); } static Pointer<Void> lower(Greeter value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiRenamesFnCloneGreeter(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory Greeter.fromSendableHandle(int handle) { return Greeter._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(Greeter value) { return 8; } static LiftRetVal<Greeter> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(Greeter.lift(pointer), 8); } static int write(Greeter value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _greeterFinalizer.detach(this); rustCall((status) => uniffiRenamesFnFreeGreeter(_ptr, status)); } @override String sayHello() { return rustCallWithLifter( (status) => uniffiRenamesFnMethodGreeterGreet( uniffiClonePointer(), status ), FfiConverterString.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiRenamesRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiRenamesRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:renames"; String describe(Shape shape,) { return rustCallWithLifter( (status) => uniffiRenamesFnFuncDescribe( FfiConverterShape.lower(shape), status ), FfiConverterString.lift, null ); } Color favouriteColor() { return rustCallWithLifter( (status) => uniffiRenamesFnFuncFavouriteColor( status ), FfiConverterColor.lift, null ); } Vec2 createVec2(int x,int y,) { return rustCallWithLifter( (status) => uniffiRenamesFnFuncMakePoint( FfiConverterInt32.lower(x),FfiConverterInt32.lower(y), status ), FfiConverterVec2.lift, null ); } int parse(String input,) { return rustCallWithLifter( (status) => uniffiRenamesFnFuncParse( FfiConverterString.lower(input), status ), FfiConverterUInt32.lift, parseErrorErrorHandler ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_clone_greeter", assetId: _uniffiAssetId ) external Pointer<Void> uniffiRenamesFnCloneGreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_free_greeter", assetId: _uniffiAssetId ) external void uniffiRenamesFnFreeGreeter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_constructor_greeter_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiRenamesFnConstructorGreeterNew( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_method_greeter_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiRenamesFnMethodGreeterGreet( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_func_describe", assetId: _uniffiAssetId ) external RustBuffer uniffiRenamesFnFuncDescribe( RustBuffer shape, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_func_favourite_color", assetId: _uniffiAssetId ) external RustBuffer uniffiRenamesFnFuncFavouriteColor( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Int32, Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_func_make_point", assetId: _uniffiAssetId ) external RustBuffer uniffiRenamesFnFuncMakePoint( int x, int y, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_renames_fn_func_parse", assetId: _uniffiAssetId ) external int uniffiRenamesFnFuncParse( RustBuffer input, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiRenamesRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiRenamesRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiRenamesRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiRenamesRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiRenamesRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiRenamesRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiRenamesRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiRenamesRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_renames_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiRenamesRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_renames_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_renames_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiRenamesRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_func_describe", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumFuncDescribe();
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_func_favourite_color", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumFuncFavouriteColor();
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_func_make_point", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumFuncMakePoint();
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_func_parse", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumFuncParse();
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_method_greeter_greet", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumMethodGreeterGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_renames_checksum_constructor_greeter_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiRenamesChecksumConstructorGreeterNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_renames_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiRenamesUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiRenamesUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiRenamesChecksumFuncDescribe() != 25644) { mismatchedChecksums.add("uniffi_renames_checksum_func_describe"); }if (uniffiRenamesChecksumFuncFavouriteColor() != 15695) { mismatchedChecksums.add("uniffi_renames_checksum_func_favourite_color"); }if (uniffiRenamesChecksumFuncMakePoint() != 131) { mismatchedChecksums.add("uniffi_renames_checksum_func_make_point"); }if (uniffiRenamesChecksumFuncParse() != 57759) { mismatchedChecksums.add("uniffi_renames_checksum_func_parse"); }if (uniffiRenamesChecksumMethodGreeterGreet() != 62191) { mismatchedChecksums.add("uniffi_renames_checksum_method_greeter_greet"); }if (uniffiRenamesChecksumConstructorGreeterNew() != 62231) { mismatchedChecksums.add("uniffi_renames_checksum_constructor_greeter_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -0,0 +1,60 @@
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+pub enum Color {
+    Red,
+    Green,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error("empty input")]
+    Empty,
+    #[error("invalid input")]
+    Invalid,
+}
+
+fn make_point(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+fn describe(shape: Shape) -> String {
+    match shape {
+        Shape::Circle { radius } => format!("circle of radius {radius}"),
+        Shape::Square { side } => format!("square of side {side}"),
+    }
+}
+
+fn favourite_color() -> Color {
+    Color::Red
+}
+
+fn parse(input: String) -> Result<u32, ParseError> {
+    if input.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    input.parse().map_err(|_| ParseError::Invalid)
+}
+
+pub struct Greeter {
+    name: String,
+}
+
+impl Greeter {
+    fn new(name: String) -> Self {
+        Self { name }
+    }
+
+    fn greet(&self) -> String {
+        format!("Hello, {}!", self.name)
+    }
+}
+
+uniffi::include_scaffolding!("api");
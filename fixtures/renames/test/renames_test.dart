@@ -0,0 +1,26 @@
+import 'package:test/test.dart';
+import '../renames.dart';
+
+void main() {
+  test('records, fields and functions use configured names', () {
+    final Vec2 point = createVec2(1, 2);
+    expect(point.dx, 1);
+    expect(point.dy, 2);
+  });
+
+  test('enum variants use configured names', () {
+    expect(describe(RoundShape(2.0)), 'circle of radius 2');
+    expect(describe(SquareShape(3.0)), 'square of side 3');
+    expect(favouriteColor(), Color.crimson);
+  });
+
+  test('methods use configured names', () {
+    expect(Greeter('Dart').sayHello(), 'Hello, Dart!');
+  });
+
+  test('error types keep their Error suffix', () {
+    expect(parse('42'), 42);
+    expect(() => parse(''), throwsA(ParseError.empty));
+    expect(() => parse('x'), throwsA(ParseError.invalid));
+  });
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+#[test]
+fn renames() -> Result<()> {
+    uniffi_dart::testing::run_test("renames", "src/api.udl", None)
+}
+
+#[test]
+fn unknown_renames_are_reported() -> Result<()> {
+    uniffi_dart::testing::assert_generation_fails(
+        "renames",
+        "src/api.udl",
+        Some("unknown.toml"),
+        &["`rename` refers to `Point.z`"],
+    )
+}
+
+#[test]
+fn renames_to_reserved_names_are_reported() -> Result<()> {
+    uniffi_dart::testing::assert_generation_fails(
+        "renames",
+        "src/api.udl",
+        Some("reserved_class.toml"),
+        &["`Point` is renamed to `Object`, which is reserved"],
+    )?;
+    uniffi_dart::testing::assert_generation_fails(
+        "renames",
+        "src/api.udl",
+        Some("reserved_member.toml"),
+        &["`Greeter.greet` is renamed to `dispose`, which is reserved"],
+    )
+}
+
+#[test]
+fn renames_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("renames", "src/api.udl", None)
+}
+
+#[test]
+fn renames_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("renames", "src/api.udl", None)
+}
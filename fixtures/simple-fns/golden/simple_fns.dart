@@ -0,0 +1,169 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+ import "uniffi_runtime.dart"; export "uniffi_runtime.dart";  abstract class MyHashSetInterface { void add(String value);bool contains(String value); } final _myHashSetFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiSimpleFnsFnFreeMyhashset(ptr, status)); }); class MyHashSet implements MyHashSetInterface { late final Pointer<Void> _ptr; MyHashSet._(this._ptr) { _myHashSetFinalizer.attach(this, _ptr, detach: this); } MyHashSet() : _ptr = rustCall((status) => uniffiSimpleFnsFnConstructorMyhashsetNew( status ), null ) { _myHashSetFinalizer.attach(this, _ptr, detach: this); } factory MyHashSet.lift(Pointer<Void> ptr) { return MyHashSet._(ptr); } static Pointer<Void> lower(MyHashSet value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiSimpleFnsFnCloneMyhashset(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory MyHashSet.fromSendableHandle(int handle) { return MyHashSet._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(MyHashSet value) { return 8; } static LiftRetVal<MyHashSet> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(MyHashSet.lift(pointer), 8); } static int write(MyHashSet value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _myHashSetFinalizer.detach(this); rustCall((status) => uniffiSimpleFnsFnFreeMyhashset(_ptr, status)); } @override void add(String value,) { return rustCall((status) { uniffiSimpleFnsFnMethodMyhashsetAdd( uniffiClonePointer(), FfiConverterString.lower(value), status ); }, null); } @override bool contains(String value,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnMethodMyhashsetContains( uniffiClonePointer(), FfiConverterString.lower(value), status ), FfiConverterBool.lift, null ); } }  void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiSimpleFnsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiSimpleFnsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1); } static int lower(int value) { if (value < 0 || value > 255) { throw ArgumentError("Value out of range for u8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, lower(value)); return 1; } } class FfiConverterInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1); } static int lower(int value) { if (value < -128 || value > 127) { throw ArgumentError("Value out of range for i8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, lower(value)); return 1; } } class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalInt8 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt8.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt8.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt8.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt8.allocationSize(value)); FfiConverterOptionalInt8.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt8.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterMapStringToString { static Map<String, String> lift(RustBuffer buf) { return FfiConverterMapStringToString.read(buf.asUint8List()).value; } static LiftRetVal<Map<String, String>> read(Uint8List buf) { final map = <String, String>{}; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final k = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += k.bytesRead; final v = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += v.bytesRead; map[k.value] = v.value; } return LiftRetVal(map, offset - buf.offsetInBytes); } static int write(Map<String, String> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (final entry in value.entries) { offset += FfiConverterString.write(entry.key, Uint8List.view(buf.buffer, offset)); offset += FfiConverterString.write(entry.value, Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(Map<String, String> value) { return value.entries .map((e) => FfiConverterString.allocationSize(e.key) + FfiConverterString.allocationSize(e.value)) .fold(4, (a, b) => a + b); } static RustBuffer lower(Map<String, String> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } class FfiConverterMapMyHashSetToUInt32 { static Map<MyHashSet, int> lift(RustBuffer buf) { return FfiConverterMapMyHashSetToUInt32.read(buf.asUint8List()).value; } static LiftRetVal<Map<MyHashSet, int>> read(Uint8List buf) { final map = <MyHashSet, int>{}; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final k = MyHashSet.read(Uint8List.view(buf.buffer, offset)); offset += k.bytesRead; final v = FfiConverterUInt32.read(Uint8List.view(buf.buffer, offset)); offset += v.bytesRead; map[k.value] = v.value; } return LiftRetVal(map, offset - buf.offsetInBytes); } static int write(Map<MyHashSet, int> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (final entry in value.entries) { offset += MyHashSet.write(entry.key, Uint8List.view(buf.buffer, offset)); offset += FfiConverterUInt32.write(entry.value, Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(Map<MyHashSet, int> value) { return value.entries .map((e) => MyHashSet.allocationSize(e.key) + FfiConverterUInt32.allocationSize(e.value)) .fold(4, (a, b) => a + b); } static RustBuffer lower(Map<MyHashSet, int> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:simple_fns"; void addToSet(MyHashSet set_,String value,) { return rustCall((status) { uniffiSimpleFnsFnFuncAddToSet( MyHashSet.lower(set_),FfiConverterString.lower(value), status ); }, null); } int byteToU32(int byte,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncByteToU32( FfiConverterUInt8.lower(byte), status ), FfiConverterUInt32.lift, null ); } void dummy(int? arg,) { return rustCall((status) { uniffiSimpleFnsFnFuncDummy( FfiConverterOptionalInt8.lower(arg), status ); }, null); } int getInt() { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncGetInt( status ), FfiConverterInt32.lift, null ); } String getString() { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncGetString( status ), FfiConverterString.lift, null ); } Map<String, String> hashMapIdentity(Map<String, String> h,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncHashMapIdentity( FfiConverterMapStringToString.lower(h), status ), FfiConverterMapStringToString.lift, null ); } MyHashSet newSet() { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncNewSet( status ), MyHashSet.lift, null ); } Map<MyHashSet, int> objectMapIdentity(Map<MyHashSet, int> m,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncObjectMapIdentity( FfiConverterMapMyHashSetToUInt32.lower(m), status ), FfiConverterMapMyHashSetToUInt32.lift, null ); } bool setContains(MyHashSet set_,String value,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncSetContains( MyHashSet.lower(set_),FfiConverterString.lower(value), status ), FfiConverterBool.lift, null ); } String stringIdentity(String s,) { return rustCallWithLifter( (status) => uniffiSimpleFnsFnFuncStringIdentity( FfiConverterString.lower(s), status ), FfiConverterString.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_clone_myhashset", assetId: _uniffiAssetId ) external Pointer<Void> uniffiSimpleFnsFnCloneMyhashset( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_free_myhashset", assetId: _uniffiAssetId ) external void uniffiSimpleFnsFnFreeMyhashset( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_constructor_myhashset_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiSimpleFnsFnConstructorMyhashsetNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_method_myhashset_add", assetId: _uniffiAssetId ) external void uniffiSimpleFnsFnMethodMyhashsetAdd( Pointer<Void> ptr, RustBuffer value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_method_myhashset_contains", assetId: _uniffiAssetId ) external int uniffiSimpleFnsFnMethodMyhashsetContains( Pointer<Void> ptr, RustBuffer value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_add_to_set", assetId: _uniffiAssetId ) external void uniffiSimpleFnsFnFuncAddToSet( Pointer<Void> set_, RustBuffer value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Uint8, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_byte_to_u32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsFnFuncByteToU32( int byte, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_dummy", assetId: _uniffiAssetId ) external void uniffiSimpleFnsFnFuncDummy( RustBuffer arg, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_get_int", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsFnFuncGetInt( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_get_string", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleFnsFnFuncGetString( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_hash_map_identity", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleFnsFnFuncHashMapIdentity( RustBuffer h, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_new_set", assetId: _uniffiAssetId ) external Pointer<Void> uniffiSimpleFnsFnFuncNewSet( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_object_map_identity", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleFnsFnFuncObjectMapIdentity( RustBuffer m, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_set_contains", assetId: _uniffiAssetId ) external int uniffiSimpleFnsFnFuncSetContains( Pointer<Void> set_, RustBuffer value, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_simple_fns_fn_func_string_identity", assetId: _uniffiAssetId ) external RustBuffer uniffiSimpleFnsFnFuncStringIdentity( RustBuffer s, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleFnsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleFnsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleFnsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiSimpleFnsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiSimpleFnsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiSimpleFnsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiSimpleFnsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_simple_fns_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_simple_fns_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiSimpleFnsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_add_to_set", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncAddToSet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_byte_to_u32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncByteToU32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_dummy", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncDummy();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_get_int", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncGetInt();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_get_string", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncGetString();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_hash_map_identity", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncHashMapIdentity();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_new_set", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncNewSet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_object_map_identity", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncObjectMapIdentity();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_set_contains", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncSetContains();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_func_string_identity", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumFuncStringIdentity();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_method_myhashset_add", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumMethodMyhashsetAdd();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_method_myhashset_contains", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumMethodMyhashsetContains();
+
+@Native<Uint16 Function()>( symbol: "uniffi_simple_fns_checksum_constructor_myhashset_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiSimpleFnsChecksumConstructorMyhashsetNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_simple_fns_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiSimpleFnsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiSimpleFnsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiSimpleFnsChecksumFuncAddToSet() != 6195) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_add_to_set"); }if (uniffiSimpleFnsChecksumFuncByteToU32() != 25150) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_byte_to_u32"); }if (uniffiSimpleFnsChecksumFuncDummy() != 36822) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_dummy"); }if (uniffiSimpleFnsChecksumFuncGetInt() != 56078) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_get_int"); }if (uniffiSimpleFnsChecksumFuncGetString() != 20849) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_get_string"); }if (uniffiSimpleFnsChecksumFuncHashMapIdentity() != 23645) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_hash_map_identity"); }if (uniffiSimpleFnsChecksumFuncNewSet() != 60547) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_new_set"); }if (uniffiSimpleFnsChecksumFuncObjectMapIdentity() != 17787) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_object_map_identity"); }if (uniffiSimpleFnsChecksumFuncSetContains() != 54516) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_set_contains"); }if (uniffiSimpleFnsChecksumFuncStringIdentity() != 2963) { mismatchedChecksums.add("uniffi_simple_fns_checksum_func_string_identity"); }if (uniffiSimpleFnsChecksumMethodMyhashsetAdd() != 32145) { mismatchedChecksums.add("uniffi_simple_fns_checksum_method_myhashset_add"); }if (uniffiSimpleFnsChecksumMethodMyhashsetContains() != 58905) { mismatchedChecksums.add("uniffi_simple_fns_checksum_method_myhashset_contains"); }if (uniffiSimpleFnsChecksumConstructorMyhashsetNew() != 28297) { mismatchedChecksums.add("uniffi_simple_fns_checksum_constructor_myhashset_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
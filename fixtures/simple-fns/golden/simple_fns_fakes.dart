@@ -0,0 +1 @@
+import "simple_fns.dart"; import "simple_fns.dart" as uniffi_bindings; abstract class SimpleFnsFunctions { void addToSet(covariant MyHashSetInterface set_, String value);int byteToU32(int byte);void dummy(int? arg);int getInt();String getString();Map<String, String> hashMapIdentity(Map<String, String> h);MyHashSetInterface newSet();Map<MyHashSetInterface, int> objectMapIdentity(covariant Map<MyHashSetInterface, int> m);bool setContains(covariant MyHashSetInterface set_, String value);String stringIdentity(String s); } class RustSimpleFnsFunctions implements SimpleFnsFunctions { const RustSimpleFnsFunctions(); @override void addToSet(MyHashSet set_, String value) => uniffi_bindings.addToSet(set_, value);@override int byteToU32(int byte) => uniffi_bindings.byteToU32(byte);@override void dummy(int? arg) => uniffi_bindings.dummy(arg);@override int getInt() => uniffi_bindings.getInt();@override String getString() => uniffi_bindings.getString();@override Map<String, String> hashMapIdentity(Map<String, String> h) => uniffi_bindings.hashMapIdentity(h);@override MyHashSetInterface newSet() => uniffi_bindings.newSet();@override Map<MyHashSetInterface, int> objectMapIdentity(Map<MyHashSet, int> m) => uniffi_bindings.objectMapIdentity(m);@override bool setContains(MyHashSet set_, String value) => uniffi_bindings.setContains(set_, value);@override String stringIdentity(String s) => uniffi_bindings.stringIdentity(s); } class FakeSimpleFnsFunctions implements SimpleFnsFunctions { void Function(MyHashSetInterface set_, String value)? onAddToSet;int Function(int byte)? onByteToU32;void Function(int? arg)? onDummy;int Function()? onGetInt;String Function()? onGetString;Map<String, String> Function(Map<String, String> h)? onHashMapIdentity;MyHashSetInterface Function()? onNewSet;Map<MyHashSetInterface, int> Function(Map<MyHashSetInterface, int> m)? onObjectMapIdentity;bool Function(MyHashSetInterface set_, String value)? onSetContains;String Function(String s)? onStringIdentity; FakeSimpleFnsFunctions({this.onAddToSet, this.onByteToU32, this.onDummy, this.onGetInt, this.onGetString, this.onHashMapIdentity, this.onNewSet, this.onObjectMapIdentity, this.onSetContains, this.onStringIdentity}); @override void addToSet(MyHashSetInterface set_, String value) => (onAddToSet ?? (throw UnimplementedError("FakeSimpleFnsFunctions.addToSet isn't faked")))(set_, value);@override int byteToU32(int byte) => (onByteToU32 ?? (throw UnimplementedError("FakeSimpleFnsFunctions.byteToU32 isn't faked")))(byte);@override void dummy(int? arg) => (onDummy ?? (throw UnimplementedError("FakeSimpleFnsFunctions.dummy isn't faked")))(arg);@override int getInt() => (onGetInt ?? (throw UnimplementedError("FakeSimpleFnsFunctions.getInt isn't faked")))();@override String getString() => (onGetString ?? (throw UnimplementedError("FakeSimpleFnsFunctions.getString isn't faked")))();@override Map<String, String> hashMapIdentity(Map<String, String> h) => (onHashMapIdentity ?? (throw UnimplementedError("FakeSimpleFnsFunctions.hashMapIdentity isn't faked")))(h);@override MyHashSetInterface newSet() => (onNewSet ?? (throw UnimplementedError("FakeSimpleFnsFunctions.newSet isn't faked")))();@override Map<MyHashSetInterface, int> objectMapIdentity(Map<MyHashSetInterface, int> m) => (onObjectMapIdentity ?? (throw UnimplementedError("FakeSimpleFnsFunctions.objectMapIdentity isn't faked")))(m);@override bool setContains(MyHashSetInterface set_, String value) => (onSetContains ?? (throw UnimplementedError("FakeSimpleFnsFunctions.setContains isn't faked")))(set_, value);@override String stringIdentity(String s) => (onStringIdentity ?? (throw UnimplementedError("FakeSimpleFnsFunctions.stringIdentity isn't faked")))(s); } class FakeMyHashSet implements MyHashSetInterface { void Function(String value)? onAdd;bool Function(String value)? onContains; FakeMyHashSet({this.onAdd, this.onContains}); @override void add(String value) => (onAdd ?? (throw UnimplementedError("FakeMyHashSet.add isn't faked")))(value);@override bool contains(String value) => (onContains ?? (throw UnimplementedError("FakeMyHashSet.contains isn't faked")))(value); }
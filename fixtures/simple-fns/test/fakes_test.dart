@@ -0,0 +1,62 @@
+import 'package:test/test.dart';
+import '../simple_fns.dart';
+import '../simple_fns_fakes.dart';
+
+int countMatches(SimpleFnsFunctions functions, List<String> words) {
+  return words.where((word) => functions.stringIdentity(word) == word).length;
+}
+
+void main() {
+  group('Fakes', () {
+    test('fake functions answer through their handlers', () {
+      final fake = FakeSimpleFnsFunctions(
+        onGetInt: () => 42,
+        onStringIdentity: (s) => s.toUpperCase(),
+      );
+      expect(fake.getInt(), 42);
+      expect(countMatches(fake, ['a', 'B', 'C']), 2);
+
+      fake.onGetInt = () => 7;
+      expect(fake.getInt(), 7);
+    });
+
+    test('calls without a handler throw UnimplementedError', () {
+      final fake = FakeSimpleFnsFunctions();
+      expect(() => fake.getString(), throwsA(isA<UnimplementedError>()));
+    });
+
+    test('Rust functions call the bindings', () {
+      const functions = RustSimpleFnsFunctions();
+      expect(functions.getInt(), 1289);
+      expect(countMatches(functions, ['a', 'B', 'C']), 3);
+    });
+
+    test('fake objects implement the object interface', () {
+      final values = <String>{};
+      final MyHashSetInterface set = FakeMyHashSet(
+        onAdd: values.add,
+        onContains: values.contains,
+      );
+      set.add('hello');
+      expect(set.contains('hello'), isTrue);
+      expect(set.contains('world'), isFalse);
+    });
+
+    test('fake objects pass through fake functions', () {
+      final values = <String>{};
+      final SimpleFnsFunctions functions = FakeSimpleFnsFunctions(
+        onNewSet: () => FakeMyHashSet(
+          onAdd: values.add,
+          onContains: values.contains,
+        ),
+        onAddToSet: (set_, value) => set_.add(value),
+        onSetContains: (set_, value) => set_.contains(value),
+      );
+      final set = functions.newSet();
+      functions.addToSet(set, 'hello');
+      expect(functions.setContains(set, 'hello'), isTrue);
+      expect(functions.setContains(set, 'world'), isFalse);
+      expect(values, {'hello'});
+    });
+  });
+}
@@ -2,5 +2,19 @@ use anyhow::Result;
 
 #[test]
 fn simple_fns() -> Result<()> {
-    uniffi_dart::testing::run_test("simple_fns", "src/api.udl", None)
+    uniffi_dart::testing::run_test("simple_fns", "src/api.udl", Some("uniffi.toml"))
+}
+
+#[test]
+fn simple_fns_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output(
+        "simple_fns",
+        "src/api.udl",
+        Some("uniffi.toml"),
+    )
+}
+
+#[test]
+fn simple_fns_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("simple_fns", "src/api.udl", Some("uniffi.toml"))
 }
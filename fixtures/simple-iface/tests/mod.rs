@@ -4,3 +4,13 @@ use anyhow::Result;
 fn simple_iface() -> Result<()> {
     uniffi_dart::testing::run_test("simple_iface", "src/api.udl", None)
 }
+
+#[test]
+fn simple_iface_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("simple_iface", "src/api.udl", None)
+}
+
+#[test]
+fn simple_iface_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("simple_iface", "src/api.udl", None)
+}
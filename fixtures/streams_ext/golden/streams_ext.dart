@@ -0,0 +1,193 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class AsyncTimerStreamStreamExtInterface { Future<int?> next(); } final _asyncTimerStreamStreamExtFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiStreamsExtFnFreeAsynctimerstreamstreamext(ptr, status)); }); class AsyncTimerStreamStreamExt implements AsyncTimerStreamStreamExtInterface { late final Pointer<Void> _ptr; AsyncTimerStreamStreamExt._(this._ptr) { _asyncTimerStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } AsyncTimerStreamStreamExt() : _ptr = rustCall((status) => uniffiStreamsExtFnConstructorAsynctimerstreamstreamextNew( status ), null ) { _asyncTimerStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } factory AsyncTimerStreamStreamExt.lift(Pointer<Void> ptr) { return AsyncTimerStreamStreamExt._(ptr); } static Pointer<Void> lower(AsyncTimerStreamStreamExt value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiStreamsExtFnCloneAsynctimerstreamstreamext(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory AsyncTimerStreamStreamExt.fromSendableHandle(int handle) { return AsyncTimerStreamStreamExt._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(AsyncTimerStreamStreamExt value) { return 8; } static LiftRetVal<AsyncTimerStreamStreamExt> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(AsyncTimerStreamStreamExt.lift(pointer), 8); } static int write(AsyncTimerStreamStreamExt value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _asyncTimerStreamStreamExtFinalizer.detach(this); rustCall((status) => uniffiStreamsExtFnFreeAsynctimerstreamstreamext(_ptr, status)); } @override Future<int?> next() { return uniffiRustCallAsync( () => uniffiStreamsExtFnMethodAsynctimerstreamstreamextNext( uniffiClonePointer(), ), ffiStreamsExtRustFuturePollRustBuffer, ffiStreamsExtRustFutureCompleteRustBuffer, ffiStreamsExtRustFutureFreeRustBuffer, FfiConverterOptionalUInt64.lift, null, ); } } asyncTimerStream() async* { final asyncTimerStream = createStreamAsyncTimerStream(); try { while (true) { final value = await asyncTimerStream.next(); if (value == null) { break; } yield value; } } catch (e) { rethrow; } } abstract class CombinedStreamsStreamExtInterface { Future<String?> next(); } final _combinedStreamsStreamExtFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiStreamsExtFnFreeCombinedstreamsstreamext(ptr, status)); }); class CombinedStreamsStreamExt implements CombinedStreamsStreamExtInterface { late final Pointer<Void> _ptr; CombinedStreamsStreamExt._(this._ptr) { _combinedStreamsStreamExtFinalizer.attach(this, _ptr, detach: this); } CombinedStreamsStreamExt() : _ptr = rustCall((status) => uniffiStreamsExtFnConstructorCombinedstreamsstreamextNew( status ), null ) { _combinedStreamsStreamExtFinalizer.attach(this, _ptr, detach: this); } factory CombinedStreamsStreamExt.lift(Pointer<Void> ptr) { return CombinedStreamsStreamExt._(ptr); } static Pointer<Void> lower(CombinedStreamsStreamExt value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiStreamsExtFnCloneCombinedstreamsstreamext(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory CombinedStreamsStreamExt.fromSendableHandle(int handle) { return CombinedStreamsStreamExt._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(CombinedStreamsStreamExt value) { return 8; } static LiftRetVal<CombinedStreamsStreamExt> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(CombinedStreamsStreamExt.lift(pointer), 8); } static int write(CombinedStreamsStreamExt value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _combinedStreamsStreamExtFinalizer.detach(this); rustCall((status) => uniffiStreamsExtFnFreeCombinedstreamsstreamext(_ptr, status)); } @override Future<String?> next() { return uniffiRustCallAsync( () => uniffiStreamsExtFnMethodCombinedstreamsstreamextNext( uniffiClonePointer(), ), ffiStreamsExtRustFuturePollRustBuffer, ffiStreamsExtRustFutureCompleteRustBuffer, ffiStreamsExtRustFutureFreeRustBuffer, FfiConverterOptionalString.lift, null, ); } } combinedStreams() async* { final combinedStreams = createStreamCombinedStreams(); try { while (true) { final value = await combinedStreams.next(); if (value == null) { break; } yield value; } } catch (e) { rethrow; } } abstract class CountStreamStreamExtInterface { Future<int?> next(); } final _countStreamStreamExtFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiStreamsExtFnFreeCountstreamstreamext(ptr, status)); }); class CountStreamStreamExt implements CountStreamStreamExtInterface { late final Pointer<Void> _ptr; CountStreamStreamExt._(this._ptr) { _countStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } CountStreamStreamExt() : _ptr = rustCall((status) => uniffiStreamsExtFnConstructorCountstreamstreamextNew( status ), null ) { _countStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } factory CountStreamStreamExt.lift(Pointer<Void> ptr) { return CountStreamStreamExt._(ptr); } static Pointer<Void> lower(CountStreamStreamExt value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiStreamsExtFnCloneCountstreamstreamext(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory CountStreamStreamExt.fromSendableHandle(int handle) { return CountStreamStreamExt._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(CountStreamStreamExt value) { return 8; } static LiftRetVal<CountStreamStreamExt> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(CountStreamStreamExt.lift(pointer), 8); } static int write(CountStreamStreamExt value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _countStreamStreamExtFinalizer.detach(this); rustCall((status) => uniffiStreamsExtFnFreeCountstreamstreamext(_ptr, status)); } @override Future<int?> next() { return uniffiRustCallAsync( () => uniffiStreamsExtFnMethodCountstreamstreamextNext( uniffiClonePointer(), ), ffiStreamsExtRustFuturePollRustBuffer, ffiStreamsExtRustFutureCompleteRustBuffer, ffiStreamsExtRustFutureFreeRustBuffer, FfiConverterOptionalInt32.lift, null, ); } } countStream() async* { final countStream = createStreamCountStream(); try { while (true) { final value = await countStream.next(); if (value == null) { break; } yield value; } } catch (e) { rethrow; } } abstract class FibonacciStreamStreamExtInterface { Future<int?> next(); } final _fibonacciStreamStreamExtFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiStreamsExtFnFreeFibonaccistreamstreamext(ptr, status)); }); class FibonacciStreamStreamExt implements FibonacciStreamStreamExtInterface { late final Pointer<Void> _ptr; FibonacciStreamStreamExt._(this._ptr) { _fibonacciStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } FibonacciStreamStreamExt() : _ptr = rustCall((status) => uniffiStreamsExtFnConstructorFibonaccistreamstreamextNew( status ), null ) { _fibonacciStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } factory FibonacciStreamStreamExt.lift(Pointer<Void> ptr) { return FibonacciStreamStreamExt._(ptr); } static Pointer<Void> lower(FibonacciStreamStreamExt value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiStreamsExtFnCloneFibonaccistreamstreamext(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory FibonacciStreamStreamExt.fromSendableHandle(int handle) { return FibonacciStreamStreamExt._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(FibonacciStreamStreamExt value) { return 8; } static LiftRetVal<FibonacciStreamStreamExt> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(FibonacciStreamStreamExt.lift(pointer), 8); } static int write(FibonacciStreamStreamExt value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _fibonacciStreamStreamExtFinalizer.detach(this); rustCall((status) => uniffiStreamsExtFnFreeFibonaccistreamstreamext(_ptr, status)); } @override Future<int?> next() { return uniffiRustCallAsync( () => uniffiStreamsExtFnMethodFibonaccistreamstreamextNext( uniffiClonePointer(), ), ffiStreamsExtRustFuturePollRustBuffer, ffiStreamsExtRustFutureCompleteRustBuffer, ffiStreamsExtRustFutureFreeRustBuffer, FfiConverterOptionalUInt64.lift, null, ); } } fibonacciStream() async* { final fibonacciStream = createStreamFibonacciStream(); try { while (true) { final value = await fibonacciStream.next(); if (value == null) { break; } yield value; } } catch (e) { rethrow; } } abstract class SimpleStreamStreamExtInterface { Future<int?> next(); } final _simpleStreamStreamExtFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiStreamsExtFnFreeSimplestreamstreamext(ptr, status)); }); class SimpleStreamStreamExt implements SimpleStreamStreamExtInterface { late final Pointer<Void> _ptr; SimpleStreamStreamExt._(this._ptr) { _simpleStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } SimpleStreamStreamExt() : _ptr = rustCall((status) => uniffiStreamsExtFnConstructorSimplestreamstreamextNew( status ), null ) { _simpleStreamStreamExtFinalizer.attach(this, _ptr, detach: this); } factory SimpleStreamStreamExt.lift(Pointer<Void> ptr) { return SimpleStreamStreamExt._(ptr); } static Pointer<Void> lower(SimpleStreamStreamExt value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiStreamsExtFnCloneSimplestreamstreamext(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory SimpleStreamStreamExt.fromSendableHandle(int handle) { return SimpleStreamStreamExt._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(SimpleStreamStreamExt value) { return 8; } static LiftRetVal<SimpleStreamStreamExt> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(SimpleStreamStreamExt.lift(pointer), 8); } static int write(SimpleStreamStreamExt value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _simpleStreamStreamExtFinalizer.detach(this); rustCall((status) => uniffiStreamsExtFnFreeSimplestreamstreamext(_ptr, status)); } @override Future<int?> next() { return uniffiRustCallAsync( () => uniffiStreamsExtFnMethodSimplestreamstreamextNext( uniffiClonePointer(), ), ffiStreamsExtRustFuturePollRustBuffer, ffiStreamsExtRustFutureCompleteRustBuffer, ffiStreamsExtRustFutureFreeRustBuffer, FfiConverterOptionalInt32.lift, null, ); } } simpleStream() async* { final simpleStream = createStreamSimpleStream(); try { while (true) { final value = await simpleStream.next(); if (value == null) { break; } yield value; } } catch (e) { rethrow; } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiStreamsExtRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiStreamsExtRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalInt32 { static int? lift( RustBuffer buf) { return FfiConverterOptionalInt32.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterInt32.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterInt32.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalInt32.allocationSize(value)); FfiConverterOptionalInt32.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterInt32.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalUInt64 { static int? lift( RustBuffer buf) { return FfiConverterOptionalUInt64.read(buf.asUint8List()).value; } static LiftRetVal<int?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterUInt64.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<int?>(result.value, result.bytesRead + 1); } static int allocationSize([int? value]) { if (value == null) { return 1; } return FfiConverterUInt64.allocationSize(value) + 1; } static RustBuffer lower( int? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalUInt64.allocationSize(value)); FfiConverterOptionalUInt64.write(value, buf); return toRustBuffer(buf); } static int write( int? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterUInt64.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalString { static String? lift( RustBuffer buf) { return FfiConverterOptionalString.read(buf.asUint8List()).value; } static LiftRetVal<String?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterString.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<String?>(result.value, result.bytesRead + 1); } static int allocationSize([String? value]) { if (value == null) { return 1; } return FfiConverterString.allocationSize(value) + 1; } static RustBuffer lower( String? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalString.allocationSize(value)); FfiConverterOptionalString.write(value, buf); return toRustBuffer(buf); } static int write( String? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterString.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:streams_ext"; AsyncTimerStreamStreamExt createStreamAsyncTimerStream() { return rustCallWithLifter( (status) => uniffiStreamsExtFnFuncCreateStreamAsyncTimerStream( status ), AsyncTimerStreamStreamExt.lift, null ); } CombinedStreamsStreamExt createStreamCombinedStreams() { return rustCallWithLifter( (status) => uniffiStreamsExtFnFuncCreateStreamCombinedStreams( status ), CombinedStreamsStreamExt.lift, null ); } CountStreamStreamExt createStreamCountStream() { return rustCallWithLifter( (status) => uniffiStreamsExtFnFuncCreateStreamCountStream( status ), CountStreamStreamExt.lift, null ); } FibonacciStreamStreamExt createStreamFibonacciStream() { return rustCallWithLifter( (status) => uniffiStreamsExtFnFuncCreateStreamFibonacciStream( status ), FibonacciStreamStreamExt.lift, null ); } SimpleStreamStreamExt createStreamSimpleStream() { return rustCallWithLifter( (status) => uniffiStreamsExtFnFuncCreateStreamSimpleStream( status ), SimpleStreamStreamExt.lift, null ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_clone_asynctimerstreamstreamext", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnCloneAsynctimerstreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_free_asynctimerstreamstreamext", assetId: _uniffiAssetId ) external void uniffiStreamsExtFnFreeAsynctimerstreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_constructor_asynctimerstreamstreamext_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnConstructorAsynctimerstreamstreamextNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_streams_ext_fn_method_asynctimerstreamstreamext_next", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnMethodAsynctimerstreamstreamextNext( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_clone_combinedstreamsstreamext", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnCloneCombinedstreamsstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_free_combinedstreamsstreamext", assetId: _uniffiAssetId ) external void uniffiStreamsExtFnFreeCombinedstreamsstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_constructor_combinedstreamsstreamext_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnConstructorCombinedstreamsstreamextNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_streams_ext_fn_method_combinedstreamsstreamext_next", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnMethodCombinedstreamsstreamextNext( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_clone_countstreamstreamext", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnCloneCountstreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_free_countstreamstreamext", assetId: _uniffiAssetId ) external void uniffiStreamsExtFnFreeCountstreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_constructor_countstreamstreamext_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnConstructorCountstreamstreamextNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_streams_ext_fn_method_countstreamstreamext_next", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnMethodCountstreamstreamextNext( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_clone_fibonaccistreamstreamext", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnCloneFibonaccistreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_free_fibonaccistreamstreamext", assetId: _uniffiAssetId ) external void uniffiStreamsExtFnFreeFibonaccistreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_constructor_fibonaccistreamstreamext_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnConstructorFibonaccistreamstreamextNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_streams_ext_fn_method_fibonaccistreamstreamext_next", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnMethodFibonaccistreamstreamextNext( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_clone_simplestreamstreamext", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnCloneSimplestreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_free_simplestreamstreamext", assetId: _uniffiAssetId ) external void uniffiStreamsExtFnFreeSimplestreamstreamext( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_constructor_simplestreamstreamext_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnConstructorSimplestreamstreamextNew( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>)>( symbol: "uniffi_streams_ext_fn_method_simplestreamstreamext_next", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnMethodSimplestreamstreamextNext( Pointer<Void> ptr);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_func_create_stream_async_timer_stream", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnFuncCreateStreamAsyncTimerStream( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_func_create_stream_combined_streams", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnFuncCreateStreamCombinedStreams( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_func_create_stream_count_stream", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnFuncCreateStreamCountStream( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_func_create_stream_fibonacci_stream", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnFuncCreateStreamFibonacciStream( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<RustCallStatus>)>( symbol: "uniffi_streams_ext_fn_func_create_stream_simple_stream", assetId: _uniffiAssetId ) external Pointer<Void> uniffiStreamsExtFnFuncCreateStreamSimpleStream( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiStreamsExtRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiStreamsExtRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiStreamsExtRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiStreamsExtRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiStreamsExtRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiStreamsExtRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiStreamsExtRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiStreamsExtRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_streams_ext_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_streams_ext_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiStreamsExtRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_func_create_stream_async_timer_stream", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumFuncCreateStreamAsyncTimerStream();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_func_create_stream_combined_streams", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumFuncCreateStreamCombinedStreams();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_func_create_stream_count_stream", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumFuncCreateStreamCountStream();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_func_create_stream_fibonacci_stream", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumFuncCreateStreamFibonacciStream();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_func_create_stream_simple_stream", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumFuncCreateStreamSimpleStream();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_method_asynctimerstreamstreamext_next", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumMethodAsynctimerstreamstreamextNext();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_method_combinedstreamsstreamext_next", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumMethodCombinedstreamsstreamextNext();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_method_countstreamstreamext_next", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumMethodCountstreamstreamextNext();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_method_fibonaccistreamstreamext_next", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumMethodFibonaccistreamstreamextNext();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_method_simplestreamstreamext_next", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumMethodSimplestreamstreamextNext();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_constructor_asynctimerstreamstreamext_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumConstructorAsynctimerstreamstreamextNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_constructor_combinedstreamsstreamext_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumConstructorCombinedstreamsstreamextNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_constructor_countstreamstreamext_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumConstructorCountstreamstreamextNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_constructor_fibonaccistreamstreamext_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumConstructorFibonaccistreamstreamextNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_streams_ext_checksum_constructor_simplestreamstreamext_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiStreamsExtChecksumConstructorSimplestreamstreamextNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_streams_ext_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiStreamsExtUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiStreamsExtUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiStreamsExtChecksumFuncCreateStreamAsyncTimerStream() != 25494) { mismatchedChecksums.add("uniffi_streams_ext_checksum_func_create_stream_async_timer_stream"); }if (uniffiStreamsExtChecksumFuncCreateStreamCombinedStreams() != 5644) { mismatchedChecksums.add("uniffi_streams_ext_checksum_func_create_stream_combined_streams"); }if (uniffiStreamsExtChecksumFuncCreateStreamCountStream() != 6088) { mismatchedChecksums.add("uniffi_streams_ext_checksum_func_create_stream_count_stream"); }if (uniffiStreamsExtChecksumFuncCreateStreamFibonacciStream() != 30098) { mismatchedChecksums.add("uniffi_streams_ext_checksum_func_create_stream_fibonacci_stream"); }if (uniffiStreamsExtChecksumFuncCreateStreamSimpleStream() != 65023) { mismatchedChecksums.add("uniffi_streams_ext_checksum_func_create_stream_simple_stream"); }if (uniffiStreamsExtChecksumMethodAsynctimerstreamstreamextNext() != 15368) { mismatchedChecksums.add("uniffi_streams_ext_checksum_method_asynctimerstreamstreamext_next"); }if (uniffiStreamsExtChecksumMethodCombinedstreamsstreamextNext() != 5427) { mismatchedChecksums.add("uniffi_streams_ext_checksum_method_combinedstreamsstreamext_next"); }if (uniffiStreamsExtChecksumMethodCountstreamstreamextNext() != 4096) { mismatchedChecksums.add("uniffi_streams_ext_checksum_method_countstreamstreamext_next"); }if (uniffiStreamsExtChecksumMethodFibonaccistreamstreamextNext() != 54111) { mismatchedChecksums.add("uniffi_streams_ext_checksum_method_fibonaccistreamstreamext_next"); }if (uniffiStreamsExtChecksumMethodSimplestreamstreamextNext() != 6169) { mismatchedChecksums.add("uniffi_streams_ext_checksum_method_simplestreamstreamext_next"); }if (uniffiStreamsExtChecksumConstructorAsynctimerstreamstreamextNew() != 19547) { mismatchedChecksums.add("uniffi_streams_ext_checksum_constructor_asynctimerstreamstreamext_new"); }if (uniffiStreamsExtChecksumConstructorCombinedstreamsstreamextNew() != 15881) { mismatchedChecksums.add("uniffi_streams_ext_checksum_constructor_combinedstreamsstreamext_new"); }if (uniffiStreamsExtChecksumConstructorCountstreamstreamextNew() != 44207) { mismatchedChecksums.add("uniffi_streams_ext_checksum_constructor_countstreamstreamext_new"); }if (uniffiStreamsExtChecksumConstructorFibonaccistreamstreamextNew() != 9061) { mismatchedChecksums.add("uniffi_streams_ext_checksum_constructor_fibonaccistreamstreamext_new"); }if (uniffiStreamsExtChecksumConstructorSimplestreamstreamextNew() != 28556) { mismatchedChecksums.add("uniffi_streams_ext_checksum_constructor_simplestreamstreamext_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -4,3 +4,13 @@ use anyhow::Result;
 fn streams_ext() -> Result<()> {
     uniffi_dart::testing::run_test("streams_ext", "src/api.udl", None)
 }
+
+#[test]
+fn streams_ext_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("streams_ext", "src/api.udl", None)
+}
+
+#[test]
+fn streams_ext_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("streams_ext", "src/api.udl", None)
+}
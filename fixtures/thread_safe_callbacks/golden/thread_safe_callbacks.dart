@@ -0,0 +1,231 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class LookupException implements Exception { RustBuffer lower(); int allocationSize(); int write( Uint8List buf); } class FfiConverterLookupException { static LookupException lift( RustBuffer buffer) { return FfiConverterLookupException.read(buffer.asUint8List()).value; } static LiftRetVal<LookupException> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final subview = Uint8List.view(buf.buffer, buf.offsetInBytes + 4); switch(index) { case 1: return FailedLookupException.read(subview); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static RustBuffer lower( LookupException value) { return value.lower(); } static int allocationSize(LookupException value) { return value.allocationSize(); } static int write( LookupException value, Uint8List buf) { return value.write(buf); } } class FailedLookupException extends LookupException { final String reason; FailedLookupException(this.reason,); FailedLookupException._(this.reason,); static LiftRetVal<FailedLookupException> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final reasonLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final reason = reasonLifted.value; newOffset += reasonLifted.bytesRead; return LiftRetVal(FailedLookupException._( reason, ), newOffset); } @override RustBuffer lower() { final buf = Uint8List(allocationSize()); write(buf); return toRustBuffer(buf); } @override int allocationSize() { return FfiConverterString.allocationSize(reason) + 4; } @override int write( Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, 1); int newOffset = buf.offsetInBytes + 4; newOffset += FfiConverterString.write(reason, Uint8List.view(buf.buffer, newOffset)); return newOffset; } @override String toString() { return "FailedLookupException($reason)"; } } class LookupExceptionErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { return FfiConverterLookupException.lift(errorBuf); } } final LookupExceptionErrorHandler lookupExceptionErrorHandler = LookupExceptionErrorHandler(); abstract class EventSink { void onEvent(String message,);Future<int> transform(int value,); } class FfiConverterCallbackInterfaceEventSink { static final _handleMap = UniffiHandleMap<EventSink>(uniffiDartThreadSafeCallbacksEventsinkNewHandle); static bool _vtableInitialized = false; static EventSink lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(EventSink value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initEventSinkVTable(); _vtableInitialized = true; } } static LiftRetVal<EventSink> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(EventSink value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(EventSink value) { return 8; } } typedef UniffiCallbackInterfaceEventSinkMethod0 = Void Function( Uint64, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceEventSinkMethod0Dart = void Function( int, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceEventSinkMethod1 = Void Function( Uint64, Uint32, Pointer<NativeFunction<UniffiForeignFutureCompleteU32>>, Uint64, Pointer<UniffiForeignFuture>); typedef UniffiCallbackInterfaceEventSinkMethod1Dart = void Function( int, int, Pointer<NativeFunction<UniffiForeignFutureCompleteU32>>, int, Pointer<UniffiForeignFuture>);typedef UniffiCallbackInterfaceEventSinkMethod1Blocking = Void Function( Uint64, Uint32, Pointer<NativeFunction<UniffiForeignFutureCompleteU32>>, Uint64, Pointer<UniffiForeignFuture>, Pointer<Void>);typedef UniffiCallbackInterfaceEventSinkFree = Void Function(Uint64); typedef UniffiCallbackInterfaceEventSinkFreeDart = void Function(int); typedef UniffiCallbackInterfaceEventSinkClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceEventSinkCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceEventSink extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod0>> onEvent;external Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod1>> transform; }  void eventSinkOnEvent(int uniffiHandle, RustBuffer message, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceEventSink._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(message); obj.onEvent(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod0>> eventSinkOnEventPointer = Pointer.fromFunction<UniffiCallbackInterfaceEventSinkMethod0>(eventSinkOnEvent);
+
+void eventSinkOnEventQueued(int uniffiHandle, RustBuffer message, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final obj = FfiConverterCallbackInterfaceEventSink._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(message); obj.onEvent(arg0,); } final _eventSinkOnEventQueuedCallable = NativeCallable<UniffiCallbackInterfaceEventSinkMethod0>.listener(eventSinkOnEventQueued) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod0>> eventSinkOnEventListenerPointer = _eventSinkOnEventQueuedCallable.nativeFunction;void eventSinkTransform( int uniffiHandle, int value, Pointer<NativeFunction<UniffiForeignFutureCompleteU32>> uniffiFutureCallback, int uniffiCallbackData, Pointer<UniffiForeignFuture> outReturn, ) { final obj = FfiConverterCallbackInterfaceEventSink._handleMap.get(uniffiHandle); final arg0 = FfiConverterUInt32.lift(value); final callback = uniffiFutureCallback.asFunction<UniffiForeignFutureCompleteU32Dart>(); final state = UniffiForeignFutureState(); final handle = uniffiForeignFutureHandleMap.insert(state); outReturn.ref.handle = handle; outReturn.ref.free = uniffiForeignFutureFreeListenerPointer; () async { try { final result = await obj.transform(arg0,); final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultU32>(); try { resultStructPtr.ref.returnValue = FfiConverterUInt32.lower(result); resultStructPtr.ref.callStatus.code = uniffiCallSuccess; callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } catch (e) { final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle); final effectiveState = removedState ?? state; if (effectiveState.cancelled) { return; } effectiveState.cancelled = true; final resultStructPtr = calloc<UniffiForeignFutureResultU32>(); try { resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError; resultStructPtr.ref.callStatus.errorBuf = FfiConverterString.lower(e.toString()); callback(uniffiCallbackData, resultStructPtr.ref); } finally { calloc.free(resultStructPtr); } } }(); } final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod1>> eventSinkTransformPointer = Pointer.fromFunction<UniffiCallbackInterfaceEventSinkMethod1>(eventSinkTransform);
+
+void eventSinkTransformBlocking( int uniffiHandle, int value, Pointer<NativeFunction<UniffiForeignFutureCompleteU32>> uniffiFutureCallback,int uniffiCallbackData,Pointer<UniffiForeignFuture> outReturn, Pointer<Void> uniffiWaiter, ) { try { eventSinkTransform( uniffiHandle, value, uniffiFutureCallback,uniffiCallbackData,outReturn, ); } finally { uniffiDartThreadSafeCallbacksEventsinkSignal(uniffiWaiter); } } final _eventSinkTransformBlockingCallable = NativeCallable<UniffiCallbackInterfaceEventSinkMethod1Blocking>.listener(eventSinkTransformBlocking) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkMethod1Blocking>> eventSinkTransformListenerPointer = _eventSinkTransformBlockingCallable.nativeFunction; void eventSinkFreeCallback(int handle) { FfiConverterCallbackInterfaceEventSink._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkFree>> eventSinkFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceEventSinkFree>(eventSinkFreeCallback); final _eventSinkFreeCallbackCallable = NativeCallable<UniffiCallbackInterfaceEventSinkFree>.listener(eventSinkFreeCallback) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceEventSinkFree>> eventSinkFreeListenerPointer = _eventSinkFreeCallbackCallable.nativeFunction;  late final Pointer<UniffiVTableCallbackInterfaceEventSink> eventSinkVTable; void initEventSinkVTable() { if (FfiConverterCallbackInterfaceEventSink._vtableInitialized) { return; } eventSinkVTable = calloc<UniffiVTableCallbackInterfaceEventSink>(); final direct = calloc<Pointer<Void>>(3); final listeners = calloc<Pointer<Void>>(3); try { direct[0] = eventSinkFreePointer.cast(); listeners[0] = eventSinkFreeListenerPointer.cast();direct[1] = eventSinkOnEventPointer.cast(); listeners[1] = eventSinkOnEventListenerPointer.cast();direct[2] = eventSinkTransformPointer.cast(); listeners[2] = eventSinkTransformListenerPointer.cast(); final registered = uniffiDartThreadSafeCallbacksEventsinkRegister( NativeApi.initializeApiDLData, direct, listeners, eventSinkVTable.cast(), ); if (registered != 0) { throw UnsupportedError( "EventSink can't be called from other threads on this Dart version", ); } } finally { calloc.free(direct); calloc.free(listeners); } rustCall((status) { uniffiThreadSafeCallbacksFnInitCallbackVtableEventsink( eventSinkVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceEventSink._vtableInitialized = true; } abstract class Lookup { String get_(String key,); } class FfiConverterCallbackInterfaceLookup { static final _handleMap = UniffiHandleMap<Lookup>(uniffiDartThreadSafeCallbacksLookupNewHandle); static bool _vtableInitialized = false; static Lookup lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Lookup value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initLookupVTable(); _vtableInitialized = true; } } static LiftRetVal<Lookup> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Lookup value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Lookup value) { return 8; } } typedef UniffiCallbackInterfaceLookupMethod0 = Void Function( Uint64, RustBuffer, Pointer<RustBuffer>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceLookupMethod0Dart = void Function( int, RustBuffer, Pointer<RustBuffer>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceLookupMethod0Blocking = Void Function( Uint64, RustBuffer, Pointer<RustBuffer>, Pointer<RustCallStatus>, Pointer<Void>);typedef UniffiCallbackInterfaceLookupFree = Void Function(Uint64); typedef UniffiCallbackInterfaceLookupFreeDart = void Function(int); typedef UniffiCallbackInterfaceLookupClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceLookupCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceLookup extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceLookupFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceLookupClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceLookupMethod0>> get_; }  void lookupGet(int uniffiHandle, RustBuffer key, Pointer<RustBuffer> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceLookup._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(key); final result = obj.get_(arg0,); outReturn.ref = FfiConverterString.lower(result); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceLookupMethod0>> lookupGetPointer = Pointer.fromFunction<UniffiCallbackInterfaceLookupMethod0>(lookupGet);
+
+void lookupGetBlocking( int uniffiHandle, RustBuffer key, Pointer<RustBuffer> outReturn,Pointer<RustCallStatus> callStatus, Pointer<Void> uniffiWaiter, ) { try { lookupGet( uniffiHandle, key, outReturn,callStatus, ); } finally { uniffiDartThreadSafeCallbacksLookupSignal(uniffiWaiter); } } final _lookupGetBlockingCallable = NativeCallable<UniffiCallbackInterfaceLookupMethod0Blocking>.listener(lookupGetBlocking) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceLookupMethod0Blocking>> lookupGetListenerPointer = _lookupGetBlockingCallable.nativeFunction; void lookupFreeCallback(int handle) { FfiConverterCallbackInterfaceLookup._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceLookupFree>> lookupFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceLookupFree>(lookupFreeCallback); final _lookupFreeCallbackCallable = NativeCallable<UniffiCallbackInterfaceLookupFree>.listener(lookupFreeCallback) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceLookupFree>> lookupFreeListenerPointer = _lookupFreeCallbackCallable.nativeFunction;  late final Pointer<UniffiVTableCallbackInterfaceLookup> lookupVTable; void initLookupVTable() { if (FfiConverterCallbackInterfaceLookup._vtableInitialized) { return; } lookupVTable = calloc<UniffiVTableCallbackInterfaceLookup>(); final direct = calloc<Pointer<Void>>(2); final listeners = calloc<Pointer<Void>>(2); try { direct[0] = lookupFreePointer.cast(); listeners[0] = lookupFreeListenerPointer.cast();direct[1] = lookupGetPointer.cast(); listeners[1] = lookupGetListenerPointer.cast(); final registered = uniffiDartThreadSafeCallbacksLookupRegister( NativeApi.initializeApiDLData, direct, listeners, lookupVTable.cast(), ); if (registered != 0) { throw UnsupportedError( "Lookup can't be called from other threads on this Dart version", ); } } finally { calloc.free(direct); calloc.free(listeners); } rustCall((status) { uniffiThreadSafeCallbacksFnInitCallbackVtableLookup( lookupVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceLookup._vtableInitialized = true; } abstract class Printer { void print(String text,); } class FfiConverterCallbackInterfacePrinter { static final _handleMap = UniffiHandleMap<Printer>(); static bool _vtableInitialized = false; static Printer lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Printer value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initPrinterVTable(); _vtableInitialized = true; } } static LiftRetVal<Printer> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Printer value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Printer value) { return 8; } } typedef UniffiCallbackInterfacePrinterMethod0 = Void Function( Uint64, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfacePrinterMethod0Dart = void Function( int, RustBuffer, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfacePrinterFree = Void Function(Uint64); typedef UniffiCallbackInterfacePrinterFreeDart = void Function(int); typedef UniffiCallbackInterfacePrinterClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfacePrinterCloneDart = int Function(int); final class UniffiVTableCallbackInterfacePrinter extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfacePrinterFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfacePrinterClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfacePrinterMethod0>> print; }  void printerPrint(int uniffiHandle, RustBuffer text, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfacePrinter._handleMap.get(uniffiHandle); final arg0 = FfiConverterString.lift(text); obj.print(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfacePrinterMethod0>> printerPrintPointer = Pointer.fromFunction<UniffiCallbackInterfacePrinterMethod0>(printerPrint); void printerFreeCallback(int handle) { FfiConverterCallbackInterfacePrinter._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfacePrinterFree>> printerFreePointer = Pointer.fromFunction<UniffiCallbackInterfacePrinterFree>(printerFreeCallback); int printerCloneCallback(int handle) { try { final obj = FfiConverterCallbackInterfacePrinter._handleMap.get(handle); final newHandle = FfiConverterCallbackInterfacePrinter._handleMap.insert(obj); return newHandle; } catch (e) { return 0; } } final Pointer<NativeFunction<UniffiCallbackInterfacePrinterClone>> printerClonePointer = Pointer.fromFunction<UniffiCallbackInterfacePrinterClone>(printerCloneCallback, 0);  late final Pointer<UniffiVTableCallbackInterfacePrinter> printerVTable; void initPrinterVTable() { if (FfiConverterCallbackInterfacePrinter._vtableInitialized) { return; } printerVTable = calloc<UniffiVTableCallbackInterfacePrinter>(); printerVTable.ref.uniffiFree = printerFreePointer; printerVTable.ref.uniffiClone = printerClonePointer; printerVTable.ref.print = printerPrintPointer; rustCall((status) { uniffiThreadSafeCallbacksFnInitCallbackVtablePrinter( printerVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfacePrinter._vtableInitialized = true; } abstract class Relay { void forward(EventSink sink,); } class FfiConverterCallbackInterfaceRelay { static final _handleMap = UniffiHandleMap<Relay>(uniffiDartThreadSafeCallbacksRelayNewHandle); static bool _vtableInitialized = false; static Relay lift(Pointer<Void> handle) { return _handleMap.get(handle.address); } static Pointer<Void> lower(Relay value) { _ensureVTableInitialized(); final handle = _handleMap.insert(value); return Pointer<Void>.fromAddress(handle); } static void _ensureVTableInitialized() { if (!_vtableInitialized) { initRelayVTable(); _vtableInitialized = true; } } static LiftRetVal<Relay> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(lift(pointer), 8); } static int write(Relay value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } static int allocationSize(Relay value) { return 8; } } typedef UniffiCallbackInterfaceRelayMethod0 = Void Function( Uint64, Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>); typedef UniffiCallbackInterfaceRelayMethod0Dart = void Function( int, Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>);typedef UniffiCallbackInterfaceRelayFree = Void Function(Uint64); typedef UniffiCallbackInterfaceRelayFreeDart = void Function(int); typedef UniffiCallbackInterfaceRelayClone = Uint64 Function(Uint64); typedef UniffiCallbackInterfaceRelayCloneDart = int Function(int); final class UniffiVTableCallbackInterfaceRelay extends Struct { external Pointer<NativeFunction<UniffiCallbackInterfaceRelayFree>> uniffiFree; external Pointer<NativeFunction<UniffiCallbackInterfaceRelayClone>> uniffiClone; external Pointer<NativeFunction<UniffiCallbackInterfaceRelayMethod0>> forward; }  void relayForward(int uniffiHandle, Pointer<Void> sink, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final status = callStatus.ref; try { final obj = FfiConverterCallbackInterfaceRelay._handleMap.get(uniffiHandle); final arg0 = FfiConverterCallbackInterfaceEventSink.lift(sink); obj.forward(arg0,); status.code = uniffiCallSuccess; } catch (e) { status.code = uniffiCallUnexpectedError; status.errorBuf = FfiConverterString.lower(e.toString()); } } final Pointer<NativeFunction<UniffiCallbackInterfaceRelayMethod0>> relayForwardPointer = Pointer.fromFunction<UniffiCallbackInterfaceRelayMethod0>(relayForward);
+
+void relayForwardQueued(int uniffiHandle, Pointer<Void> sink, Pointer<Void> outReturn, Pointer<RustCallStatus> callStatus) { final obj = FfiConverterCallbackInterfaceRelay._handleMap.get(uniffiHandle); final arg0 = FfiConverterCallbackInterfaceEventSink.lift(sink); obj.forward(arg0,); } final _relayForwardQueuedCallable = NativeCallable<UniffiCallbackInterfaceRelayMethod0>.listener(relayForwardQueued) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceRelayMethod0>> relayForwardListenerPointer = _relayForwardQueuedCallable.nativeFunction; void relayFreeCallback(int handle) { FfiConverterCallbackInterfaceRelay._handleMap.maybeRemove(handle); } final Pointer<NativeFunction<UniffiCallbackInterfaceRelayFree>> relayFreePointer = Pointer.fromFunction<UniffiCallbackInterfaceRelayFree>(relayFreeCallback); final _relayFreeCallbackCallable = NativeCallable<UniffiCallbackInterfaceRelayFree>.listener(relayFreeCallback) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiCallbackInterfaceRelayFree>> relayFreeListenerPointer = _relayFreeCallbackCallable.nativeFunction;  late final Pointer<UniffiVTableCallbackInterfaceRelay> relayVTable; void initRelayVTable() { if (FfiConverterCallbackInterfaceRelay._vtableInitialized) { return; } relayVTable = calloc<UniffiVTableCallbackInterfaceRelay>(); final direct = calloc<Pointer<Void>>(2); final listeners = calloc<Pointer<Void>>(2); try { direct[0] = relayFreePointer.cast(); listeners[0] = relayFreeListenerPointer.cast();direct[1] = relayForwardPointer.cast(); listeners[1] = relayForwardListenerPointer.cast(); final registered = uniffiDartThreadSafeCallbacksRelayRegister( NativeApi.initializeApiDLData, direct, listeners, relayVTable.cast(), ); if (registered != 0) { throw UnsupportedError( "Relay can't be called from other threads on this Dart version", ); } } finally { calloc.free(direct); calloc.free(listeners); } rustCall((status) { uniffiThreadSafeCallbacksFnInitCallbackVtableRelay( relayVTable, ); checkCallStatus(NullRustCallStatusErrorHandler(), status); }); FfiConverterCallbackInterfaceRelay._vtableInitialized = true; }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiThreadSafeCallbacksRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiThreadSafeCallbacksRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); final class UniffiForeignFutureResultU32 extends Struct { @Uint32() external int returnValue;external RustCallStatus callStatus; }typedef UniffiForeignFutureCompleteU32 = Void Function(Uint64, UniffiForeignFutureResultU32);typedef UniffiForeignFutureCompleteU32Dart = void Function(int, UniffiForeignFutureResultU32);final _uniffiForeignFutureFreeCallable = NativeCallable<UniffiForeignFutureFree>.listener(_uniffiForeignFutureFree) ..keepIsolateAlive = false; final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreeListenerPointer = _uniffiForeignFutureFreeCallable.nativeFunction; const _uniffiAssetId = "package:uniffi/uniffi:thread_safe_callbacks"; void emitFromThreads(EventSink sink,int count,) { return rustCall((status) { uniffiThreadSafeCallbacksFnFuncEmitFromThreads( FfiConverterCallbackInterfaceEventSink.lower(sink),FfiConverterUInt32.lower(count), status ); }, null); } void forgetLookups() { return rustCall((status) { uniffiThreadSafeCallbacksFnFuncForgetLookups( status ); }, null); } String lookupHere(Lookup lookup,String key,) { return rustCallWithLifter( (status) => uniffiThreadSafeCallbacksFnFuncLookupHere( FfiConverterCallbackInterfaceLookup.lower(lookup),FfiConverterString.lower(key), status ), FfiConverterString.lift, lookupExceptionErrorHandler ); } Future<String> lookupOnThread(Lookup lookup,String key,) { return uniffiRustCallAsync( () => uniffiThreadSafeCallbacksFnFuncLookupOnThread( FfiConverterCallbackInterfaceLookup.lower(lookup),FfiConverterString.lower(key), ), ffiThreadSafeCallbacksRustFuturePollRustBuffer, ffiThreadSafeCallbacksRustFutureCompleteRustBuffer, ffiThreadSafeCallbacksRustFutureFreeRustBuffer, FfiConverterString.lift, lookupExceptionErrorHandler, ); } String lookupRemembered(String name,String key,) { return rustCallWithLifter( (status) => uniffiThreadSafeCallbacksFnFuncLookupRemembered( FfiConverterString.lower(name),FfiConverterString.lower(key), status ), FfiConverterString.lift, lookupExceptionErrorHandler ); } Future<String> lookupRememberedOnThread(String name,String key,) { return uniffiRustCallAsync( () => uniffiThreadSafeCallbacksFnFuncLookupRememberedOnThread( FfiConverterString.lower(name),FfiConverterString.lower(key), ), ffiThreadSafeCallbacksRustFuturePollRustBuffer, ffiThreadSafeCallbacksRustFutureCompleteRustBuffer, ffiThreadSafeCallbacksRustFutureFreeRustBuffer, FfiConverterString.lift, lookupExceptionErrorHandler, ); } void relayOnThread(Relay relay,EventSink sink,) { return rustCall((status) { uniffiThreadSafeCallbacksFnFuncRelayOnThread( FfiConverterCallbackInterfaceRelay.lower(relay),FfiConverterCallbackInterfaceEventSink.lower(sink), status ); }, null); } void rememberLookup(String name,Lookup lookup,) { return rustCall((status) { uniffiThreadSafeCallbacksFnFuncRememberLookup( FfiConverterString.lower(name),FfiConverterCallbackInterfaceLookup.lower(lookup), status ); }, null); } Future<int> transformOnThread(EventSink sink,int value,) { return uniffiRustCallAsync( () => uniffiThreadSafeCallbacksFnFuncTransformOnThread( FfiConverterCallbackInterfaceEventSink.lower(sink),FfiConverterUInt32.lower(value), ), ffiThreadSafeCallbacksRustFuturePollU32, ffiThreadSafeCallbacksRustFutureCompleteU32, ffiThreadSafeCallbacksRustFutureFreeU32, FfiConverterUInt32.lift, null, ); } @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_clone_announcer", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnCloneAnnouncer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_free_announcer", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFreeAnnouncer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_method_announcer_announce", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnMethodAnnouncerAnnounce( Pointer<Void> ptr, Pointer<Void> printer, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_clone_eventsink", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnCloneEventsink( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_free_eventsink", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFreeEventsink( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceEventSink>)>( symbol: "uniffi_thread_safe_callbacks_fn_init_callback_vtable_eventsink", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnInitCallbackVtableEventsink( Pointer<UniffiVTableCallbackInterfaceEventSink> vtable);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_method_eventsink_on_event", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnMethodEventsinkOnEvent( Pointer<Void> ptr, RustBuffer message, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint32)>( symbol: "uniffi_thread_safe_callbacks_fn_method_eventsink_transform", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnMethodEventsinkTransform( Pointer<Void> ptr, int value);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_clone_lookup", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnCloneLookup( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_free_lookup", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFreeLookup( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceLookup>)>( symbol: "uniffi_thread_safe_callbacks_fn_init_callback_vtable_lookup", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnInitCallbackVtableLookup( Pointer<UniffiVTableCallbackInterfaceLookup> vtable);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_method_lookup_get", assetId: _uniffiAssetId ) external RustBuffer uniffiThreadSafeCallbacksFnMethodLookupGet( Pointer<Void> ptr, RustBuffer key, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_clone_printer", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnClonePrinter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_free_printer", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFreePrinter( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfacePrinter>)>( symbol: "uniffi_thread_safe_callbacks_fn_init_callback_vtable_printer", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnInitCallbackVtablePrinter( Pointer<UniffiVTableCallbackInterfacePrinter> vtable);
+
+@Native<Void Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_method_printer_print", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnMethodPrinterPrint( Pointer<Void> ptr, RustBuffer text, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_clone_relay", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnCloneRelay( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_free_relay", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFreeRelay( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<UniffiVTableCallbackInterfaceRelay>)>( symbol: "uniffi_thread_safe_callbacks_fn_init_callback_vtable_relay", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnInitCallbackVtableRelay( Pointer<UniffiVTableCallbackInterfaceRelay> vtable);
+
+@Native<Void Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_method_relay_forward", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnMethodRelayForward( Pointer<Void> ptr, Pointer<Void> sink, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_announce_on_thread", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFuncAnnounceOnThread( Pointer<Void> announcer, Pointer<Void> printer, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_emit_from_threads", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFuncEmitFromThreads( Pointer<Void> sink, int count, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_forget_lookups", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFuncForgetLookups( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_lookup_here", assetId: _uniffiAssetId ) external RustBuffer uniffiThreadSafeCallbacksFnFuncLookupHere( Pointer<Void> lookup, RustBuffer key, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, RustBuffer)>( symbol: "uniffi_thread_safe_callbacks_fn_func_lookup_on_thread", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnFuncLookupOnThread( Pointer<Void> lookup, RustBuffer key);
+
+@Native<RustBuffer Function( RustBuffer, RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_lookup_remembered", assetId: _uniffiAssetId ) external RustBuffer uniffiThreadSafeCallbacksFnFuncLookupRemembered( RustBuffer name, RustBuffer key, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, RustBuffer)>( symbol: "uniffi_thread_safe_callbacks_fn_func_lookup_remembered_on_thread", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnFuncLookupRememberedOnThread( RustBuffer name, RustBuffer key);
+
+@Native<Void Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_relay_on_thread", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFuncRelayOnThread( Pointer<Void> relay, Pointer<Void> sink, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_thread_safe_callbacks_fn_func_remember_lookup", assetId: _uniffiAssetId ) external void uniffiThreadSafeCallbacksFnFuncRememberLookup( RustBuffer name, Pointer<Void> lookup, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Uint32)>( symbol: "uniffi_thread_safe_callbacks_fn_func_transform_on_thread", assetId: _uniffiAssetId ) external Pointer<Void> uniffiThreadSafeCallbacksFnFuncTransformOnThread( Pointer<Void> sink, int value);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiThreadSafeCallbacksRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiThreadSafeCallbacksRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiThreadSafeCallbacksRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiThreadSafeCallbacksRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiThreadSafeCallbacksRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiThreadSafeCallbacksRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_thread_safe_callbacks_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_thread_safe_callbacks_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiThreadSafeCallbacksRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_announce_on_thread", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncAnnounceOnThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_emit_from_threads", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncEmitFromThreads();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_forget_lookups", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncForgetLookups();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_lookup_here", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncLookupHere();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_lookup_on_thread", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncLookupOnThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_lookup_remembered", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncLookupRemembered();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_lookup_remembered_on_thread", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncLookupRememberedOnThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_relay_on_thread", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncRelayOnThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_remember_lookup", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncRememberLookup();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_func_transform_on_thread", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumFuncTransformOnThread();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_announcer_announce", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodAnnouncerAnnounce();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_eventsink_on_event", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodEventsinkOnEvent();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_eventsink_transform", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodEventsinkTransform();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_lookup_get", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodLookupGet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_printer_print", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodPrinterPrint();
+
+@Native<Uint16 Function()>( symbol: "uniffi_thread_safe_callbacks_checksum_method_relay_forward", assetId: _uniffiAssetId ) external int uniffiThreadSafeCallbacksChecksumMethodRelayForward();
+
+@Native<Uint32 Function()>( symbol: "ffi_thread_safe_callbacks_uniffi_contract_version", assetId: _uniffiAssetId ) external int ffiThreadSafeCallbacksUniffiContractVersion();
+
+@Native<Int8 Function(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>)>( symbol: "uniffi_dart_thread_safe_callbacks_eventsink_register", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksEventsinkRegister(Pointer<Void> api, Pointer<Pointer<Void>> direct, Pointer<Pointer<Void>> listeners, Pointer<Pointer<Void>> vtable);
+
+@Native<Uint64 Function()>( symbol: "uniffi_dart_thread_safe_callbacks_eventsink_new_handle", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksEventsinkNewHandle();
+
+@Native<Void Function(Pointer<Void>)>( symbol: "uniffi_dart_thread_safe_callbacks_eventsink_signal", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiDartThreadSafeCallbacksEventsinkSignal(Pointer<Void> waiter);
+
+@Native<Int8 Function(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>)>( symbol: "uniffi_dart_thread_safe_callbacks_lookup_register", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksLookupRegister(Pointer<Void> api, Pointer<Pointer<Void>> direct, Pointer<Pointer<Void>> listeners, Pointer<Pointer<Void>> vtable);
+
+@Native<Uint64 Function()>( symbol: "uniffi_dart_thread_safe_callbacks_lookup_new_handle", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksLookupNewHandle();
+
+@Native<Void Function(Pointer<Void>)>( symbol: "uniffi_dart_thread_safe_callbacks_lookup_signal", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiDartThreadSafeCallbacksLookupSignal(Pointer<Void> waiter);
+
+@Native<Int8 Function(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>)>( symbol: "uniffi_dart_thread_safe_callbacks_relay_register", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksRelayRegister(Pointer<Void> api, Pointer<Pointer<Void>> direct, Pointer<Pointer<Void>> listeners, Pointer<Pointer<Void>> vtable);
+
+@Native<Uint64 Function()>( symbol: "uniffi_dart_thread_safe_callbacks_relay_new_handle", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiDartThreadSafeCallbacksRelayNewHandle();
+
+@Native<Void Function(Pointer<Void>)>( symbol: "uniffi_dart_thread_safe_callbacks_relay_signal", assetId: _uniffiAssetId, isLeaf: true ) external void uniffiDartThreadSafeCallbacksRelaySignal(Pointer<Void> waiter);
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiThreadSafeCallbacksUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiThreadSafeCallbacksChecksumFuncAnnounceOnThread() != 36001) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_announce_on_thread"); }if (uniffiThreadSafeCallbacksChecksumFuncEmitFromThreads() != 51) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_emit_from_threads"); }if (uniffiThreadSafeCallbacksChecksumFuncForgetLookups() != 64512) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_forget_lookups"); }if (uniffiThreadSafeCallbacksChecksumFuncLookupHere() != 45425) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_lookup_here"); }if (uniffiThreadSafeCallbacksChecksumFuncLookupOnThread() != 16114) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_lookup_on_thread"); }if (uniffiThreadSafeCallbacksChecksumFuncLookupRemembered() != 25327) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_lookup_remembered"); }if (uniffiThreadSafeCallbacksChecksumFuncLookupRememberedOnThread() != 54360) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_lookup_remembered_on_thread"); }if (uniffiThreadSafeCallbacksChecksumFuncRelayOnThread() != 57544) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_relay_on_thread"); }if (uniffiThreadSafeCallbacksChecksumFuncRememberLookup() != 9598) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_remember_lookup"); }if (uniffiThreadSafeCallbacksChecksumFuncTransformOnThread() != 9148) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_func_transform_on_thread"); }if (uniffiThreadSafeCallbacksChecksumMethodAnnouncerAnnounce() != 40191) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_announcer_announce"); }if (uniffiThreadSafeCallbacksChecksumMethodEventsinkOnEvent() != 56494) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_eventsink_on_event"); }if (uniffiThreadSafeCallbacksChecksumMethodEventsinkTransform() != 8758) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_eventsink_transform"); }if (uniffiThreadSafeCallbacksChecksumMethodLookupGet() != 17736) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_lookup_get"); }if (uniffiThreadSafeCallbacksChecksumMethodPrinterPrint() != 11143) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_printer_print"); }if (uniffiThreadSafeCallbacksChecksumMethodRelayForward() != 16194) { mismatchedChecksums.add("uniffi_thread_safe_callbacks_checksum_method_relay_forward"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Implemented in Dart and invoked from threads spawned by Rust.
+#[uniffi_dart::thread_safe]
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait EventSink: Send + Sync {
+    fn on_event(&self, message: String);
+    async fn transform(&self, value: u32) -> u32;
+}
+
+/// Calls `on_event` once from each of `count` worker threads.
+#[uniffi::export]
+pub fn emit_from_threads(sink: Arc<dyn EventSink>, count: u32) {
+    let workers: Vec<_> = (0..count)
+        .map(|i| {
+            let sink = sink.clone();
+            thread::spawn(move || sink.on_event(format!("event {i}")))
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
+
+/// Blocks a worker thread on the async `transform` callback and returns its result.
+#[uniffi::export]
+pub async fn transform_on_thread(sink: Arc<dyn EventSink>, value: u32) -> u32 {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    thread::spawn(move || {
+        let result = futures::executor::block_on(sink.transform(value));
+        let _ = tx.send(result);
+    });
+    rx.await.unwrap()
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum LookupError {
+    #[error("lookup failed: {reason}")]
+    Failed { reason: String },
+}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for LookupError {
+    fn from(e: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        Self::Failed { reason: e.reason }
+    }
+}
+
+// A sync method handing back a value or an error, which a Rust thread waits for.
+#[uniffi_dart::thread_safe]
+#[uniffi::export(with_foreign)]
+pub trait Lookup: Send + Sync {
+    fn get(&self, key: String) -> Result<String, LookupError>;
+}
+
+/// Calls `get` on the calling thread.
+#[uniffi::export]
+pub fn lookup_here(lookup: Arc<dyn Lookup>, key: String) -> Result<String, LookupError> {
+    lookup.get(key)
+}
+
+/// Calls `get` from a worker thread, which blocks until Dart answered.
+#[uniffi::export]
+pub async fn lookup_on_thread(lookup: Arc<dyn Lookup>, key: String) -> Result<String, LookupError> {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    thread::spawn(move || {
+        let _ = tx.send(lookup.get(key));
+    });
+    rx.await.unwrap()
+}
+
+static REMEMBERED: Mutex<BTreeMap<String, Arc<dyn Lookup>>> = Mutex::new(BTreeMap::new());
+
+fn remembered(name: &str) -> Arc<dyn Lookup> {
+    REMEMBERED.lock().unwrap()[name].clone()
+}
+
+/// Keeps `lookup` under `name` until `forget_lookups` is called.
+#[uniffi::export]
+pub fn remember_lookup(name: String, lookup: Arc<dyn Lookup>) {
+    REMEMBERED.lock().unwrap().insert(name, lookup);
+}
+
+/// Calls `get` on the lookup remembered under `name` on the calling thread.
+#[uniffi::export]
+pub fn lookup_remembered(name: String, key: String) -> Result<String, LookupError> {
+    remembered(&name).get(key)
+}
+
+/// Calls `get` on the lookup remembered under `name` from a worker thread.
+#[uniffi::export]
+pub async fn lookup_remembered_on_thread(name: String, key: String) -> Result<String, LookupError> {
+    lookup_on_thread(remembered(&name), key).await
+}
+
+/// Drops all remembered lookups.
+#[uniffi::export]
+pub fn forget_lookups() {
+    REMEMBERED.lock().unwrap().clear();
+}
+
+// Passes a Dart-implemented `EventSink`, whose handle Rust clones on the calling thread, back
+// to Dart, which works because `EventSink` is thread-safe as well.
+#[uniffi_dart::thread_safe]
+#[uniffi::export(with_foreign)]
+pub trait Relay: Send + Sync {
+    fn forward(&self, sink: Arc<dyn EventSink>);
+}
+
+/// Calls `forward` from a worker thread.
+#[uniffi::export]
+pub fn relay_on_thread(relay: Arc<dyn Relay>, sink: Arc<dyn EventSink>) {
+    thread::spawn(move || relay.forward(sink)).join().unwrap()
+}
+
+// Not thread-safe, so Dart implementations can only be handed back to Dart on the Dart thread.
+#[uniffi::export(with_foreign)]
+pub trait Printer: Send + Sync {
+    fn print(&self, text: String);
+}
+
+// Passing a Dart-implemented `Printer` back to Dart from a Rust thread clones its handle there,
+// so the generator rejects the interface (and skips it with `skip_unsupported`).
+#[uniffi_dart::thread_safe]
+#[uniffi::export(with_foreign)]
+pub trait Announcer: Send + Sync {
+    fn announce(&self, printer: Arc<dyn Printer>);
+}
+
+/// Calls `announce` from a worker thread.
+#[uniffi::export]
+pub fn announce_on_thread(announcer: Arc<dyn Announcer>, printer: Arc<dyn Printer>) {
+    thread::spawn(move || announcer.announce(printer))
+        .join()
+        .unwrap()
+}
+
+uniffi::setup_scaffolding!();
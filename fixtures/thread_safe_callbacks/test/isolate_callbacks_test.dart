@@ -0,0 +1,49 @@
+import 'dart:isolate';
+
+import 'package:test/test.dart';
+import '../thread_safe_callbacks.dart';
+
+class IsolateLookup implements Lookup {
+  @override
+  String get(String key) => '$key from ${Isolate.current.debugName}';
+}
+
+/// Hands an [IsolateLookup] to Rust under `name`, then stays alive until told to exit.
+void _rememberLookup((String, SendPort) args) {
+  final (name, ready) = args;
+  rememberLookup(name, IsolateLookup());
+  final exit = ReceivePort();
+  ready.send(exit.sendPort);
+  exit.listen((_) => exit.close());
+}
+
+/// Spawns an isolate remembering a lookup under `name`, returning the port that ends it.
+Future<SendPort> _spawnRemembering(String name) async {
+  final ready = ReceivePort();
+  await Isolate.spawn(_rememberLookup, (name, ready.sendPort), debugName: name);
+  return await ready.first as SendPort;
+}
+
+void main() {
+  test('callbacks from live isolates reach the isolate they came from', () async {
+    final exits = [
+      await _spawnRemembering('first'),
+      await _spawnRemembering('second'),
+    ];
+    try {
+      expect(
+        await Future.wait([
+          lookupRememberedOnThread('first', 'a'),
+          lookupRememberedOnThread('second', 'b'),
+        ]),
+        ['a from first', 'b from second'],
+      );
+      expect(lookupRemembered('second', 'c'), 'c from second');
+    } finally {
+      forgetLookups();
+      for (final exit in exits) {
+        exit.send(null);
+      }
+    }
+  });
+}
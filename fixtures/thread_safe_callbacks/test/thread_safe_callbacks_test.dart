@@ -0,0 +1,99 @@
+import 'dart:async';
+
+import 'package:test/test.dart';
+import '../thread_safe_callbacks.dart';
+
+class RecordingSink implements EventSink {
+  final events = <String>[];
+  final _done = Completer<void>();
+  final int expected;
+
+  RecordingSink(this.expected);
+
+  Future<void> get done => _done.future;
+
+  @override
+  void onEvent(String message) {
+    events.add(message);
+    if (events.length == expected) {
+      _done.complete();
+    }
+  }
+
+  @override
+  Future<int> transform(int value) async {
+    await Future.delayed(Duration(milliseconds: 10));
+    return value * 2;
+  }
+}
+
+class MapLookup implements Lookup {
+  final Map<String, String> entries;
+
+  MapLookup(this.entries);
+
+  @override
+  String get(String key) {
+    final value = entries[key];
+    if (value == null) {
+      throw StateError('no entry for $key');
+    }
+    return value;
+  }
+}
+
+class RecordingRelay implements Relay {
+  final _forwarded = Completer<EventSink>();
+
+  Future<EventSink> get forwarded => _forwarded.future;
+
+  @override
+  void forward(EventSink sink) {
+    _forwarded.complete(sink);
+  }
+}
+
+void main() {
+  test('sync callbacks can be invoked from Rust threads', () async {
+    final sink = RecordingSink(8);
+    emitFromThreads(sink, 8);
+    await sink.done.timeout(Duration(seconds: 5));
+    expect(
+      sink.events.toSet(),
+      {for (var i = 0; i < 8; i++) 'event $i'},
+    );
+  });
+
+  test('async callbacks can be awaited from a blocked Rust thread', () async {
+    final sink = RecordingSink(0);
+    expect(await transformOnThread(sink, 21), 42);
+  });
+
+  test('sync callbacks return values to the calling thread', () {
+    expect(lookupHere(MapLookup({'a': 'alpha'}), 'a'), 'alpha');
+  });
+
+  test('sync callbacks return values to a blocked Rust thread', () async {
+    expect(await lookupOnThread(MapLookup({'a': 'alpha'}), 'a'), 'alpha');
+  });
+
+  test('errors thrown by sync callbacks reach a blocked Rust thread', () async {
+    await expectLater(
+      lookupOnThread(MapLookup({}), 'missing'),
+      throwsA(
+        isA<FailedLookupException>().having(
+          (e) => e.reason,
+          'reason',
+          contains('no entry for missing'),
+        ),
+      ),
+    );
+  });
+
+  test('thread-safe trait objects can be passed back from Rust threads', () async {
+    final sink = RecordingSink(0);
+    final relay = RecordingRelay();
+    relayOnThread(relay, sink);
+    expect(await relay.forwarded.timeout(Duration(seconds: 5)), same(sink));
+  });
+}
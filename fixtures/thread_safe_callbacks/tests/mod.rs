@@ -0,0 +1,28 @@
+use anyhow::Result;
+
+#[test]
+fn thread_safe_callbacks() -> Result<()> {
+    uniffi_dart::testing::run_test_library_mode("thread_safe_callbacks")
+}
+
+#[test]
+fn thread_safe_callbacks_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("thread_safe_callbacks")
+}
+
+#[test]
+fn thread_safe_callbacks_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("thread_safe_callbacks")
+}
+
+#[test]
+fn thread_safe_callbacks_reject_unsafe_trait_objects() -> Result<()> {
+    uniffi_dart::testing::assert_generation_fails_library_mode(
+        "thread_safe_callbacks",
+        "strict.toml",
+        &[
+            "`Announcer.announce`: passes `Printer`",
+            "`announce_on_thread`: uses `Announcer`",
+        ],
+    )
+}
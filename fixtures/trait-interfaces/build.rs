@@ -1,3 +0,0 @@
-fn main() {
-    uniffi_dart::generate_scaffolding("./src/api.udl".into()).unwrap();
-}
@@ -93,4 +93,4 @@ impl Registry {
     }
 }
 
-uniffi::include_scaffolding!("api");
+uniffi::setup_scaffolding!();
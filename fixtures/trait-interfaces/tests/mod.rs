@@ -2,5 +2,15 @@ use anyhow::Result;
 
 #[test]
 fn trait_interfaces() -> Result<()> {
-    uniffi_dart::testing::run_test("trait_interfaces", "src/api.udl", None)
+    uniffi_dart::testing::run_test_library_mode("trait_interfaces")
+}
+
+#[test]
+fn trait_interfaces_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output_library_mode("trait_interfaces")
+}
+
+#[test]
+fn trait_interfaces_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot_library_mode("trait_interfaces")
 }
@@ -0,0 +1,149 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  abstract class TraitMethodsInterface {} final _traitMethodsFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitMethodsFnFreeTraitmethods(ptr, status)); }); class TraitMethods implements TraitMethodsInterface { late final Pointer<Void> _ptr; TraitMethods._(this._ptr) { _traitMethodsFinalizer.attach(this, _ptr, detach: this); } TraitMethods(String name,) : _ptr = rustCall((status) => uniffiTraitMethodsFnConstructorTraitmethodsNew( FfiConverterString.lower(name), status ), null ) { _traitMethodsFinalizer.attach(this, _ptr, detach: this); } factory TraitMethods.lift(Pointer<Void> ptr) { return TraitMethods._(ptr); } static Pointer<Void> lower(TraitMethods value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitMethodsFnCloneTraitmethods(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory TraitMethods.fromSendableHandle(int handle) { return TraitMethods._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(TraitMethods value) { return 8; } static LiftRetVal<TraitMethods> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(TraitMethods.lift(pointer), 8); } static int write(TraitMethods value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _traitMethodsFinalizer.detach(this); rustCall((status) => uniffiTraitMethodsFnFreeTraitmethods(_ptr, status)); } String debugString() { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitDebug( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override String toString() { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitDisplay( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override bool operator ==(Object other) { if (identical(this, other)) { return true; } if (other is! TraitMethods) { return false; } return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitEqEq( uniffiClonePointer(), TraitMethods.lower(other), status ), FfiConverterBool.lift, null ); }@override int get hashCode { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitHash( uniffiClonePointer(), status ), FfiConverterUInt64.lift, null ); } }  abstract class ProcTraitMethodsInterface {} final _procTraitMethodsFinalizer = Finalizer<Pointer<Void>>((ptr) { rustCall((status) => uniffiTraitMethodsFnFreeProctraitmethods(ptr, status)); }); class ProcTraitMethods implements ProcTraitMethodsInterface { late final Pointer<Void> _ptr; ProcTraitMethods._(this._ptr) { _procTraitMethodsFinalizer.attach(this, _ptr, detach: this); } ProcTraitMethods(String val,) : _ptr = rustCall((status) => uniffiTraitMethodsFnConstructorProctraitmethodsNew( FfiConverterString.lower(val), status ), null ) { _procTraitMethodsFinalizer.attach(this, _ptr, detach: this); } factory ProcTraitMethods.lift(Pointer<Void> ptr) { return ProcTraitMethods._(ptr); } static Pointer<Void> lower(ProcTraitMethods value) { return value.uniffiClonePointer(); } Pointer<Void> uniffiClonePointer() { return rustCall((status) => uniffiTraitMethodsFnCloneProctraitmethods(_ptr, status)); } int toSendableHandle() { return uniffiClonePointer().address; } factory ProcTraitMethods.fromSendableHandle(int handle) { return ProcTraitMethods._(Pointer<Void>.fromAddress(handle)); } static int allocationSize(ProcTraitMethods value) { return 8; } static LiftRetVal<ProcTraitMethods> read(Uint8List buf) { final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0); final pointer = Pointer<Void>.fromAddress(handle); return LiftRetVal(ProcTraitMethods.lift(pointer), 8); } static int write(ProcTraitMethods value, Uint8List buf) { final handle = lower(value); buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, handle.address); return 8; } void dispose() { _procTraitMethodsFinalizer.detach(this); rustCall((status) => uniffiTraitMethodsFnFreeProctraitmethods(_ptr, status)); } String debugString() { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitDebug( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override String toString() { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitDisplay( uniffiClonePointer(), status ), FfiConverterString.lift, null ); }@override bool operator ==(Object other) { if (identical(this, other)) { return true; } if (other is! ProcTraitMethods) { return false; } return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitEqEq( uniffiClonePointer(), ProcTraitMethods.lower(other), status ), FfiConverterBool.lift, null ); }@override int get hashCode { return rustCallWithLifter( (status) => uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitHash( uniffiClonePointer(), status ), FfiConverterUInt64.lift, null ); } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiTraitMethodsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiTraitMethodsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:trait_methods"; @Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_clone_traitmethods", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitMethodsFnCloneTraitmethods( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_free_traitmethods", assetId: _uniffiAssetId ) external void uniffiTraitMethodsFnFreeTraitmethods( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_constructor_traitmethods_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitMethodsFnConstructorTraitmethodsNew( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_traitmethods_uniffi_trait_debug", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitDebug( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_traitmethods_uniffi_trait_display", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitDisplay( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_traitmethods_uniffi_trait_eq_eq", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitEqEq( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_traitmethods_uniffi_trait_eq_ne", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitEqNe( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_traitmethods_uniffi_trait_hash", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodTraitmethodsUniffiTraitHash( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_clone_proctraitmethods", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitMethodsFnCloneProctraitmethods( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_free_proctraitmethods", assetId: _uniffiAssetId ) external void uniffiTraitMethodsFnFreeProctraitmethods( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Pointer<Void> Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_constructor_proctraitmethods_new", assetId: _uniffiAssetId ) external Pointer<Void> uniffiTraitMethodsFnConstructorProctraitmethodsNew( RustBuffer val, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_proctraitmethods_uniffi_trait_debug", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitDebug( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_proctraitmethods_uniffi_trait_display", assetId: _uniffiAssetId ) external RustBuffer uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitDisplay( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_proctraitmethods_uniffi_trait_eq_eq", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitEqEq( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_proctraitmethods_uniffi_trait_eq_ne", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitEqNe( Pointer<Void> ptr, Pointer<Void> other, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "uniffi_trait_methods_fn_method_proctraitmethods_uniffi_trait_hash", assetId: _uniffiAssetId ) external int uniffiTraitMethodsFnMethodProctraitmethodsUniffiTraitHash( Pointer<Void> ptr, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiTraitMethodsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiTraitMethodsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiTraitMethodsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiTraitMethodsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiTraitMethodsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiTraitMethodsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiTraitMethodsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_trait_methods_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_trait_methods_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiTraitMethodsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_methods_checksum_constructor_traitmethods_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitMethodsChecksumConstructorTraitmethodsNew();
+
+@Native<Uint16 Function()>( symbol: "uniffi_trait_methods_checksum_constructor_proctraitmethods_new", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTraitMethodsChecksumConstructorProctraitmethodsNew();
+
+@Native<Uint32 Function()>( symbol: "ffi_trait_methods_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiTraitMethodsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiTraitMethodsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiTraitMethodsChecksumConstructorTraitmethodsNew() != 40841) { mismatchedChecksums.add("uniffi_trait_methods_checksum_constructor_traitmethods_new"); }if (uniffiTraitMethodsChecksumConstructorProctraitmethodsNew() != 55590) { mismatchedChecksums.add("uniffi_trait_methods_checksum_constructor_proctraitmethods_new"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
@@ -4,3 +4,13 @@ use anyhow::Result;
 fn trait_methods() -> Result<()> {
     uniffi_dart::testing::run_test("trait_methods", "src/api.udl", None)
 }
+
+#[test]
+fn trait_methods_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("trait_methods", "src/api.udl", None)
+}
+
+#[test]
+fn trait_methods_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("trait_methods", "src/api.udl", None)
+}
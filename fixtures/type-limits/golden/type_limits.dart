@@ -0,0 +1,161 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiTypeLimitsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiTypeLimitsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint8(0), 1); } static int lower(int value) { if (value < 0 || value > 255) { throw ArgumentError("Value out of range for u8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint8(0, lower(value)); return 1; } } class FfiConverterInt8 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt8(0), 1); } static int lower(int value) { if (value < -128 || value > 127) { throw ArgumentError("Value out of range for i8: $value"); } return value; } static int allocationSize([int value = 0]) { return 1; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt8(0, lower(value)); return 1; } } class FfiConverterUInt16 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint16(0), 2); } static int lower(int value) { if (value < 0 || value > 65535) { throw ArgumentError("Value out of range for u16: $value"); } return value; } static int allocationSize([int value = 0]) { return 2; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint16(0, lower(value)); return 2; } } class FfiConverterInt16 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt16(0), 2); } static int lower(int value) { if (value < -32768 || value > 32767) { throw ArgumentError("Value out of range for i16: $value"); } return value; } static int allocationSize([int value = 0]) { return 2; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt16(0, lower(value)); return 2; } } class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt32(0), 4); } static int lower(int value) { if (value < -2147483648 || value > 2147483647) { throw ArgumentError("Value out of range for i32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, lower(value)); return 4; } } class FfiConverterUInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0), 8); } static int lower(int value) { if (value < 0) { throw ArgumentError("Value out of range for u64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value)); return 8; } } class FfiConverterInt64 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getInt64(0), 8); } static int lower(int value) { if (value < -9223372036854775808 || value > 9223372036854775807) { throw ArgumentError("Value out of range for i64: $value"); } return value; } static int allocationSize([int value = 0]) { return 8; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, lower(value)); return 8; } } class FfiConverterDouble32 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat32(0), 4); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 4; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat32(0, value); return FfiConverterDouble32.allocationSize(); } } class FfiConverterDouble64 { static double lift(double value) => value; static LiftRetVal<double> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getFloat64(0), 8); } static double lower(double value) => value; static int allocationSize([double value = 0]) { return 8; } static int write(double value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setFloat64(0, value); return FfiConverterDouble64.allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterUint8List { static Uint8List lift(RustBuffer value) { return FfiConverterUint8List.read(value.asUint8List()).value; } static LiftRetVal<Uint8List> read(Uint8List buf) { final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); final bytes = Uint8List.view(buf.buffer, buf.offsetInBytes + 4, length); return LiftRetVal(bytes, length + 4); } static RustBuffer lower(Uint8List value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } static int allocationSize([Uint8List? value]) { if (value == null) { return 4; } return 4 + value.length; } static int write(Uint8List value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); buf.setRange(4, 4 + value.length, value); return 4 + value.length; } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:type_limits"; Uint8List takeBytes(Uint8List v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeBytes( FfiConverterUint8List.lower(v), status ), FfiConverterUint8List.lift, null ); } double takeF32(double v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeF32( v, status ), FfiConverterDouble32.lift, null ); } double takeF64(double v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeF64( v, status ), FfiConverterDouble64.lift, null ); } int takeI16(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeI16( FfiConverterInt16.lower(v), status ), FfiConverterInt16.lift, null ); } int takeI32(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeI32( FfiConverterInt32.lower(v), status ), FfiConverterInt32.lift, null ); } int takeI64(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeI64( FfiConverterInt64.lower(v), status ), FfiConverterInt64.lift, null ); } int takeI8(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeI8( FfiConverterInt8.lower(v), status ), FfiConverterInt8.lift, null ); } String takeString(String v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeString( FfiConverterString.lower(v), status ), FfiConverterString.lift, null ); } int takeU16(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeU16( FfiConverterUInt16.lower(v), status ), FfiConverterUInt16.lift, null ); } int takeU32(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeU32( FfiConverterUInt32.lower(v), status ), FfiConverterUInt32.lift, null ); } int takeU64(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeU64( FfiConverterUInt64.lower(v), status ), FfiConverterUInt64.lift, null ); } int takeU8(int v,) { return rustCallWithLifter( (status) => uniffiTypeLimitsFnFuncTakeU8( FfiConverterUInt8.lower(v), status ), FfiConverterUInt8.lift, null ); } @Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_bytes", assetId: _uniffiAssetId ) external RustBuffer uniffiTypeLimitsFnFuncTakeBytes( RustBuffer v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Float Function( Float, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_f32", assetId: _uniffiAssetId, isLeaf: true ) external double uniffiTypeLimitsFnFuncTakeF32( double v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Double Function( Double, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_f64", assetId: _uniffiAssetId, isLeaf: true ) external double uniffiTypeLimitsFnFuncTakeF64( double v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int16 Function( Int16, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_i16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeI16( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int32 Function( Int32, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_i32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeI32( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int64 Function( Int64, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_i64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeI64( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Int8 Function( Int8, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_i8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeI8( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_string", assetId: _uniffiAssetId ) external RustBuffer uniffiTypeLimitsFnFuncTakeString( RustBuffer v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function( Uint16, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_u16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeU16( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint32 Function( Uint32, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_u32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeU32( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint64 Function( Uint64, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_u64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeU64( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint8 Function( Uint8, Pointer<RustCallStatus>)>( symbol: "uniffi_type_limits_fn_func_take_u8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsFnFuncTakeU8( int v, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiTypeLimitsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiTypeLimitsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiTypeLimitsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiTypeLimitsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiTypeLimitsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiTypeLimitsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiTypeLimitsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_type_limits_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_type_limits_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiTypeLimitsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_bytes", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeBytes();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_f32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeF32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_f64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeF64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_i16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeI16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_i32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeI32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_i64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeI64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_i8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeI8();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_string", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeString();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_u16", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeU16();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_u32", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeU32();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_u64", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeU64();
+
+@Native<Uint16 Function()>( symbol: "uniffi_type_limits_checksum_func_take_u8", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiTypeLimitsChecksumFuncTakeU8();
+
+@Native<Uint32 Function()>( symbol: "ffi_type_limits_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiTypeLimitsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiTypeLimitsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiTypeLimitsChecksumFuncTakeBytes() != 54218) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_bytes"); }if (uniffiTypeLimitsChecksumFuncTakeF32() != 37723) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_f32"); }if (uniffiTypeLimitsChecksumFuncTakeF64() != 55996) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_f64"); }if (uniffiTypeLimitsChecksumFuncTakeI16() != 37971) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_i16"); }if (uniffiTypeLimitsChecksumFuncTakeI32() != 16443) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_i32"); }if (uniffiTypeLimitsChecksumFuncTakeI64() != 31424) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_i64"); }if (uniffiTypeLimitsChecksumFuncTakeI8() != 37357) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_i8"); }if (uniffiTypeLimitsChecksumFuncTakeString() != 63888) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_string"); }if (uniffiTypeLimitsChecksumFuncTakeU16() != 42026) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_u16"); }if (uniffiTypeLimitsChecksumFuncTakeU32() != 2462) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_u32"); }if (uniffiTypeLimitsChecksumFuncTakeU64() != 31) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_u64"); }if (uniffiTypeLimitsChecksumFuncTakeU8() != 15523) { mismatchedChecksums.add("uniffi_type_limits_checksum_func_take_u8"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
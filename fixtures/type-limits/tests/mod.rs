@@ -4,3 +4,13 @@ use anyhow::Result;
 fn type_limits() -> Result<()> {
     uniffi_dart::testing::run_test("type_limits", "src/api.udl", None)
 }
+
+#[test]
+fn type_limits_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output("type_limits", "src/api.udl", None)
+}
+
+#[test]
+fn type_limits_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("type_limits", "src/api.udl", None)
+}
@@ -0,0 +1,133 @@
+import "dart:async";
+import "dart:convert";
+import "dart:ffi";
+import "dart:typed_data";
+import "package:ffi/ffi.dart";
+
+  class Retry { final String label;final int attempts;final bool jitter;final Backoff backoff;final List<String> reasons;final Window? window; Retry(this.label,this.attempts,this.jitter,this.backoff,this.reasons,this.window,); } class FfiConverterRetry { static Retry lift( RustBuffer buf) { return FfiConverterRetry.read(buf.asUint8List()).value; } static LiftRetVal<Retry> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final labelLifted = FfiConverterString.read(Uint8List.view(buf.buffer, newOffset)); final label = labelLifted.value; newOffset += labelLifted.bytesRead;final attemptsLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final attempts = attemptsLifted.value; newOffset += attemptsLifted.bytesRead;final jitterLifted = FfiConverterBool.read(Uint8List.view(buf.buffer, newOffset)); final jitter = jitterLifted.value; newOffset += jitterLifted.bytesRead;final backoffLifted = FfiConverterBackoff.read(Uint8List.view(buf.buffer, newOffset)); final backoff = backoffLifted.value; newOffset += backoffLifted.bytesRead;final reasonsLifted = FfiConverterSequenceString.read(Uint8List.view(buf.buffer, newOffset)); final reasons = reasonsLifted.value; newOffset += reasonsLifted.bytesRead;final windowLifted = FfiConverterOptionalWindow.read(Uint8List.view(buf.buffer, newOffset)); final window = windowLifted.value; newOffset += windowLifted.bytesRead; return LiftRetVal(Retry( label,attempts,jitter,backoff,reasons,window, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Retry value) { final totalLength = FfiConverterString.allocationSize(value.label) +FfiConverterUInt32.allocationSize(value.attempts) +FfiConverterBool.allocationSize(value.jitter) +FfiConverterBackoff.allocationSize(value.backoff) +FfiConverterSequenceString.allocationSize(value.reasons) +FfiConverterOptionalWindow.allocationSize(value.window) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Retry value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterString.write(value.label, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt32.write(value.attempts, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterBool.write(value.jitter, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterBackoff.write(value.backoff, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterSequenceString.write(value.reasons, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterOptionalWindow.write(value.window, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Retry value) { return FfiConverterString.allocationSize(value.label) +FfiConverterUInt32.allocationSize(value.attempts) +FfiConverterBool.allocationSize(value.jitter) +FfiConverterBackoff.allocationSize(value.backoff) +FfiConverterSequenceString.allocationSize(value.reasons) +FfiConverterOptionalWindow.allocationSize(value.window) + 0; } } class Window { final int start;final int end; Window(this.start,this.end,); } class FfiConverterWindow { static Window lift( RustBuffer buf) { return FfiConverterWindow.read(buf.asUint8List()).value; } static LiftRetVal<Window> read( Uint8List buf) { int newOffset = buf.offsetInBytes; final startLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final start = startLifted.value; newOffset += startLifted.bytesRead;final endLifted = FfiConverterUInt32.read(Uint8List.view(buf.buffer, newOffset)); final end = endLifted.value; newOffset += endLifted.bytesRead; return LiftRetVal(Window( start,end, ), newOffset - buf.offsetInBytes); } static RustBuffer lower( Window value) { final totalLength = FfiConverterUInt32.allocationSize(value.start) +FfiConverterUInt32.allocationSize(value.end) + 0; final buf = Uint8List(totalLength); write(value, buf); return toRustBuffer(buf); } static int write( Window value, Uint8List buf) { int newOffset = buf.offsetInBytes; newOffset += FfiConverterUInt32.write(value.start, Uint8List.view(buf.buffer, newOffset));newOffset += FfiConverterUInt32.write(value.end, Uint8List.view(buf.buffer, newOffset)); return newOffset - buf.offsetInBytes; } static int allocationSize(Window value) { return FfiConverterUInt32.allocationSize(value.start) +FfiConverterUInt32.allocationSize(value.end) + 0; } } enum Backoff { linear,exponential, ; } class FfiConverterBackoff { static LiftRetVal<Backoff> read( Uint8List buf) { final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); switch(index) { case 1: return LiftRetVal( Backoff.linear, 4, );case 2: return LiftRetVal( Backoff.exponential, 4, ); default: throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant"); } } static Backoff lift( RustBuffer buffer) { return FfiConverterBackoff.read(buffer.asUint8List()).value; } static RustBuffer lower( Backoff input) { return toRustBuffer(createUint8ListFromInt(input.index + 1)); } static int allocationSize(Backoff value) { return 4; } static int write( Backoff value, Uint8List buf) { buf.buffer .asByteData(buf.offsetInBytes) .setInt32(0, value.index + 1); return 4; } }   class UniffiInternalError implements Exception { static const int bufferOverflow = 0; static const int incompleteData = 1; static const int unexpectedOptionalTag = 2; static const int unexpectedEnumCase = 3; static const int unexpectedNullPointer = 4; static const int unexpectedRustCallStatusCode = 5; static const int unexpectedRustCallError = 6; static const int unexpectedStaleHandle = 7; static const int rustPanic = 8; final int errorCode; final String? panicMessage; const UniffiInternalError(this.errorCode, this.panicMessage); static UniffiInternalError panicked(String message) { return UniffiInternalError(rustPanic, message); } @override String toString() { switch (errorCode) { case bufferOverflow: return "UniFfi::BufferOverflow"; case incompleteData: return "UniFfi::IncompleteData"; case unexpectedOptionalTag: return "UniFfi::UnexpectedOptionalTag"; case unexpectedEnumCase: return "UniFfi::UnexpectedEnumCase"; case unexpectedNullPointer: return "UniFfi::UnexpectedNullPointer"; case unexpectedRustCallStatusCode: return "UniFfi::UnexpectedRustCallStatusCode"; case unexpectedRustCallError: return "UniFfi::UnexpectedRustCallError"; case unexpectedStaleHandle: return "UniFfi::UnexpectedStaleHandle"; case rustPanic: return "UniFfi::rustPanic: $panicMessage"; default: return "UniFfi::UnknownError: $errorCode"; } } } sealed class UniffiContractMismatchException implements Exception { const UniffiContractMismatchException(); } class UniffiContractVersionMismatchException extends UniffiContractMismatchException { final int bindingsVersion; final int scaffoldingVersion; const UniffiContractVersionMismatchException({ required this.bindingsVersion, required this.scaffoldingVersion, }); @override String toString() { return "UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion"; } } class UniffiApiChecksumMismatchException extends UniffiContractMismatchException { final List<String> mismatchedChecksums; const UniffiApiChecksumMismatchException(this.mismatchedChecksums); @override String toString() { return "UniFfi::ApiChecksumMismatch: ${mismatchedChecksums.join(", ")}"; } } const int uniffiCallSuccess = 0; const int uniffiCallError = 1; const int uniffiCallUnexpectedError = 2; final class RustCallStatus extends Struct { @Int8() external int code; external RustBuffer errorBuf; } abstract class UniffiRustCallStatusErrorHandler { Exception lift(RustBuffer errorBuf); } final class RustBuffer extends Struct { @Uint64() external int capacity; @Uint64() external int len; external Pointer<Uint8> data; Uint8List asUint8List() { final dataList = data.asTypedList(len); final byteData = ByteData.sublistView(dataList); return Uint8List.view(byteData.buffer); } @override String toString() { return "RustBuffer{capacity: $capacity, len: $len, data: $data}"; } } final class ForeignBytes extends Struct { @Int32() external int len; external Pointer<Uint8> data; void free() { calloc.free(data); } } class LiftRetVal<T> { final T value; final int bytesRead; const LiftRetVal(this.value, this.bytesRead); LiftRetVal<T> copyWithOffset(int offset) { return LiftRetVal(value, bytesRead + offset); } } abstract class FfiConverter<D, F> { const FfiConverter(); D lift(F value); F lower(D value); D read(ByteData buffer, int offset); void write(D value, ByteData buffer, int offset); int size(D value); } mixin FfiConverterPrimitive<T> on FfiConverter<T, T> { @override T lift(T value) => value; @override T lower(T value) => value; } Uint8List createUint8ListFromInt(int value) { int length = value.bitLength ~/ 8 + 1; if (length != 4 && length != 8) { length = (value < 0x100000000) ? 4 : 8; } Uint8List uint8List = Uint8List(length); for (int i = length - 1; i >= 0; i--) { uint8List[i] = value & 0xFF; value >>= 8; } return uint8List; } const int uniffiRustFuturePollReady = 0; const int uniffiRustFuturePollMaybeReady = 1; typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8); typedef UniffiForeignFutureFree = Void Function(Uint64); typedef UniffiForeignFutureFreeDart = void Function(int); final class UniffiForeignFuture extends Struct { @Uint64() external int handle; external Pointer<NativeFunction<UniffiForeignFutureFree>> free; } class UniffiHandleMap<T> { final Map<int, T> _map = {}; final int Function()? _reserve; int _counter = 1; UniffiHandleMap([this._reserve]); int insert(T obj) { final reserve = _reserve; final int handle; if (reserve != null) { handle = reserve(); } else { handle = _counter; _counter += 2; } _map[handle] = obj; return handle; } T get(int handle) { final obj = _map[handle]; if (obj == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } return obj; } void remove(int handle) { if (maybeRemove(handle) == null) { throw UniffiInternalError( UniffiInternalError.unexpectedStaleHandle, "Handle not found"); } } T? maybeRemove(int handle) { return _map.remove(handle); } } void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) { if (status.ref.code == uniffiCallSuccess) { return; } else if (status.ref.code == uniffiCallError) { throw errorHandler.lift(status.ref.errorBuf); } else if (status.ref.code == uniffiCallUnexpectedError) { if (status.ref.errorBuf.len > 0) { throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf)); } else { throw UniffiInternalError.panicked("Rust panic"); } } else { throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}"); } } T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final result = callback(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return result; } finally { calloc.free(status); } } T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) { _uniffiEnsureInitialized(); final status = calloc<RustCallStatus>(); try { final rawResult = ffiCall(status); checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status); return lifter(rawResult); } finally { calloc.free(status); } } class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler { @override Exception lift(RustBuffer errorBuf) { errorBuf.free(); return UniffiInternalError.panicked("Unexpected CALL_ERROR"); } } extension _UniffiRustBufferOps on RustBuffer { void free() { rustCall((status) => ffiUnsupportedItemsRustbufferFree(this, status)); } } RustBuffer toRustBuffer(Uint8List data) { final length = data.length; final Pointer<Uint8> frameData = calloc<Uint8>(length); final pointerList = frameData.asTypedList(length); pointerList.setAll(0, data); final bytes = calloc<ForeignBytes>(); bytes.ref.len = length; bytes.ref.data = frameData; return rustCall((status) => ffiUnsupportedItemsRustbufferFromBytes(bytes.ref, status)); }  class FfiConverterUInt32 { static int lift(int value) => value; static LiftRetVal<int> read(Uint8List buf) { return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).getUint32(0), 4); } static int lower(int value) { if (value < 0 || value > 4294967295) { throw ArgumentError("Value out of range for u32: $value"); } return value; } static int allocationSize([int value = 0]) { return 4; } static int write(int value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setUint32(0, lower(value)); return 4; } } class FfiConverterBool { static bool lift( int value) { return value == 1; } static int lower( bool value) { return value ? 1 :0; } static LiftRetVal<bool> read( Uint8List buf) { return LiftRetVal(FfiConverterBool.lift(buf.first), 1); } static RustBuffer lowerIntoRustBuffer( bool value) { return toRustBuffer(Uint8List.fromList([FfiConverterBool.lower(value)])); } static int allocationSize([bool value = false]) { return 1; } static int write( bool value, Uint8List buf) { buf.setAll(0, [value ? 1 : 0]); return allocationSize(); } } class FfiConverterString { static String lift( RustBuffer buf) { return utf8.decoder.convert(buf.asUint8List()); } static RustBuffer lower( String value) { return toRustBuffer(Utf8Encoder().convert(value)); } static LiftRetVal<String> read( Uint8List buf) { final end = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0) + 4; return LiftRetVal(utf8.decoder.convert(buf, 4, end), end); } static int allocationSize([String value = ""]) { return utf8.encoder.convert(value).length + 4; } static int write( String value, Uint8List buf) { final list = utf8.encoder.convert(value); buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, list.length); buf.setAll(4, list); return list.length + 4; } } class FfiConverterOptionalRetry { static Retry? lift( RustBuffer buf) { return FfiConverterOptionalRetry.read(buf.asUint8List()).value; } static LiftRetVal<Retry?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterRetry.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Retry?>(result.value, result.bytesRead + 1); } static int allocationSize([Retry? value]) { if (value == null) { return 1; } return FfiConverterRetry.allocationSize(value) + 1; } static RustBuffer lower( Retry? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalRetry.allocationSize(value)); FfiConverterOptionalRetry.write(value, buf); return toRustBuffer(buf); } static int write( Retry? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterRetry.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterOptionalWindow { static Window? lift( RustBuffer buf) { return FfiConverterOptionalWindow.read(buf.asUint8List()).value; } static LiftRetVal<Window?> read( Uint8List buf) { if (ByteData.view(buf.buffer, buf.offsetInBytes).getInt8(0) == 0){ return LiftRetVal(null, 1); } final result = FfiConverterWindow.read(Uint8List.view(buf.buffer, buf.offsetInBytes + 1)); return LiftRetVal<Window?>(result.value, result.bytesRead + 1); } static int allocationSize([Window? value]) { if (value == null) { return 1; } return FfiConverterWindow.allocationSize(value) + 1; } static RustBuffer lower( Window? value) { if (value == null) { return toRustBuffer(Uint8List.fromList([0])); } final buf = Uint8List(FfiConverterOptionalWindow.allocationSize(value)); FfiConverterOptionalWindow.write(value, buf); return toRustBuffer(buf); } static int write( Window? value, Uint8List buf) { if (value == null) { buf[0] = 0; return 1; } buf[0] = 1; return FfiConverterWindow.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1; } } class FfiConverterSequenceString { static List<String> lift( RustBuffer buf) { return FfiConverterSequenceString.read(buf.asUint8List()).value; } static LiftRetVal<List<String>> read( Uint8List buf) { List<String> res = []; final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0); int offset = buf.offsetInBytes + 4; for (var i = 0; i < length; i++) { final ret = FfiConverterString.read(Uint8List.view(buf.buffer, offset)); offset += ret.bytesRead; res.add(ret.value); } return LiftRetVal(res, offset - buf.offsetInBytes); } static int write( List<String> value, Uint8List buf) { buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length); int offset = buf.offsetInBytes + 4; for (var i = 0; i < value.length; i++) { offset += FfiConverterString.write(value[i], Uint8List.view(buf.buffer, offset)); } return offset - buf.offsetInBytes; } static int allocationSize(List<String> value) { return value.map((l) => FfiConverterString.allocationSize(l)).fold(0, (a, b) => a + b) + 4; } static RustBuffer lower( List<String> value) { final buf = Uint8List(allocationSize(value)); write(value, buf); return toRustBuffer(buf); } } final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>(); Future<T> uniffiRustCallAsync<T, F>( Pointer<Void> Function() rustFutureFunc, void Function(Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>) pollFunc, F Function(Pointer<Void>, Pointer<RustCallStatus>) completeFunc, void Function(Pointer<Void>) freeFunc, T Function(F) liftFunc, [ UniffiRustCallStatusErrorHandler? errorHandler, ]) async { _uniffiEnsureInitialized(); final rustFuture = rustFutureFunc(); final completer = Completer<int>(); final handle = _uniffiRustFutureContinuationHandles.insert(completer); final callbackData = Pointer<Void>.fromAddress(handle); late final NativeCallable<UniffiRustFutureContinuationCallback> callback; void repoll() { pollFunc( rustFuture, callback.nativeFunction, callbackData, ); } void onResponse(int data, int pollResult) { if (pollResult == uniffiRustFuturePollReady) { final readyCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (readyCompleter != null && !readyCompleter.isCompleted) { readyCompleter.complete(pollResult); } } else if (pollResult == uniffiRustFuturePollMaybeReady) { repoll(); } else { final errorCompleter = _uniffiRustFutureContinuationHandles.maybeRemove(data); if (errorCompleter != null && !errorCompleter.isCompleted) { errorCompleter.completeError( UniffiInternalError.panicked( "Unexpected poll result from Rust future: $pollResult", ), ); } } } callback = NativeCallable<UniffiRustFutureContinuationCallback>.listener( onResponse, ); try { repoll(); await completer.future; final status = calloc<RustCallStatus>(); try { final result = completeFunc(rustFuture, status); checkCallStatus( errorHandler ?? NullRustCallStatusErrorHandler(), status, ); return liftFunc(result); } finally { calloc.free(status); } } finally { callback.close(); _uniffiRustFutureContinuationHandles.maybeRemove(handle); freeFunc(rustFuture); } } class UniffiForeignFutureState { bool cancelled = false; } final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>(); void _uniffiForeignFutureFree(int handle) { final state = uniffiForeignFutureHandleMap.maybeRemove(handle); if (state != null) { state.cancelled = true; } } final Pointer<NativeFunction<UniffiForeignFutureFree>> uniffiForeignFutureFreePointer = Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree); const _uniffiAssetId = "package:uniffi/uniffi:unsupported_items"; String describeRetry(Retry? retry,) { return rustCallWithLifter( (status) => uniffiUnsupportedItemsFnFuncDescribeRetry( FfiConverterOptionalRetry.lower(retry), status ), FfiConverterString.lift, null ); } String greet(String name,) { return rustCallWithLifter( (status) => uniffiUnsupportedItemsFnFuncGreet( FfiConverterString.lower(name), status ), FfiConverterString.lift, null ); } @Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_unsupported_items_fn_func_describe_retry", assetId: _uniffiAssetId ) external RustBuffer uniffiUnsupportedItemsFnFuncDescribeRetry( RustBuffer retry, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_unsupported_items_fn_func_event_title", assetId: _uniffiAssetId ) external RustBuffer uniffiUnsupportedItemsFnFuncEventTitle( RustBuffer event, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_unsupported_items_fn_func_greet", assetId: _uniffiAssetId ) external RustBuffer uniffiUnsupportedItemsFnFuncGreet( RustBuffer name, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "uniffi_unsupported_items_fn_func_new_event", assetId: _uniffiAssetId ) external RustBuffer uniffiUnsupportedItemsFnFuncNewEvent( RustBuffer title, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Pointer<RustCallStatus>)>( symbol: "uniffi_unsupported_items_fn_func_now", assetId: _uniffiAssetId ) external RustBuffer uniffiUnsupportedItemsFnFuncNow( Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rustbuffer_alloc", assetId: _uniffiAssetId ) external RustBuffer ffiUnsupportedItemsRustbufferAlloc( int size, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( ForeignBytes, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rustbuffer_from_bytes", assetId: _uniffiAssetId ) external RustBuffer ffiUnsupportedItemsRustbufferFromBytes( ForeignBytes bytes, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( RustBuffer, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rustbuffer_free", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustbufferFree( RustBuffer buf, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<RustBuffer Function( RustBuffer, Uint64, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rustbuffer_reserve", assetId: _uniffiAssetId ) external RustBuffer ffiUnsupportedItemsRustbufferReserve( RustBuffer buf, int additional, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_u8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollU8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_u8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelU8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_u8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeU8( Pointer<Void> handle);
+
+@Native<Uint8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_u8", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteU8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_i8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollI8( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_i8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelI8( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_i8", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeI8( Pointer<Void> handle);
+
+@Native<Int8 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_i8", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteI8( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_u16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollU16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_u16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelU16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_u16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeU16( Pointer<Void> handle);
+
+@Native<Uint16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_u16", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteU16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_i16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollI16( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_i16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelI16( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_i16", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeI16( Pointer<Void> handle);
+
+@Native<Int16 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_i16", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteI16( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_u32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollU32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_u32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelU32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_u32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeU32( Pointer<Void> handle);
+
+@Native<Uint32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_u32", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteU32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_i32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollI32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_i32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelI32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_i32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeI32( Pointer<Void> handle);
+
+@Native<Int32 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_i32", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteI32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_u64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollU64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_u64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelU64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_u64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeU64( Pointer<Void> handle);
+
+@Native<Uint64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_u64", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteU64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_i64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollI64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_i64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelI64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_i64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeI64( Pointer<Void> handle);
+
+@Native<Int64 Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_i64", assetId: _uniffiAssetId ) external int ffiUnsupportedItemsRustFutureCompleteI64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_f32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollF32( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_f32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelF32( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_f32", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeF32( Pointer<Void> handle);
+
+@Native<Float Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_f32", assetId: _uniffiAssetId ) external double ffiUnsupportedItemsRustFutureCompleteF32( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_f64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollF64( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_f64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelF64( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_f64", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeF64( Pointer<Void> handle);
+
+@Native<Double Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_f64", assetId: _uniffiAssetId ) external double ffiUnsupportedItemsRustFutureCompleteF64( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_rust_buffer", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollRustBuffer( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_rust_buffer", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelRustBuffer( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_rust_buffer", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeRustBuffer( Pointer<Void> handle);
+
+@Native<RustBuffer Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_rust_buffer", assetId: _uniffiAssetId ) external RustBuffer ffiUnsupportedItemsRustFutureCompleteRustBuffer( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Void Function( Pointer<Void>, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>>, Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_poll_void", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFuturePollVoid( Pointer<Void> handle, Pointer<NativeFunction<UniffiRustFutureContinuationCallback>> callback, Pointer<Void> callbackData);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_cancel_void", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCancelVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>)>( symbol: "ffi_unsupported_items_rust_future_free_void", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureFreeVoid( Pointer<Void> handle);
+
+@Native<Void Function( Pointer<Void>, Pointer<RustCallStatus>)>( symbol: "ffi_unsupported_items_rust_future_complete_void", assetId: _uniffiAssetId ) external void ffiUnsupportedItemsRustFutureCompleteVoid( Pointer<Void> handle, Pointer<RustCallStatus> uniffiStatus);
+
+@Native<Uint16 Function()>( symbol: "uniffi_unsupported_items_checksum_func_describe_retry", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiUnsupportedItemsChecksumFuncDescribeRetry();
+
+@Native<Uint16 Function()>( symbol: "uniffi_unsupported_items_checksum_func_event_title", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiUnsupportedItemsChecksumFuncEventTitle();
+
+@Native<Uint16 Function()>( symbol: "uniffi_unsupported_items_checksum_func_greet", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiUnsupportedItemsChecksumFuncGreet();
+
+@Native<Uint16 Function()>( symbol: "uniffi_unsupported_items_checksum_func_new_event", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiUnsupportedItemsChecksumFuncNewEvent();
+
+@Native<Uint16 Function()>( symbol: "uniffi_unsupported_items_checksum_func_now", assetId: _uniffiAssetId, isLeaf: true ) external int uniffiUnsupportedItemsChecksumFuncNow();
+
+@Native<Uint32 Function()>( symbol: "ffi_unsupported_items_uniffi_contract_version", assetId: _uniffiAssetId, isLeaf: true ) external int ffiUnsupportedItemsUniffiContractVersion();
+
+ void _checkApiVersion() { final bindingsVersion = 30; final scaffoldingVersion = ffiUnsupportedItemsUniffiContractVersion(); if (bindingsVersion != scaffoldingVersion) { throw UniffiContractVersionMismatchException( bindingsVersion: bindingsVersion, scaffoldingVersion: scaffoldingVersion, ); } } void _checkApiChecksums() { final mismatchedChecksums = <String>[]; if (uniffiUnsupportedItemsChecksumFuncDescribeRetry() != 12935) { mismatchedChecksums.add("uniffi_unsupported_items_checksum_func_describe_retry"); }if (uniffiUnsupportedItemsChecksumFuncEventTitle() != 15487) { mismatchedChecksums.add("uniffi_unsupported_items_checksum_func_event_title"); }if (uniffiUnsupportedItemsChecksumFuncGreet() != 65292) { mismatchedChecksums.add("uniffi_unsupported_items_checksum_func_greet"); }if (uniffiUnsupportedItemsChecksumFuncNewEvent() != 23027) { mismatchedChecksums.add("uniffi_unsupported_items_checksum_func_new_event"); }if (uniffiUnsupportedItemsChecksumFuncNow() != 50734) { mismatchedChecksums.add("uniffi_unsupported_items_checksum_func_now"); } if (mismatchedChecksums.isNotEmpty) { throw UniffiApiChecksumMismatchException(mismatchedChecksums); } } bool _uniffiInitialized = false; void _uniffiEnsureInitialized() { if (_uniffiInitialized) { return; } _checkApiVersion(); _checkApiChecksums(); _uniffiInitialized = true; } void ensureInitialized() { _uniffiEnsureInitialized(); } @Deprecated("Use ensureInitialized instead") void initialize() { ensureInitialized(); }
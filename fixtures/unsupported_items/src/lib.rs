@@ -0,0 +1,64 @@
+use std::time::SystemTime;
+
+fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+fn now() -> SystemTime {
+    SystemTime::now()
+}
+
+pub struct Event {
+    title: String,
+    at: SystemTime,
+}
+
+fn new_event(title: String) -> Event {
+    Event { title, at: now() }
+}
+
+fn event_title(event: Event) -> String {
+    event.title
+}
+
+pub struct Retry {
+    label: String,
+    attempts: u32,
+    jitter: bool,
+    backoff: Backoff,
+    reasons: Vec<String>,
+    window: Option<Window>,
+}
+
+pub enum Backoff {
+    Linear,
+    Exponential,
+}
+
+pub struct Window {
+    start: u32,
+    end: u32,
+}
+
+fn describe_retry(retry: Option<Retry>) -> String {
+    let Some(retry) = retry else {
+        return "no retry".to_string();
+    };
+    let backoff = match retry.backoff {
+        Backoff::Linear => "linear",
+        Backoff::Exponential => "exponential",
+    };
+    let window = retry
+        .window
+        .map(|window| format!(" in {}..{}", window.start, window.end))
+        .unwrap_or_default();
+    format!(
+        "{} x{} {backoff}{}{window} ({} reasons)",
+        retry.label,
+        retry.attempts,
+        if retry.jitter { " with jitter" } else { "" },
+        retry.reasons.len()
+    )
+}
+
+uniffi::include_scaffolding!("api");
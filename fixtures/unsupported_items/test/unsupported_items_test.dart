@@ -0,0 +1,14 @@
+import 'package:test/test.dart';
+import '../unsupported_items.dart';
+
+void main() {
+  test('supported functions are generated', () {
+    expect(greet('Dart'), 'Hello, Dart!');
+  });
+
+  test('fields with default values are generated', () {
+    expect(describeRetry(null), 'no retry');
+    final retry = Retry('retry', 3, true, Backoff.exponential, ['busy'], Window(1, 5));
+    expect(describeRetry(retry), 'retry x3 exponential with jitter in 1..5 (1 reasons)');
+  });
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+
+#[test]
+fn unsupported_items() -> Result<()> {
+    uniffi_dart::testing::run_test("unsupported_items", "src/api.udl", Some("uniffi.toml"))
+}
+
+#[test]
+fn unsupported_items_are_reported() -> Result<()> {
+    uniffi_dart::testing::assert_generation_fails(
+        "unsupported_items",
+        "src/api.udl",
+        Some("strict.toml"),
+        &[
+            "`now`: timestamps",
+            "`Event.at`: timestamps",
+            "`new_event`: uses `Event`",
+            "`event_title`: uses `Event`",
+        ],
+    )
+}
+
+#[test]
+fn unsupported_items_output_is_deterministic() -> Result<()> {
+    uniffi_dart::testing::assert_deterministic_output(
+        "unsupported_items",
+        "src/api.udl",
+        Some("uniffi.toml"),
+    )
+}
+
+#[test]
+fn unsupported_items_matches_snapshot() -> Result<()> {
+    uniffi_dart::testing::assert_snapshot("unsupported_items", "src/api.udl", Some("uniffi.toml"))
+}
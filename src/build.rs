@@ -1,6 +1,7 @@
-use anyhow::{Context, Result};
-use camino::Utf8Path;
-use std::env;
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::{env, fs};
+use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
 
 pub fn generate_scaffolding(udl_file: &Utf8Path) -> Result<()> {
     uniffi_build::generate_scaffolding(udl_file)?;
@@ -16,3 +17,113 @@ pub fn generate_scaffolding(udl_file: &Utf8Path) -> Result<()> {
     )?;
     Ok(())
 }
+
+/// Generate the Dart bindings of a crate from its cdylib, for crates without a UDL file.
+///
+/// A build script runs before its own crate is linked, so call this from the build script of a
+/// crate listing `crate_name` under `[build-dependencies]` (a crate packaging the bindings, for
+/// instance). Cargo builds the dependency's cdylib first and reruns the build script whenever it
+/// changes, so the bindings never drift from the Rust code. Every component in the cdylib is
+/// written to `out_dir`, or `$OUT_DIR` when it's `None`.
+///
+/// The cdylib is looked up in cargo's default layout, in the `deps` directory of the host
+/// profile, which also holds with `--target` (build dependencies are always built for the host).
+/// Builds moving artifacts elsewhere (a custom `--artifact-dir`, a build system other than cargo)
+/// should locate the cdylib themselves and call [`generate_dependency_bindings_from_library`].
+pub fn generate_dependency_bindings(crate_name: &str, out_dir: Option<&Utf8Path>) -> Result<()> {
+    let build_out_dir = Utf8PathBuf::from(env::var("OUT_DIR").context("$OUT_DIR missing?!")?);
+    let target = env::var("TARGET").ok();
+    let cdylib = dependency_cdylib(&build_out_dir, target.as_deref(), crate_name)?;
+    if !cdylib.exists() {
+        bail!(
+            "`{cdylib}` not found; is `{crate_name}` a build dependency with a `cdylib` crate type?"
+        );
+    }
+    generate_dependency_bindings_from_library(&cdylib, Some(out_dir.unwrap_or(&build_out_dir)))
+}
+
+/// Generate the Dart bindings of every component in `cdylib`, like
+/// [`generate_dependency_bindings`], when the caller knows where the cdylib is.
+///
+/// The bindings aren't run through `dart format`, so a build produces the same files on every
+/// machine whether or not the Dart SDK is installed.
+pub fn generate_dependency_bindings_from_library(
+    cdylib: &Utf8Path,
+    out_dir: Option<&Utf8Path>,
+) -> Result<()> {
+    let out_dir = match out_dir {
+        Some(out_dir) => out_dir.to_owned(),
+        None => Utf8PathBuf::from(env::var("OUT_DIR").context("$OUT_DIR missing?!")?),
+    };
+    println!("cargo:rerun-if-changed={cdylib}");
+
+    let metadata = cargo_metadata::MetadataCommand::new()
+        .exec()
+        .context("Failed to run cargo metadata")?;
+    let sources =
+        crate::gen::generate_library_sources(cdylib, &CrateConfigSupplier::from(metadata))?;
+    fs::create_dir_all(&out_dir)?;
+    for (file_name, source) in sources {
+        let path = out_dir.join(file_name);
+        fs::write(&path, source).with_context(|| format!("writing `{path}`"))?;
+    }
+    Ok(())
+}
+
+/// Where cargo puts the cdylib of the build dependency `crate_name`, given the build script's
+/// `$OUT_DIR` and `$TARGET`.
+fn dependency_cdylib(
+    build_out_dir: &Utf8Path,
+    target: Option<&str>,
+    crate_name: &str,
+) -> Result<Utf8PathBuf> {
+    // `$OUT_DIR` is `<target-dir>/[<triple>/]<profile>/build/<package>-<hash>/out`, the triple
+    // being there when building with `--target`; build dependencies are built for the host into
+    // `<target-dir>/<profile>/deps` either way
+    let Some(profile_dir) = build_out_dir.ancestors().nth(3) else {
+        bail!("unexpected $OUT_DIR layout: {build_out_dir}");
+    };
+    let Some(profile) = profile_dir.file_name() else {
+        bail!("unexpected $OUT_DIR layout: {build_out_dir}");
+    };
+    let host_profile_dir = match (profile_dir.parent(), target) {
+        (Some(triple_dir), Some(target)) if triple_dir.file_name() == Some(target) => triple_dir
+            .parent()
+            .with_context(|| format!("unexpected $OUT_DIR layout: {build_out_dir}"))?
+            .join(profile),
+        _ => profile_dir.to_owned(),
+    };
+    Ok(host_profile_dir.join("deps").join(format!(
+        "{}{}{}",
+        env::consts::DLL_PREFIX,
+        crate_name.replace('-', "_"),
+        env::consts::DLL_SUFFIX
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_host_cdylib() {
+        let cdylib = Utf8Path::new("/work/target/release/deps").join(format!(
+            "{}my_lib{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        ));
+        for (out_dir, target) in [
+            ("/work/target/release/build/app-0123abcd/out", None),
+            (
+                "/work/target/aarch64-linux-android/release/build/app-0123abcd/out",
+                Some("aarch64-linux-android"),
+            ),
+        ] {
+            assert_eq!(
+                dependency_cdylib(Utf8Path::new(out_dir), target, "my-lib").unwrap(),
+                cdylib
+            );
+        }
+        assert!(dependency_cdylib(Utf8Path::new("out"), None, "my_lib").is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! Runtime of the callback interfaces marked with `#[uniffi_dart::thread_safe]`.
+//!
+//! The attribute builds the vtable Rust calls the Dart implementation through out of trampolines.
+//! Every isolate implementing the interface registers its own functions, and Rust hands out the
+//! handles of Dart objects from a process-wide counter, remembering the isolate each belongs to.
+//! Called on the thread that isolate runs on, a trampoline calls the Dart function directly.
+//! Called from any other thread, it posts the call to the isolate through a
+//! `NativeCallable.listener` and blocks until Dart signals that the call completed. Methods that
+//! return nothing and can't fail, and freeing handles, are always queued through the listener
+//! instead, even on the isolate's thread, so they never re-enter Dart during a Rust call.
+//!
+//! A blocked thread only resumes once the isolate ran the call, so Rust must not wait on such a
+//! thread from the isolate's own thread, nor call into an isolate that has shut down.
+
+use std::collections::BTreeMap;
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, RwLock};
+
+/// The next handle to hand out. As of uniffi 0.30, foreign handles must have the lowest bit set.
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// The functions an isolate registered for an interface, by vtable slot: the free function
+/// first, then the methods in declaration order.
+struct Registration {
+    isolate: usize,
+    direct: Vec<usize>,
+    listeners: Vec<usize>,
+}
+
+/// A handle of a Dart object, with the references to it Rust holds.
+struct Owner {
+    registration: Arc<Registration>,
+    references: usize,
+}
+
+/// How a trampoline reaches the Dart function of a vtable slot.
+pub enum Target {
+    /// A `Pointer.fromFunction`, callable because this thread runs the owning isolate.
+    Direct(*const c_void),
+    /// A `NativeCallable.listener` posting to the owning isolate.
+    Listener(*const c_void),
+}
+
+/// The Dart side of a callback interface.
+pub struct Interface {
+    /// The functions of each isolate implementing the interface, by isolate.
+    registrations: RwLock<BTreeMap<usize, Arc<Registration>>>,
+    owners: Mutex<BTreeMap<u64, Owner>>,
+}
+
+impl Interface {
+    pub const fn new() -> Self {
+        Self {
+            registrations: RwLock::new(BTreeMap::new()),
+            owners: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Registers the functions of the calling isolate, which receives the calls to the objects
+    /// it hands to Rust afterwards. Fails if the Dart API doesn't let Rust tell which isolate a
+    /// thread runs.
+    ///
+    /// # Safety
+    ///
+    /// `api` must be `NativeApi.initializeApiDLData`, and `direct` and `listeners` must point to
+    /// `len` function pointers each.
+    pub unsafe fn register(
+        &self,
+        api: *mut c_void,
+        direct: *const *const c_void,
+        listeners: *const *const c_void,
+        len: usize,
+    ) -> bool {
+        if !init_api(api) {
+            return false;
+        }
+        let slots = |ptr: *const *const c_void| {
+            std::slice::from_raw_parts(ptr, len)
+                .iter()
+                .map(|&f| f as usize)
+                .collect()
+        };
+        let isolate = current_isolate();
+        let registration = Registration {
+            isolate,
+            direct: slots(direct),
+            listeners: slots(listeners),
+        };
+        self.registrations
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(isolate, Arc::new(registration));
+        true
+    }
+
+    /// Hands out a handle for an object of the calling isolate, which must have registered.
+    pub fn new_handle(&self) -> u64 {
+        let registration = self
+            .registrations
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&current_isolate())
+            .cloned()
+            .expect("handles are only requested after registering");
+        let handle = NEXT_HANDLE.fetch_add(2, Ordering::Relaxed);
+        self.owners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                handle,
+                Owner {
+                    registration,
+                    references: 1,
+                },
+            );
+        handle
+    }
+
+    /// How to reach the Dart function of `slot` for `handle` from the current thread.
+    pub fn target(&self, handle: u64, slot: usize) -> Target {
+        let registration = self.registration(handle);
+        if registration.isolate == current_isolate() {
+            Target::Direct(registration.direct[slot] as *const c_void)
+        } else {
+            Target::Listener(registration.listeners[slot] as *const c_void)
+        }
+    }
+
+    /// The listener of `slot` for `handle`, for calls queued even on the owning isolate's thread.
+    pub fn listener(&self, handle: u64, slot: usize) -> *const c_void {
+        self.registration(handle).listeners[slot] as *const c_void
+    }
+
+    fn registration(&self, handle: u64) -> Arc<Registration> {
+        self.owners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&handle)
+            .map(|owner| owner.registration.clone())
+            .expect("Rust only calls the handles it holds")
+    }
+
+    /// Adds a reference to `handle`, which Rust frees separately.
+    pub fn clone_handle(&self, handle: u64) -> u64 {
+        if let Some(owner) = self
+            .owners
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_mut(&handle)
+        {
+            owner.references += 1;
+        }
+        handle
+    }
+
+    /// Drops a reference to `handle`. Once the last one is gone, returns the listener freeing it
+    /// in the owning isolate.
+    pub fn release_handle(&self, handle: u64) -> Option<*const c_void> {
+        let mut owners = self.owners.lock().unwrap_or_else(|e| e.into_inner());
+        let owner = owners.get_mut(&handle)?;
+        owner.references -= 1;
+        if owner.references > 0 {
+            return None;
+        }
+        let owner = owners.remove(&handle)?;
+        Some(owner.registration.listeners[0] as *const c_void)
+    }
+}
+
+impl Default for Interface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Blocks a trampoline until Dart completed the call it posted.
+#[derive(Default)]
+pub struct Waiter {
+    done: Mutex<bool>,
+    completed: Condvar,
+}
+
+impl Waiter {
+    /// Returns the waiter along with the pointer Dart passes to [`signal`] once done.
+    pub fn new() -> (Arc<Self>, *const Self) {
+        let waiter = Arc::new(Self::default());
+        let signal = Arc::into_raw(waiter.clone());
+        (waiter, signal)
+    }
+
+    pub fn wait(&self) {
+        let mut done = self.done.lock().unwrap_or_else(|e| e.into_inner());
+        while !*done {
+            done = self.completed.wait(done).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+}
+
+/// Wakes the trampoline waiting on `waiter`.
+///
+/// # Safety
+///
+/// `waiter` must be a pointer returned by [`Waiter::new`], signalled only once.
+pub unsafe fn signal(waiter: *const Waiter) {
+    let waiter = Arc::from_raw(waiter);
+    *waiter.done.lock().unwrap_or_else(|e| e.into_inner()) = true;
+    waiter.completed.notify_one();
+}
+
+// Layout of `NativeApi.initializeApiDLData`, see `dart_api_dl.h` in the Dart SDK.
+#[repr(C)]
+struct DartApiEntry {
+    name: *const c_char,
+    function: *const c_void,
+}
+
+#[repr(C)]
+struct DartApi {
+    major: c_int,
+    minor: c_int,
+    functions: *const DartApiEntry,
+}
+
+type CurrentIsolateFn = unsafe extern "C" fn() -> *mut c_void;
+
+static CURRENT_ISOLATE: OnceLock<Option<CurrentIsolateFn>> = OnceLock::new();
+
+unsafe fn init_api(api: *mut c_void) -> bool {
+    CURRENT_ISOLATE
+        .get_or_init(|| {
+            let api = &*(api as *const DartApi);
+            if api.major != 2 {
+                return None;
+            }
+            let mut entry = api.functions;
+            while !(*entry).name.is_null() {
+                if CStr::from_ptr((*entry).name) == c"Dart_CurrentIsolate" {
+                    return Some(std::mem::transmute::<*const c_void, CurrentIsolateFn>(
+                        (*entry).function,
+                    ));
+                }
+                entry = entry.add(1);
+            }
+            None
+        })
+        .is_some()
+}
+
+/// The isolate the current thread runs, or 0 for threads outside of Dart.
+fn current_isolate() -> usize {
+    match CURRENT_ISOLATE.get() {
+        Some(Some(current_isolate)) => unsafe { current_isolate() as usize },
+        _ => 0,
+    }
+}
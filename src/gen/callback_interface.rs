@@ -1,35 +1,54 @@
-use crate::gen::CodeType;
+use crate::gen::naming::Names;
+use crate::gen::{CodeType, Config};
 use genco::prelude::*;
 use heck::ToUpperCamelCase;
-use uniffi_bindgen::interface::Type;
 use uniffi_bindgen::interface::{
     ffi::{FfiStruct, FfiType},
     AsType, Method,
 };
+use uniffi_bindgen::ComponentInterface;
 
+use crate::gen::imports::{uses, DART_FFI, DART_TYPED_DATA, PACKAGE_FFI};
 use crate::gen::oracle::{AsCodeType, DartCodeOracle};
 use crate::gen::render::AsRenderable;
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 
+/// Docstring line `#[uniffi_dart::thread_safe]` marks callback interfaces with.
+const THREAD_SAFE_MARKER: &str = "uniffi-dart: thread_safe";
+
 #[derive(Debug)]
 pub struct CallbackInterfaceCodeType {
     name: String,
-    self_type: Type,
 }
 
 impl CallbackInterfaceCodeType {
-    pub fn new(name: String, self_type: Type) -> Self {
-        Self { name, self_type }
+    pub fn new(name: String) -> Self {
+        Self { name }
     }
 }
 
 impl CodeType for CallbackInterfaceCodeType {
-    fn type_label(&self) -> String {
-        super::DartCodeOracle::class_name(&self.name)
+    fn type_label(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.name,
+            DartCodeOracle::class_name(names, &self.name),
+        )
+    }
+
+    fn canonical_name(&self, names: &Names) -> String {
+        format!(
+            "CallbackInterface{}",
+            DartCodeOracle::class_name(names, &self.name)
+        )
     }
 
-    fn canonical_name(&self) -> String {
-        format!("CallbackInterface{}", self.type_label())
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.name,
+            format!("FfiConverter{}", self.canonical_name(names)),
+        )
     }
 
     fn initialization_fn(&self) -> Option<String> {
@@ -39,7 +58,7 @@ impl CodeType for CallbackInterfaceCodeType {
 
 impl Renderable for CallbackInterfaceCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        type_helper.include_once_check(&self.canonical_name(), &self.self_type);
+        let names = type_helper.get_names();
         let callback = type_helper
             .get_ci()
             .get_callback_interface_definition(&self.name)
@@ -48,7 +67,7 @@ impl Renderable for CallbackInterfaceCodeType {
         // Generate all necessary components for the callback interface
         let interface = generate_callback_interface(
             callback.name(),
-            &callback.as_codetype().ffi_converter_name(),
+            &callback.as_codetype().ffi_converter_name(names),
             &callback.methods(),
             type_helper,
         );
@@ -63,6 +82,7 @@ impl Renderable for CallbackInterfaceCodeType {
             .to_string();
         let ffi_module = DartCodeOracle::infer_ffi_module(type_helper.get_ci(), move || namespace);
         let vtable_init = generate_callback_interface_vtable_init_function(
+            type_helper,
             callback.name(),
             &callback.methods(),
             &ffi_module,
@@ -83,22 +103,19 @@ pub fn generate_callback_interface(
     methods: &[&Method],
     type_helper: &dyn TypeHelperRenderer,
 ) -> dart::Tokens {
-    let cls_name = &DartCodeOracle::class_name(callback_name);
-    let ffi_conv_name = &DartCodeOracle::class_name(ffi_converter_name);
+    let names = type_helper.get_names();
+    let cls_name = &DartCodeOracle::class_name(names, callback_name);
+    let ffi_conv_name = ffi_converter_name;
     let init_fn_name = &format!("init{callback_name}VTable");
 
-    // TODO: Use global deduplication to avoid generating duplicate async types
-    // when multiple async callback interfaces are defined
-    let mut async_struct_defs: Vec<dart::Tokens> = Vec::new();
-    let mut async_completion_typedefs: Vec<dart::Tokens> = Vec::new();
-
+    // Async result structs and completion typedefs are shared by all callback interfaces
     for method in methods {
         if method.is_async() {
             let struct_def = method.foreign_future_ffi_result_struct();
             let struct_name = struct_def.name().to_string();
 
             if !type_helper.include_once_by_name(&struct_name) {
-                async_struct_defs.push(generate_foreign_future_struct_definition(
+                type_helper.add_support_definition(generate_foreign_future_struct_definition(
                     &struct_def,
                     type_helper,
                 ));
@@ -106,7 +123,7 @@ pub fn generate_callback_interface(
 
             let completion_name = foreign_future_completion_name(method);
             if !type_helper.include_once_by_name(&completion_name) {
-                async_completion_typedefs.push(generate_foreign_future_completion_typedef(
+                type_helper.add_support_definition(generate_foreign_future_completion_typedef(
                     &completion_name,
                     &struct_name,
                 ));
@@ -114,26 +131,37 @@ pub fn generate_callback_interface(
         }
     }
 
-    let async_support = if !async_struct_defs.is_empty() || !async_completion_typedefs.is_empty() {
-        quote! {
-            $(for typedef in &async_completion_typedefs => $typedef)
-            $(for struct_def in &async_struct_defs => $struct_def)
-        }
+    let thread_safe = is_thread_safe(
+        type_helper.get_ci(),
+        callback_name,
+        type_helper.get_config(),
+    );
+    // Thread-safe interfaces have Rust hand out the handles and remember the isolate each one
+    // came from, so implementations can be handed to Rust from several isolates at once. Other
+    // interfaces keep a single vtable per process, taken over by whichever isolate registers
+    // it last.
+    let handle_map = if thread_safe {
+        let new_handle_fn = DartCodeOracle::ffi_function_name(&dispatch_symbol(
+            type_helper.get_ci(),
+            callback_name,
+            "new_handle",
+        ));
+        quote!(UniffiHandleMap<$cls_name>($new_handle_fn))
     } else {
-        quote!()
+        quote!(UniffiHandleMap<$cls_name>())
     };
-
     let tokens = quote! {
+        $(uses([DART_FFI, DART_TYPED_DATA]))
         // This is the abstract class to be implemented
         abstract class $cls_name {
             $(for m in methods {
-                $(generate_callback_methods_definitions(m, type_helper))
+                $(generate_callback_methods_definitions(names, m))
             })
         }
 
         // This is the type helper to convert from FFI to Dart
         class $ffi_conv_name {
-            static final _handleMap = UniffiHandleMap<$cls_name>();
+            static final _handleMap = $handle_map;
             static bool _vtableInitialized = false;
 
             static $cls_name lift(Pointer<Void> handle) {
@@ -170,26 +198,20 @@ pub fn generate_callback_interface(
             }
         }
 
-        // Additional support definitions for async callbacks
-        $async_support
-
         // We must define callback signatures
-        $(generate_callback_methods_signatures(cls_name, methods, type_helper))
+        $(generate_callback_methods_signatures(cls_name, methods, thread_safe, type_helper))
     };
 
     tokens
 }
 
-fn generate_callback_methods_definitions(
-    method: &Method,
-    type_helper: &dyn TypeHelperRenderer,
-) -> dart::Tokens {
-    let method_name = DartCodeOracle::fn_name(method.name());
+fn generate_callback_methods_definitions(names: &Names, method: &Method) -> dart::Tokens {
+    let method_name = DartCodeOracle::member_name(names, method.object_name(), method.name());
     let dart_args = &method
         .arguments()
         .iter()
         .map(|arg| {
-            let arg_type = arg.as_renderable().render_type(&arg.as_type(), type_helper);
+            let arg_type = arg.as_renderable().render_type(&arg.as_type(), names);
             let arg_name = DartCodeOracle::var_name(arg.name());
 
             quote!($arg_type $arg_name)
@@ -198,13 +220,13 @@ fn generate_callback_methods_definitions(
 
     let ret_type = if method.is_async() {
         if let Some(ret) = method.return_type() {
-            let rendered = ret.as_renderable().render_type(ret, type_helper);
+            let rendered = ret.as_renderable().render_type(ret, names);
             quote!(Future<$rendered>)
         } else {
             quote!(Future<void>)
         }
     } else if let Some(ret) = method.return_type() {
-        ret.as_renderable().render_type(ret, type_helper)
+        ret.as_renderable().render_type(ret, names)
     } else {
         quote!(void)
     };
@@ -217,8 +239,10 @@ fn generate_callback_methods_definitions(
 fn generate_callback_methods_signatures(
     callback_name: &str,
     methods: &[&Method],
+    thread_safe: bool,
     type_helper: &dyn TypeHelperRenderer,
 ) -> dart::Tokens {
+    let names = type_helper.get_names();
     let mut tokens = dart::Tokens::new();
     for (method_index, method) in methods.iter().enumerate() {
         //let method_name = DartCodeOracle::fn_name(method.name());
@@ -231,7 +255,7 @@ fn generate_callback_methods_signatures(
             .arguments()
             .iter()
             .map(|arg| {
-                DartCodeOracle::native_type_label(Some(&arg.as_type()), type_helper.get_ci())
+                DartCodeOracle::native_type_label(names, Some(&arg.as_type()), type_helper.get_ci())
             })
             .collect();
 
@@ -239,7 +263,11 @@ fn generate_callback_methods_signatures(
             .arguments()
             .iter()
             .map(|arg| {
-                DartCodeOracle::native_dart_type_label(Some(&arg.as_type()), type_helper.get_ci())
+                DartCodeOracle::native_dart_type_label(
+                    names,
+                    Some(&arg.as_type()),
+                    type_helper.get_ci(),
+                )
             })
             .collect();
 
@@ -249,28 +277,42 @@ fn generate_callback_methods_signatures(
             let completion_pointer = format!("Pointer<NativeFunction<{}>>", completion_native);
 
             tokens.append(quote! {
-                typedef $ffi_method_type = Void Function(
+                typedef $(&ffi_method_type) = Void Function(
                     Uint64, $(for arg in &arg_native_types => $arg,)
                     $(&completion_pointer), Uint64, Pointer<UniffiForeignFuture>);
                 typedef $dart_method_type = void Function(
                     int, $(for arg in &arg_dart_types => $arg,)
                     $(&completion_pointer), int, Pointer<UniffiForeignFuture>);
             });
+            if thread_safe {
+                tokens.append(quote! {
+                    typedef $(&ffi_method_type)Blocking = Void Function(
+                        Uint64, $(for arg in &arg_native_types => $arg,)
+                        $(&completion_pointer), Uint64, Pointer<UniffiForeignFuture>, Pointer<Void>);
+                });
+            }
         } else {
             let method_return_type = if let Some(ret) = method.return_type() {
-                DartCodeOracle::native_type_label(Some(ret), type_helper.get_ci())
+                DartCodeOracle::native_type_label(names, Some(ret), type_helper.get_ci())
             } else {
                 quote!(Void)
             };
 
             tokens.append(quote! {
-                typedef $ffi_method_type = Void Function(
+                typedef $(&ffi_method_type) = Void Function(
                     Uint64, $(for arg in &arg_native_types => $arg,)
                     Pointer<$(&method_return_type)>, Pointer<RustCallStatus>);
                 typedef $dart_method_type = void Function(
                     int, $(for arg in &arg_dart_types => $arg,)
                     Pointer<$(&method_return_type)>, Pointer<RustCallStatus>);
             });
+            if thread_safe && !is_queued(method) {
+                tokens.append(quote! {
+                    typedef $(&ffi_method_type)Blocking = Void Function(
+                        Uint64, $(for arg in &arg_native_types => $arg,)
+                        Pointer<$(&method_return_type)>, Pointer<RustCallStatus>, Pointer<Void>);
+                });
+            }
         }
     }
 
@@ -307,10 +349,21 @@ pub fn generate_callback_functions(
     methods: &[&Method],
     type_helper: &dyn TypeHelperRenderer,
 ) -> dart::Tokens {
-    let cls_name = &DartCodeOracle::class_name(callback_name);
+    let names = type_helper.get_names();
+    let cls_name = &DartCodeOracle::class_name(names, callback_name);
+    let thread_safe = is_thread_safe(
+        type_helper.get_ci(),
+        callback_name,
+        type_helper.get_config(),
+    );
+    let signal_fn = DartCodeOracle::ffi_function_name(&dispatch_symbol(
+        type_helper.get_ci(),
+        callback_name,
+        "signal",
+    ));
 
     let functions: Vec<dart::Tokens> = methods.iter().enumerate().map(|(index, m)| {
-        let method_name = &DartCodeOracle::fn_name(m.name()).to_string();
+        let method_name = &DartCodeOracle::member_name(names, m.object_name(), m.name());
         let ffi_method_type = &format!("UniffiCallbackInterface{callback_name}Method{index}");
         let _dart_method_type = &format!("UniffiCallbackInterface{callback_name}Method{index}Dart");
 
@@ -320,7 +373,7 @@ pub fn generate_callback_functions(
             .iter()
             .map(|arg| {
                 let arg_name = DartCodeOracle::var_name(arg.name());
-                DartCodeOracle::callback_param_type(&arg.as_type(), &arg_name, type_helper.get_ci())
+                DartCodeOracle::callback_param_type(names, &arg.as_type(), &arg_name, type_helper.get_ci())
             })
             .collect();
 
@@ -331,7 +384,7 @@ pub fn generate_callback_functions(
             .enumerate()
             .map(|(arg_idx, arg)| {
                 let arg_name = DartCodeOracle::var_name(arg.name());
-                DartCodeOracle::callback_arg_lift_indexed(&arg.as_type(), &arg_name, arg_idx)
+                DartCodeOracle::callback_arg_lift_indexed(names, &arg.as_type(), &arg_name, arg_idx)
             })
             .collect();
 
@@ -345,7 +398,7 @@ pub fn generate_callback_functions(
 
         // Generate the function body
         let callback_method_name =
-            &format!("{}{}", &DartCodeOracle::fn_name(callback_name), &DartCodeOracle::class_name(m.name()));
+            &format!("{}{}", &DartCodeOracle::fn_name(callback_name), &DartCodeOracle::class_name(names, m.name()));
 
         if m.is_async() {
             let completion_base = foreign_future_completion_name(m);
@@ -356,18 +409,45 @@ pub fn generate_callback_functions(
             let struct_tokens = DartCodeOracle::ffi_struct_name(result_struct.name());
             let struct_tokens_alt = struct_tokens.clone();
 
-            if let Some(ret) = m.return_type() {
-                type_helper.include_once_check(&ret.as_codetype().canonical_name(), ret);
-            }
-
             let success_return = if let Some(ret) = m.return_type() {
-                let converter = ret.as_codetype().ffi_converter_name();
+                let converter = ret.as_codetype().ffi_converter_name(names);
                 quote!(resultStructPtr.ref.returnValue = $(&converter).lower(result);)
             } else {
                 quote!()
             };
 
-            quote! {
+            // Rust drops the future, and frees it, on whichever thread it awaited it on
+            let future_free_pointer = if thread_safe {
+                if !type_helper.include_once_by_name("uniffiForeignFutureFreeListenerPointer") {
+                    type_helper.add_support_definition(quote! {
+                        final _uniffiForeignFutureFreeCallable =
+                            NativeCallable<UniffiForeignFutureFree>.listener(_uniffiForeignFutureFree)
+                                ..keepIsolateAlive = false;
+                        final Pointer<NativeFunction<UniffiForeignFutureFree>>
+                            uniffiForeignFutureFreeListenerPointer =
+                                _uniffiForeignFutureFreeCallable.nativeFunction;
+                    });
+                }
+                "uniffiForeignFutureFreeListenerPointer"
+            } else {
+                "uniffiForeignFutureFreePointer"
+            };
+            let handoff = thread_safe.then(|| {
+                blocking_handoff(
+                    callback_method_name,
+                    &format!("{ffi_method_type}Blocking"),
+                    &signal_fn,
+                    &param_types,
+                    &m.arguments().iter().map(|arg| DartCodeOracle::var_name(arg.name())).collect::<Vec<_>>(),
+                    &[
+                        (quote!($(&completion_pointer)), "uniffiFutureCallback"),
+                        (quote!(int), "uniffiCallbackData"),
+                        (quote!(Pointer<UniffiForeignFuture>), "outReturn"),
+                    ],
+                )
+            });
+
+            let mut tokens = quote! {
                 void $callback_method_name(
                     int uniffiHandle,
                     $(for param in &param_types => $param,)
@@ -378,15 +458,15 @@ pub fn generate_callback_functions(
                     final obj = FfiConverterCallbackInterface$cls_name._handleMap.get(uniffiHandle);
                     $(arg_lifts)
                     final callback = uniffiFutureCallback.asFunction<$(&completion_dart)>();
-                    final state = _UniffiForeignFutureState();
-                    final handle = _uniffiForeignFutureHandleMap.insert(state);
+                    final state = UniffiForeignFutureState();
+                    final handle = uniffiForeignFutureHandleMap.insert(state);
                     outReturn.ref.handle = handle;
-                    outReturn.ref.free = _uniffiForeignFutureFreePointer;
+                    outReturn.ref.free = $future_free_pointer;
 
                     () async {
                         try {
                             final result = await obj.$method_name($(for arg in &arg_names => $arg,));
-                            final removedState = _uniffiForeignFutureHandleMap.maybeRemove(handle);
+                            final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle);
                             final effectiveState = removedState ?? state;
                             if (effectiveState.cancelled) {
                                 return;
@@ -395,13 +475,13 @@ pub fn generate_callback_functions(
                             final resultStructPtr = calloc<$struct_tokens>();
                             try {
                                 $success_return
-                                resultStructPtr.ref.callStatus.code = CALL_SUCCESS;
+                                resultStructPtr.ref.callStatus.code = uniffiCallSuccess;
                                 callback(uniffiCallbackData, resultStructPtr.ref);
                             } finally {
                                 calloc.free(resultStructPtr);
                             }
                         } catch (e) {
-                            final removedState = _uniffiForeignFutureHandleMap.maybeRemove(handle);
+                            final removedState = uniffiForeignFutureHandleMap.maybeRemove(handle);
                             final effectiveState = removedState ?? state;
                             if (effectiveState.cancelled) {
                                 return;
@@ -409,7 +489,7 @@ pub fn generate_callback_functions(
                             effectiveState.cancelled = true;
                             final resultStructPtr = calloc<$struct_tokens_alt>();
                             try {
-                                resultStructPtr.ref.callStatus.code = CALL_UNEXPECTED_ERROR;
+                                resultStructPtr.ref.callStatus.code = uniffiCallUnexpectedError;
                                 resultStructPtr.ref.callStatus.errorBuf =
                                     FfiConverterString.lower(e.toString());
                                 callback(uniffiCallbackData, resultStructPtr.ref);
@@ -422,20 +502,55 @@ pub fn generate_callback_functions(
 
                 final Pointer<NativeFunction<$ffi_method_type>> $(callback_method_name)Pointer =
                     Pointer.fromFunction<$ffi_method_type>($callback_method_name);
+            };
+            if let Some(handoff) = handoff {
+                tokens.line();
+                tokens.append(handoff);
             }
+            tokens
         } else {
             // Handle return value using the oracle
             let call_dart_method = if let Some(ret) = m.return_type() {
-                DartCodeOracle::callback_return_handling(ret, method_name, arg_names)
+                DartCodeOracle::callback_return_handling(names, ret, method_name, arg_names.clone())
             } else {
                 // Handle void return types
-                DartCodeOracle::callback_void_handling(method_name, arg_names)
+                DartCodeOracle::callback_void_handling(method_name, arg_names.clone())
             };
 
             // Get the appropriate out return type
             let out_return_type = DartCodeOracle::callback_out_return_type(m.return_type());
 
-            quote! {
+            let handoff = if !thread_safe {
+                None
+            } else if is_queued(m) {
+                // Rust doesn't wait for these, so neither `outReturn` nor `callStatus` may be
+                // touched. Errors thrown by the implementation surface as uncaught errors in the
+                // isolate.
+                let queued_fn = format!("{callback_method_name}Queued");
+                Some(quote! {
+                    void $(&queued_fn)(int uniffiHandle, $(for param in &param_types => $param,) $(&out_return_type) outReturn, Pointer<RustCallStatus> callStatus) {
+                        final obj = FfiConverterCallbackInterface$cls_name._handleMap.get(uniffiHandle);
+                        $(arg_lifts.clone())
+                        obj.$method_name($(for arg in &arg_names => $arg,));
+                    }
+
+                    $(listener_pointer(&queued_fn, &format!("{callback_method_name}ListenerPointer"), ffi_method_type))
+                })
+            } else {
+                Some(blocking_handoff(
+                    callback_method_name,
+                    &format!("{ffi_method_type}Blocking"),
+                    &signal_fn,
+                    &param_types,
+                    &m.arguments().iter().map(|arg| DartCodeOracle::var_name(arg.name())).collect::<Vec<_>>(),
+                    &[
+                        (out_return_type.clone(), "outReturn"),
+                        (quote!(Pointer<RustCallStatus>), "callStatus"),
+                    ],
+                ))
+            };
+
+            let mut tokens = quote! {
                 void $callback_method_name(int uniffiHandle, $(for param in &param_types => $param,) $out_return_type outReturn, Pointer<RustCallStatus> callStatus) {
                     final status = callStatus.ref;
                     try {
@@ -443,14 +558,19 @@ pub fn generate_callback_functions(
                         $(arg_lifts)
                         $call_dart_method
                     } catch (e) {
-                        status.code = CALL_UNEXPECTED_ERROR;
+                        status.code = uniffiCallUnexpectedError;
                         status.errorBuf = FfiConverterString.lower(e.toString());
                     }
                 }
 
                 final Pointer<NativeFunction<$ffi_method_type>> $(callback_method_name)Pointer =
                     Pointer.fromFunction<$ffi_method_type>($callback_method_name);
+            };
+            if let Some(handoff) = handoff {
+                tokens.line();
+                tokens.append(handoff);
             }
+            tokens
         }
     }).collect();
 
@@ -465,32 +585,116 @@ pub fn generate_callback_functions(
     let clone_callback_type = &format!("UniffiCallbackInterface{callback_name}Clone");
 
     quote! {
+        $(uses([DART_FFI, PACKAGE_FFI]))
         $(functions)
 
         void $free_callback_fn(int handle) {
-            try {
-                FfiConverterCallbackInterface$cls_name._handleMap.remove(handle);
-            } catch (e) {
-                // Optionally log error, but do not return anything.
-            }
+            // Freeing an unknown handle is a no-op
+            FfiConverterCallbackInterface$cls_name._handleMap.maybeRemove(handle);
         }
 
         final Pointer<NativeFunction<$free_callback_type>> $free_callback_pointer =
             Pointer.fromFunction<$free_callback_type>($free_callback_fn);
 
-        int $clone_callback_fn(int handle) {
+        $(if thread_safe {
+            $(listener_pointer(free_callback_fn, &format!("{}FreeListenerPointer", DartCodeOracle::fn_name(callback_name)), free_callback_type))
+        } else {
+            int $clone_callback_fn(int handle) {
+                try {
+                    final obj = FfiConverterCallbackInterface$cls_name._handleMap.get(handle);
+                    final newHandle = FfiConverterCallbackInterface$cls_name._handleMap.insert(obj);
+                    return newHandle;
+                } catch (e) {
+                    // Return 0 on error, which should trigger an error on the Rust side
+                    return 0;
+                }
+            }
+
+            final Pointer<NativeFunction<$clone_callback_type>> $clone_callback_pointer =
+                Pointer.fromFunction<$clone_callback_type>($clone_callback_fn, 0);
+        })
+    }
+}
+
+/// Whether the implementations of `callback_name` may be called from any thread: it's listed in
+/// the config's `thread_safe_callbacks`, or marked by `#[uniffi_dart::thread_safe]`.
+pub(super) fn is_thread_safe(
+    ci: &ComponentInterface,
+    callback_name: &str,
+    config: &Config,
+) -> bool {
+    let docstring = match ci.get_callback_interface_definition(callback_name) {
+        Some(callback) => callback.docstring(),
+        None => ci
+            .get_object_definition(callback_name)
+            .and_then(|obj| obj.docstring()),
+    };
+    config.is_thread_safe_callback(callback_name)
+        || docstring.is_some_and(|doc| doc.lines().any(|line| line.trim() == THREAD_SAFE_MARKER))
+}
+
+/// Symbol of a function `#[uniffi_dart::thread_safe]` exports for `callback_name`.
+pub(super) fn dispatch_symbol(
+    ci: &ComponentInterface,
+    callback_name: &str,
+    suffix: &str,
+) -> String {
+    format!(
+        "uniffi_dart_{}_{}_{suffix}",
+        ci.crate_name(),
+        callback_name.to_lowercase()
+    )
+}
+
+/// Whether Rust queues calls to a thread-safe method made off the Dart thread instead of waiting
+/// for them, because there's nothing to hand back.
+fn is_queued(method: &Method) -> bool {
+    !method.is_async() && method.return_type().is_none() && method.throws_type().is_none()
+}
+
+/// Exposes `fn_name` to Rust threads through a listener taking the waiter to signal once done,
+/// as `{fn_name}ListenerPointer`. `params` and `out_params` are the parameters of `fn_name` after
+/// the handle.
+fn blocking_handoff(
+    fn_name: &str,
+    native_type: &str,
+    signal_fn: &str,
+    params: &[dart::Tokens],
+    arg_names: &[String],
+    out_params: &[(dart::Tokens, &str)],
+) -> dart::Tokens {
+    let blocking_fn = format!("{fn_name}Blocking");
+    quote! {
+        void $(&blocking_fn)(
+            int uniffiHandle,
+            $(for param in params => $param,)
+            $(for (ty, name) in out_params => $ty $(*name),)
+            Pointer<Void> uniffiWaiter,
+        ) {
             try {
-                final obj = FfiConverterCallbackInterface$cls_name._handleMap.get(handle);
-                final newHandle = FfiConverterCallbackInterface$cls_name._handleMap.insert(obj);
-                return newHandle;
-            } catch (e) {
-                // Return 0 on error, which should trigger an error on the Rust side
-                return 0;
+                $fn_name(
+                    uniffiHandle,
+                    $(for arg in arg_names => $arg,)
+                    $(for (_, name) in out_params => $(*name),)
+                );
+            } finally {
+                $signal_fn(uniffiWaiter);
             }
         }
 
-        final Pointer<NativeFunction<$clone_callback_type>> $clone_callback_pointer =
-            Pointer.fromFunction<$clone_callback_type>($clone_callback_fn, 0);
+        $(listener_pointer(&blocking_fn, &format!("{fn_name}ListenerPointer"), native_type))
+    }
+}
+
+/// Exposes `fn_name` as `pointer_name` through a `NativeCallable.listener`, which may be invoked
+/// from any thread. It doesn't keep the isolate alive on its own, matching `Pointer.fromFunction`.
+fn listener_pointer(fn_name: &str, pointer_name: &str, native_type: &str) -> dart::Tokens {
+    let callable_name = format!("_{fn_name}Callable");
+    quote! {
+        final $(&callable_name) = NativeCallable<$native_type>.listener($fn_name)
+            ..keepIsolateAlive = false;
+        final Pointer<NativeFunction<$native_type>> $pointer_name =
+            $(&callable_name).nativeFunction;
     }
 }
 
@@ -569,41 +773,93 @@ fn foreign_future_field_annotation(field_type: &FfiType) -> Option<dart::Tokens>
 }
 
 pub fn generate_callback_interface_vtable_init_function(
+    type_helper: &dyn TypeHelperRenderer,
     callback_name: &str,
     methods: &[&Method],
     ffi_module: &str,
 ) -> dart::Tokens {
+    let names = type_helper.get_names();
+    let ci = type_helper.get_ci();
     let vtable_name = &format!("UniffiVTableCallbackInterface{callback_name}");
     let vtable_static_instance_name =
         format!("{}{}", DartCodeOracle::fn_name(callback_name), "VTable");
     let init_fn_name = &format!("init{callback_name}VTable");
-    let snake_callback = callback_name.to_lowercase();
+    let init_vtable_fn = DartCodeOracle::ffi_function_name(&format!(
+        "uniffi_{ffi_module}_fn_init_callback_vtable_{}",
+        callback_name.to_lowercase()
+    ));
+    let fn_prefix = DartCodeOracle::fn_name(callback_name);
+
+    let fill_vtable = if is_thread_safe(ci, callback_name, type_helper.get_config()) {
+        // The vtable is made of the trampolines `#[uniffi_dart::thread_safe]` generates, which
+        // call the functions registered here directly on this isolate's thread and through their
+        // listeners from any other thread
+        let register_fn =
+            DartCodeOracle::ffi_function_name(&dispatch_symbol(ci, callback_name, "register"));
+        let slots: Vec<(String, String)> =
+            std::iter::once(("Free".to_string(), "Free".to_string()))
+                .chain(methods.iter().map(|m| {
+                    let method = DartCodeOracle::class_name(names, m.name());
+                    (method.clone(), method)
+                }))
+                .collect();
+        let slot_count = slots.len();
+        quote! {
+            final direct = calloc<Pointer<Void>>($slot_count);
+            final listeners = calloc<Pointer<Void>>($slot_count);
+            try {
+                $(for (index, (direct_name, listener_name)) in slots.iter().enumerate() {
+                    direct[$index] = $(&fn_prefix)$(direct_name)Pointer.cast();
+                    listeners[$index] = $(&fn_prefix)$(listener_name)ListenerPointer.cast();
+                })
+                final registered = $register_fn(
+                    NativeApi.initializeApiDLData,
+                    direct,
+                    listeners,
+                    $(&vtable_static_instance_name).cast(),
+                );
+                if (registered != 0) {
+                    throw UnsupportedError(
+                        $(quoted(format!("{callback_name} can't be called from other threads on this Dart version"))),
+                    );
+                }
+            } finally {
+                calloc.free(direct);
+                calloc.free(listeners);
+            }
+        }
+    } else {
+        quote! {
+            $(&vtable_static_instance_name).ref.uniffiFree = $(&fn_prefix)FreePointer;
+            $(&vtable_static_instance_name).ref.uniffiClone = $(&fn_prefix)ClonePointer;
+            $(for m in methods {
+                $(&vtable_static_instance_name).ref.$(DartCodeOracle::fn_name(m.name())) = $(&fn_prefix)$(DartCodeOracle::class_name(names, m.name()))Pointer;
+            })
+        }
+    };
 
     quote! {
+        $(uses([DART_FFI, PACKAGE_FFI]))
         late final Pointer<$vtable_name> $(&vtable_static_instance_name);
 
         void $init_fn_name() {
             // Make initialization idempotent - return early if already initialized
-            if (FfiConverterCallbackInterface$(DartCodeOracle::class_name(callback_name))._vtableInitialized) {
+            if (FfiConverterCallbackInterface$(DartCodeOracle::class_name(names, callback_name))._vtableInitialized) {
                 return;
             }
 
             $(&vtable_static_instance_name) = calloc<$vtable_name>();
-            $(&vtable_static_instance_name).ref.uniffiFree = $(format!("{}FreePointer", DartCodeOracle::fn_name(callback_name)));
-            $(&vtable_static_instance_name).ref.uniffiClone = $(format!("{}ClonePointer", DartCodeOracle::fn_name(callback_name)));
-            $(for m in methods {
-                $(&vtable_static_instance_name).ref.$(DartCodeOracle::fn_name(m.name())) = $(DartCodeOracle::fn_name(callback_name))$(DartCodeOracle::class_name(m.name()))Pointer;
-            })
+            $fill_vtable
 
             rustCall((status) {
-                uniffi_$(ffi_module)_fn_init_callback_vtable_$(snake_callback)(
+                $init_vtable_fn(
                     $(vtable_static_instance_name),
                 );
                 checkCallStatus(NullRustCallStatusErrorHandler(), status);
             });
 
             // Update the flag to prevent re-initialization
-            FfiConverterCallbackInterface$(DartCodeOracle::class_name(callback_name))._vtableInitialized = true;
+            FfiConverterCallbackInterface$(DartCodeOracle::class_name(names, callback_name))._vtableInitialized = true;
         }
     }
 }
@@ -1,30 +1,37 @@
+use anyhow::{bail, Result};
 use std::fmt::Debug;
 use uniffi_bindgen::pipeline::general::nodes::Literal;
 
+use crate::gen::naming::Names;
+
 /// A trait tor the implementation.
 pub trait CodeType: Debug {
     /// The language specific label used to reference this type. This will be used in
     /// method signatures and property declarations.
-    fn type_label(&self) -> String;
+    fn type_label(&self, names: &Names) -> String;
 
     /// A representation of this type label that can be used as part of another
     /// identifier. e.g. `read_foo()`, or `FooInternals`.
     ///
     /// This is especially useful when creating specialized objects or methods to deal
     /// with this type only.
-    fn canonical_name(&self) -> String {
-        self.type_label()
+    fn canonical_name(&self, names: &Names) -> String {
+        self.type_label(names)
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
-        unimplemented!("Unimplemented for {}", self.type_label())
+    /// The Dart expression of a default value of this type.
+    fn literal(&self, names: &Names, literal: &Literal) -> Result<String> {
+        bail!(
+            "default values of `{}` are not supported: {literal:?}",
+            self.type_label(names)
+        )
     }
 
     /// Name of the FfiConverter
     ///
     /// This is the object that contains the lower, write, lift, and read methods for this type.
-    fn ffi_converter_name(&self) -> String {
-        format!("FfiConverter{}", self.canonical_name())
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        format!("FfiConverter{}", self.canonical_name(names))
     }
 
     /// Function to run at startup
@@ -33,19 +40,19 @@ pub trait CodeType: Debug {
     }
 
     // The following must create an instance of the converter object
-    fn lower(&self) -> String {
-        format!("{}.lower", self.ffi_converter_name())
+    fn lower(&self, names: &Names) -> String {
+        format!("{}.lower", self.ffi_converter_name(names))
     }
 
-    fn write(&self) -> String {
-        format!("{}.write", self.ffi_converter_name())
+    fn write(&self, names: &Names) -> String {
+        format!("{}.write", self.ffi_converter_name(names))
     }
 
-    fn lift(&self) -> String {
-        format!("{}.lift", self.ffi_converter_name())
+    fn lift(&self, names: &Names) -> String {
+        format!("{}.lift", self.ffi_converter_name(names))
     }
 
-    fn read(&self) -> String {
-        format!("{}.read", self.ffi_converter_name())
+    fn read(&self, names: &Names) -> String {
+        format!("{}.read", self.ffi_converter_name(names))
     }
 }
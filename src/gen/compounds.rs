@@ -1,24 +1,25 @@
+use crate::gen::naming::Names;
 use crate::gen::CodeType;
 use genco::lang::dart;
 use genco::prelude::*;
 use paste::paste;
 use uniffi_bindgen::interface::Type;
 
+use super::imports::{uses, DART_TYPED_DATA};
 use super::oracle::{AsCodeType, DartCodeOracle};
-use crate::gen::render::{AsRenderable, Renderable, TypeHelperRenderer};
+use crate::gen::render::{Renderable, TypeHelperRenderer};
 
 macro_rules! impl_code_type_for_compound {
      ($T:ty, $type_label_pattern:literal, $canonical_name_pattern: literal) => {
         paste! {
             #[derive(Debug)]
             pub struct $T {
-                self_type: Type,
                 inner: Type,
             }
 
             impl $T {
-                pub fn new(self_type: Type, inner: Type) -> Self {
-                    Self { self_type, inner }
+                pub fn new(inner: Type) -> Self {
+                    Self { inner }
                 }
                 fn inner(&self) -> &Type {
                     &self.inner
@@ -26,12 +27,12 @@ macro_rules! impl_code_type_for_compound {
             }
 
             impl CodeType for $T  {
-                fn type_label(&self) -> String {
-                    format!($type_label_pattern, DartCodeOracle::find(self.inner()).type_label())
+                fn type_label(&self, names: &Names) -> String {
+                    format!($type_label_pattern, DartCodeOracle::find(self.inner()).type_label(names))
                 }
 
-                fn canonical_name(&self) -> String {
-                    format!($canonical_name_pattern, DartCodeOracle::find(self.inner()).canonical_name())
+                fn canonical_name(&self, names: &Names) -> String {
+                    format!($canonical_name_pattern, DartCodeOracle::find(self.inner()).canonical_name(names))
                 }
             }
         }
@@ -43,36 +44,22 @@ macro_rules! impl_renderable_for_compound {
        paste! {
             impl Renderable for $T {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                    type_helper.include_once_check(&self.ffi_converter_name(), &self.self_type);
+                    let names = type_helper.get_names();
                     let inner_codetype = DartCodeOracle::find(self.inner());
 
-                    let original_canonical = inner_codetype.canonical_name();
-                    let canonical_with_exception =
-                        DartCodeOracle::exception_safe_name(&original_canonical);
-                    let inner_already_registered =
-                        type_helper.include_once_check(&original_canonical, &self.inner())
-                            || (canonical_with_exception != original_canonical
-                                && type_helper.include_once_check(
-                                    &canonical_with_exception,
-                                    &self.inner(),
-                                ));
-
-                    let raw_type_label = inner_codetype.type_label();
-                    let inner_type_label =
-                        DartCodeOracle::exception_safe_name(&raw_type_label);
+                    let inner_canonical = inner_codetype.canonical_name(names);
+                    let inner_type_label = inner_codetype.type_label(names);
 
                     let cl_name_buf =
-                        format!($canonical_name_pattern, canonical_with_exception.as_str());
+                        format!($canonical_name_pattern, inner_canonical.as_str());
                     let cl_name = &cl_name_buf;
                     let type_label_buf =
                         format!($type_label_pattern, inner_type_label.as_str());
                     let type_label = &type_label_buf;
 
-                    let raw_converter_name = inner_codetype.ffi_converter_name();
-                    let inner_cl_converter_name_buf =
-                        DartCodeOracle::exception_safe_name(&raw_converter_name);
-                    let inner_cl_converter_name = &inner_cl_converter_name_buf;
-                    let inner_data_type_buf = canonical_with_exception
+                    let inner_converter_name = inner_codetype.ffi_converter_name(names);
+                    let inner_cl_converter_name = &inner_converter_name;
+                    let inner_data_type_buf = inner_canonical
                         .as_str()
                         .replace("UInt", "Uint")
                         .replace("Double", "Float");
@@ -80,22 +67,10 @@ macro_rules! impl_renderable_for_compound {
                     let _inner_type_signature =
                         if inner_data_type.contains("Float") { "double" } else { "int" };
 
-                    // Render inner helper for Sequences and primitives that haven't been rendered yet
-                    let inner_helper = if !inner_already_registered {
-                        match self.inner() {
-                            Type::Sequence { .. }
-                            | Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64
-                            | Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64
-                            | Type::Float32 | Type::Float64 => {
-                                self.inner().as_renderable().render_type_helper(type_helper)
-                            }
-                            _ => quote!()
-                        }
-                    } else {
-                        quote!()
-                    };
+                    let typed_data = uses([DART_TYPED_DATA]);
 
                     quote! {
+                        $typed_data
                         class $cl_name {
 
                             static $type_label lift( RustBuffer buf) {
@@ -123,17 +98,9 @@ macro_rules! impl_renderable_for_compound {
                                     return toRustBuffer(Uint8List.fromList([0]));
                                 }
 
-                                final length = $cl_name.allocationSize(value);
-
-                                final Pointer<Uint8> frameData = calloc<Uint8>(length); // Allocate a pointer large enough.
-                                final buf = frameData.asTypedList(length); // Create a list that uses our pointer to copy in the data.
-
+                                final buf = Uint8List($cl_name.allocationSize(value));
                                 $cl_name.write(value, buf);
-
-                                final bytes = calloc<ForeignBytes>();
-                                bytes.ref.len = length;
-                                bytes.ref.data = frameData;
-                                return RustBuffer.fromBytes(bytes.ref);
+                                return toRustBuffer(buf);
                             }
 
                             static int write( $type_label value, Uint8List buf) {
@@ -147,7 +114,6 @@ macro_rules! impl_renderable_for_compound {
                                 return $inner_cl_converter_name.write(value, Uint8List.view(buf.buffer, buf.offsetInBytes + 1)) + 1;
                             }
                         }
-                        $inner_helper
                     }
                 }
             }
@@ -159,42 +125,33 @@ macro_rules! impl_renderable_for_compound {
             impl Renderable for SequenceCodeType {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
 
-                    let converter_name = self.ffi_converter_name();
-                    if type_helper.include_once_check(&converter_name, &self.self_type) {
-                        return quote!();
-                    }
-                    let inner_codetype = self.inner().as_codetype();
+                    let names = type_helper.get_names();
 
-                    let original_canonical = inner_codetype.canonical_name();
-                    let canonical_with_exception =
-                        DartCodeOracle::exception_safe_name(&original_canonical);
-                    type_helper.include_once_check(&original_canonical, &self.inner());
-                    if canonical_with_exception != original_canonical {
-                        type_helper.include_once_check(&canonical_with_exception, &self.inner());
-                    }
+                    let inner_codetype = self.inner().as_codetype();
 
-                    let raw_type_label = inner_codetype.type_label();
-                    let inner_type_label =
-                        DartCodeOracle::exception_safe_name(&raw_type_label);
+                    let inner_canonical = inner_codetype.canonical_name(names);
+                    let inner_type_label = inner_codetype.type_label(names);
 
                     let cl_name_buf =
-                        format!($canonical_name_pattern, canonical_with_exception.as_str());
+                        format!($canonical_name_pattern, inner_canonical.as_str());
                     let cl_name = &cl_name_buf;
                     let type_label_buf = format!("List<{}>", inner_type_label.as_str());
                     let type_label = &type_label_buf;
 
-                    let raw_converter_name = inner_codetype.ffi_converter_name();
-                    let inner_cl_converter_name_buf =
-                        DartCodeOracle::exception_safe_name(&raw_converter_name);
-                    let inner_cl_converter_name = &inner_cl_converter_name_buf;
-                    let inner_data_type = canonical_with_exception
+                    let inner_converter_name = inner_codetype.ffi_converter_name(names);
+                    let inner_cl_converter_name = &inner_converter_name;
+                    let inner_data_type = inner_canonical
                         .as_str()
                         .replace("UInt", "Uint")
                         .replace("Double", "Float");
                     let _inner_type_signature = if inner_data_type.contains("Float") { "double" } else { "int" };
 
 
+                    let typed_data = uses([DART_TYPED_DATA]);
+
+
                     quote! {
+                        $typed_data
                         class $cl_name {
 
                             static $type_label lift( RustBuffer buf) {
@@ -247,18 +204,13 @@ impl_renderable_for_compound!(SequenceCodeType, "FfiConverterSequence{}");
 // Map<K, V>
 #[derive(Debug)]
 pub struct MapCodeType {
-    self_type: Type,
     key: Type,
     value: Type,
 }
 
 impl MapCodeType {
-    pub fn new(self_type: Type, key: Type, value: Type) -> Self {
-        Self {
-            self_type,
-            key,
-            value,
-        }
+    pub fn new(key: Type, value: Type) -> Self {
+        Self { key, value }
     }
 
     fn key(&self) -> &Type {
@@ -271,43 +223,42 @@ impl MapCodeType {
 }
 
 impl CodeType for MapCodeType {
-    fn type_label(&self) -> String {
+    fn type_label(&self, names: &Names) -> String {
         format!(
             "Map<{}, {}>",
-            DartCodeOracle::find(self.key()).type_label(),
-            DartCodeOracle::find(self.value()).type_label()
+            DartCodeOracle::find(self.key()).type_label(names),
+            DartCodeOracle::find(self.value()).type_label(names)
         )
     }
 
-    fn canonical_name(&self) -> String {
-        let key = DartCodeOracle::find(self.key()).canonical_name();
-        let val = DartCodeOracle::find(self.value()).canonical_name();
+    fn canonical_name(&self, names: &Names) -> String {
+        let key = DartCodeOracle::find(self.key()).canonical_name(names);
+        let val = DartCodeOracle::find(self.value()).canonical_name(names);
         format!("Map{}To{}", key, val)
     }
 }
 
 impl Renderable for MapCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        type_helper.include_once_check(&self.ffi_converter_name(), &self.self_type);
-
+        let names = type_helper.get_names();
         let key_codetype = DartCodeOracle::find(self.key());
         let val_codetype = DartCodeOracle::find(self.value());
 
-        type_helper.include_once_check(&key_codetype.canonical_name(), self.key());
-        type_helper.include_once_check(&val_codetype.canonical_name(), self.value());
-
-        let cl_name = &self.ffi_converter_name();
-        let key_type_label_owned = key_codetype.type_label();
-        let val_type_label_owned = val_codetype.type_label();
+        let cl_name = &self.ffi_converter_name(names);
+        let key_type_label_owned = key_codetype.type_label(names);
+        let val_type_label_owned = val_codetype.type_label(names);
         let key_type_label = &key_type_label_owned;
         let val_type_label = &val_type_label_owned;
 
-        let key_conv_owned = key_codetype.ffi_converter_name();
-        let val_conv_owned = val_codetype.ffi_converter_name();
+        let key_conv_owned = key_codetype.ffi_converter_name(names);
+        let val_conv_owned = val_codetype.ffi_converter_name(names);
         let key_conv = &key_conv_owned;
         let val_conv = &val_conv_owned;
 
+        let typed_data = uses([DART_TYPED_DATA]);
+
         quote! {
+            $typed_data
             class $cl_name {
                 static Map<$key_type_label, $val_type_label> lift(RustBuffer buf) {
                     return $cl_name.read(buf.asUint8List()).value;
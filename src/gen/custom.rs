@@ -1,6 +1,7 @@
 use super::oracle::{AsCodeType, DartCodeOracle};
 use super::render::{Renderable, TypeHelperRenderer};
 use super::CodeType;
+use crate::gen::naming::Names;
 use genco::prelude::*;
 use uniffi_bindgen::interface::AsType;
 use uniffi_bindgen::interface::Type;
@@ -23,8 +24,24 @@ impl CustomCodeType {
 }
 
 impl CodeType for CustomCodeType {
-    fn type_label(&self) -> String {
-        DartCodeOracle::class_name(&self.name)
+    fn type_label(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.name,
+            DartCodeOracle::class_name(names, &self.name),
+        )
+    }
+
+    fn canonical_name(&self, names: &Names) -> String {
+        DartCodeOracle::class_name(names, &self.name)
+    }
+
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.name,
+            format!("FfiConverter{}", self.canonical_name(names)),
+        )
     }
 }
 
@@ -40,12 +57,11 @@ impl AsType for CustomCodeType {
 
 impl Renderable for CustomCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        type_helper.include_once_check(&self.name, &self.as_type());
-
-        let ffi_converter_name = &self.ffi_converter_name();
-        let type_name = &self.type_label();
-        let builtin_ffi_converter_name = &(*self.builtin).as_codetype().ffi_converter_name();
-        let builtin_name = DartCodeOracle::dart_type_label(Some(&*self.builtin));
+        let names = type_helper.get_names();
+        let ffi_converter_name = &self.ffi_converter_name(names);
+        let type_name = &self.type_label(names);
+        let builtin_ffi_converter_name = &(*self.builtin).as_codetype().ffi_converter_name(names);
+        let builtin_name = DartCodeOracle::dart_type_label(names, Some(&*self.builtin));
 
         quote! {
             typedef $(type_name) = $(builtin_name);
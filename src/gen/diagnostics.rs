@@ -0,0 +1,267 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use uniffi_bindgen::interface::{ffi::FfiFunction, AsType, Method, ObjectImpl, Type};
+use uniffi_bindgen::ComponentInterface;
+
+use crate::gen::callback_interface::is_thread_safe;
+use crate::gen::oracle::DartCodeOracle;
+use crate::gen::split::callable_types;
+use crate::gen::Config;
+
+/// A construct of a component that can't be expressed in the generated Dart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub crate_name: String,
+    /// The offending item: `function`, `Type`, `Type.member` or an FFI symbol.
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: `{}`: {}", self.crate_name, self.item, self.message)
+    }
+}
+
+/// Everything a component uses that the generator doesn't support, along with the top-level
+/// items (functions and types) that have to be left out to generate the rest.
+#[derive(Debug, Default)]
+pub struct Unsupported {
+    pub diagnostics: Vec<Diagnostic>,
+    skipped_items: BTreeSet<String>,
+    skipped_ffi_functions: BTreeSet<String>,
+}
+
+impl Unsupported {
+    pub fn check(ci: &ComponentInterface, config: &Config) -> Self {
+        let mut unsupported = Self::default();
+        let crate_name = ci.crate_name();
+
+        for (item, member, message) in thread_safe_callback_problems(ci, config) {
+            unsupported.diagnostics.push(Diagnostic {
+                crate_name: crate_name.to_string(),
+                item: member,
+                message,
+            });
+            unsupported.skipped_items.insert(item);
+        }
+
+        // Leaving out a type also leaves out everything using it, so repeat until nothing changes
+        loop {
+            let mut found = Vec::new();
+            for (item, members) in items(ci) {
+                if unsupported.skipped_items.contains(&item) {
+                    continue;
+                }
+                let problem = members.iter().find_map(|(member, types)| {
+                    types.iter().find_map(|ty| {
+                        unsupported
+                            .type_problem(ty, &item)
+                            .map(|message| (member.clone(), message))
+                    })
+                });
+                if let Some((member, message)) = problem {
+                    found.push((item, member, message));
+                }
+            }
+            if found.is_empty() {
+                break;
+            }
+            for (item, member, message) in found {
+                unsupported.diagnostics.push(Diagnostic {
+                    crate_name: crate_name.to_string(),
+                    item: member,
+                    message,
+                });
+                unsupported.skipped_items.insert(item);
+            }
+        }
+
+        // The vtable struct a skipped callback interface registers isn't generated
+        for (name, _, init) in callback_interfaces(ci) {
+            if unsupported.skipped_items.contains(name) {
+                unsupported
+                    .skipped_ffi_functions
+                    .insert(init.name().to_string());
+            }
+        }
+
+        for fun in ci.iter_ffi_function_definitions() {
+            let unsupported_type = fun
+                .arguments()
+                .into_iter()
+                .map(|arg| arg.type_())
+                .chain(fun.return_type().cloned())
+                .find(|ty| !DartCodeOracle::is_supported_ffi_type(ty));
+            if let Some(ty) = unsupported_type {
+                unsupported.diagnostics.push(Diagnostic {
+                    crate_name: crate_name.to_string(),
+                    item: fun.name().to_string(),
+                    message: format!("FFI type `{ty:?}` is not supported"),
+                });
+                unsupported
+                    .skipped_ffi_functions
+                    .insert(fun.name().to_string());
+            }
+        }
+
+        unsupported
+    }
+
+    /// Whether the function or type named `item` is left out of the bindings.
+    pub fn is_skipped(&self, item: &str) -> bool {
+        self.skipped_items.contains(item)
+    }
+
+    pub fn is_ffi_function_skipped(&self, name: &str) -> bool {
+        self.skipped_ffi_functions.contains(name)
+    }
+
+    /// Whether `ty`, or any type nested in it, can't be generated.
+    pub fn skips_type(&self, ty: &Type) -> bool {
+        self.type_problem(ty, "").is_some()
+    }
+
+    fn type_problem(&self, ty: &Type, item: &str) -> Option<String> {
+        ty.iter_types().find_map(|ty| match ty {
+            Type::Timestamp => Some("timestamps (`SystemTime`) are not supported".to_string()),
+            _ => match ty.name() {
+                Some(name) if name != item && self.skipped_items.contains(name) => {
+                    Some(format!("uses `{name}`, which is unsupported"))
+                }
+                _ => None,
+            },
+        })
+    }
+}
+
+/// Callback interfaces and traits implementable in Dart, with their methods and the FFI function
+/// registering their vtable.
+pub(super) fn callback_interfaces(
+    ci: &ComponentInterface,
+) -> impl Iterator<Item = (&str, Vec<&Method>, &FfiFunction)> {
+    ci.callback_interface_definitions()
+        .iter()
+        .map(|cbi| (cbi.name(), cbi.methods(), cbi.ffi_init_callback()))
+        .chain(
+            ci.object_definitions()
+                .iter()
+                .filter(|obj| obj.has_callback_interface())
+                .map(|obj| (obj.name(), obj.methods(), obj.ffi_init_callback())),
+        )
+}
+
+/// Methods of thread-safe callback interfaces that can't be called from a Rust thread: Rust can't
+/// pass a Dart-implemented trait object back to Dart off the Dart thread, unless its trait is
+/// thread-safe as well.
+fn thread_safe_callback_problems(
+    ci: &ComponentInterface,
+    config: &Config,
+) -> Vec<(String, String, String)> {
+    let mut problems = Vec::new();
+    for (name, methods, _) in callback_interfaces(ci) {
+        if !is_thread_safe(ci, name, config) {
+            continue;
+        }
+        let problem = methods.iter().find_map(|meth| {
+            thread_safe_method_problem(ci, config, meth)
+                .map(|message| (format!("{name}.{}", meth.name()), message))
+        });
+        if let Some((member, message)) = problem {
+            problems.push((name.to_string(), member, message));
+        }
+    }
+    problems
+}
+
+fn thread_safe_method_problem(
+    ci: &ComponentInterface,
+    config: &Config,
+    meth: &Method,
+) -> Option<String> {
+    meth.arguments().iter().find_map(|arg| {
+        arg.as_type().iter_types().find_map(|ty| match ty {
+            Type::Object {
+                name,
+                imp: ObjectImpl::CallbackTrait,
+                ..
+            } if !is_thread_safe(ci, name, config) => Some(format!(
+                "passes `{name}`, which Rust can only hand back to Dart on the Dart thread, to a thread-safe callback"
+            )),
+            _ => None,
+        })
+    })
+}
+
+/// Types used by each member (`Type.member`) of an item.
+type Members = Vec<(String, Vec<Type>)>;
+
+/// The types each top-level item refers to, grouped by the member using them.
+fn items(ci: &ComponentInterface) -> Vec<(String, Members)> {
+    let mut items = Vec::new();
+    for fun in ci.function_definitions() {
+        let members = vec![(fun.name().to_string(), callable_types(fun))];
+        items.push((fun.name().to_string(), members));
+    }
+    for rec in ci.record_definitions() {
+        let members = rec
+            .fields()
+            .iter()
+            .map(|f| (format!("{}.{}", rec.name(), f.name()), vec![f.as_type()]))
+            .collect();
+        items.push((rec.name().to_string(), members));
+    }
+    for enum_ in ci.enum_definitions() {
+        let members = enum_
+            .variants()
+            .iter()
+            .map(|v| {
+                let types = v.fields().iter().map(|f| f.as_type()).collect();
+                (format!("{}.{}", enum_.name(), v.name()), types)
+            })
+            .collect();
+        items.push((enum_.name().to_string(), members));
+    }
+    for obj in ci.object_definitions() {
+        let mut members = Vec::new();
+        for cons in obj.constructors() {
+            members.push((
+                format!("{}.{}", obj.name(), cons.name()),
+                callable_types(cons),
+            ));
+        }
+        for meth in obj.methods() {
+            members.push((
+                format!("{}.{}", obj.name(), meth.name()),
+                callable_types(meth),
+            ));
+        }
+        for trait_impl in obj.trait_impls() {
+            members.push((obj.name().to_string(), vec![trait_impl.trait_ty.clone()]));
+        }
+        items.push((obj.name().to_string(), members));
+    }
+    for cbi in ci.callback_interface_definitions() {
+        let members = cbi
+            .methods()
+            .into_iter()
+            .map(|meth| {
+                (
+                    format!("{}.{}", cbi.name(), meth.name()),
+                    callable_types(meth),
+                )
+            })
+            .collect();
+        items.push((cbi.name().to_string(), members));
+    }
+    for ty in ci.iter_local_types() {
+        if let Type::Custom { name, builtin, .. } = ty {
+            items.push((
+                name.clone(),
+                vec![(name.clone(), vec![(**builtin).clone()])],
+            ));
+        }
+    }
+    items
+}
@@ -1,9 +1,11 @@
+use crate::gen::naming::Names;
 use crate::gen::CodeType;
+use anyhow::{bail, Result};
 use genco::prelude::*;
-use heck::ToLowerCamelCase;
 use uniffi_bindgen::interface::{AsType, Enum, Field, Type};
 use uniffi_bindgen::pipeline::general::nodes::Literal;
 
+use super::imports::{uses, DART_TYPED_DATA};
 use super::oracle::{AsCodeType, DartCodeOracle};
 use super::render::{AsRenderable, Renderable, TypeHelperRenderer};
 
@@ -19,37 +21,40 @@ impl EnumCodeType {
 }
 
 impl CodeType for EnumCodeType {
-    fn type_label(&self) -> String {
-        DartCodeOracle::class_name(&self.id)
+    fn type_label(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(names, &self.id, DartCodeOracle::class_name(names, &self.id))
     }
 
-    fn canonical_name(&self) -> String {
-        DartCodeOracle::class_name(&self.id)
+    fn canonical_name(&self, names: &Names) -> String {
+        DartCodeOracle::class_name(names, &self.id)
     }
 
-    fn literal(&self, literal: &Literal) -> String {
-        if let Literal::Enum(v, _) = literal {
-            format!(
-                "{}{}",
-                self.type_label(),
-                DartCodeOracle::enum_variant_name(v)
-            )
-        } else {
-            unreachable!();
-        }
+    fn literal(&self, names: &Names, literal: &Literal) -> Result<String> {
+        let Literal::Enum(v, _) = literal else {
+            bail!("`{literal:?}` is not a variant of `{}`", self.id);
+        };
+        Ok(format!(
+            "{}{}",
+            self.type_label(names),
+            DartCodeOracle::enum_variant_name(names, &self.id, v)
+        ))
     }
 
-    fn ffi_converter_name(&self) -> String {
-        format!("FfiConverter{}", &DartCodeOracle::class_name(&self.id))
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.id,
+            format!(
+                "FfiConverter{}",
+                &DartCodeOracle::class_name(names, &self.id)
+            ),
+        )
     }
 }
 
 impl Renderable for EnumCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        let canonical = self.canonical_name();
-        if type_helper.check(&canonical) {
-            quote!()
-        } else if let Some(enum_) = type_helper.get_enum(&self.id) {
+        if let Some(enum_) = type_helper.get_enum(&self.id) {
             generate_enum(enum_, type_helper)
         } else {
             unreachable!()
@@ -58,8 +63,9 @@ impl Renderable for EnumCodeType {
 }
 
 pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let dart_cls_name = &DartCodeOracle::class_name(obj.name());
-    let ffi_converter_name = &obj.as_codetype().ffi_converter_name();
+    let names = type_helper.get_names();
+    let dart_cls_name = &DartCodeOracle::class_name(names, obj.name());
+    let ffi_converter_name = &obj.as_codetype().ffi_converter_name(names);
     if obj.is_flat() {
         let is_error_enum = type_helper.get_ci().is_name_used_as_error(obj.name());
         let implements_exception = if is_error_enum {
@@ -70,8 +76,8 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
         // For flat error enums, generate an error handler
         let error_handler_class = if is_error_enum {
-            let error_handler_name = format!("{dart_cls_name}ErrorHandler");
-            let instance_name = dart_cls_name.to_lower_camel_case();
+            let error_handler_name = DartCodeOracle::error_handler_class_name(names, obj.name());
+            let instance_name = DartCodeOracle::error_handler_instance(names, obj.name());
             quote! {
                 class $(&error_handler_name) extends UniffiRustCallStatusErrorHandler {
                     @override
@@ -80,16 +86,17 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     }
                 }
 
-                final $(&error_handler_name) $(instance_name)ErrorHandler = $(&error_handler_name)();
+                final $(&error_handler_name) $(&instance_name) = $(&error_handler_name)();
             }
         } else {
             quote!()
         };
 
         quote! {
+            $(uses([DART_TYPED_DATA]))
             enum $dart_cls_name $implements_exception {
                 $(for variant in obj.variants() =>
-                $(DartCodeOracle::enum_variant_name(variant.name())),)
+                $(DartCodeOracle::enum_variant_name(names, obj.name(), variant.name())),)
                 ;
             }
 
@@ -100,7 +107,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
                             return LiftRetVal(
-                                $dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name())),
+                                $dart_cls_name.$(DartCodeOracle::enum_variant_name(names, obj.name(), variant.name())),
                                 4,
                             );
                         )
@@ -117,7 +124,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     return toRustBuffer(createUint8ListFromInt(input.index + 1));
                 }
 
-                static int allocationSize($dart_cls_name _value) {
+                static int allocationSize($dart_cls_name value) {
                     return 4;
                 }
 
@@ -135,28 +142,28 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
         let mut variants = vec![];
 
         // helper functions to get the sanitized field name and type strings
-        fn field_name(field: &Field, field_num: usize) -> String {
+        fn variant_field_name(
+            names: &Names,
+            owner: &str,
+            field: &Field,
+            field_num: usize,
+        ) -> String {
             if field.name().is_empty() {
                 format!("v{field_num}")
             } else {
-                DartCodeOracle::var_name(field.name())
+                DartCodeOracle::member_name(names, owner, field.name())
             }
         }
-        fn field_type(field: &Field, type_helper: &dyn TypeHelperRenderer) -> String {
+        fn field_type(names: &Names, field: &Field) -> String {
             field
                 .as_type()
                 .as_renderable()
-                .render_type(&field.as_type(), type_helper)
+                .render_type(&field.as_type(), names)
                 .to_string()
                 .expect("Could not stringify type")
-                .replace("Error", "Exception")
         }
-        fn field_ffi_converter_name(field: &Field) -> String {
-            field
-                .as_type()
-                .as_codetype()
-                .ffi_converter_name()
-                .replace("Error", "Exception")
+        fn field_ffi_converter_name(names: &Names, field: &Field) -> String {
+            field.as_type().as_codetype().ffi_converter_name(names)
         }
         fn is_flat_enum(field: &Field, type_helper: &dyn TypeHelperRenderer) -> bool {
             if let Type::Enum { name, .. } = &field.as_type() {
@@ -168,14 +175,11 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
         }
 
         for (index, variant_obj) in obj.variants().iter().enumerate() {
-            for f in variant_obj.fields() {
-                type_helper.include_once_check(&f.as_codetype().canonical_name(), &f.as_type());
-            }
-            let variant_dart_cls_name = &format!(
-                "{}{}",
-                DartCodeOracle::class_name(variant_obj.name()),
-                dart_cls_name
-            );
+            let variant_dart_cls_name =
+                &DartCodeOracle::variant_class_name(names, obj.name(), variant_obj.name());
+            let owner = format!("{}.{}", obj.name(), variant_obj.name());
+            let field_name =
+                |field: &Field, field_num| variant_field_name(names, &owner, field, field_num);
 
             // Prepare constructor parameters
             let constructor_params = variant_obj
@@ -184,11 +188,10 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                 .enumerate()
                 .map(|(i, field)| {
                     let param_name = field_name(field, i);
-                    let param_type = field_type(field, type_helper);
                     if variant_obj.fields().len() > 1 {
-                        quote!(required $param_type this.$param_name)
+                        quote!(required this.$param_name)
                     } else {
-                        quote!($param_type this.$param_name)
+                        quote!(this.$param_name)
                     }
                 })
                 .collect::<Vec<_>>();
@@ -204,15 +207,15 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                 if is_flat_enum(field, type_helper) {
                     // Handle flat enums specially - they serialize as int32 (4 bytes)
                     quote!(
-                        final $(field_name(field, i))_int = buf.buffer.asByteData(new_offset).getInt32(0);
-                        final $(field_name(field, i)) = $(field_ffi_converter_name(field)).lift(toRustBuffer(createUint8ListFromInt($(field_name(field, i))_int)));
-                        new_offset += 4;
+                        final $(field_name(field, i))Int = buf.buffer.asByteData(newOffset).getInt32(0);
+                        final $(field_name(field, i)) = $(field_ffi_converter_name(names, field)).lift(toRustBuffer(createUint8ListFromInt($(field_name(field, i))Int)));
+                        newOffset += 4;
                     )
                 } else {
                     quote!(
-                        final $(field_name(field, i))_lifted = $(field_ffi_converter_name(field)).read(Uint8List.view(buf.buffer, new_offset));
-                        final $(field_name(field, i)) = $(field_name(field, i))_lifted.value;
-                        new_offset += $(field_name(field, i))_lifted.bytesRead;
+                        final $(field_name(field, i))Lifted = $(field_ffi_converter_name(names, field)).read(Uint8List.view(buf.buffer, newOffset));
+                        final $(field_name(field, i)) = $(field_name(field, i))Lifted.value;
+                        newOffset += $(field_name(field, i))Lifted.bytesRead;
                     )
                 }
             }).collect();
@@ -222,7 +225,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                 if is_flat_enum(field, type_helper) {
                     quote!(4 + ) // Flat enums are 4 bytes (int32)
                 } else {
-                    quote!($(field_ffi_converter_name(field)).allocationSize($(field_name(field, i))) + )
+                    quote!($(field_ffi_converter_name(names, field)).allocationSize($(field_name(field, i))) + )
                 }
             }).collect();
 
@@ -231,14 +234,14 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                 if is_flat_enum(field, type_helper) {
                     // Handle flat enums specially - lower to RustBuffer and extract int32
                     quote!(
-                        final $(field_name(field, i))_buffer = $(field_ffi_converter_name(field)).lower($(field_name(field, i)));
-                        final $(field_name(field, i))_int = $(field_name(field, i))_buffer.asUint8List().buffer.asByteData().getInt32(0);
-                        buf.buffer.asByteData(new_offset).setInt32(0, $(field_name(field, i))_int);
-                        new_offset += 4;
+                        final $(field_name(field, i))Buffer = $(field_ffi_converter_name(names, field)).lower($(field_name(field, i)));
+                        final $(field_name(field, i))Int = $(field_name(field, i))Buffer.asUint8List().buffer.asByteData().getInt32(0);
+                        buf.buffer.asByteData(newOffset).setInt32(0, $(field_name(field, i))Int);
+                        newOffset += 4;
                     )
                 } else {
                     quote!(
-                        new_offset += $(field_ffi_converter_name(field)).write($(field_name(field, i)), Uint8List.view(buf.buffer, new_offset));
+                        newOffset += $(field_ffi_converter_name(names, field)).write($(field_name(field, i)), Uint8List.view(buf.buffer, newOffset));
                     )
                 }
             }).collect();
@@ -276,21 +279,21 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
             variants.push(quote!{
                 class $variant_dart_cls_name extends $dart_cls_name {
-                    $(for (i, field) in variant_obj.fields().iter().enumerate() => final $(field_type(field, type_helper)) $(field_name(field, i));  )
+                    $(for (i, field) in variant_obj.fields().iter().enumerate() => final $(field_type(names, field)) $(field_name(field, i));  )
 
                     // Add the public const constructor
                     $variant_dart_cls_name($constructor_param_list);
 
                     // Keep the private constructor used by `read`
-                    $variant_dart_cls_name._($(for (i, field) in variant_obj.fields().iter().enumerate() => $(field_type(field, type_helper)) this.$(field_name(field, i)), ));
+                    $variant_dart_cls_name._($(for (i, field) in variant_obj.fields().iter().enumerate() => this.$(field_name(field, i)), ));
 
                     static LiftRetVal<$variant_dart_cls_name> read( Uint8List buf) {
-                        int new_offset = buf.offsetInBytes;
+                        int newOffset = buf.offsetInBytes;
 
                         $(for code in &field_read_code => $code)
                         return LiftRetVal($variant_dart_cls_name._(
                             $(for (i, field) in variant_obj.fields().iter().enumerate() => $(field_name(field, i)),)
-                        ), new_offset);
+                        ), newOffset);
                     }
 
                     @override
@@ -308,11 +311,11 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     @override
                     int write( Uint8List buf) {
                         buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, $(index + 1)); // write index into first position;
-                        int new_offset = buf.offsetInBytes + 4;
+                        int newOffset = buf.offsetInBytes + 4;
 
                         $(for code in &field_write_code => $code)
 
-                        return new_offset;
+                        return newOffset;
                     }
 
                     $to_string_method
@@ -329,8 +332,8 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
         // For error enums, also generate an error handler
         let error_handler_class = if is_error_enum {
-            let error_handler_name = format!("{dart_cls_name}ErrorHandler");
-            let instance_name = dart_cls_name.to_lower_camel_case();
+            let error_handler_name = DartCodeOracle::error_handler_class_name(names, obj.name());
+            let instance_name = DartCodeOracle::error_handler_instance(names, obj.name());
             quote! {
                 class $(&error_handler_name) extends UniffiRustCallStatusErrorHandler {
                     @override
@@ -339,13 +342,14 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     }
                 }
 
-                final $(&error_handler_name) $(instance_name)ErrorHandler = $(&error_handler_name)();
+                final $(&error_handler_name) $(&instance_name) = $(&error_handler_name)();
             }
         } else {
             quote!()
         };
 
         quote! {
+            $(uses([DART_TYPED_DATA]))
             abstract class $dart_cls_name $implements_exception {
                 RustBuffer lower();
                 int allocationSize();
@@ -363,7 +367,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     switch(index) {
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
-                            return $(format!("{}{}", DartCodeOracle::class_name(variant.name()), dart_cls_name)).read(subview);
+                            return $(DartCodeOracle::variant_class_name(names, obj.name(), variant.name())).read(subview);
                         )
                         default:  throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
                     }
@@ -0,0 +1,137 @@
+use std::collections::BTreeSet;
+
+use genco::prelude::*;
+use uniffi_bindgen::interface::Type;
+
+use crate::gen::oracle::{AsCodeType, DartCodeOracle};
+use crate::gen::render::TypeHelperRenderer;
+
+use super::stream::ExportedStream;
+use super::{functions, logging};
+
+/// Directory, relative to the output directory, holding the internal libraries.
+pub const INTERNAL_DIR: &str = "src";
+
+/// Top-level names of the user-facing API: types, their exceptions and functions. Everything
+/// else (converters, `RustBuffer`, error handlers, `@Native` externs) stays internal.
+pub fn public_symbols(type_helper: &dyn TypeHelperRenderer) -> BTreeSet<String> {
+    let names = type_helper.get_names();
+    let ci = type_helper.get_ci();
+    let mut symbols = BTreeSet::new();
+
+    // Thrown for panics and contract mismatches, so callers need to be able to catch it
+    symbols.insert("UniffiInternalError".to_string());
+    symbols.insert("UniffiContractMismatchException".to_string());
+    symbols.insert("UniffiContractVersionMismatchException".to_string());
+    symbols.insert("UniffiApiChecksumMismatchException".to_string());
+    symbols.insert("ensureInitialized".to_string());
+    symbols.insert("initialize".to_string());
+
+    for fun in ci.function_definitions() {
+        if type_helper.is_skipped(fun.name()) {
+            continue;
+        }
+        if functions::runs_in_background(fun, fun.name(), ci, type_helper.get_config()) {
+            symbols.insert(DartCodeOracle::background_name(names, "", fun.name()));
+        }
+        symbols.insert(DartCodeOracle::function_name(names, fun.name()));
+    }
+
+    for rec in ci.record_definitions() {
+        if type_helper.is_skipped(rec.name()) {
+            continue;
+        }
+        symbols.insert(DartCodeOracle::class_name(names, rec.name()));
+    }
+
+    for enum_ in ci.enum_definitions() {
+        if type_helper.is_skipped(enum_.name()) {
+            continue;
+        }
+        let cls_name = DartCodeOracle::class_name(names, enum_.name());
+        if !enum_.is_flat() {
+            for variant in enum_.variants() {
+                symbols.insert(DartCodeOracle::variant_class_name(
+                    names,
+                    enum_.name(),
+                    variant.name(),
+                ));
+            }
+        }
+        symbols.insert(cls_name);
+    }
+
+    for obj in ci.object_definitions() {
+        if type_helper.is_skipped(obj.name()) {
+            continue;
+        }
+        symbols.insert(DartCodeOracle::class_name(names, obj.name()));
+        symbols.insert(DartCodeOracle::object_interface_name(names, obj));
+        if let Some(stream) = ExportedStream::of(ci, obj) {
+            symbols.insert(stream.fn_name());
+        }
+    }
+
+    for cbi in ci.callback_interface_definitions() {
+        if type_helper.is_skipped(cbi.name()) {
+            continue;
+        }
+        symbols.insert(DartCodeOracle::class_name(names, cbi.name()));
+    }
+
+    for ty in ci.iter_local_types() {
+        if let Type::Custom { name, .. } = ty {
+            if type_helper.is_skipped(name) {
+                continue;
+            }
+            symbols.insert(ty.as_codetype().type_label(names));
+        }
+    }
+
+    symbols.extend(dependent_symbols(type_helper));
+
+    if logging::has_log_forwarding(type_helper) {
+        symbols.insert(logging::LOG_STREAM_FN_NAME.to_string());
+    }
+
+    symbols
+}
+
+/// Names the bindings of components using this one's types refer to: its converters, error
+/// handlers and `RustBuffer`.
+fn dependent_symbols(type_helper: &dyn TypeHelperRenderer) -> BTreeSet<String> {
+    let names = type_helper.get_names();
+    let ci = type_helper.get_ci();
+    let mut symbols = BTreeSet::from(["RustBuffer".to_string()]);
+    for ty in ci.iter_local_types() {
+        let Some(name) = ty.name() else {
+            continue;
+        };
+        if type_helper.is_skipped(name) {
+            continue;
+        }
+        match ty {
+            Type::Record { .. }
+            | Type::Enum { .. }
+            | Type::Object { .. }
+            | Type::CallbackInterface { .. }
+            | Type::Custom { .. } => {
+                symbols.insert(ty.as_codetype().ffi_converter_name(names));
+            }
+            _ => continue,
+        }
+        if ci.is_name_used_as_error(name) {
+            symbols.insert(DartCodeOracle::error_handler_name(names, ty));
+        }
+    }
+    symbols
+}
+
+/// The public entry point of a component: re-exports the user-facing part of the internal
+/// library at `internal_uri`.
+pub fn generate_public_library(internal_uri: &str, symbols: &BTreeSet<String>) -> dart::Tokens {
+    quote! {
+        $(format!("export \"{internal_uri}\""))
+            show $(for symbol in symbols join (, ) => $symbol);
+    }
+}
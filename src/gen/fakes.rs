@@ -0,0 +1,216 @@
+use genco::prelude::*;
+use heck::ToUpperCamelCase;
+use uniffi_bindgen::interface::{AsType, Callable};
+use uniffi_bindgen::ComponentInterface;
+
+use crate::gen::naming::Names;
+use crate::gen::oracle::DartCodeOracle;
+use crate::gen::render::{AsRenderable, TypeHelperRenderer};
+
+/// Prefix the fakes library imports the bindings under, so the real implementation of the
+/// function group can call the top-level functions its methods are named after.
+const BINDINGS_PREFIX: &str = "uniffi_bindings";
+
+/// File name of the fakes library of `namespace`, written next to `<namespace>.dart`.
+pub fn file_name(namespace: &str) -> String {
+    format!("{namespace}_fakes.dart")
+}
+
+/// Name of the interface over the top-level functions of `namespace`.
+fn functions_interface_name(namespace: &str) -> String {
+    format!("{}Functions", namespace.to_upper_camel_case())
+}
+
+/// `<namespace>_fakes.dart`: an interface over the component's top-level functions with an
+/// implementation calling them, and an in-memory fake of that interface and of every object
+/// interface. A fake answers each call through an `on<Method>` handler set by the test and
+/// throws `UnimplementedError` for calls without one, so tests never load the native library.
+pub fn generate_fakes(type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let names = type_helper.get_names();
+    let ci = type_helper.get_ci();
+    let public_import = format!("{}.dart", ci.namespace());
+
+    let functions: Vec<_> = ci
+        .function_definitions()
+        .iter()
+        .filter(|fun| !type_helper.is_skipped(fun.name()))
+        .map(|fun| {
+            FakeMember::new(
+                names,
+                ci,
+                fun,
+                DartCodeOracle::function_name(names, fun.name()),
+            )
+        })
+        .collect();
+    let functions_group = if functions.is_empty() {
+        quote!()
+    } else {
+        generate_functions_group(ci.namespace(), &functions)
+    };
+
+    let mut objects = Vec::new();
+    for obj in ci.object_definitions() {
+        if type_helper.is_skipped(obj.name()) {
+            continue;
+        }
+        let methods: Vec<_> = obj
+            .methods()
+            .into_iter()
+            .map(|method| {
+                FakeMember::new(
+                    names,
+                    ci,
+                    method,
+                    DartCodeOracle::member_name(names, obj.name(), method.name()),
+                )
+            })
+            .collect();
+        objects.push(generate_fake(
+            &format!("Fake{}", DartCodeOracle::class_name(names, obj.name())),
+            &DartCodeOracle::object_interface_name(names, obj),
+            &methods,
+        ));
+    }
+
+    quote! {
+        $(format!("import \"{public_import}\""));
+        $(if !functions.is_empty() => $(format!("import \"{public_import}\" as {BINDINGS_PREFIX}"));)
+
+        $functions_group
+
+        $(for object in objects => $object)
+    }
+}
+
+/// A function or method of a faked interface. Objects are typed as their interfaces, so fakes
+/// of them can be passed and returned.
+struct FakeMember {
+    name: String,
+    return_type: dart::Tokens,
+    params: Vec<FakeParam>,
+}
+
+struct FakeParam {
+    name: String,
+    ty: dart::Tokens,
+    /// Type the bindings take, which the implementation calling them narrows the parameter to.
+    concrete_ty: dart::Tokens,
+    holds_objects: bool,
+}
+
+impl FakeMember {
+    fn new(names: &Names, ci: &ComponentInterface, callable: &impl Callable, name: String) -> Self {
+        let return_type = match callable.return_type() {
+            Some(ret) => DartCodeOracle::interface_type_label(names, ci, ret),
+            None => quote!(void),
+        };
+        Self {
+            name,
+            return_type: if callable.is_async() {
+                quote!(Future<$return_type>)
+            } else {
+                return_type
+            },
+            params: callable
+                .arguments()
+                .into_iter()
+                .map(|arg| {
+                    let arg_type = arg.as_type();
+                    FakeParam {
+                        name: DartCodeOracle::var_name(arg.name()),
+                        ty: DartCodeOracle::interface_type_label(names, ci, &arg_type),
+                        concrete_ty: arg.as_renderable().render_type(&arg_type, names),
+                        holds_objects: DartCodeOracle::holds_object_interfaces(ci, &arg_type),
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn handler_name(&self) -> String {
+        format!("on{}", self.name.to_upper_camel_case())
+    }
+
+    fn signature(&self) -> dart::Tokens {
+        quote!($(&self.return_type) $(&self.name)($(self.params())))
+    }
+
+    fn params(&self) -> dart::Tokens {
+        quote!($(for param in &self.params join (, ) => $(&param.ty) $(&param.name)))
+    }
+
+    /// Signature of the interface, whose implementations may narrow parameters holding objects.
+    fn abstract_signature(&self) -> dart::Tokens {
+        let params: Vec<dart::Tokens> = self
+            .params
+            .iter()
+            .map(|param| {
+                if param.holds_objects {
+                    quote!(covariant $(&param.ty) $(&param.name))
+                } else {
+                    quote!($(&param.ty) $(&param.name))
+                }
+            })
+            .collect();
+        quote!($(&self.return_type) $(&self.name)($(for param in params join (, ) => $param)))
+    }
+
+    /// Signature of the implementation calling the bindings, taking the types they take.
+    fn concrete_signature(&self) -> dart::Tokens {
+        quote!($(&self.return_type) $(&self.name)($(for param in &self.params join (, ) =>
+            $(&param.concrete_ty) $(&param.name))))
+    }
+
+    fn args(&self) -> dart::Tokens {
+        quote!($(for param in &self.params join (, ) => $(&param.name)))
+    }
+}
+
+/// The interface over the top-level functions, the implementation calling them and its fake.
+fn generate_functions_group(namespace: &str, functions: &[FakeMember]) -> dart::Tokens {
+    let interface_name = &functions_interface_name(namespace);
+    let rust_name = &format!("Rust{interface_name}");
+
+    quote! {
+        abstract class $interface_name {
+            $(for fun in functions => $(fun.abstract_signature());)
+        }
+
+        class $rust_name implements $interface_name {
+            const $rust_name();
+
+            $(for fun in functions =>
+                @override
+                $(fun.concrete_signature()) => $BINDINGS_PREFIX.$(&fun.name)($(fun.args()));
+            )
+        }
+
+        $(generate_fake(&format!("Fake{interface_name}"), interface_name, functions))
+    }
+}
+
+/// A fake implementing `interface_name` through a nullable handler per member.
+fn generate_fake(fake_name: &str, interface_name: &str, members: &[FakeMember]) -> dart::Tokens {
+    if members.is_empty() {
+        return quote! {
+            class $fake_name implements $interface_name {}
+        };
+    }
+
+    quote! {
+        class $fake_name implements $interface_name {
+            $(for member in members =>
+                $(&member.return_type) Function($(member.params()))? $(member.handler_name());
+            )
+
+            $fake_name({$(for member in members join (, ) => this.$(member.handler_name()))});
+
+            $(for member in members =>
+                @override
+                $(member.signature()) =>
+                    ($(member.handler_name()) ?? (throw UnimplementedError($(quoted(format!("{fake_name}.{} isn't faked", member.name))))))($(member.args()));
+            )
+        }
+    }
+}
@@ -1,20 +1,250 @@
+use std::collections::HashSet;
+
 use genco::prelude::*;
-use heck::ToLowerCamelCase;
-use uniffi_bindgen::interface::{AsType, Function};
+use uniffi_bindgen::interface::{AsType, Callable, Function, ObjectImpl, Type};
+use uniffi_bindgen::ComponentInterface;
 
 use crate::gen::oracle::DartCodeOracle;
 use crate::gen::render::AsRenderable;
 
+use super::imports::{uses, DART_FFI, DART_ISOLATE};
 use super::oracle::AsCodeType;
 use super::render::TypeHelperRenderer;
+use super::Config;
+
+/// Docstring line added by `#[uniffi_dart::run_in_background]` to request a background variant.
+const RUN_IN_BACKGROUND_MARKER: &str = "uniffi-dart: run_in_background";
+
+/// Whether a background (`...InBackground`) variant should be generated for a callable: it's
+/// requested, through the config's `run_in_background` or the proc-macro attribute, and can run
+/// on a helper isolate.
+pub(super) fn runs_in_background(
+    callable: &impl Callable,
+    item_name: &str,
+    ci: &ComponentInterface,
+    config: &Config,
+) -> bool {
+    (config.runs_in_background(item_name) || has_background_marker(callable))
+        && can_run_in_background(callable, ci)
+}
+
+fn has_background_marker(callable: &impl Callable) -> bool {
+    callable.docstring().is_some_and(|doc| {
+        doc.lines()
+            .any(|line| line.trim() == RUN_IN_BACKGROUND_MARKER)
+    })
+}
+
+/// `Isolate.run` copies arguments, results and errors between isolates, which rules out anything
+/// wrapping a native handle. Async calls don't block the calling isolate in the first place.
+fn can_run_in_background(callable: &impl Callable, ci: &ComponentInterface) -> bool {
+    !callable.is_async()
+        && callable
+            .arguments()
+            .iter()
+            .map(|arg| arg.as_type())
+            .chain(callable.return_type().cloned())
+            .chain(callable.throws_type().cloned())
+            .all(|ty| is_isolate_sendable(&ty, ci, &mut HashSet::new()))
+}
+
+/// Background variant requests that don't produce one: unknown entries of the config's
+/// `run_in_background`, and functions and methods, listed there or marked with the proc-macro
+/// attribute, that can't run on a helper isolate.
+pub(super) fn background_warnings(ci: &ComponentInterface, config: &Config) -> Vec<String> {
+    let unsupported = |item_name: &str| {
+        format!(
+            "`{item_name}` is async or passes objects or callbacks, so it can't run on a helper \
+             isolate and gets no `InBackground` variant"
+        )
+    };
+    let mut warnings = Vec::new();
+    for func in ci.function_definitions() {
+        if has_background_marker(func)
+            && !config.runs_in_background(func.name())
+            && !can_run_in_background(func, ci)
+        {
+            warnings.push(unsupported(func.name()));
+        }
+    }
+    for obj in ci.object_definitions() {
+        for method in obj.methods() {
+            let item_name = format!("{}.{}", obj.name(), method.name());
+            if has_background_marker(method)
+                && !config.runs_in_background(&item_name)
+                && !can_run_in_background(method, ci)
+            {
+                warnings.push(unsupported(&item_name));
+            }
+        }
+    }
+    for entry in config.run_in_background() {
+        if let Some(func) = ci.get_function_definition(entry) {
+            if !can_run_in_background(func, ci) {
+                warnings.push(unsupported(entry));
+            }
+            continue;
+        }
+        let (object_name, method_name) = match entry.split_once('.') {
+            Some((object_name, method_name)) => (object_name, Some(method_name)),
+            None => (entry.as_str(), None),
+        };
+        let methods = ci
+            .get_object_definition(object_name)
+            .map(|obj| obj.methods())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|method| method_name.is_none_or(|name| method.name() == name))
+            .collect::<Vec<_>>();
+        if methods.is_empty() {
+            warnings.push(format!(
+                "`{entry}` in `run_in_background` isn't an exported function, object or method"
+            ));
+        }
+        for method in methods {
+            if !can_run_in_background(method, ci) {
+                warnings.push(unsupported(&format!("{object_name}.{}", method.name())));
+            }
+        }
+    }
+    warnings
+}
+
+/// Whether `callable` is passed something Rust can call back into Dart through: a callback
+/// interface or a trait object Dart can implement, wherever it's defined.
+pub(super) fn passes_callbacks(callable: &impl Callable) -> bool {
+    callable.arguments().iter().any(|arg| {
+        arg.as_type().iter_types().any(|ty| {
+            matches!(
+                ty,
+                Type::CallbackInterface { .. }
+                    | Type::Object {
+                        imp: ObjectImpl::CallbackTrait,
+                        ..
+                    }
+            )
+        })
+    })
+}
+
+/// Functions and methods that can call back into Dart, by exported item name, which rules out
+/// leaf calls. Methods of traits Dart can implement may be dispatched to Dart themselves.
+pub(super) fn calling_back(ci: &ComponentInterface) -> Vec<(String, String)> {
+    let mut items = Vec::new();
+    for fun in ci.function_definitions() {
+        if passes_callbacks(fun) {
+            items.push((fun.name().to_string(), fun.ffi_func().name().to_string()));
+        }
+    }
+    for obj in ci.object_definitions() {
+        for cons in obj.constructors() {
+            if passes_callbacks(cons) {
+                items.push((
+                    format!("{}.{}", obj.name(), cons.name()),
+                    cons.ffi_func().name().to_string(),
+                ));
+            }
+        }
+        for meth in obj.methods() {
+            if obj.has_callback_interface() || passes_callbacks(meth) {
+                items.push((
+                    format!("{}.{}", obj.name(), meth.name()),
+                    meth.ffi_func().name().to_string(),
+                ));
+            }
+        }
+    }
+    items
+}
+
+/// Entries of the config's `leaf_functions` that are ignored because the item can call back
+/// into Dart, which a leaf call doesn't allow.
+pub(super) fn leaf_warnings(ci: &ComponentInterface, config: &Config) -> Vec<String> {
+    calling_back(ci)
+        .into_iter()
+        .filter(|(item_name, _)| config.leaf_override(item_name) == Some(true))
+        .map(|(item_name, _)| {
+            format!(
+                "`{item_name}` in `leaf_functions` passes a callback, which can call back into \
+                 Dart, so it isn't a leaf call"
+            )
+        })
+        .collect()
+}
+
+fn is_isolate_sendable(ty: &Type, ci: &ComponentInterface, seen: &mut HashSet<String>) -> bool {
+    match ty {
+        Type::Object { .. } | Type::CallbackInterface { .. } => false,
+        Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+            is_isolate_sendable(inner_type, ci, seen)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => is_isolate_sendable(key_type, ci, seen) && is_isolate_sendable(value_type, ci, seen),
+        Type::Custom { builtin, .. } => is_isolate_sendable(builtin, ci, seen),
+        Type::Record { name, .. } => {
+            !seen.insert(name.clone())
+                || ci.get_record_definition(name).is_none_or(|rec| {
+                    rec.fields()
+                        .iter()
+                        .all(|f| is_isolate_sendable(&f.as_type(), ci, seen))
+                })
+        }
+        Type::Enum { name, .. } => {
+            !seen.insert(name.clone())
+                || ci.get_enum_definition(name).is_none_or(|en| {
+                    en.variants().iter().all(|v| {
+                        v.fields()
+                            .iter()
+                            .all(|f| is_isolate_sendable(&f.as_type(), ci, seen))
+                    })
+                })
+        }
+        _ => true,
+    }
+}
+
+/// Generates a `Future`-returning variant of a synchronous function that runs the call on a
+/// helper isolate, keeping slow Rust work off the calling (UI) isolate.
+pub(super) fn generate_background_function(
+    func: &Function,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let names = type_helper.get_names();
+    if !runs_in_background(
+        func,
+        func.name(),
+        type_helper.get_ci(),
+        type_helper.get_config(),
+    ) {
+        return quote!();
+    }
+
+    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), names)) $(DartCodeOracle::var_name(arg.name())),));
+    let ret = match func.return_type() {
+        Some(ret) => ret.as_renderable().render_type(ret, names),
+        None => quote!(void),
+    };
+    let fn_name = DartCodeOracle::function_name(names, func.name());
+    let background_name = DartCodeOracle::background_name(names, "", func.name());
+
+    quote!(
+        $(uses([DART_ISOLATE]))
+        Future<$ret> $background_name($args) {
+            return Isolate.run(() => $(&fn_name)($(for arg in &func.arguments() => $(DartCodeOracle::var_name(arg.name())),)));
+        }
+    )
+}
 
 pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+    let names = type_helper.get_names();
+    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), names)) $(DartCodeOracle::var_name(arg.name())),));
 
     let (ret, lifter) = if let Some(ret) = func.return_type() {
         (
-            ret.as_renderable().render_type(ret, type_helper),
-            quote!($(ret.as_codetype().lift())),
+            ret.as_renderable().render_type(ret, names),
+            quote!($(ret.as_codetype().lift(names))),
         )
     } else {
         (quote!(void), quote!((_) {}))
@@ -22,10 +252,7 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
 
     // Check if function can throw errors
     let error_handler = if let Some(error_type) = func.throws_type() {
-        let error_name = DartCodeOracle::class_name(error_type.name().unwrap_or("UnknownError"));
-        // Use the consistent Exception naming for error handlers
-        let handler_name = format!("{}ErrorHandler", error_name.to_lower_camel_case());
-        quote!($(handler_name))
+        quote!($(DartCodeOracle::error_handler_name(names, error_type)))
     } else {
         quote!(null)
     };
@@ -36,7 +263,7 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
         let async_lifter = if let Some(ret_type) = func.return_type() {
             match ret_type {
                 uniffi_bindgen::interface::Type::Object { .. } => {
-                    quote!((ptr) => $lifter(Pointer<Void>.fromAddress(ptr)))
+                    quote!($(uses([DART_FFI]))(ptr) => $lifter(Pointer<Void>.fromAddress(ptr)))
                 }
                 _ => lifter.clone(),
             }
@@ -45,10 +272,10 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
         };
 
         quote!(
-            Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
+            Future<$ret> $(DartCodeOracle::function_name(names, func.name()))($args) {
                 return uniffiRustCallAsync(
-                  () => $(func.ffi_func().name())(
-                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),)
+                  () => $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
+                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
@@ -60,20 +287,20 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
         )
     } else if ret == quote!(void) {
         quote!(
-            $ret $(DartCodeOracle::fn_name(func.name()))($args) {
+            $ret $(DartCodeOracle::function_name(names, func.name()))($args) {
                 return rustCall((status) {
-                    $(func.ffi_func().name())(
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                    $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),) status
                     );
                 }, $error_handler);
             }
         )
     } else {
         quote!(
-            $ret $(DartCodeOracle::fn_name(func.name()))($args) {
+            $ret $(DartCodeOracle::function_name(names, func.name()))($args) {
                 return rustCallWithLifter(
-                    (status) => $(func.ffi_func().name())(
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                    (status) => $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),) status
                     ),
                     $lifter,
                     $error_handler
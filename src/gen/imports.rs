@@ -0,0 +1,21 @@
+use genco::prelude::*;
+
+// Libraries the bindings use. Renderers register the ones their code references through `uses`,
+// so a file imports only what it needs and none of its imports is unused.
+pub const DART_ASYNC: &str = "dart:async";
+pub const DART_CONVERT: &str = "dart:convert";
+pub const DART_FFI: &str = "dart:ffi";
+pub const DART_IO: &str = "dart:io";
+pub const DART_ISOLATE: &str = "dart:isolate";
+pub const DART_TYPED_DATA: &str = "dart:typed_data";
+pub const PACKAGE_FFI: &str = "package:ffi/ffi.dart";
+
+/// Registers imports of `libraries` with the file the tokens end up in, without rendering
+/// anything.
+pub fn uses<const N: usize>(libraries: [&'static str; N]) -> dart::Tokens {
+    let mut tokens = dart::Tokens::new();
+    for library in libraries {
+        tokens.register(dart::import(library, ""));
+    }
+    tokens
+}
@@ -0,0 +1,71 @@
+use genco::prelude::*;
+
+use super::imports::{uses, DART_ASYNC};
+use crate::gen::render::TypeHelperRenderer;
+
+/// Name of the callback interface exported by `uniffi_dart::export_logging!`.
+pub const LOG_SINK_NAME: &str = "DartLogSink";
+const INSTALL_FN_NAME: &str = "install_dart_log_sink";
+/// Name of the generated Dart function exposing forwarded records as a stream.
+pub const LOG_STREAM_FN_NAME: &str = "rustLogRecords";
+
+/// Whether the component exports the log forwarding items from `uniffi_dart::export_logging!`.
+pub fn has_log_forwarding(type_helper: &dyn TypeHelperRenderer) -> bool {
+    let ci = type_helper.get_ci();
+    ci.get_object_definition(LOG_SINK_NAME)
+        .is_some_and(|obj| obj.has_callback_interface())
+        && ci.get_function_definition(INSTALL_FN_NAME).is_some()
+}
+
+pub fn generate_log_stream(type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    if !has_log_forwarding(type_helper) {
+        return quote!();
+    }
+
+    quote! {
+        $(uses([DART_ASYNC]))
+        class _UniffiDartLogSinkForwarder implements DartLogSink {
+            final StreamController<DartLogRecord> _controller;
+
+            _UniffiDartLogSinkForwarder(this._controller);
+
+            @override
+            void log(DartLogRecord record) {
+                if (!_controller.isClosed) {
+                    _controller.add(record);
+                }
+            }
+        }
+
+        // The forwarder Rust currently sends records to, if it was installed from this isolate.
+        _UniffiDartLogSinkForwarder? _uniffiActiveLogForwarder;
+
+        // Forwards Rust log records up to `maxLevel` while the stream is listened to. Rust has a
+        // single global sink, so only the most recently listened stream receives records. The
+        // stream fails if the application registered a different Rust logger.
+        Stream<DartLogRecord> $LOG_STREAM_FN_NAME({DartLogLevel maxLevel = DartLogLevel.info}) {
+            late final StreamController<DartLogRecord> controller;
+            late final _UniffiDartLogSinkForwarder forwarder;
+            controller = StreamController<DartLogRecord>(
+                onListen: () {
+                    forwarder = _UniffiDartLogSinkForwarder(controller);
+                    try {
+                        installDartLogSink(forwarder, maxLevel);
+                        _uniffiActiveLogForwarder = forwarder;
+                    } catch (error, stackTrace) {
+                        controller.addError(error, stackTrace);
+                        controller.close();
+                    }
+                },
+                onCancel: () {
+                    // A newer stream replaced this one's sink, leave that in place
+                    if (identical(_uniffiActiveLogForwarder, forwarder)) {
+                        _uniffiActiveLogForwarder = null;
+                        uninstallDartLogSink();
+                    }
+                },
+            );
+            return controller.stream;
+        }
+    }
+}
@@ -1,21 +1,22 @@
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::io::Read;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::process::Command;
 
 use anyhow::{bail, Context, Result};
 use camino::{Utf8Path, Utf8PathBuf};
-use cargo_metadata::Metadata;
 
 use genco::fmt;
 use genco::prelude::*;
 use serde::{Deserialize, Serialize};
 use toml;
+use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
 use uniffi_bindgen::BindgenCrateConfigSupplier;
 use uniffi_bindgen::Component;
 // use uniffi_bindgen::MergeWith;
+use self::diagnostics::Unsupported;
+use self::imports::{uses, DART_FFI, DART_IO};
+use self::naming::Names;
 use self::render::Renderer;
-use self::types::TypeHelpersRenderer;
+use self::types::{RenderedBindings, TypeHelpersRenderer};
 use crate::gen::oracle::DartCodeOracle;
 use uniffi_bindgen::{BindingGenerator, ComponentInterface};
 
@@ -23,25 +24,122 @@ mod callback_interface;
 mod code_type;
 mod compounds;
 mod custom;
+mod diagnostics;
 mod enums;
+mod exports;
+mod fakes;
 mod functions;
+mod imports;
+mod logging;
+mod naming;
 mod objects;
 mod oracle;
+mod package;
 mod primitives;
 mod records;
 mod render;
+mod runtime;
+mod split;
 pub mod stream;
 mod types;
 
 pub use code_type::CodeType;
+pub use package::Package;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Config {
     package_name: Option<String>,
     cdylib_name: Option<String>,
+    /// Dart packages publishing the bindings of other crates, keyed by crate name or namespace.
+    /// Values are a package name (imported as `package:<pkg>/<namespace>.dart`) or a full path
+    /// inside a package ending in `.dart`.
     #[serde(default)]
     external_packages: HashMap<String, String>,
     asset_id: Option<String>,
+    /// Annotate FFI functions that only take and return primitives with `isLeaf: true`.
+    /// Defaults to `true`; automatic detection is skipped for components that define
+    /// callback interfaces, since any Rust call could re-enter Dart through them.
+    leaf_calls: Option<bool>,
+    /// Per-function overrides for leaf calls, keyed by the exported item name
+    /// (`function` or `Object.method`).
+    #[serde(default)]
+    leaf_functions: HashMap<String, bool>,
+    /// Exported items that get an additional `Future`-returning `...InBackground` variant which
+    /// runs the call on a helper isolate. Entries are function names, object names (all of the
+    /// object's methods) or `Object.method`.
+    #[serde(default)]
+    run_in_background: Vec<String>,
+    /// Callback interfaces whose methods may be invoked from any Rust thread, in addition to the
+    /// ones marked with `#[uniffi_dart::thread_safe]`. The Rust trait must carry that attribute
+    /// as well, which exports the functions the calls are handed to the implementing isolate
+    /// through. Each object's calls go to the isolate that handed it to Rust, so several isolates
+    /// can implement the interface at once. Calls made off that isolate's thread block until it
+    /// ran them, except for those to sync methods returning nothing that can't fail, which are
+    /// queued and can't report errors back to Rust. Methods taking a Dart-implementable trait
+    /// object of an interface that isn't thread-safe are unsupported.
+    #[serde(default)]
+    thread_safe_callbacks: Vec<String>,
+    /// Import the asset-independent runtime (`RustBuffer`, `UniffiInternalError`,
+    /// `FfiConverter`, ...) from a shared `uniffi_runtime.dart` written next to the bindings,
+    /// instead of inlining it into every component.
+    shared_runtime: Option<bool>,
+    /// Import URI of an existing shared runtime library, e.g. one published as its own package.
+    /// Implies `shared_runtime`; no runtime file is written. Relative URIs resolve against the
+    /// library holding the bindings, which is `src/` when `hide_internals` is enabled.
+    runtime_import: Option<String>,
+    /// Write the bindings to an internal `src/<namespace>.dart` library and make `<namespace>.dart`
+    /// re-export only the user-facing API, hiding the FFI externs and helpers. The converters of
+    /// the exported types and `RustBuffer` stay exported for the bindings of dependent crates.
+    /// Defaults to `false`.
+    hide_internals: Option<bool>,
+    /// Write each record, enum, object and callback interface to its own file under
+    /// `<namespace>/`, next to a core file with the helpers and functions, and re-export them
+    /// all from a barrel library. Speeds up analysis of very large components.
+    split_output: Option<bool>,
+    /// Leave out functions and types using constructs the generator doesn't support, along with
+    /// everything depending on them, and print a warning for each. By default generation fails
+    /// with a list of all unsupported items instead.
+    skip_unsupported: Option<bool>,
+    /// Dart names for individual items, keyed by `Type`, `function` or `Type.member`, where the
+    /// member is a method, constructor, field or enum variant. For enums with fields,
+    /// `Enum.Variant` names the variant's class. Other names are derived as usual, steering
+    /// clear of these.
+    #[serde(default)]
+    rename: HashMap<String, String>,
+    /// Rewrite an `Error` suffix of class names to `Exception`, e.g. `ParseError` to
+    /// `ParseException`. Defaults to `true`; single types can be excluded through `rename`.
+    error_to_exception: Option<bool>,
+    /// Path of the Rust crate relative to the Dart package root. When set, the generated
+    /// `hook/build.dart` builds the crate with cargo for the requested target instead of
+    /// bundling a prebuilt library. The cargo profile defaults to `release` and can be changed
+    /// through the `cargo_profile` user define.
+    cargo_crate_path: Option<String>,
+    /// Features of the crate enabled by the build hook.
+    #[serde(default)]
+    cargo_features: Vec<String>,
+    /// The cargo executable the build hook runs. Defaults to `cargo`.
+    cargo_binary: Option<String>,
+    /// Load the native library with `DynamicLibrary.open` instead of through Native Assets, for
+    /// older SDKs and plain `dart run` scripts. The library is opened from the path in the
+    /// `library_path_env` variable if set, then from the first of `library_search_paths`
+    /// holding it, and finally by file name through the platform's library search.
+    dynamic_library: Option<bool>,
+    /// Directories searched for the native library in `dynamic_library` mode. Relative paths
+    /// resolve against the working directory.
+    #[serde(default)]
+    library_search_paths: Vec<String>,
+    /// Environment variable holding the path of the native library in `dynamic_library` mode.
+    /// Defaults to `UNIFFI_<CDYLIB_NAME>_LIBRARY_PATH`.
+    library_path_env: Option<String>,
+    /// Check the contract version and API checksums against the native library on the first
+    /// call into it, throwing a `UniffiContractMismatchException` on a mismatch. Defaults to
+    /// `true`; release builds can turn it off, leaving the checks to explicit
+    /// `ensureInitialized()` calls.
+    contract_checks: Option<bool>,
+    /// Also write `<namespace>_fakes.dart`, with an interface over the top-level functions and
+    /// in-memory fakes of it and of every object interface, for tests that run without the
+    /// native library.
+    fakes: Option<bool>,
 }
 
 impl From<&ComponentInterface> for Config {
@@ -49,8 +147,7 @@ impl From<&ComponentInterface> for Config {
         Config {
             package_name: Some(ci.namespace().to_owned()),
             cdylib_name: Some(ci.namespace().to_owned()),
-            external_packages: HashMap::new(),
-            asset_id: None,
+            ..Default::default()
         }
     }
 }
@@ -82,36 +179,354 @@ impl Config {
             format!("uniffi:{}", self.cdylib_name())
         }
     }
+
+    /// The import URI for bindings of external types defined in `crate_name`: the public library
+    /// of the package named in `external_packages`, or without an entry, the bindings generated
+    /// next to these ones.
+    pub fn external_import_uri(&self, crate_name: &str, namespace: &str) -> String {
+        let package = self
+            .external_packages
+            .get(crate_name)
+            .or_else(|| self.external_packages.get(namespace));
+        match package {
+            Some(path) if path.ends_with(".dart") => format!("package:{path}"),
+            Some(package) => format!("package:{package}/{namespace}.dart"),
+            None => format!("{namespace}.dart"),
+        }
+    }
+
+    pub fn hide_internals(&self) -> bool {
+        self.hide_internals.unwrap_or(false)
+    }
+
+    pub fn split_output(&self) -> bool {
+        self.split_output.unwrap_or(false)
+    }
+
+    /// Path of the library holding the full bindings, relative to the output directory.
+    fn internal_path(&self, namespace: &str) -> String {
+        if self.hide_internals() {
+            format!("{}/{namespace}.dart", exports::INTERNAL_DIR)
+        } else {
+            format!("{namespace}.dart")
+        }
+    }
+
+    /// The configured renames, keyed by item.
+    pub fn renames(&self) -> BTreeMap<String, String> {
+        self.rename
+            .iter()
+            .map(|(item, name)| (item.clone(), name.clone()))
+            .collect()
+    }
+
+    pub fn error_to_exception(&self) -> bool {
+        self.error_to_exception.unwrap_or(true)
+    }
+
+    pub fn skip_unsupported(&self) -> bool {
+        self.skip_unsupported.unwrap_or(false)
+    }
+
+    pub fn shared_runtime(&self) -> bool {
+        self.shared_runtime.unwrap_or(false) || self.runtime_import.is_some()
+    }
+
+    pub fn runtime_import(&self) -> String {
+        self.runtime_import
+            .clone()
+            .unwrap_or_else(|| runtime::RUNTIME_FILE_NAME.to_string())
+    }
+
+    /// Whether the shared runtime library has to be written alongside the bindings.
+    fn writes_runtime(&self) -> bool {
+        self.shared_runtime() && self.runtime_import.is_none()
+    }
+
+    pub fn leaf_calls(&self) -> bool {
+        self.leaf_calls.unwrap_or(true)
+    }
+
+    /// Returns the explicit leaf-call setting for an exported item, if one was configured.
+    pub fn leaf_override(&self, item_name: &str) -> Option<bool> {
+        self.leaf_functions.get(item_name).copied()
+    }
+
+    pub fn run_in_background(&self) -> &[String] {
+        &self.run_in_background
+    }
+
+    /// Whether `item_name` (`function` or `Object.method`) was configured to run in background.
+    pub fn runs_in_background(&self, item_name: &str) -> bool {
+        let object_name = item_name.split_once('.').map(|(object, _)| object);
+        self.run_in_background
+            .iter()
+            .any(|entry| entry == item_name || Some(entry.as_str()) == object_name)
+    }
+
+    pub fn cargo_crate_path(&self) -> Option<&str> {
+        self.cargo_crate_path.as_deref()
+    }
+
+    pub fn cargo_features(&self) -> &[String] {
+        &self.cargo_features
+    }
+
+    pub fn cargo_binary(&self) -> String {
+        self.cargo_binary.clone().unwrap_or_else(|| "cargo".into())
+    }
+
+    pub fn dynamic_library(&self) -> bool {
+        self.dynamic_library.unwrap_or(false)
+    }
+
+    pub fn library_search_paths(&self) -> &[String] {
+        &self.library_search_paths
+    }
+
+    pub fn library_path_env(&self) -> String {
+        self.library_path_env.clone().unwrap_or_else(|| {
+            format!(
+                "UNIFFI_{}_LIBRARY_PATH",
+                self.cdylib_name().to_ascii_uppercase()
+            )
+        })
+    }
+
+    pub fn contract_checks(&self) -> bool {
+        self.contract_checks.unwrap_or(true)
+    }
+
+    pub fn fakes(&self) -> bool {
+        self.fakes.unwrap_or(false)
+    }
+
+    pub fn is_thread_safe_callback(&self, callback_name: &str) -> bool {
+        self.thread_safe_callbacks
+            .iter()
+            .any(|entry| entry == callback_name)
+    }
+}
+
+/// A native function the bindings call, declared as an `@Native` extern or looked up in the
+/// library loaded at runtime.
+struct FfiExtern<'a> {
+    symbol: &'a str,
+    native_return_type: dart::Tokens,
+    native_args: dart::Tokens,
+    dart_return_type: dart::Tokens,
+    dart_args: dart::Tokens,
+    dart_arg_types: dart::Tokens,
+    is_leaf: bool,
+}
+
+impl FfiExtern<'_> {
+    fn render(&self, config: &Config, asset_id: &str) -> dart::Tokens {
+        let dart_name = DartCodeOracle::ffi_function_name(self.symbol);
+        let Self {
+            symbol,
+            native_return_type,
+            native_args,
+            dart_return_type,
+            dart_args,
+            dart_arg_types,
+            is_leaf,
+        } = self;
+
+        if config.dynamic_library() {
+            // Looked up on first use, then called just like the @Native externs
+            return quote! {
+                final $dart_return_type Function($dart_arg_types) $(&dart_name) =
+                  _uniffiLibrary.lookupFunction<
+                    $native_return_type Function($native_args),
+                    $dart_return_type Function($dart_arg_types)
+                  >($(quoted(*symbol))$(if *is_leaf => , isLeaf: true));
+                $['\n']
+            };
+        }
+
+        // Generate @Native annotation with assetId
+        // The Dart name differs from the native symbol, so the symbol is passed explicitly
+        // assetId references the _uniffiAssetId constant
+        quote! {
+            @Native<$native_return_type Function($native_args)>(
+              symbol: $(quoted(*symbol)),
+              assetId: $asset_id$(if *is_leaf => , isLeaf: true)
+            )
+            external $dart_return_type $(&dart_name)($dart_args);
+            $['\n']
+        }
+    }
 }
 
 pub struct DartWrapper<'a> {
     config: &'a Config,
     ci: &'a ComponentInterface,
+    names: &'a Names,
     type_renderer: TypeHelpersRenderer<'a>,
 }
 
 impl<'a> DartWrapper<'a> {
-    pub fn new(ci: &'a ComponentInterface, config: &'a Config) -> Self {
-        let type_renderer = TypeHelpersRenderer::new(ci);
+    pub fn new(ci: &'a ComponentInterface, config: &'a Config, names: &'a Names) -> Self {
+        let type_renderer = TypeHelpersRenderer::new(ci, config, names);
         DartWrapper {
             ci,
             config,
+            names,
             type_renderer,
         }
     }
 
-    fn generate(&self) -> dart::Tokens {
-        let package_name = &self.config.package_name();
+    /// Generates `<namespace>.dart`, exposing the user-facing part of the bindings written to
+    /// `internal_path`.
+    fn generate_public_library(&self, internal_path: &str) -> dart::Tokens {
+        let symbols = exports::public_symbols(&self.type_renderer);
+        exports::generate_public_library(internal_path, &symbols)
+    }
+
+    /// Generates `<namespace>_fakes.dart`, faking the public library for tests.
+    fn generate_fakes(&self) -> dart::Tokens {
+        fakes::generate_fakes(&self.type_renderer)
+    }
 
+    fn generate(&self) -> dart::Tokens {
         let (type_helper_code, functions_definitions) = &self.type_renderer.render();
+        self.generate_library(type_helper_code, functions_definitions)
+    }
+
+    /// Generates the core library and one library per type definition, keyed by file name.
+    fn generate_split(&self) -> Vec<(String, dart::Tokens)> {
+        let RenderedBindings {
+            imports,
+            runtime_export,
+            type_definitions,
+            helpers,
+            functions,
+        } = self.type_renderer.render_parts();
+
+        let core_import = format!("import \"{}\"", split::CORE_FILE_NAME);
+        let mut files = Vec::new();
+        let mut type_imports = quote!();
+        for (ty, definition) in type_definitions {
+            let file_name = split::type_file_name(self.names, &ty);
+            let dependencies = split::type_dependencies(self.names, self.ci, &ty);
+            type_imports.append(quote!($(format!("import \"{file_name}\""));));
+            files.push((
+                file_name,
+                quote! {
+                    $(&imports)
+                    $(&core_import);
+                    $(for dep in dependencies => $(format!("import \"{dep}\""));)
+
+                    $definition
+                },
+            ));
+        }
+
+        let core_code = quote! {
+            $imports
+            $type_imports
+            $runtime_export
+
+            $helpers
+        };
+        files.insert(
+            0,
+            (
+                split::CORE_FILE_NAME.to_string(),
+                self.generate_library(&core_code, &functions),
+            ),
+        );
+        files
+    }
+
+    /// Opens the native library with `DynamicLibrary.open`, for `dynamic_library` mode.
+    fn dynamic_library_loader(&self) -> dart::Tokens {
+        let cdylib_name = self.config.cdylib_name();
+        let search_paths = self.config.library_search_paths();
+        quote! {
+            $(uses([DART_FFI, DART_IO]))
+            // Generated by uniffi-dart – do NOT edit.
+            // The native library is opened from the path in the configured environment variable
+            // if set, then from the first search path holding it, and finally by file name
+            // through the platform's library search.
+            final DynamicLibrary _uniffiLibrary = _uniffiOpenLibrary();
+
+            DynamicLibrary _uniffiOpenLibrary() {
+                final String fileName;
+                if (Platform.isWindows) {
+                    fileName = $(quoted(format!("{cdylib_name}.dll")));
+                } else if (Platform.isMacOS || Platform.isIOS) {
+                    fileName = $(quoted(format!("lib{cdylib_name}.dylib")));
+                } else {
+                    fileName = $(quoted(format!("lib{cdylib_name}.so")));
+                }
+                final path = Platform.environment[$(quoted(self.config.library_path_env()))];
+                if (path != null && path.isNotEmpty) {
+                    return DynamicLibrary.open(path);
+                }
+                const searchPaths = <String>[$(for path in search_paths join (, ) => $(quoted(path)))];
+                for (final dir in searchPaths) {
+                    final candidate = File(dir + Platform.pathSeparator + fileName);
+                    if (candidate.existsSync()) {
+                        return DynamicLibrary.open(candidate.path);
+                    }
+                }
+                return DynamicLibrary.open(fileName);
+            }
+        }
+    }
+
+    fn generate_library(
+        &self,
+        type_helper_code: &dart::Tokens,
+        functions_definitions: &dart::Tokens,
+    ) -> dart::Tokens {
+        let package_name = &self.config.package_name();
 
         // Generate @Native external function definitions
-        fn uniffi_function_definitions(ci: &ComponentInterface, asset_id: &str) -> dart::Tokens {
+        fn uniffi_function_definitions(
+            ci: &ComponentInterface,
+            config: &Config,
+            unsupported: &Unsupported,
+            asset_id: &str,
+        ) -> dart::Tokens {
             let mut definitions = quote!();
             let mut defined_functions = HashSet::new(); // Track defined function names
 
+            // Map FFI symbols back to the exported item names used for per-function overrides
+            let mut item_names = HashMap::new();
+            for fun in ci.function_definitions() {
+                item_names.insert(fun.ffi_func().name().to_owned(), fun.name().to_owned());
+            }
+            for obj in ci.object_definitions() {
+                for cons in obj.constructors() {
+                    item_names.insert(
+                        cons.ffi_func().name().to_owned(),
+                        format!("{}.{}", obj.name(), cons.name()),
+                    );
+                }
+                for meth in obj.methods() {
+                    item_names.insert(
+                        meth.ffi_func().name().to_owned(),
+                        format!("{}.{}", obj.name(), meth.name()),
+                    );
+                }
+            }
+
+            // Leaf calls must not call back into Dart, so skip detection when callbacks exist, and
+            // never make a call passing one a leaf call
+            let detect_leaf_calls = config.leaf_calls() && !ci.has_callback_definitions();
+            let calling_back = functions::calling_back(ci)
+                .into_iter()
+                .map(|(_, ffi_name)| ffi_name)
+                .collect::<HashSet<_>>();
+
             for fun in ci.iter_ffi_function_definitions() {
                 let fun_name = fun.name().to_owned();
+                if unsupported.is_ffi_function_skipped(&fun_name) {
+                    continue;
+                }
 
                 // Check for duplicate function names
                 if !defined_functions.insert(fun_name.clone()) {
@@ -134,40 +549,102 @@ impl<'a> DartWrapper<'a> {
                     None => quote! { void },
                 };
 
-                let (native_args, dart_args) = {
+                let (native_args, dart_args, dart_arg_types) = {
                     let mut native_arg_vec = vec![];
                     let mut dart_arg_with_names_vec = vec![];
+                    let mut dart_arg_type_vec = vec![];
 
                     for arg in fun.arguments() {
-                        let arg_name = arg.name();
+                        let arg_name = DartCodeOracle::var_name(arg.name());
                         let native_type =
                             DartCodeOracle::ffi_native_type_label(Some(&arg.type_()), ci);
                         let dart_type = DartCodeOracle::ffi_dart_type_label(Some(&arg.type_()), ci);
 
                         native_arg_vec.push(native_type);
-                        dart_arg_with_names_vec.push(quote!($dart_type $arg_name));
+                        dart_arg_with_names_vec.push(quote!($(&dart_type) $(&arg_name)));
+                        dart_arg_type_vec.push(dart_type);
                     }
 
                     if fun.has_rust_call_status_arg() {
                         native_arg_vec.push(quote!(Pointer<RustCallStatus>));
                         dart_arg_with_names_vec.push(quote!(Pointer<RustCallStatus> uniffiStatus));
+                        dart_arg_type_vec.push(quote!(Pointer<RustCallStatus>));
                     }
 
                     let native_args = quote!($(for (i, arg) in native_arg_vec.iter().enumerate() => $(if i > 0 => , )$[' ']$arg));
                     let dart_args = quote!($(for (i, arg) in dart_arg_with_names_vec.iter().enumerate() => $(if i > 0 => , )$[' ']$arg));
-                    (native_args, dart_args)
+                    let dart_arg_types = quote!($(for (i, arg) in dart_arg_type_vec.iter().enumerate() => $(if i > 0 => , )$[' ']$arg));
+                    (native_args, dart_args, dart_arg_types)
                 };
 
-                // Generate @Native annotation with assetId
-                // @Native uses the function name as symbol automatically
-                // assetId references the _uniffiAssetId constant
-                definitions.append(quote! {
-                    @Native<$(&native_return_type) Function($(&native_args))>(
-                      assetId: $asset_id
-                    )
-                    external $(&dart_return_type) $fun_name($(&dart_args));
-                    $['\n']
-                });
+                let is_leaf = !calling_back.contains(&fun_name)
+                    && item_names
+                        .get(&fun_name)
+                        .and_then(|item_name| config.leaf_override(item_name))
+                        .unwrap_or_else(|| {
+                            detect_leaf_calls && DartCodeOracle::is_primitive_ffi_function(&fun)
+                        });
+
+                definitions.append(
+                    FfiExtern {
+                        symbol: &fun_name,
+                        native_return_type,
+                        native_args,
+                        dart_return_type,
+                        dart_args,
+                        dart_arg_types,
+                        is_leaf,
+                    }
+                    .render(config, asset_id),
+                );
+            }
+
+            // Registration of the Dart functions behind `#[uniffi_dart::thread_safe]` interfaces
+            for (name, _, init_fn) in diagnostics::callback_interfaces(ci) {
+                if !callback_interface::is_thread_safe(ci, name, config)
+                    || unsupported.is_ffi_function_skipped(init_fn.name())
+                {
+                    continue;
+                }
+                let register = callback_interface::dispatch_symbol(ci, name, "register");
+                definitions.append(
+                    FfiExtern {
+                        symbol: &register,
+                        native_return_type: quote!(Int8),
+                        native_args: quote!(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>),
+                        dart_return_type: quote!(int),
+                        dart_args: quote!(Pointer<Void> api, Pointer<Pointer<Void>> direct, Pointer<Pointer<Void>> listeners, Pointer<Pointer<Void>> vtable),
+                        dart_arg_types: quote!(Pointer<Void>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>, Pointer<Pointer<Void>>),
+                        is_leaf: true,
+                    }
+                    .render(config, asset_id),
+                );
+                let new_handle = callback_interface::dispatch_symbol(ci, name, "new_handle");
+                definitions.append(
+                    FfiExtern {
+                        symbol: &new_handle,
+                        native_return_type: quote!(Uint64),
+                        native_args: quote!(),
+                        dart_return_type: quote!(int),
+                        dart_args: quote!(),
+                        dart_arg_types: quote!(),
+                        is_leaf: true,
+                    }
+                    .render(config, asset_id),
+                );
+                let signal = callback_interface::dispatch_symbol(ci, name, "signal");
+                definitions.append(
+                    FfiExtern {
+                        symbol: &signal,
+                        native_return_type: quote!(Void),
+                        native_args: quote!(Pointer<Void>),
+                        dart_return_type: quote!(void),
+                        dart_args: quote!(Pointer<Void> waiter),
+                        dart_arg_types: quote!(Pointer<Void>),
+                        is_leaf: true,
+                    }
+                    .render(config, asset_id),
+                );
             }
 
             definitions
@@ -175,43 +652,68 @@ impl<'a> DartWrapper<'a> {
 
         let asset_id_suffix = &self.config.asset_id(); // e.g., "uniffi:hello_world"
 
-        quote! {
-            library $package_name;
+        let library_loading = if self.config.dynamic_library() {
+            self.dynamic_library_loader()
+        } else {
+            quote! {
+                // Generated by uniffi-dart – do NOT edit.
+                // This asset ID is used by @Native annotations to locate the native library
+                // via Native Assets. Dart automatically prefixes asset names with "package:{packageName}/",
+                // so we construct the full ID here to match what the build hook registers.
+                // The asset ID format is: package:{dart_package_name}/uniffi:{cdylib_name}
+                const _uniffiAssetId = $(quoted(format!("package:{}/{}", package_name, asset_id_suffix)));
+            }
+        };
 
+        quote! {
+            $(uses([DART_FFI]))
             $(type_helper_code) // Imports, Types and Type Helper
 
-            // Generated by uniffi-dart – do NOT edit.
-            // This asset ID is used by @Native annotations to locate the native library
-            // via Native Assets. Dart automatically prefixes asset names with "package:{packageName}/",
-            // so we construct the full ID here to match what the build hook registers.
-            // The asset ID format is: package:{dart_package_name}/uniffi:{cdylib_name}
-            const _uniffiAssetId = $(quoted(format!("package:{}/{}", package_name, asset_id_suffix)));
+            $library_loading
 
             $(functions_definitions)
 
-            // FFI function definitions using @Native
-            $(uniffi_function_definitions(self.ci, "_uniffiAssetId"))
+            // FFI function definitions
+            $(uniffi_function_definitions(self.ci, self.config, self.type_renderer.unsupported(), "_uniffiAssetId"))
 
             // API version and checksum validation
             void _checkApiVersion() {
                 final bindingsVersion = $(self.ci.uniffi_contract_version());
-                final scaffoldingVersion = $(self.ci.ffi_uniffi_contract_version().name())();
+                final scaffoldingVersion = $(DartCodeOracle::ffi_function_name(self.ci.ffi_uniffi_contract_version().name()))();
                 if (bindingsVersion != scaffoldingVersion) {
-                  throw UniffiInternalError.panicked("UniFFI contract version mismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion");
+                  throw UniffiContractVersionMismatchException(
+                    bindingsVersion: bindingsVersion,
+                    scaffoldingVersion: scaffoldingVersion,
+                  );
                 }
             }
 
             void _checkApiChecksums() {
+                final mismatchedChecksums = <String>[];
                 $(for (name, expected_checksum) in self.ci.iter_checksums() =>
-                    if ($(name)() != $expected_checksum) {
-                      throw UniffiInternalError.panicked("UniFFI API checksum mismatch");
+                    if ($(DartCodeOracle::ffi_function_name(&name))() != $expected_checksum) {
+                      mismatchedChecksums.add($(quoted(name)));
                     }
                 )
+                if (mismatchedChecksums.isNotEmpty) {
+                  throw UniffiApiChecksumMismatchException(mismatchedChecksums);
+                }
             }
 
-            void ensureInitialized() {
+            // Set once the checks passed, so they run on the first call only
+            bool _uniffiInitialized = false;
+
+            void _uniffiEnsureInitialized() {
+                if (_uniffiInitialized) {
+                  return;
+                }
                 _checkApiVersion();
                 _checkApiChecksums();
+                _uniffiInitialized = true;
+            }
+
+            void ensureInitialized() {
+                _uniffiEnsureInitialized();
             }
 
             // Backwards-compatible entry point used by existing tests
@@ -225,6 +727,108 @@ impl<'a> DartWrapper<'a> {
 
 pub struct DartBindingGenerator;
 
+impl DartBindingGenerator {
+    /// Render every component, keyed by path relative to the output directory.
+    fn render(
+        &self,
+        components: &[Component<Config>],
+        try_format_code: bool,
+    ) -> Result<BTreeMap<Utf8PathBuf, String>> {
+        // Report every unsupported construct of every component at once, before rendering anything
+        let mut errors = Vec::new();
+        let mut component_names = Vec::new();
+        for Component { ci, config, .. } in components {
+            // Colliding names get renamed; everything generated below goes through these
+            let names = Names::resolve(ci, config)?;
+            for rename in names.renames() {
+                println!("WARNING: {}: {rename}", ci.crate_name());
+            }
+            for warning in functions::background_warnings(ci, config) {
+                println!("WARNING: {}: {warning}", ci.crate_name());
+            }
+            for warning in functions::leaf_warnings(ci, config) {
+                println!("WARNING: {}: {warning}", ci.crate_name());
+            }
+            component_names.push(names);
+
+            let unsupported = Unsupported::check(ci, config);
+            if config.skip_unsupported() {
+                for diagnostic in &unsupported.diagnostics {
+                    println!("WARNING: skipping unsupported item {diagnostic}");
+                }
+            } else {
+                errors.extend(unsupported.diagnostics);
+            }
+        }
+        if !errors.is_empty() {
+            bail!(
+                "Can't generate Dart bindings for {} unsupported item(s) (set `skip_unsupported = true` to leave them out):\n{}",
+                errors.len(),
+                errors
+                    .iter()
+                    .map(|diagnostic| format!("  {diagnostic}"))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+
+        let mut sources = BTreeMap::new();
+        let mut runtime_dirs = BTreeSet::new();
+        for (Component { ci, config, .. }, names) in components.iter().zip(component_names) {
+            let internal_path = config.internal_path(ci.namespace());
+            let filename = Utf8PathBuf::from(&internal_path);
+            let library_dir = filename.parent().unwrap_or(Utf8Path::new("")).to_owned();
+
+            let wrapper = DartWrapper::new(ci, config, &names);
+            let runtime_dir = if config.split_output() {
+                let split_dir = library_dir.join(ci.namespace());
+                let mut paths = Vec::new();
+                for (file_name, tokens) in wrapper.generate_split() {
+                    sources.insert(
+                        split_dir.join(&file_name),
+                        render_dart_file(&tokens, try_format_code)?,
+                    );
+                    paths.push(format!("{}/{file_name}", ci.namespace()));
+                }
+                let barrel = split::generate_barrel(&paths);
+                sources.insert(filename, render_dart_file(&barrel, try_format_code)?);
+                split_dir
+            } else {
+                sources.insert(
+                    filename,
+                    render_dart_file(&wrapper.generate(), try_format_code)?,
+                );
+                library_dir
+            };
+            if config.hide_internals() {
+                let filename = Utf8PathBuf::from(format!("{}.dart", ci.namespace()));
+                let tokens = wrapper.generate_public_library(&internal_path);
+                sources.insert(filename, render_dart_file(&tokens, try_format_code)?);
+            }
+            if config.fakes() {
+                sources.insert(
+                    fakes::file_name(ci.namespace()).into(),
+                    render_dart_file(&wrapper.generate_fakes(), try_format_code)?,
+                );
+            }
+
+            if config.writes_runtime() {
+                runtime_dirs.insert(runtime_dir);
+            }
+        }
+
+        // The runtime is imported relative to the bindings, so it lives next to them
+        for dir in runtime_dirs {
+            let tokens = runtime::generate_runtime_definitions();
+            sources.insert(
+                dir.join(runtime::RUNTIME_FILE_NAME),
+                render_dart_file(&tokens, try_format_code)?,
+            );
+        }
+        Ok(sources)
+    }
+}
+
 impl BindingGenerator for DartBindingGenerator {
     type Config = Config;
 
@@ -233,20 +837,12 @@ impl BindingGenerator for DartBindingGenerator {
         settings: &uniffi_bindgen::GenerationSettings,
         components: &[uniffi_bindgen::Component<Self::Config>],
     ) -> Result<()> {
-        for Component { ci, config, .. } in components {
-            let filename = settings.out_dir.join(format!("{}.dart", ci.namespace()));
-            let tokens = DartWrapper::new(ci, config).generate();
-            let file = std::fs::File::create(filename)?;
-
-            let mut w = fmt::IoWriter::new(file);
-
-            let mut fmt = fmt::Config::from_lang::<Dart>();
-            if settings.try_format_code {
-                fmt = fmt.with_indentation(fmt::Indentation::Space(2));
+        for (path, source) in self.render(components, settings.try_format_code)? {
+            let filename = settings.out_dir.join(path);
+            if let Some(dir) = filename.parent() {
+                std::fs::create_dir_all(dir)?;
             }
-            let config = dart::Config::default();
-
-            tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+            std::fs::write(&filename, source)?;
         }
 
         // Run full Dart formatter on the output directory as a best-effort step.
@@ -293,93 +889,86 @@ impl BindingGenerator for DartBindingGenerator {
     }
 }
 
-pub struct LocalConfigSupplier(String);
-impl BindgenCrateConfigSupplier for LocalConfigSupplier {
-    fn get_udl(&self, _crate_name: &str, _udl_name: &str) -> Result<String> {
-        let file = std::fs::File::open(self.0.clone())?;
-        let mut reader = std::io::BufReader::new(file);
-        let mut content = String::new();
-        reader.read_to_string(&mut content)?;
-        Ok(content)
+/// Renders a file, importing the libraries its renderers registered with `imports::uses`.
+fn render_dart_file(tokens: &dart::Tokens, try_format_code: bool) -> Result<String> {
+    let mut w = fmt::FmtWriter::new(String::new());
+
+    let mut fmt = fmt::Config::from_lang::<Dart>();
+    if try_format_code {
+        fmt = fmt.with_indentation(fmt::Indentation::Space(2));
     }
-}
+    let config = dart::Config::default();
 
-/// Config supplier for library mode that locates UDL files from dependency crates.
-/// This implementation matches uniffi_bindgen's CrateConfigSupplier approach.
-pub struct ConfigFileSupplier {
-    config_file_path: String,
-    crate_paths: HashMap<String, Utf8PathBuf>,
+    tokens.format_file(&mut w.as_formatter(&fmt), &config)?;
+    Ok(w.into_inner())
 }
 
-impl ConfigFileSupplier {
-    /// Create a new ConfigFileSupplier from cargo metadata and a config file path
-    pub fn new(config_file_path: String, metadata: Metadata) -> Self {
-        // Build a map of crate names to their manifest directories
-        // This matches uniffi_bindgen's CrateConfigSupplier::from(Metadata) implementation
-        let crate_paths: HashMap<String, Utf8PathBuf> = metadata
-            .packages
-            .iter()
-            .flat_map(|p| {
-                p.targets
-                    .iter()
-                    .filter(|t| {
-                        !t.is_bin()
-                            && !t.is_example()
-                            && !t.is_test()
-                            && !t.is_bench()
-                            && !t.is_custom_build()
-                    })
-                    .filter_map(|t| {
-                        p.manifest_path
-                            .parent()
-                            .map(|p| (t.name.replace('-', "_"), p.to_owned()))
-                    })
-            })
-            .collect();
+/// Generate the bindings of a component in memory, keyed by their path relative to the output
+/// directory. Nothing is written to disk and `dart format` isn't run.
+pub fn generate_sources(
+    ci: &ComponentInterface,
+    config: &Config,
+) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    let mut components = vec![Component {
+        ci: ci.clone(),
+        config: config.clone(),
+    }];
+    let settings = uniffi_bindgen::GenerationSettings::default();
+    DartBindingGenerator.update_component_configs(&settings, &mut components)?;
+    DartBindingGenerator.render(&components, false)
+}
 
-        Self {
-            config_file_path,
-            crate_paths,
-        }
-    }
+/// Generate the bindings of every component in a cdylib in memory, like [`generate_sources`].
+///
+/// `config_supplier` provides each crate's config and UDL files;
+/// [`uniffi_bindgen::EmptyCrateConfigSupplier`] is enough for proc-macro only crates using the
+/// default config.
+pub fn generate_library_sources(
+    library_path: &Utf8Path,
+    config_supplier: &dyn BindgenCrateConfigSupplier,
+) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    let settings = uniffi_bindgen::GenerationSettings {
+        cdylib: uniffi_bindgen::library_mode::calc_cdylib_name(library_path).map(str::to_owned),
+        ..Default::default()
+    };
+    let components = library_components(library_path, config_supplier, None, &settings)?;
+    DartBindingGenerator.render(&components, false)
 }
 
-impl BindgenCrateConfigSupplier for ConfigFileSupplier {
-    fn get_udl(&self, crate_name: &str, udl_name: &str) -> Result<String> {
-        // This implementation matches uniffi_bindgen's CrateConfigSupplier::get_udl
-        let path = self
-            .crate_paths
-            .get(crate_name)
-            .context(format!("No path known to UDL files for '{crate_name}'"))?
-            .join("src")
-            .join(format!("{udl_name}.udl"));
-        if path.exists() {
-            Ok(std::fs::read_to_string(path)?)
-        } else {
-            bail!(format!("No UDL file found at '{path}'"));
-        }
-    }
+/// The components of a cdylib, each configured by its crate's config with `config_override`
+/// merged on top.
+fn library_components(
+    library_path: &Utf8Path,
+    config_supplier: &dyn BindgenCrateConfigSupplier,
+    config_override: Option<&toml::value::Table>,
+    settings: &uniffi_bindgen::GenerationSettings,
+) -> Result<Vec<Component<Config>>> {
+    let mut components =
+        uniffi_bindgen::library_mode::find_components(library_path, config_supplier)
+            .with_context(|| format!("finding components in `{library_path}`"))?
+            .into_iter()
+            .map(|Component { ci, mut config }| {
+                if let Some(config_override) = config_override {
+                    merge_toml(&mut config, config_override.clone());
+                }
+                let config = DartBindingGenerator.new_config(&toml::Value::Table(config))?;
+                Ok(Component { ci, config })
+            })
+            .collect::<Result<Vec<_>>>()?;
+    DartBindingGenerator.update_component_configs(settings, &mut components)?;
+    Ok(components)
+}
 
-    fn get_toml(&self, _crate_name: &str) -> Result<Option<toml::value::Table>> {
-        // Load the config file specified for this binding generation
-        let file = std::fs::File::open(self.config_file_path.clone())?;
-        let mut reader = std::io::BufReader::new(file);
-        let mut content = String::new();
-        reader.read_to_string(&mut content)?;
-        let toml_value: toml::value::Value = toml::from_str(&content)?;
-        if let toml::value::Value::Table(table) = toml_value {
-            Ok(Some(table))
-        } else {
-            Ok(None)
+/// Merge `b` into `a`, the way uniffi applies a config file override.
+fn merge_toml(a: &mut toml::value::Table, b: toml::value::Table) {
+    for (key, value) in b {
+        match (a.get_mut(&key), value) {
+            (Some(toml::Value::Table(a)), toml::Value::Table(b)) => merge_toml(a, b),
+            (_, value) => {
+                a.insert(key, value);
+            }
         }
     }
-
-    fn get_toml_path(&self, crate_name: &str) -> Option<Utf8PathBuf> {
-        // This implementation matches uniffi_bindgen's CrateConfigSupplier::get_toml_path
-        self.crate_paths
-            .get(crate_name)
-            .map(|p| p.join("uniffi.toml"))
-    }
 }
 
 pub fn generate_dart_bindings(
@@ -390,28 +979,13 @@ pub fn generate_dart_bindings(
     library_mode: bool,
 ) -> anyhow::Result<()> {
     if library_mode {
-        // In library mode, we need cargo metadata to locate UDL files from dependencies
-        let metadata = cargo_metadata::MetadataCommand::new()
-            .exec()
-            .context("Failed to run cargo metadata")?;
-
-        let config_supplier: Box<dyn BindgenCrateConfigSupplier> =
-            if let Some(config_path) = config_file_override {
-                Box::new(ConfigFileSupplier::new(config_path.to_string(), metadata))
-            } else {
-                Box::new(LocalConfigSupplier(udl_file.to_string()))
-            };
-
-        uniffi_bindgen::library_mode::generate_bindings(
+        generate_library_bindings(
             library_file,
-            None, // crate name filter
-            &DartBindingGenerator {},
-            config_supplier.as_ref(),
             None,
-            out_dir_override.unwrap(),
+            config_file_override,
+            out_dir_override,
             true,
-        )?;
-        Ok(())
+        )
     } else {
         // Note: library_file is needed by uniffi_bindgen to extract metadata from proc macros,
         // even though we don't use it for DynamicLibrary.open() anymore (Native Assets handle that)
@@ -426,3 +1000,199 @@ pub fn generate_dart_bindings(
         )
     }
 }
+
+/// Config supplier that reads every crate's UDL from a single file.
+#[deprecated(
+    note = "use `uniffi_bindgen::cargo_metadata::CrateConfigSupplier`, which locates \
+                     each crate's UDL files and `uniffi.toml`"
+)]
+pub struct LocalConfigSupplier(pub String);
+
+#[allow(deprecated)]
+impl BindgenCrateConfigSupplier for LocalConfigSupplier {
+    fn get_udl(&self, _crate_name: &str, _udl_name: &str) -> Result<String> {
+        Ok(std::fs::read_to_string(&self.0)?)
+    }
+}
+
+/// Config supplier for library mode that locates UDL files from dependency crates and
+/// configures every crate with the same config file.
+#[deprecated(
+    note = "use `generate_library_bindings`, which configures each crate with its own \
+                     `uniffi.toml` and merges a config file override on top"
+)]
+pub struct ConfigFileSupplier {
+    config_file_path: String,
+    crates: CrateConfigSupplier,
+}
+
+#[allow(deprecated)]
+impl ConfigFileSupplier {
+    /// Create a new ConfigFileSupplier from cargo metadata and a config file path
+    pub fn new(config_file_path: String, metadata: cargo_metadata::Metadata) -> Self {
+        Self {
+            config_file_path,
+            crates: CrateConfigSupplier::from(metadata),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl BindgenCrateConfigSupplier for ConfigFileSupplier {
+    fn get_udl(&self, crate_name: &str, udl_name: &str) -> Result<String> {
+        self.crates.get_udl(crate_name, udl_name)
+    }
+
+    fn get_toml(&self, _crate_name: &str) -> Result<Option<toml::value::Table>> {
+        read_toml(Utf8Path::new(&self.config_file_path)).map(Some)
+    }
+
+    fn get_toml_path(&self, crate_name: &str) -> Option<Utf8PathBuf> {
+        self.crates.get_toml_path(crate_name)
+    }
+}
+
+/// Generate the bindings of the components in a cdylib, each configured by its crate's
+/// `uniffi.toml` with `config_file_override` merged on top. Crates are located through
+/// `cargo metadata`, run from the current directory.
+///
+/// `crates` limits the output to the named crates, so a workspace can ship the bindings of its
+/// public crates only. Bindings are written next to the cdylib unless `out_dir_override` is given.
+pub fn generate_library_bindings(
+    library_file: &Utf8Path,
+    crates: Option<&[&str]>,
+    config_file_override: Option<&Utf8Path>,
+    out_dir_override: Option<&Utf8Path>,
+    try_format_code: bool,
+) -> Result<()> {
+    write_library_bindings(
+        library_file,
+        crates,
+        config_file_override,
+        out_dir_override,
+        try_format_code,
+    )
+    .map(|_| ())
+}
+
+/// [`generate_library_bindings`], returning the components written.
+fn write_library_bindings(
+    library_file: &Utf8Path,
+    crates: Option<&[&str]>,
+    config_file_override: Option<&Utf8Path>,
+    out_dir_override: Option<&Utf8Path>,
+    try_format_code: bool,
+) -> Result<Vec<Component<Config>>> {
+    let metadata = cargo_metadata::MetadataCommand::new()
+        .exec()
+        .context("Failed to run cargo metadata")?;
+    let config_supplier = CrateConfigSupplier::from(metadata);
+    let config_override = config_file_override.map(read_toml).transpose()?;
+
+    let out_dir = match out_dir_override {
+        Some(out_dir) => out_dir.to_owned(),
+        None => library_file
+            .parent()
+            .with_context(|| format!("`{library_file}` has no parent directory"))?
+            .to_owned(),
+    };
+    let settings = uniffi_bindgen::GenerationSettings {
+        out_dir,
+        try_format_code,
+        cdylib: uniffi_bindgen::library_mode::calc_cdylib_name(library_file).map(str::to_owned),
+    };
+    let mut components = library_components(
+        library_file,
+        &config_supplier,
+        config_override.as_ref(),
+        &settings,
+    )?;
+
+    if let Some(crates) = crates {
+        for name in crates {
+            if !components.iter().any(|c| c.ci.crate_name() == *name) {
+                bail!("crate `{name}` isn't part of `{library_file}`");
+            }
+        }
+        components.retain(|c| crates.contains(&c.ci.crate_name()));
+    }
+
+    std::fs::create_dir_all(&settings.out_dir)?;
+    DartBindingGenerator.write_bindings(&settings, &components)?;
+    Ok(components)
+}
+
+/// Generate a complete Dart package for the components in a cdylib: their bindings in `lib/`,
+/// the cdylib itself next to the `pubspec.yaml` unless the build hook runs cargo, and the
+/// [`Package`] scaffold around them.
+///
+/// `crates` limits the package to the named crates, like in [`generate_library_bindings`]. Their
+/// configs have to agree on the package name and asset ID.
+pub fn generate_package(
+    library_file: &Utf8Path,
+    crates: Option<&[&str]>,
+    package_dir: &Utf8Path,
+) -> Result<Package> {
+    let components = write_library_bindings(
+        library_file,
+        crates,
+        None,
+        Some(&package_dir.join("lib")),
+        true,
+    )?;
+    let Some(first) = components.first() else {
+        bail!("`{library_file}` has no UniFFI components");
+    };
+    for Component { ci, config, .. } in &components[1..] {
+        if config.package_name() != first.config.package_name()
+            || config.asset_id() != first.config.asset_id()
+        {
+            bail!(
+                "`{}` and `{}` disagree on `package_name` or `asset_id`, so they can't share a package",
+                first.ci.crate_name(),
+                ci.crate_name()
+            );
+        }
+    }
+
+    let mut package = Package::new(&first.config, components.iter().map(|c| c.ci.namespace()));
+    // Without a crate to build, the hook bundles the cdylib we were given
+    if first.config.cargo_crate_path().is_none() {
+        let Some(file_name) = library_file.file_name() else {
+            bail!("`{library_file}` isn't a file");
+        };
+        std::fs::copy(library_file, package_dir.join(file_name))?;
+        package = package.with_native_library(file_name);
+    }
+    package.write(package_dir)?;
+    Ok(package)
+}
+
+/// The config bindings are generated with for the crate in `crate_root`, linked into the cdylib
+/// `cdylib_name`: the crate's `uniffi.toml` with `config_file_override` merged on top.
+pub fn load_config(
+    crate_root: &Utf8Path,
+    config_file_override: Option<&Utf8Path>,
+    cdylib_name: &str,
+) -> Result<Config> {
+    let crate_config = crate_root.join("uniffi.toml");
+    let mut config = if crate_config.exists() {
+        read_toml(&crate_config)?
+    } else {
+        toml::value::Table::new()
+    };
+    if let Some(config_file_override) = config_file_override {
+        merge_toml(&mut config, read_toml(config_file_override)?);
+    }
+    let mut config = DartBindingGenerator.new_config(&toml::Value::Table(config))?;
+    config
+        .cdylib_name
+        .get_or_insert_with(|| cdylib_name.to_owned());
+    Ok(config)
+}
+
+fn read_toml(path: &Utf8Path) -> Result<toml::value::Table> {
+    let content =
+        std::fs::read_to_string(path).with_context(|| format!("reading config `{path}`"))?;
+    toml::from_str(&content).with_context(|| format!("parsing config `{path}`"))
+}
@@ -0,0 +1,633 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use anyhow::{bail, Result};
+use heck::ToLowerCamelCase;
+use uniffi_bindgen::interface::Type;
+use uniffi_bindgen::ComponentInterface;
+
+use crate::gen::oracle::DartCodeOracle;
+use crate::gen::{functions, Config};
+
+/// Classes from the libraries the bindings import, and the runtime's own classes. A generated
+/// class with one of these names would shadow it.
+const RESERVED_CLASS_NAMES: &[&str] = &[
+    // dart:core
+    "BigInt",
+    "Comparable",
+    "DateTime",
+    "Deprecated",
+    "Duration",
+    "Enum",
+    "Error",
+    "Exception",
+    "Expando",
+    "Finalizer",
+    "Function",
+    "Future",
+    "Invocation",
+    "Iterable",
+    "Iterator",
+    "List",
+    "Map",
+    "MapEntry",
+    "Match",
+    "Null",
+    "Object",
+    "Pattern",
+    "Record",
+    "RegExp",
+    "Set",
+    "Sink",
+    "StackTrace",
+    "Stream",
+    "String",
+    "StringBuffer",
+    "Symbol",
+    "Type",
+    "Uri",
+    "WeakReference",
+    // dart:async, dart:io, dart:isolate, dart:typed_data
+    "Completer",
+    "Directory",
+    "File",
+    "Isolate",
+    "Platform",
+    "StreamController",
+    "ByteData",
+    "Uint8List",
+    // dart:ffi
+    "Bool",
+    "Double",
+    "Float",
+    "Handle",
+    "Int8",
+    "Int16",
+    "Int32",
+    "Int64",
+    "Native",
+    "NativeCallable",
+    "NativeFunction",
+    "Pointer",
+    "Struct",
+    "Uint8",
+    "Uint16",
+    "Uint32",
+    "Uint64",
+    "Void",
+    // Runtime
+    "FfiConverter",
+    "FfiConverterPrimitive",
+    "ForeignBytes",
+    "LiftRetVal",
+    "NullRustCallStatusErrorHandler",
+    "RustBuffer",
+    "RustCallStatus",
+    "UniffiApiChecksumMismatchException",
+    "UniffiContractMismatchException",
+    "UniffiContractVersionMismatchException",
+    "UniffiForeignFuture",
+    "UniffiForeignFutureState",
+    "UniffiHandleMap",
+    "UniffiInternalError",
+    "UniffiRustCallStatusErrorHandler",
+];
+
+/// Top-level functions of `dart:core`, and functions and constants of the bindings themselves.
+const RESERVED_FUNCTION_NAMES: &[&str] = &[
+    "checkCallStatus",
+    "createUint8ListFromInt",
+    "ensureInitialized",
+    "identical",
+    "identityHashCode",
+    "initialize",
+    "print",
+    "rustCall",
+    "rustCallWithLifter",
+    "toRustBuffer",
+    "uniffiCallError",
+    "uniffiCallSuccess",
+    "uniffiCallUnexpectedError",
+    "uniffiRustCallAsync",
+    "uniffiRustFuturePollMaybeReady",
+    "uniffiRustFuturePollReady",
+];
+
+/// Members every Dart object has.
+const OBJECT_MEMBERS: &[&str] = &["hashCode", "noSuchMethod", "runtimeType", "toString"];
+
+/// Members generated on every object class, next to its exported methods.
+const GENERATED_OBJECT_MEMBERS: &[&str] = &[
+    "allocationSize",
+    "dispose",
+    "fromSendableHandle",
+    "lift",
+    "lower",
+    "read",
+    "toSendableHandle",
+    "uniffiClonePointer",
+    "write",
+];
+
+/// Members every Dart `enum` has.
+const ENUM_MEMBERS: &[&str] = &["index", "name", "values"];
+
+/// Members generated on every variant class of an enum with fields.
+const GENERATED_VARIANT_MEMBERS: &[&str] = &["allocationSize", "lower", "read", "write"];
+
+/// Variables of the generated `read` methods, which declare a local for each field of the value
+/// they read.
+const READ_LOCALS: &[&str] = &["buf", "newOffset"];
+
+/// A Dart identifier that had to differ from the default mapping of its Rust name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    /// The Rust item: `Type`, `Type.member` or `function`.
+    pub item: String,
+    pub from: String,
+    pub to: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Rename {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is generated as `{}` instead of `{}`: {}",
+            self.item, self.to, self.from, self.reason
+        )
+    }
+}
+
+/// The Dart identifiers of a component that were configured in `rename`, or whose default names
+/// collide with each other or with names Dart already defines. Everything not listed keeps its
+/// default name.
+#[derive(Debug, Default)]
+pub struct Names {
+    /// Whether the `Error` suffix of class names is kept rather than rewritten to `Exception`.
+    keep_error_suffix: bool,
+    classes: BTreeMap<String, String>,
+    interfaces: BTreeMap<String, String>,
+    variant_classes: BTreeMap<(String, String), String>,
+    /// Keyed by owning type (empty for top-level functions) and member.
+    members: BTreeMap<(String, String), String>,
+    /// Names of the `InBackground` variants of members, keyed like `members`.
+    background_members: BTreeMap<(String, String), String>,
+    /// Error handler classes of the types thrown as errors, keyed by type.
+    error_handler_classes: BTreeMap<String, String>,
+    /// The instances of the error handler classes, keyed by type.
+    error_handlers: BTreeMap<String, String>,
+    /// Types defined by other components, keyed by name, with the prefix their library is
+    /// imported under.
+    external_prefixes: BTreeMap<String, String>,
+    renames: Vec<Rename>,
+}
+
+/// A name that has to be unique within a scope, e.g. all classes of a library.
+struct Candidate {
+    item: String,
+    default: String,
+    /// Tried before falling back to appending underscores.
+    alternative: Option<String>,
+}
+
+impl Names {
+    pub fn resolve(ci: &ComponentInterface, config: &Config) -> Result<Self> {
+        let error_to_exception = config.error_to_exception();
+        let mut names = Names {
+            keep_error_suffix: !error_to_exception,
+            ..Names::default()
+        };
+        let mut configured = config.renames();
+
+        for ty in ci.iter_external_types() {
+            if let (Some(name), Ok(namespace)) = (ty.name(), ci.namespace_for_type(ty)) {
+                names
+                    .external_prefixes
+                    .insert(name.to_string(), namespace.to_string());
+            }
+        }
+
+        // Classes. Types keep their names over the classes derived from them, and a type whose
+        // name needed no rewriting beats one that was rewritten into it (`FooError` → `FooException`).
+        let mut types = Vec::new();
+        for ty in ci.iter_local_types() {
+            let name = match ty {
+                Type::Record { name, .. }
+                | Type::Enum { name, .. }
+                | Type::Object { name, .. }
+                | Type::CallbackInterface { name, .. }
+                | Type::Custom { name, .. } => name,
+                _ => continue,
+            };
+            let default = DartCodeOracle::default_class_name(name, error_to_exception);
+            let plain = DartCodeOracle::default_class_name(name, false);
+            types.push(Candidate {
+                item: name.clone(),
+                alternative: (plain != default).then_some(plain),
+                default,
+            });
+        }
+        types.sort_by_key(|c| (c.alternative.is_some(), c.item.clone()));
+        let mut taken = BTreeMap::new();
+        for (name, resolved) in
+            names.assign(types, RESERVED_CLASS_NAMES, &mut configured, &mut taken)?
+        {
+            names.classes.insert(name, resolved);
+        }
+
+        let final_class = |names: &Names, name: &str| {
+            names
+                .classes
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| DartCodeOracle::default_class_name(name, error_to_exception))
+        };
+
+        let mut interfaces = Vec::new();
+        for obj in ci.object_definitions() {
+            if !obj.has_callback_interface() && !obj.is_trait_interface() {
+                interfaces.push(Candidate {
+                    item: obj.name().to_string(),
+                    default: format!("{}Interface", final_class(&names, obj.name())),
+                    alternative: None,
+                });
+            }
+        }
+        for (name, resolved) in names.assign(
+            interfaces,
+            RESERVED_CLASS_NAMES,
+            &mut BTreeMap::new(),
+            &mut taken,
+        )? {
+            names.interfaces.insert(name, resolved);
+        }
+
+        let thrown = ci
+            .enum_definitions()
+            .iter()
+            .map(|e| e.name())
+            .chain(ci.object_definitions().iter().map(|o| o.name()))
+            .filter(|name| ci.is_name_used_as_error(name))
+            .collect::<Vec<_>>();
+        let handler_classes = thrown
+            .iter()
+            .map(|name| Candidate {
+                item: error_handler_item(name),
+                default: format!("{}ErrorHandler", final_class(&names, name)),
+                alternative: None,
+            })
+            .collect();
+        for (item, resolved) in names.assign(
+            handler_classes,
+            RESERVED_CLASS_NAMES,
+            &mut BTreeMap::new(),
+            &mut taken,
+        )? {
+            names
+                .error_handler_classes
+                .insert(thrown_type(&item).to_string(), resolved);
+        }
+
+        let mut variant_classes = Vec::new();
+        for enum_ in ci.enum_definitions().iter().filter(|e| !e.is_flat()) {
+            let enum_class = final_class(&names, enum_.name());
+            for variant in enum_.variants() {
+                variant_classes.push(Candidate {
+                    item: format!("{}.{}", enum_.name(), variant.name()),
+                    default: format!(
+                        "{}{enum_class}",
+                        DartCodeOracle::default_class_name(variant.name(), error_to_exception)
+                    ),
+                    alternative: None,
+                });
+            }
+        }
+        for (item, resolved) in names.assign(
+            variant_classes,
+            RESERVED_CLASS_NAMES,
+            &mut configured,
+            &mut taken,
+        )? {
+            let (enum_name, variant) = item.split_once('.').expect("variant item");
+            names
+                .variant_classes
+                .insert((enum_name.to_string(), variant.to_string()), resolved);
+        }
+
+        // Members, each type being its own scope
+        let functions = ci.function_definitions().iter().map(|f| f.name());
+        let mut taken =
+            names.resolve_members("", functions, RESERVED_FUNCTION_NAMES, &mut configured)?;
+        let background = ci
+            .function_definitions()
+            .iter()
+            .filter(|f| functions::runs_in_background(*f, f.name(), ci, config))
+            .map(|f| f.name());
+        names.resolve_background_variants("", background, RESERVED_FUNCTION_NAMES, &mut taken)?;
+        let handlers = thrown
+            .iter()
+            .map(|name| Candidate {
+                item: error_handler_item(name),
+                default: format!(
+                    "{}ErrorHandler",
+                    final_class(&names, name).to_lower_camel_case()
+                ),
+                alternative: None,
+            })
+            .collect();
+        for (item, resolved) in names.assign(
+            handlers,
+            RESERVED_FUNCTION_NAMES,
+            &mut BTreeMap::new(),
+            &mut taken,
+        )? {
+            names
+                .error_handlers
+                .insert(thrown_type(&item).to_string(), resolved);
+        }
+
+        let object_members = [OBJECT_MEMBERS, GENERATED_OBJECT_MEMBERS].concat();
+        for obj in ci.object_definitions() {
+            let constructors = obj
+                .constructors()
+                .into_iter()
+                .map(|c| c.name())
+                .filter(|name| *name != "new");
+            let methods = obj.methods().into_iter().map(|m| m.name());
+            let mut taken = names.resolve_members(
+                obj.name(),
+                constructors.chain(methods),
+                &object_members,
+                &mut configured,
+            )?;
+            let background = obj
+                .methods()
+                .into_iter()
+                .filter(|m| {
+                    let item_name = format!("{}.{}", obj.name(), m.name());
+                    functions::runs_in_background(*m, &item_name, ci, config)
+                })
+                .map(|m| m.name());
+            names.resolve_background_variants(
+                obj.name(),
+                background,
+                &object_members,
+                &mut taken,
+            )?;
+        }
+        for cbi in ci.callback_interface_definitions() {
+            let methods = cbi.methods().into_iter().map(|m| m.name());
+            names.resolve_members(cbi.name(), methods, OBJECT_MEMBERS, &mut configured)?;
+        }
+        let record_members = [OBJECT_MEMBERS, READ_LOCALS].concat();
+        for rec in ci.record_definitions() {
+            let fields = rec.fields().iter().map(|f| f.name());
+            names.resolve_members(rec.name(), fields, &record_members, &mut configured)?;
+        }
+        let enum_members = [OBJECT_MEMBERS, ENUM_MEMBERS].concat();
+        for enum_ in ci.enum_definitions().iter().filter(|e| e.is_flat()) {
+            let variants = enum_.variants().iter().map(|v| v.name());
+            names.resolve_members(enum_.name(), variants, &enum_members, &mut configured)?;
+        }
+        // Fields of tuple variants are named after their position, which can't collide
+        let variant_members = [OBJECT_MEMBERS, GENERATED_VARIANT_MEMBERS, READ_LOCALS].concat();
+        for enum_ in ci.enum_definitions().iter().filter(|e| !e.is_flat()) {
+            for variant in enum_.variants() {
+                let owner = format!("{}.{}", enum_.name(), variant.name());
+                let fields = variant
+                    .fields()
+                    .iter()
+                    .map(|f| f.name())
+                    .filter(|name| !name.is_empty());
+                names.resolve_members(&owner, fields, &variant_members, &mut configured)?;
+            }
+        }
+
+        // Every entry used was taken out of `configured`
+        if let Some(item) = configured.keys().next() {
+            bail!(
+                "{}: `rename` refers to `{item}`, which isn't a type, function, method, constructor, field or enum variant",
+                ci.crate_name()
+            );
+        }
+
+        Ok(names)
+    }
+
+    pub fn renames(&self) -> &[Rename] {
+        &self.renames
+    }
+
+    /// Whether the `Error` suffix of class names is rewritten to `Exception`.
+    pub fn error_to_exception(&self) -> bool {
+        !self.keep_error_suffix
+    }
+
+    pub fn class_name(&self, name: &str) -> Option<&str> {
+        self.classes.get(name).map(String::as_str)
+    }
+
+    pub fn interface_name(&self, object: &str) -> Option<&str> {
+        self.interfaces.get(object).map(String::as_str)
+    }
+
+    pub fn variant_class_name(&self, enum_name: &str, variant: &str) -> Option<&str> {
+        self.variant_classes
+            .get(&(enum_name.to_string(), variant.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn error_handler_class_name(&self, type_name: &str) -> Option<&str> {
+        self.error_handler_classes
+            .get(type_name)
+            .map(String::as_str)
+    }
+
+    pub fn error_handler_name(&self, type_name: &str) -> Option<&str> {
+        self.error_handlers.get(type_name).map(String::as_str)
+    }
+
+    /// The prefix the library defining the external type `type_name` is imported under.
+    pub fn external_prefix(&self, type_name: &str) -> Option<&str> {
+        self.external_prefixes.get(type_name).map(String::as_str)
+    }
+
+    pub fn member_name(&self, owner: &str, member: &str) -> Option<&str> {
+        self.members
+            .get(&(owner.to_string(), member.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn background_name(&self, owner: &str, member: &str) -> Option<&str> {
+        self.background_members
+            .get(&(owner.to_string(), member.to_string()))
+            .map(String::as_str)
+    }
+
+    fn resolve_members<'a>(
+        &mut self,
+        owner: &str,
+        members: impl Iterator<Item = &'a str>,
+        reserved: &[&str],
+        configured: &mut BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut candidates = members
+            .map(|member| Candidate {
+                item: member.to_string(),
+                default: DartCodeOracle::var_name(member),
+                alternative: None,
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|a, b| a.item.cmp(&b.item));
+        let candidates = candidates
+            .into_iter()
+            .map(|c| Candidate {
+                item: if owner.is_empty() {
+                    c.item
+                } else {
+                    format!("{owner}.{}", c.item)
+                },
+                ..c
+            })
+            .collect();
+        let mut taken = BTreeMap::new();
+        for (item, resolved) in self.assign(candidates, reserved, configured, &mut taken)? {
+            let member = item.rsplit_once('.').map_or(item.as_str(), |(_, m)| m);
+            self.members
+                .insert((owner.to_string(), member.to_string()), resolved);
+        }
+        Ok(taken)
+    }
+
+    /// Names the `InBackground` variants of the functions or methods `members` of `owner` after
+    /// their member, within the scope whose names are `taken`. The members keep their names.
+    fn resolve_background_variants<'a>(
+        &mut self,
+        owner: &str,
+        members: impl Iterator<Item = &'a str>,
+        reserved: &[&str],
+        taken: &mut BTreeMap<String, String>,
+    ) -> Result<()> {
+        let mut candidates = Vec::new();
+        let mut items = BTreeMap::new();
+        for member in members {
+            let item = if owner.is_empty() {
+                format!("{member} (in background)")
+            } else {
+                format!("{owner}.{member} (in background)")
+            };
+            let member_name = match self.member_name(owner, member) {
+                Some(name) => name.to_string(),
+                None => DartCodeOracle::var_name(member),
+            };
+            items.insert(item.clone(), member);
+            candidates.push(Candidate {
+                item,
+                default: format!("{member_name}InBackground"),
+                alternative: None,
+            });
+        }
+        candidates.sort_by(|a, b| a.item.cmp(&b.item));
+        for (item, resolved) in self.assign(candidates, reserved, &mut BTreeMap::new(), taken)? {
+            self.background_members
+                .insert((owner.to_string(), items[&item].to_string()), resolved);
+        }
+        Ok(())
+    }
+
+    /// Gives candidates with a configured name that name, and each of the others, in order, its
+    /// default name unless that's reserved or taken by an earlier one. Returns the candidates
+    /// that didn't get their default name. A configured name that's reserved or taken is an
+    /// error.
+    fn assign(
+        &mut self,
+        candidates: Vec<Candidate>,
+        reserved: &[&str],
+        configured: &mut BTreeMap<String, String>,
+        taken: &mut BTreeMap<String, String>,
+    ) -> Result<Vec<(String, String)>> {
+        let reserved = reserved.iter().copied().collect::<BTreeSet<_>>();
+        let is_free = |name: &str, taken: &BTreeMap<String, String>| {
+            !reserved.contains(name) && !taken.contains_key(name)
+        };
+
+        let mut renamed = Vec::new();
+        let (explicit, candidates): (Vec<_>, Vec<_>) = candidates
+            .into_iter()
+            .partition(|candidate| configured.contains_key(&candidate.item));
+        for candidate in explicit {
+            let name = configured.remove(&candidate.item).expect("configured name");
+            if !is_identifier(&name) || DartCodeOracle::is_reserved_identifier(&name) {
+                bail!(
+                    "`{}` is renamed to `{name}`, which isn't a valid Dart identifier",
+                    candidate.item
+                );
+            }
+            if reserved.contains(name.as_str()) {
+                bail!(
+                    "`{}` is renamed to `{name}`, which is reserved by Dart or the generated code",
+                    candidate.item
+                );
+            }
+            if let Some(owner) = taken.get(&name) {
+                bail!(
+                    "`{}` is renamed to `{name}`, which is already used by `{owner}`",
+                    candidate.item
+                );
+            }
+            taken.insert(name.clone(), candidate.item.clone());
+            renamed.push((candidate.item, name));
+        }
+
+        for candidate in candidates {
+            if is_free(&candidate.default, taken) {
+                taken.insert(candidate.default, candidate.item);
+                continue;
+            }
+
+            let reason = match taken.get(&candidate.default) {
+                Some(owner) => format!("`{}` is already used by `{owner}`", candidate.default),
+                None => format!(
+                    "`{}` is reserved by Dart or the generated code",
+                    candidate.default
+                ),
+            };
+            let mut resolved = match candidate.alternative {
+                Some(alternative) if is_free(&alternative, taken) => alternative,
+                _ => format!("{}_", candidate.default),
+            };
+            while !is_free(&resolved, taken) {
+                resolved.push('_');
+            }
+
+            taken.insert(resolved.clone(), candidate.item.clone());
+            self.renames.push(Rename {
+                item: candidate.item.clone(),
+                from: candidate.default,
+                to: resolved.clone(),
+                reason,
+            });
+            renamed.push((candidate.item, resolved));
+        }
+        Ok(renamed)
+    }
+}
+
+/// The item the error handler of the thrown type `name` is reported as.
+fn error_handler_item(name: &str) -> String {
+    format!("{name} (error handler)")
+}
+
+/// The thrown type of an item made by [`error_handler_item`].
+fn thrown_type(item: &str) -> &str {
+    item.strip_suffix(" (error handler)").unwrap_or(item)
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
@@ -5,17 +5,20 @@ use crate::gen::callback_interface::{
     generate_callback_functions, generate_callback_interface,
     generate_callback_interface_vtable_init_function, generate_callback_vtable_interface,
 };
+use crate::gen::naming::Names;
 use crate::gen::CodeType;
 use heck::ToLowerCamelCase;
 use std::string::ToString;
 use uniffi_bindgen::interface::{AsType, Method, Object, ObjectImpl, UniffiTrait};
-use uniffi_bindgen::pipeline::general::nodes::Literal;
+use uniffi_bindgen::ComponentInterface;
 
 use crate::gen::oracle::{AsCodeType, DartCodeOracle};
 use crate::gen::render::AsRenderable;
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 
-use super::stream::generate_stream;
+use super::functions;
+use super::imports::{uses, DART_FFI, DART_ISOLATE, DART_TYPED_DATA};
+use super::stream::{generate_stream, ExportedStream};
 
 #[derive(Debug)]
 pub struct ObjectCodeType {
@@ -30,32 +33,32 @@ impl ObjectCodeType {
 }
 
 impl CodeType for ObjectCodeType {
-    fn type_label(&self) -> String {
-        DartCodeOracle::class_name(&self.id)
+    fn type_label(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(names, &self.id, DartCodeOracle::class_name(names, &self.id))
     }
 
-    fn canonical_name(&self) -> String {
-        self.id.to_string()
+    fn canonical_name(&self, names: &Names) -> String {
+        DartCodeOracle::class_name(names, &self.id)
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
-        unreachable!();
-    }
-
-    fn ffi_converter_name(&self) -> String {
-        match self.imp {
-            ObjectImpl::Struct => self.canonical_name().to_string(), // Objects will use factory methods
-            ObjectImpl::CallbackTrait => format!("FfiConverterCallbackInterface{}", self.id),
-            ObjectImpl::Trait => self.canonical_name().to_string(),
-        }
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        let name = match self.imp {
+            ObjectImpl::Struct => self.canonical_name(names).to_string(), // Objects will use factory methods
+            ObjectImpl::CallbackTrait => {
+                format!(
+                    "FfiConverterCallbackInterface{}",
+                    self.canonical_name(names)
+                )
+            }
+            ObjectImpl::Trait => self.canonical_name(names).to_string(),
+        };
+        DartCodeOracle::qualified(names, &self.id, name)
     }
 }
 
 impl Renderable for ObjectCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        if type_helper.check(&self.id) {
-            quote!()
-        } else if let Some(obj) = type_helper.get_object(&self.id) {
+        if let Some(obj) = type_helper.get_object(&self.id) {
             generate_object(obj, type_helper)
         } else {
             unreachable!()
@@ -63,12 +66,11 @@ impl Renderable for ObjectCodeType {
     }
 }
 pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    type_helper.include_once_check(obj.name(), &obj.as_type());
-
+    let names = type_helper.get_names();
     if obj.has_callback_interface() {
         let interface = generate_callback_interface(
             obj.name(),
-            &obj.as_codetype().ffi_converter_name(),
+            &obj.as_codetype().ffi_converter_name(names),
             &obj.methods(),
             type_helper,
         );
@@ -84,6 +86,7 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
         let ffi_module =
             DartCodeOracle::infer_ffi_module(type_helper.get_ci(), move || fallback_namespace);
         let vtable_init = generate_callback_interface_vtable_init_function(
+            type_helper,
             obj.name(),
             &obj.methods(),
             &ffi_module,
@@ -98,61 +101,50 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
         return generate_trait_object(obj, type_helper);
     }
 
-    let cls_name = &DartCodeOracle::class_name(obj.name());
-    let interface_name = DartCodeOracle::object_interface_name(type_helper.get_ci(), obj);
-    let interface_definition = generate_object_interface(obj, &interface_name, type_helper);
-    let finalizer_cls_name = &format!("{cls_name}Finalizer");
-    let ffi_object_free_name = obj.ffi_object_free().name();
-    let ffi_object_clone_name = obj.ffi_object_clone().name();
-
-    // Stream workaround, make it more elegant later
-
-    let stream_glue = if obj.name().contains("StreamExt") {
-        generate_stream(obj, type_helper)
-    } else {
-        quote!()
+    let cls_name = &DartCodeOracle::class_name(names, obj.name());
+    let interface_name = DartCodeOracle::object_interface_name(names, obj);
+    let interface_definition =
+        generate_object_interface(names, type_helper.get_ci(), obj, &interface_name);
+    let finalizer_name = &format!("_{}Finalizer", cls_name.to_lower_camel_case());
+    let ffi_object_free_name = &DartCodeOracle::ffi_function_name(obj.ffi_object_free().name());
+    let ffi_object_clone_name = &DartCodeOracle::ffi_function_name(obj.ffi_object_clone().name());
+
+    let stream_glue = match ExportedStream::of(type_helper.get_ci(), obj) {
+        Some(stream) => generate_stream(&stream),
+        None => quote!(),
     };
 
     let mut constructor_definitions: Vec<dart::Tokens> = Vec::new();
     let mut async_constructor_factories: Vec<dart::Tokens> = Vec::new();
 
     for constructor in obj.constructors() {
-        let ffi_func_name = constructor.ffi_func().name();
+        let ffi_func_name = &DartCodeOracle::ffi_function_name(constructor.ffi_func().name());
         let constructor_name = constructor.name();
 
         let dart_constructor_decl = if constructor_name == "new" {
             quote!($cls_name)
         } else {
-            quote!($cls_name.$(DartCodeOracle::fn_name(constructor_name)))
+            quote!($cls_name.$(DartCodeOracle::member_name(names, obj.name(), constructor_name)))
         };
 
         // Check if function can throw errors
         let error_handler = if let Some(error_type) = constructor.throws_type() {
-            let error_name =
-                DartCodeOracle::class_name(error_type.name().unwrap_or("UnknownError"));
-            // Use the consistent Exception naming for error handlers
-            let handler_name = format!("{}ErrorHandler", error_name.to_lower_camel_case());
-            quote!($(handler_name))
+            quote!($(DartCodeOracle::error_handler_name(names, error_type)))
         } else {
             quote!(null)
         };
 
         let dart_params = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::dart_type_label(Some(&arg.as_type()))) $(DartCodeOracle::var_name(arg.name())),
+            $(DartCodeOracle::dart_type_label(names, Some(&arg.as_type()))) $(DartCodeOracle::var_name(arg.name())),
         ));
 
         let ffi_call_args = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::lower_arg_with_callback_handling(arg)),)
+            $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),)
         );
 
-        // Ensure argument types are included
-        for arg in constructor.arguments() {
-            type_helper.include_once_check(&arg.as_codetype().canonical_name(), &arg.as_type());
-        }
-
         if constructor.is_async() {
             async_constructor_factories.push(quote! {
-                static Future<$cls_name> $(DartCodeOracle::fn_name(constructor_name))($dart_params) {
+                static Future<$cls_name> $(DartCodeOracle::member_name(names, obj.name(), constructor_name))($dart_params) {
                     return uniffiRustCallAsync(
                       () => $ffi_func_name(
                         $ffi_call_args
@@ -174,7 +166,7 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                     ),
                     $error_handler
                 ) {
-                     _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                     $finalizer_name.attach(this, _ptr, detach: this);
                 }
             });
         }
@@ -185,8 +177,8 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
 
     let error_handler_class = if is_error_interface {
         // Generate error handlers for specific error interfaces
-        let error_handler_name = format!("{cls_name}ErrorHandler");
-        let instance_name = cls_name.to_lower_camel_case();
+        let error_handler_name = DartCodeOracle::error_handler_class_name(names, obj.name());
+        let instance_name = DartCodeOracle::error_handler_instance(names, obj.name());
         quote! {
             class $(&error_handler_name) extends UniffiRustCallStatusErrorHandler {
                 @override
@@ -195,7 +187,7 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                 }
             }
 
-            final $(&error_handler_name) $(instance_name)ErrorHandler = $(&error_handler_name)();
+            final $(&error_handler_name) $(&instance_name) = $(&error_handler_name)();
         }
     } else {
         quote!()
@@ -216,7 +208,8 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             uniffi_bindgen::interface::Type::CallbackInterface { name, .. } => name,
             _ => continue, // Skip if it's not an Object or CallbackInterface
         };
-        let trait_iface = DartCodeOracle::trait_interface_name(type_helper.get_ci(), trait_name);
+        let trait_iface =
+            DartCodeOracle::trait_interface_name(names, type_helper.get_ci(), trait_name);
         if !implements.contains(&trait_iface) {
             implements.push(trait_iface);
         }
@@ -248,12 +241,13 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             quote!()
         };
 
-    let trait_methods = generate_trait_helpers(obj, type_helper);
+    let trait_methods = generate_trait_helpers(names, obj);
 
     quote! {
+        $(uses([DART_FFI, DART_TYPED_DATA]))
         $interface_definition
 
-        final _$finalizer_cls_name = Finalizer<Pointer<Void>>((ptr) {
+        final $finalizer_name = Finalizer<Pointer<Void>>((ptr) {
           rustCall((status) => $ffi_object_free_name(ptr, status));
         });
 
@@ -262,7 +256,7 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
 
             // Private constructor for internal use / lift
             $cls_name._(this._ptr) {
-                _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                $finalizer_name.attach(this, _ptr, detach: this);
             }
 
             // Public constructors generated from UDL
@@ -282,6 +276,18 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                 return rustCall((status) => $ffi_object_clone_name(_ptr, status));
             }
 
+            // Returns a new reference to the Rust object as a plain integer that can be sent to
+            // another isolate and turned back into an object with `fromSendableHandle`.
+            // Every handle must be passed to `fromSendableHandle` exactly once, or it leaks.
+            int toSendableHandle() {
+                return uniffiClonePointer().address;
+            }
+
+            // Takes ownership of a handle created by `toSendableHandle`, usually in another isolate.
+            factory $cls_name.fromSendableHandle(int handle) {
+                return $cls_name._(Pointer<Void>.fromAddress(handle));
+            }
+
             // A Rust pointer is 8 bytes
             static int allocationSize($cls_name value) {
                 return 8;
@@ -300,14 +306,17 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             }
 
             void dispose() {
-                _$finalizer_cls_name.detach(this);
+                $finalizer_name.detach(this);
                 rustCall((status) => $ffi_object_free_name(_ptr, status));
             }
 
             $to_string_method
             $trait_methods
 
-            $(for mt in &obj.methods() => $(generate_method(mt, type_helper)))
+            $(for mt in &obj.methods() =>
+                $(generate_method(mt, type_helper))
+                $(generate_background_method(mt, type_helper))
+            )
         }
 
         $error_handler_class
@@ -318,13 +327,14 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
 
 #[allow(unused_variables)]
 pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let names = type_helper.get_names();
     // if func.takes_self_by_arc() {} // TODO: Do something about this condition
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), names)) $(DartCodeOracle::var_name(arg.name())),));
 
     let (ret, lifter) = if let Some(ret) = func.return_type() {
         (
-            ret.as_renderable().render_type(ret, type_helper),
-            quote!($(ret.as_codetype().lift())),
+            ret.as_renderable().render_type(ret, names),
+            quote!($(ret.as_codetype().lift(names))),
         )
     } else {
         (quote!(void), quote!((_) {}))
@@ -332,10 +342,7 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
 
     // Check if function can throw errors
     let error_handler = if let Some(error_type) = func.throws_type() {
-        let error_name = DartCodeOracle::class_name(error_type.name().unwrap_or("UnknownError"));
-        // Use the consistent Exception naming for error handlers
-        let handler_name = format!("{}ErrorHandler", error_name.to_lower_camel_case());
-        quote!($(handler_name))
+        quote!($(DartCodeOracle::error_handler_name(names, error_type)))
     } else {
         quote!(null)
     };
@@ -354,11 +361,12 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
         };
 
         quote!(
-            Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
+            @override
+            Future<$ret> $(DartCodeOracle::member_name(names, func.object_name(), func.name()))($args) {
                 return uniffiRustCallAsync(
-                  () => $(func.ffi_func().name())(
+                  () => $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
                     uniffiClonePointer(),
-                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),)
+                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
@@ -371,22 +379,24 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
         )
     } else if ret == quote!(void) {
         quote!(
-            $ret $(DartCodeOracle::fn_name(func.name()))($args) {
+            @override
+            $ret $(DartCodeOracle::member_name(names, func.object_name(), func.name()))($args) {
                 return rustCall((status) {
-                    $(func.ffi_func().name())(
+                    $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
                         uniffiClonePointer(),
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),) status
                     );
                 }, $error_handler);
             }
         )
     } else {
         quote!(
-            $ret $(DartCodeOracle::fn_name(func.name()))($args) {
+            @override
+            $ret $(DartCodeOracle::member_name(names, func.object_name(), func.name()))($args) {
                 return rustCallWithLifter(
-                    (status) => $(func.ffi_func().name())(
+                    (status) => $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
                         uniffiClonePointer(),
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),) status
                     ),
                     $lifter,
                     $error_handler
@@ -396,7 +406,64 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
     }
 }
 
-fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+/// Generates a `Future`-returning variant of a synchronous method that runs the call on a helper
+/// isolate. The object itself can't be sent across isolates, so a cloned handle is passed by
+/// address to a static helper whose closure doesn't capture `this`.
+fn generate_background_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let names = type_helper.get_names();
+    let item_name = format!("{}.{}", func.object_name(), func.name());
+    if !functions::runs_in_background(
+        func,
+        &item_name,
+        type_helper.get_ci(),
+        type_helper.get_config(),
+    ) {
+        return quote!();
+    }
+
+    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), names)) $(DartCodeOracle::var_name(arg.name())),));
+    let arg_names =
+        quote!($(for arg in &func.arguments() => $(DartCodeOracle::var_name(arg.name())),));
+    let background_name = &DartCodeOracle::background_name(names, func.object_name(), func.name());
+
+    let error_handler = if let Some(error_type) = func.throws_type() {
+        quote!($(DartCodeOracle::error_handler_name(names, error_type)))
+    } else {
+        quote!(null)
+    };
+
+    let ffi_call = quote!(
+        $(DartCodeOracle::ffi_function_name(func.ffi_func().name()))(
+            Pointer<Void>.fromAddress(uniffiHandle),
+            $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(names, arg)),) status
+        )
+    );
+
+    let (ret, call) = if let Some(ret) = func.return_type() {
+        (
+            ret.as_renderable().render_type(ret, names),
+            quote!(rustCallWithLifter((status) => $ffi_call, $(ret.as_codetype().lift(names)), $error_handler)),
+        )
+    } else {
+        (
+            quote!(void),
+            quote!(rustCall((status) { $ffi_call; }, $error_handler)),
+        )
+    };
+
+    quote!(
+        $(uses([DART_ISOLATE]))
+        Future<$(&ret)> $background_name($(&args)) {
+            return _$background_name(uniffiClonePointer().address, $arg_names);
+        }
+
+        static Future<$(&ret)> _$background_name(int uniffiHandle, $(&args)) {
+            return Isolate.run(() => $call);
+        }
+    )
+}
+
+fn generate_trait_helpers(names: &Names, obj: &Object) -> dart::Tokens {
     let mut tokens = quote!();
     let mut generated_display = false;
     let mut generated_debug = false;
@@ -409,7 +476,7 @@ fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
                 if generated_display {
                     continue;
                 }
-                let call = trait_method_call(fmt, type_helper, &[]);
+                let call = trait_method_call(names, fmt, &[]);
                 tokens.append(quote! {
                     @override
                     String toString() {
@@ -422,7 +489,7 @@ fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
                 if generated_debug {
                     continue;
                 }
-                let call = trait_method_call(fmt, type_helper, &[]);
+                let call = trait_method_call(names, fmt, &[]);
                 tokens.append(quote! {
                     String debugString() {
                         return $call;
@@ -434,14 +501,14 @@ fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
                 if generated_eq {
                     continue;
                 }
-                let call = trait_method_call(eq, type_helper, &[quote!(other)]);
+                let call = trait_method_call(names, eq, &[quote!(other)]);
                 tokens.append(quote! {
                     @override
                     bool operator ==(Object other) {
                         if (identical(this, other)) {
                             return true;
                         }
-                        if (other is! $(DartCodeOracle::class_name(obj.name()))) {
+                        if (other is! $(DartCodeOracle::class_name(names, obj.name()))) {
                             return false;
                         }
                         return $call;
@@ -453,7 +520,7 @@ fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
                 if generated_hash {
                     continue;
                 }
-                let call = trait_method_call(hash, type_helper, &[]);
+                let call = trait_method_call(names, hash, &[]);
                 tokens.append(quote! {
                     @override
                     int get hashCode {
@@ -472,32 +539,28 @@ fn generate_trait_helpers(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
     tokens
 }
 
-fn trait_method_call(
-    method: &Method,
-    type_helper: &dyn TypeHelperRenderer,
-    arg_exprs: &[dart::Tokens],
-) -> dart::Tokens {
+fn trait_method_call(names: &Names, method: &Method, arg_exprs: &[dart::Tokens]) -> dart::Tokens {
     assert_eq!(method.arguments().len(), arg_exprs.len());
 
-    let ffi_name = method.ffi_func().name();
+    let ffi_name = &DartCodeOracle::ffi_function_name(method.ffi_func().name());
 
     let error_handler = if let Some(error_type) = method.throws_type() {
-        let error_name = DartCodeOracle::class_name(error_type.name().unwrap_or("UnknownError"));
-        let handler_name = format!("{}ErrorHandler", error_name.to_lower_camel_case());
-        quote!($(handler_name))
+        quote!($(DartCodeOracle::error_handler_name(names, error_type)))
     } else {
         quote!(null)
     };
 
     let mut lowered_args = Vec::new();
     for (arg, expr) in method.arguments().into_iter().zip(arg_exprs.iter()) {
-        type_helper.include_once_check(&arg.as_codetype().canonical_name(), &arg.as_type());
-        lowered_args.push(DartCodeOracle::type_lower_fn(&arg.as_type(), expr.clone()));
+        lowered_args.push(DartCodeOracle::type_lower_fn(
+            names,
+            &arg.as_type(),
+            expr.clone(),
+        ));
     }
 
     if let Some(ret) = method.return_type() {
-        type_helper.include_once_check(&ret.as_codetype().canonical_name(), ret);
-        let lifter = quote!($(ret.as_codetype().lift()));
+        let lifter = quote!($(ret.as_codetype().lift(names)));
         quote!(
             rustCallWithLifter(
                 (status) => $ffi_name(
@@ -523,19 +586,19 @@ fn trait_method_call(
 }
 
 fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    type_helper.include_once_check(obj.name(), &obj.as_type());
-
-    let cls_name = &DartCodeOracle::class_name(obj.name());
+    let names = type_helper.get_names();
+    let cls_name = &DartCodeOracle::class_name(names, obj.name());
     let impl_name = format!("_{cls_name}Impl");
-    let finalizer_field = format!("_{cls_name}ImplFinalizer");
 
-    let ffi_object_free_name = obj.ffi_object_free().name();
-    let ffi_object_clone_name = obj.ffi_object_clone().name();
+    let ffi_object_free_name = &DartCodeOracle::ffi_function_name(obj.ffi_object_free().name());
+    let ffi_object_clone_name = &DartCodeOracle::ffi_function_name(obj.ffi_object_clone().name());
+
+    let unsupported_message = format!("Only Rust-implemented {cls_name} values are supported.");
 
     let abstract_methods = obj
         .methods()
         .into_iter()
-        .map(|method| generate_interface_method(method, type_helper));
+        .map(|method| generate_interface_method(names, type_helper.get_ci(), method));
 
     let concrete_methods = obj
         .methods()
@@ -543,6 +606,7 @@ fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
         .map(|method| generate_method(method, type_helper));
 
     quote! {
+        $(uses([DART_FFI, DART_TYPED_DATA]))
         abstract class $cls_name {
             factory $cls_name.lift(Pointer<Void> ptr) {
                 // UniFFI 0.30.0: Check if handle is from foreign side (lowest bit set)
@@ -562,14 +626,14 @@ fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
                 if (value is $(&impl_name)) {
                     return value.uniffiClonePointer();
                 }
-                throw UnsupportedError("Only Rust-implemented $cls_name values are supported.");
+                throw UnsupportedError($(quoted(&unsupported_message)));
             }
 
             static int allocationSize($cls_name value) {
                 if (value is $(&impl_name)) {
                     return $(&impl_name).allocationSize(value);
                 }
-                throw UnsupportedError("Only Rust-implemented $cls_name values are supported.");
+                throw UnsupportedError($(quoted(&unsupported_message)));
             }
 
             static LiftRetVal<$cls_name> read(Uint8List buf) {
@@ -591,15 +655,15 @@ fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
 
         final class $(&impl_name) implements $cls_name {
             $(&impl_name)._internal(this._ptr) {
-                $(&finalizer_field).attach(this, _ptr, detach: this);
+                _finalizer.attach(this, _ptr, detach: this);
             }
 
-            static final Finalizer<Pointer<Void>> $(&finalizer_field) =
+            static final Finalizer<Pointer<Void>> _finalizer =
                 Finalizer<Pointer<Void>>((ptr) {
                     rustCall((status) => $ffi_object_free_name(ptr, status));
                 });
 
-            Pointer<Void> _ptr;
+            final Pointer<Void> _ptr;
 
             static int allocationSize($(&impl_name) _) => 8;
 
@@ -609,7 +673,7 @@ fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
 
             @override
             void dispose() {
-                $(&finalizer_field).detach(this);
+                _finalizer.detach(this);
                 rustCall((status) => $ffi_object_free_name(_ptr, status));
             }
 
@@ -619,14 +683,15 @@ fn generate_trait_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) ->
 }
 
 fn generate_object_interface(
+    names: &Names,
+    ci: &ComponentInterface,
     obj: &Object,
     interface_name: &str,
-    type_helper: &dyn TypeHelperRenderer,
 ) -> dart::Tokens {
     let method_tokens: Vec<dart::Tokens> = obj
         .methods()
         .into_iter()
-        .map(|method| generate_interface_method(method, type_helper))
+        .map(|method| generate_interface_method(names, ci, method))
         .collect();
 
     if method_tokens.is_empty() {
@@ -642,17 +707,25 @@ fn generate_object_interface(
     }
 }
 
+/// Interface methods type objects as their interfaces, which the object class narrows back to
+/// its concrete parameter types through `covariant`.
 fn generate_interface_method(
+    names: &Names,
+    ci: &ComponentInterface,
     method: &Method,
-    type_helper: &dyn TypeHelperRenderer,
 ) -> dart::Tokens {
     let arg_tokens: Vec<dart::Tokens> = method
         .arguments()
         .into_iter()
         .map(|arg| {
-            let ty = arg.as_renderable().render_type(&arg.as_type(), type_helper);
+            let arg_type = arg.as_type();
+            let ty = DartCodeOracle::interface_type_label(names, ci, &arg_type);
             let name = DartCodeOracle::var_name(arg.name());
-            quote!($ty $name)
+            if DartCodeOracle::holds_object_interfaces(ci, &arg_type) {
+                quote!(covariant $ty $name)
+            } else {
+                quote!($ty $name)
+            }
         })
         .collect();
 
@@ -661,27 +734,18 @@ fn generate_interface_method(
     } else {
         quote!($(for arg in arg_tokens.iter() join (, ) => $arg))
     };
-    let ret_type = method_return_type_tokens(method, type_helper);
-    let method_name = DartCodeOracle::fn_name(method.name());
+    let ret_type = match method.return_type() {
+        Some(ret) => DartCodeOracle::interface_type_label(names, ci, ret),
+        None => quote!(void),
+    };
+    let ret_type = if method.is_async() {
+        quote!(Future<$ret_type>)
+    } else {
+        ret_type
+    };
+    let method_name = DartCodeOracle::member_name(names, method.object_name(), method.name());
 
     quote!(
         $ret_type $method_name($params);
     )
 }
-
-fn method_return_type_tokens(
-    method: &Method,
-    type_helper: &dyn TypeHelperRenderer,
-) -> dart::Tokens {
-    let base = if let Some(ret) = method.return_type() {
-        ret.as_renderable().render_type(ret, type_helper)
-    } else {
-        quote!(void)
-    };
-
-    if method.is_async() {
-        quote!(Future<$base>)
-    } else {
-        base
-    }
-}
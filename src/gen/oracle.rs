@@ -1,14 +1,17 @@
 use genco::lang::dart;
 use genco::quote;
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
-use uniffi_bindgen::interface::ffi::ExternalFfiMetadata;
+use uniffi_bindgen::interface::ffi::{ExternalFfiMetadata, FfiFunction};
 use uniffi_bindgen::interface::{Argument, Object, ObjectImpl};
 
 use crate::gen::CodeType;
 use uniffi_bindgen::interface::{AsType, Callable, FfiType, Type};
 use uniffi_bindgen::ComponentInterface;
 
+use crate::gen::imports::{uses, DART_TYPED_DATA};
+use crate::gen::naming::Names;
 use crate::gen::primitives;
+use crate::gen::render::AsRenderable;
 
 // use super::render::{AsRenderable, Renderable};
 use super::{callback_interface, compounds, custom, enums, objects, records};
@@ -35,10 +38,63 @@ impl DartCodeOracle {
     }
 
     /// Get the idiomatic Dart rendering of a class name (for enums, records, errors, etc).
-    pub fn class_name(nm: &str) -> String {
+    pub fn class_name(names: &Names, nm: &str) -> String {
+        match names.class_name(nm) {
+            Some(name) => name.to_string(),
+            None => Self::default_class_name(nm, names.error_to_exception()),
+        }
+    }
+
+    /// The class of type `name` as referenced from these bindings.
+    pub fn type_name(names: &Names, name: &str) -> String {
+        Self::qualified(names, name, Self::class_name(names, name))
+    }
+
+    /// `name`, declared by the library defining type `type_name`, as referenced from these
+    /// bindings: the libraries of other components are only imported with a prefix.
+    pub fn qualified(names: &Names, type_name: &str, name: String) -> String {
+        match names.external_prefix(type_name) {
+            Some(prefix) => format!("{prefix}.{name}"),
+            None => name,
+        }
+    }
+
+    /// The error handler instance passed to `rustCall` for calls throwing `error_type`.
+    pub fn error_handler_name(names: &Names, error_type: &Type) -> String {
+        let type_name = error_type.name().unwrap_or("UnknownError");
+        Self::qualified(
+            names,
+            type_name,
+            Self::error_handler_instance(names, type_name),
+        )
+    }
+
+    /// The error handler instance of `type_name`, declared next to it.
+    pub fn error_handler_instance(names: &Names, type_name: &str) -> String {
+        match names.error_handler_name(type_name) {
+            Some(name) => name.to_string(),
+            None => format!(
+                "{}ErrorHandler",
+                Self::class_name(names, type_name).to_lower_camel_case()
+            ),
+        }
+    }
+
+    /// The error handler class of `type_name`, lifting the errors it's thrown as.
+    pub fn error_handler_class_name(names: &Names, type_name: &str) -> String {
+        match names.error_handler_class_name(type_name) {
+            Some(name) => name.to_string(),
+            None => format!("{}ErrorHandler", Self::class_name(names, type_name)),
+        }
+    }
+
+    /// The class name of `nm` before renames and collisions with other names are applied.
+    pub fn default_class_name(nm: &str, error_to_exception: bool) -> String {
         let name = Self::sanitize_identifier(&nm.to_upper_camel_case());
-        // Handle the special case where the name is exactly "Error" to avoid conflicts with Dart's Exception
-        if name == "Error" {
+        if !error_to_exception {
+            name
+        } else if name == "Error" {
+            // Handle the special case where the name is exactly "Error" to avoid conflicts with Dart's Exception
             "ErrorException".to_string()
         } else {
             // Only replace "Error" at the end of the name for other cases
@@ -49,9 +105,16 @@ impl DartCodeOracle {
         }
     }
 
-    /// Replace any occurrence of `Error` with `Exception` to avoid Dart naming conflicts.
-    pub fn exception_safe_name(name: &str) -> String {
-        name.replace("Error", "Exception")
+    /// Get the Dart class name of a variant of the non-flat enum `enum_name`.
+    pub fn variant_class_name(names: &Names, enum_name: &str, variant: &str) -> String {
+        match names.variant_class_name(enum_name, variant) {
+            Some(name) => name.to_string(),
+            None => format!(
+                "{}{}",
+                Self::default_class_name(variant, names.error_to_exception()),
+                Self::class_name(names, enum_name)
+            ),
+        }
     }
 
     /// Get the idiomatic Dart rendering of a function name.
@@ -64,9 +127,36 @@ impl DartCodeOracle {
         Self::sanitize_identifier(&nm.to_lower_camel_case())
     }
 
-    /// Get the idiomatic Dart rendering of an individual enum variant.
-    pub fn enum_variant_name(nm: &str) -> String {
-        Self::sanitize_identifier(&nm.to_lower_camel_case())
+    /// Get the Dart name of a top-level function.
+    pub fn function_name(names: &Names, nm: &str) -> String {
+        Self::member_name(names, "", nm)
+    }
+
+    /// Get the Dart name of a member (field, method or named constructor) of the type `owner`.
+    pub fn member_name(names: &Names, owner: &str, nm: &str) -> String {
+        match names.member_name(owner, nm) {
+            Some(name) => name.to_string(),
+            None => Self::var_name(nm),
+        }
+    }
+
+    /// Get the Dart name of the `InBackground` variant of the function or method `nm` of `owner`
+    /// (empty for top-level functions).
+    pub fn background_name(names: &Names, owner: &str, nm: &str) -> String {
+        match names.background_name(owner, nm) {
+            Some(name) => name.to_string(),
+            None => format!("{}InBackground", Self::member_name(names, owner, nm)),
+        }
+    }
+
+    /// Get the Dart name of the FFI function exported as the native symbol `symbol`.
+    pub fn ffi_function_name(symbol: &str) -> String {
+        symbol.to_lower_camel_case()
+    }
+
+    /// Get the idiomatic Dart rendering of a variant of the flat enum `enum_name`.
+    pub fn enum_variant_name(names: &Names, enum_name: &str, nm: &str) -> String {
+        Self::member_name(names, enum_name, nm)
     }
 
     /// Get the idiomatic Dart rendering of an FFI callback function name
@@ -131,6 +221,28 @@ impl DartCodeOracle {
         quote!(RustBuffer)
     }
 
+    /// Whether an FFI type can appear in an `@Native` signature; see `ffi_dart_type_label`.
+    pub fn is_supported_ffi_type(ffi_type: &FfiType) -> bool {
+        matches!(
+            ffi_type,
+            FfiType::Int8
+                | FfiType::UInt8
+                | FfiType::Int16
+                | FfiType::UInt16
+                | FfiType::Int32
+                | FfiType::UInt32
+                | FfiType::Int64
+                | FfiType::UInt64
+                | FfiType::Float32
+                | FfiType::Float64
+                | FfiType::RustBuffer(_)
+                | FfiType::ForeignBytes
+                | FfiType::Handle
+                | FfiType::Callback(_)
+                | FfiType::Reference(_)
+        )
+    }
+
     // TODO: Replace instances of `generate_ffi_dart_type` with ffi_type_label
     pub fn ffi_dart_type_label(
         ffi_type: Option<&FfiType>,
@@ -153,7 +265,8 @@ impl DartCodeOracle {
                 FfiType::Handle => quote!(Pointer<Void>),
                 FfiType::Callback(name) => quote!($(Self::ffi_callback_name(name))),
                 FfiType::Reference(inner) => quote!($(Self::ffi_type_label_by_reference(inner))),
-                _ => panic!("Unimplemented FfiType: {ret_type:?}"), // Fallback implementation
+                // Rejected up front by `diagnostics::Unsupported`
+                _ => unreachable!("Unsupported FfiType: {ret_type:?}"),
             }
         } else {
             quote!(void)
@@ -181,13 +294,38 @@ impl DartCodeOracle {
                 FfiType::Handle => quote!(Pointer<Void>),
                 FfiType::Callback(name) => quote!($(Self::ffi_callback_name(name))),
                 FfiType::Reference(inner) => quote!($(Self::ffi_type_label_by_reference(inner))),
-                _ => panic!("Unimplemented FfiType: {ret_type:?}"), // Fallback implementation
+                // Rejected up front by `diagnostics::Unsupported`
+                _ => unreachable!("Unsupported FfiType: {ret_type:?}"),
             }
         } else {
             quote!(void)
         }
     }
 
+    /// Whether an FFI function only passes primitives across the boundary, which makes it a
+    /// candidate for a Dart leaf call (`isLeaf: true`).
+    pub fn is_primitive_ffi_function(fun: &FfiFunction) -> bool {
+        fn is_primitive(ffi_type: &FfiType) -> bool {
+            matches!(
+                ffi_type,
+                FfiType::Int8
+                    | FfiType::UInt8
+                    | FfiType::Int16
+                    | FfiType::UInt16
+                    | FfiType::Int32
+                    | FfiType::UInt32
+                    | FfiType::Int64
+                    | FfiType::UInt64
+                    | FfiType::Float32
+                    | FfiType::Float64
+            )
+        }
+
+        !fun.is_async()
+            && fun.arguments().iter().all(|arg| is_primitive(&arg.type_()))
+            && fun.return_type().is_none_or(is_primitive)
+    }
+
     fn ffi_type_label_by_reference(ffi_type: &FfiType) -> dart::Tokens {
         match ffi_type {
             FfiType::Int8 => quote!(Int8),
@@ -229,7 +367,7 @@ impl DartCodeOracle {
     //     }
     // }
 
-    pub fn type_lower_fn(ty: &Type, inner: dart::Tokens) -> dart::Tokens {
+    pub fn type_lower_fn(names: &Names, ty: &Type, inner: dart::Tokens) -> dart::Tokens {
         match ty {
             Type::Int8
             | Type::Int16
@@ -239,7 +377,7 @@ impl DartCodeOracle {
             | Type::UInt16
             | Type::UInt32
             | Type::UInt64 => {
-                quote!($(ty.as_codetype().ffi_converter_name()).lower($inner))
+                quote!($(ty.as_codetype().ffi_converter_name(names)).lower($inner))
             }
             Type::Float32 | Type::Float64 => inner,
             Type::Boolean
@@ -249,32 +387,32 @@ impl DartCodeOracle {
             | Type::Enum { .. }
             | Type::Optional { .. }
             | Type::Record { .. } => {
-                quote!($(ty.as_codetype().ffi_converter_name()).lower($inner))
+                quote!($(ty.as_codetype().ffi_converter_name(names)).lower($inner))
             }
-            _ => quote!($(ty.as_codetype().ffi_converter_name()).lower($inner)), // Fallback implementation
+            _ => quote!($(ty.as_codetype().ffi_converter_name(names)).lower($inner)), // Fallback implementation
         }
     }
 
     /// With @Native, async functions are called directly by name
     pub fn async_poll(callable: impl Callable, ci: &ComponentInterface) -> dart::Tokens {
         let ffi_func = callable.ffi_rust_future_poll(ci);
-        quote!($ffi_func)
+        quote!($(Self::ffi_function_name(&ffi_func)))
     }
 
     /// With @Native, async functions are called directly by name
     pub fn async_complete(callable: impl Callable, ci: &ComponentInterface) -> dart::Tokens {
         let ffi_func = callable.ffi_rust_future_complete(ci);
-        quote!($ffi_func)
+        quote!($(Self::ffi_function_name(&ffi_func)))
     }
 
     /// With @Native, async functions are called directly by name
     pub fn async_free(callable: impl Callable, ci: &ComponentInterface) -> dart::Tokens {
         let ffi_func = callable.ffi_rust_future_free(ci);
-        quote!($ffi_func)
+        quote!($(Self::ffi_function_name(&ffi_func)))
     }
 
     /// Get the idiomatic Dart rendering of a class name based on `Type`.
-    pub fn dart_type_label(type_: Option<&Type>) -> dart::Tokens {
+    pub fn dart_type_label(names: &Names, type_: Option<&Type>) -> dart::Tokens {
         if let Some(ret_type) = type_ {
             match ret_type {
                 Type::UInt8
@@ -287,21 +425,21 @@ impl DartCodeOracle {
                 | Type::Int64 => quote!(int),
                 Type::Float32 | Type::Float64 => quote!(double),
                 Type::Boolean => quote!(bool),
-                Type::Bytes => quote!(Uint8List),
+                Type::Bytes => quote!($(uses([DART_TYPED_DATA]))Uint8List),
                 Type::String => quote!(String),
                 Type::Timestamp => quote!(DateTime),
                 Type::Duration => quote!(Duration),
                 // Reference types
                 Type::Object { name, .. } => {
-                    let class_name = &DartCodeOracle::class_name(name);
+                    let class_name = &DartCodeOracle::type_name(names, name);
                     quote!($class_name)
                 }
                 Type::Optional { inner_type } => {
-                    let inner = DartCodeOracle::dart_type_label(Some(inner_type));
+                    let inner = DartCodeOracle::dart_type_label(names, Some(inner_type));
                     quote!($inner?)
                 }
                 Type::Sequence { inner_type } => {
-                    let inner = DartCodeOracle::dart_type_label(Some(inner_type));
+                    let inner = DartCodeOracle::dart_type_label(names, Some(inner_type));
                     quote!(List<$inner>)
                 }
                 Type::Map {
@@ -309,20 +447,20 @@ impl DartCodeOracle {
                     value_type,
                     ..
                 } => {
-                    let key = DartCodeOracle::dart_type_label(Some(key_type));
-                    let value = DartCodeOracle::dart_type_label(Some(value_type));
+                    let key = DartCodeOracle::dart_type_label(names, Some(key_type));
+                    let value = DartCodeOracle::dart_type_label(names, Some(value_type));
                     quote!(Map<$key, $value>)
                 }
                 Type::Enum { name, .. } => {
-                    let enum_name = &DartCodeOracle::class_name(name);
+                    let enum_name = &DartCodeOracle::type_name(names, name);
                     quote!($enum_name)
                 }
                 Type::Record { name, .. } => {
-                    let rec_name = &DartCodeOracle::class_name(name);
+                    let rec_name = &DartCodeOracle::type_name(names, name);
                     quote!($rec_name)
                 }
                 Type::Custom { name, .. } => {
-                    let type_name = &DartCodeOracle::class_name(name);
+                    let type_name = &DartCodeOracle::type_name(names, name);
                     quote!($type_name)
                 }
                 _ => quote!(dynamic),
@@ -334,6 +472,7 @@ impl DartCodeOracle {
 
     /// Get the native Dart FFI type rendering based on `Type`.
     pub fn native_type_label(
+        names: &Names,
         native_ret_type: Option<&Type>,
         ci: &ComponentInterface,
     ) -> dart::Tokens {
@@ -366,7 +505,7 @@ impl DartCodeOracle {
                     Self::rust_buffer_name_with_path(module_path, ci)
                 }
                 Type::Custom { name, .. } => {
-                    let class_name = &DartCodeOracle::class_name(name);
+                    let class_name = &DartCodeOracle::type_name(names, name);
                     quote!($class_name)
                 }
                 _ => quote!(Pointer<Void>),
@@ -378,6 +517,7 @@ impl DartCodeOracle {
 
     /// Get the native Dart FFI type rendering based on `Type`.
     pub fn native_dart_type_label(
+        names: &Names,
         native_ret_type: Option<&Type>,
         ci: &ComponentInterface,
     ) -> dart::Tokens {
@@ -410,7 +550,7 @@ impl DartCodeOracle {
                     Self::rust_buffer_name_with_path(module_path, ci)
                 }
                 Type::Custom { name, .. } => {
-                    let type_name = &DartCodeOracle::class_name(name);
+                    let type_name = &DartCodeOracle::type_name(names, name);
                     quote!($type_name)
                 }
                 _ => quote!(dynamic),
@@ -422,16 +562,18 @@ impl DartCodeOracle {
 
     // Method to get the appropriate callback parameter type
     pub fn callback_param_type(
+        names: &Names,
         arg_type: &Type,
         arg_name: &str,
         ci: &ComponentInterface,
     ) -> dart::Tokens {
-        let type_label = DartCodeOracle::native_dart_type_label(Some(arg_type), ci);
+        let type_label = DartCodeOracle::native_dart_type_label(names, Some(arg_type), ci);
         quote!($type_label $arg_name)
     }
 
     // Method to generate code for handling callback return values
     pub fn callback_return_handling(
+        names: &Names,
         ret_type: &Type,
         method_name: &str,
         args: Vec<dart::Tokens>,
@@ -453,7 +595,7 @@ impl DartCodeOracle {
             | Type::Int32
             | Type::Int64 => {
                 // For primitive integer return values
-                let lowered = ret_type.as_codetype().ffi_converter_name();
+                let lowered = ret_type.as_codetype().ffi_converter_name(names);
                 quote!(
                     final result = obj.$method_name($(for arg in &args => $arg,));
                     outReturn.value = $lowered.lower(result);
@@ -479,7 +621,7 @@ impl DartCodeOracle {
                         }
                     )
                 } else {
-                    let lowered = ret_type.as_codetype().ffi_converter_name();
+                    let lowered = ret_type.as_codetype().ffi_converter_name(names);
                     quote!(
                         final result = obj.$method_name($(for arg in &args => $arg,));
                         if (result == null) {
@@ -499,11 +641,11 @@ impl DartCodeOracle {
                 quote!(
                     final result = obj.$method_name($(for arg in &args => $arg,));
                     outReturn.ref = FfiConverterString.lower(result);
-                    status.code = CALL_SUCCESS;
+                    status.code = uniffiCallSuccess;
                 )
             }
             Type::Object { .. } => {
-                let lowered = ret_type.as_codetype().ffi_converter_name();
+                let lowered = ret_type.as_codetype().ffi_converter_name(names);
                 quote!(
                     final result = obj.$method_name($(for arg in &args => $arg,));
                     outReturn.value = $lowered.lower(result);
@@ -518,7 +660,7 @@ impl DartCodeOracle {
                     )
                 } else {
                     // For other sequence types
-                    let lowered = ret_type.as_codetype().ffi_converter_name();
+                    let lowered = ret_type.as_codetype().ffi_converter_name(names);
                     quote!(
                         final result = obj.$method_name($(for arg in &args => $arg,));
                         outReturn.ref = $lowered.lower(result);
@@ -527,7 +669,7 @@ impl DartCodeOracle {
             }
             _ => {
                 // For other return types
-                let lowered = ret_type.as_codetype().ffi_converter_name();
+                let lowered = ret_type.as_codetype().ffi_converter_name(names);
                 quote!(
                     final result = obj.$method_name($(for arg in &args => $arg,));
                     outReturn.ref = $lowered.lower(result);
@@ -563,12 +705,13 @@ impl DartCodeOracle {
     pub fn callback_void_handling(method_name: &str, args: Vec<dart::Tokens>) -> dart::Tokens {
         quote!(
             obj.$method_name($(for arg in &args => $arg,));
-            status.code = CALL_SUCCESS;
+            status.code = uniffiCallSuccess;
         )
     }
 
     // Method to get the appropriate lift expression for callback arguments with indexed variable names
     pub fn callback_arg_lift_indexed(
+        names: &Names,
         arg_type: &Type,
         arg_name: &str,
         arg_idx: usize,
@@ -577,7 +720,7 @@ impl DartCodeOracle {
         if let Type::Boolean = arg_type {
             quote!(final bool_arg$(arg_idx) = $arg_name == 1;)
         } else if let Type::Enum { .. } = arg_type {
-            let converter = arg_type.as_codetype().ffi_converter_name();
+            let converter = arg_type.as_codetype().ffi_converter_name(names);
             quote!(final arg$(arg_idx) = $converter.read(createUint8ListFromInt($arg_name)).value;)
         } else if let Type::Bytes = arg_type {
             quote!(final arg$(arg_idx) = FfiConverterUint8List.lift($arg_name);)
@@ -587,18 +730,18 @@ impl DartCodeOracle {
             if let Type::String = **inner_type {
                 quote!(final arg$(arg_idx) = FfiConverterOptionalString.lift($arg_name);)
             } else {
-                let converter = arg_type.as_codetype().ffi_converter_name();
+                let converter = arg_type.as_codetype().ffi_converter_name(names);
                 quote!(final arg$(arg_idx) = $converter.lift($arg_name);)
             }
         } else if let Type::Sequence { inner_type } = arg_type {
             if let Type::Int32 = **inner_type {
                 quote!(final arg$(arg_idx) = FfiConverterSequenceInt32.lift($arg_name);)
             } else {
-                let converter = arg_type.as_codetype().ffi_converter_name();
+                let converter = arg_type.as_codetype().ffi_converter_name(names);
                 quote!(final arg$(arg_idx) = $converter.lift($arg_name);)
             }
         } else {
-            let converter = arg_type.as_codetype().ffi_converter_name();
+            let converter = arg_type.as_codetype().ffi_converter_name(names);
             quote!(final arg$(arg_idx) = $converter.lift($arg_name);)
         }
     }
@@ -613,8 +756,9 @@ impl DartCodeOracle {
     }
 
     /// Lower argument with special handling for callback traits
-    pub fn lower_arg_with_callback_handling(arg: &Argument) -> dart::Tokens {
-        let base_lower = Self::type_lower_fn(&arg.as_type(), quote!($(Self::var_name(arg.name()))));
+    pub fn lower_arg_with_callback_handling(names: &Names, arg: &Argument) -> dart::Tokens {
+        let base_lower =
+            Self::type_lower_fn(names, &arg.as_type(), quote!($(Self::var_name(arg.name()))));
         match arg.as_type() {
             Type::Object {
                 imp: ObjectImpl::CallbackTrait,
@@ -625,27 +769,82 @@ impl DartCodeOracle {
         }
     }
 
-    pub fn object_interface_name(_ci: &ComponentInterface, obj: &Object) -> String {
-        let class_name = Self::class_name(obj.name());
+    pub fn object_interface_name(names: &Names, obj: &Object) -> String {
+        let class_name = Self::class_name(names, obj.name());
         if obj.has_callback_interface() || obj.is_trait_interface() {
             class_name
         } else {
-            format!("{class_name}Interface")
+            match names.interface_name(obj.name()) {
+                Some(name) => name.to_string(),
+                None => format!("{class_name}Interface"),
+            }
         }
     }
 
-    pub fn trait_interface_name(ci: &ComponentInterface, name: &str) -> String {
+    /// Type of a parameter or return value in an object or function interface: objects are
+    /// typed as their interface, so that fakes can stand in for them.
+    pub fn interface_type_label(names: &Names, ci: &ComponentInterface, ty: &Type) -> dart::Tokens {
+        if !Self::holds_object_interfaces(ci, ty) {
+            return ty.as_renderable().render_type(ty, names);
+        }
+        match ty {
+            Type::Optional { inner_type } => {
+                let inner = Self::interface_type_label(names, ci, inner_type);
+                quote!($inner?)
+            }
+            Type::Sequence { inner_type } => {
+                let inner = Self::interface_type_label(names, ci, inner_type);
+                quote!(List<$inner>)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                let key = Self::interface_type_label(names, ci, key_type);
+                let value = Self::interface_type_label(names, ci, value_type);
+                quote!(Map<$key, $value>)
+            }
+            Type::Object { name, .. } => match ci.get_object_definition(name) {
+                Some(obj) => quote!($(Self::object_interface_name(names, obj))),
+                None => ty.as_renderable().render_type(ty, names),
+            },
+            _ => ty.as_renderable().render_type(ty, names),
+        }
+    }
+
+    /// Whether `ty` holds objects typed differently in interfaces, whose implementations then
+    /// narrow the parameter back to the object class through `covariant`.
+    pub fn holds_object_interfaces(ci: &ComponentInterface, ty: &Type) -> bool {
+        match ty {
+            Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+                Self::holds_object_interfaces(ci, inner_type)
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                Self::holds_object_interfaces(ci, key_type)
+                    || Self::holds_object_interfaces(ci, value_type)
+            }
+            Type::Object { name, .. } => ci
+                .get_object_definition(name)
+                .is_some_and(|obj| !obj.has_callback_interface() && !obj.is_trait_interface()),
+            _ => false,
+        }
+    }
+
+    pub fn trait_interface_name(names: &Names, ci: &ComponentInterface, name: &str) -> String {
         if let Some(obj) = ci.get_object_definition(name) {
-            let class_name = Self::class_name(obj.name());
+            let class_name = Self::class_name(names, obj.name());
             if obj.has_callback_interface() || obj.is_trait_interface() {
                 class_name
             } else {
-                Self::object_interface_name(ci, obj)
+                Self::object_interface_name(names, obj)
             }
         } else if let Some(callback) = ci.get_callback_interface_definition(name) {
-            Self::class_name(callback.name())
+            Self::class_name(names, callback.name())
         } else {
-            Self::class_name(name)
+            Self::type_name(names, name)
         }
     }
 }
@@ -739,34 +938,28 @@ impl<T: AsType> AsCodeType for T {
             Type::Duration => Box::new(primitives::DurationCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Object { name, imp, .. } => Box::new(objects::ObjectCodeType::new(name, imp)),
-            Type::Optional { inner_type } => Box::new(compounds::OptionalCodeType::new(
-                self.as_type(),
-                *inner_type,
-            )),
-            Type::Sequence { inner_type } => Box::new(compounds::SequenceCodeType::new(
-                self.as_type(),
-                *inner_type,
-            )),
+            Type::Optional { inner_type } => {
+                Box::new(compounds::OptionalCodeType::new(*inner_type))
+            }
+            Type::Sequence { inner_type } => {
+                Box::new(compounds::SequenceCodeType::new(*inner_type))
+            }
             Type::Map {
                 key_type,
                 value_type,
                 ..
-            } => Box::new(compounds::MapCodeType::new(
-                self.as_type(),
-                *key_type,
-                *value_type,
-            )),
+            } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record { name, .. } => Box::new(records::RecordCodeType::new(name)),
-            Type::CallbackInterface { name, .. } => Box::new(
-                callback_interface::CallbackInterfaceCodeType::new(name, self.as_type()),
-            ),
+            Type::CallbackInterface { name, .. } => {
+                Box::new(callback_interface::CallbackInterfaceCodeType::new(name))
+            }
             Type::Custom {
                 name,
                 module_path,
                 builtin,
             } => Box::new(custom::CustomCodeType::new(name, module_path, builtin)),
-            _ => todo!("As Type for Type::{:?}", self.as_type()),
+            _ => unreachable!("Unsupported Type::{:?}", self.as_type()),
         }
     }
 }
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+
+use super::Config;
+
+/// The files making a publishable Dart package around generated bindings: a `pubspec.yaml`, a
+/// Native Assets build hook bundling the native library, analysis options, a library exporting
+/// the bindings and an example test. The bindings themselves go in `lib/`.
+#[derive(Debug, Clone)]
+pub struct Package {
+    name: String,
+    asset_id: String,
+    cdylib_name: String,
+    namespaces: Vec<String>,
+    native_library: NativeLibrary,
+    /// The bindings open the library themselves, so there's no build hook
+    dynamic_library: bool,
+    version: String,
+    description: String,
+}
+
+/// Where the build hook gets the native library from
+#[derive(Debug, Clone)]
+enum NativeLibrary {
+    /// A library shipped with the package, relative to its root
+    Prebuilt(String),
+    /// The cdylib of a crate, built by the hook
+    Cargo {
+        crate_path: String,
+        features: Vec<String>,
+        binary: String,
+    },
+}
+
+impl Package {
+    /// The package of the bindings generated with `config` for the components in `namespaces`.
+    /// Its build hook runs cargo if `config` sets `cargo_crate_path`.
+    pub fn new<S: Into<String>>(config: &Config, namespaces: impl IntoIterator<Item = S>) -> Self {
+        let cdylib_name = config.cdylib_name();
+        let native_library = match config.cargo_crate_path() {
+            Some(crate_path) => NativeLibrary::Cargo {
+                crate_path: crate_path.to_owned(),
+                features: config.cargo_features().to_vec(),
+                binary: config.cargo_binary(),
+            },
+            None => NativeLibrary::Prebuilt(format!(
+                "{}{cdylib_name}{}",
+                std::env::consts::DLL_PREFIX,
+                std::env::consts::DLL_SUFFIX
+            )),
+        };
+        Self {
+            name: config.package_name(),
+            asset_id: config.asset_id(),
+            description: format!("Dart bindings for {cdylib_name}"),
+            cdylib_name,
+            namespaces: namespaces.into_iter().map(Into::into).collect(),
+            native_library,
+            dynamic_library: config.dynamic_library(),
+            version: "1.0.0".into(),
+        }
+    }
+
+    /// Bundle the native library at `path`, relative to the package root, instead of building
+    /// it. Defaults to the cdylib's file name on the host platform.
+    pub fn with_native_library<P: Into<String>>(mut self, path: P) -> Self {
+        self.native_library = NativeLibrary::Prebuilt(path.into());
+        self
+    }
+
+    pub fn with_version<V: Into<String>>(mut self, version: V) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    pub fn with_description<D: Into<String>>(mut self, description: D) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn pubspec(&self) -> String {
+        // Bindings opening the library themselves don't need Native Assets, but they still rely on
+        // `NativeCallable` (Dart 3.1) and its `keepIsolateAlive` (Dart 3.3)
+        let (sdk, native_assets) = if self.dynamic_library {
+            ("'>=3.3.0'", "")
+        } else {
+            ("'>=3.10.0'", "  code_assets: ^1.0.0\n  hooks: ^1.0.0\n")
+        };
+        format!(
+            r#"name: {}
+description: {}
+version: {}
+
+environment:
+  sdk: {sdk}
+dev_dependencies:
+  lints: '>=3.0.0 <7.0.0'
+  test: ^1.24.3
+dependencies:
+  ffi: ^2.0.1
+{native_assets}"#,
+            self.name, self.description, self.version
+        )
+    }
+
+    /// `hook/build.dart`, registering the native library under the asset ID the bindings load.
+    /// `None` for bindings loading the library with `DynamicLibrary.open`.
+    pub fn build_hook(&self) -> Option<String> {
+        if self.dynamic_library {
+            return None;
+        }
+        Some(match &self.native_library {
+            NativeLibrary::Prebuilt(path) => self.prebuilt_hook(path),
+            NativeLibrary::Cargo {
+                crate_path,
+                features,
+                binary,
+            } => self.cargo_hook(crate_path, features, binary),
+        })
+    }
+
+    fn prebuilt_hook(&self, path: &str) -> String {
+        format!(
+            r#"// Generated by uniffi-dart – do NOT edit.
+import 'package:code_assets/code_assets.dart';
+import 'package:hooks/hooks.dart';
+
+void main(List<String> args) async {{
+  await build(args, (input, output) async {{
+    // Dart prefixes the name with "package:<packageName>/", giving the ID the bindings use
+    output.assets.code.add(
+      CodeAsset(
+        package: input.packageName,
+        name: {},
+        linkMode: DynamicLoadingBundled(),
+        file: input.packageRoot.resolve({}),
+      ),
+    );
+  }});
+}}
+"#,
+            dart_string(&self.asset_id),
+            dart_string(path)
+        )
+    }
+
+    fn cargo_hook(&self, crate_path: &str, features: &[String], binary: &str) -> String {
+        let crate_dir = dart_string(&format!("{}/", crate_path.trim_end_matches('/')));
+        let features = if features.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "      '--features', {},\n",
+                dart_string(&features.join(","))
+            )
+        };
+        let binary = dart_string(binary);
+        format!(
+            r#"// Generated by uniffi-dart – do NOT edit.
+import 'dart:io';
+
+import 'package:code_assets/code_assets.dart';
+import 'package:hooks/hooks.dart';
+
+void main(List<String> args) async {{
+  await build(args, (input, output) async {{
+    if (!input.config.buildCodeAssets) {{
+      return;
+    }}
+    final code = input.config.code;
+    final crateDir = input.packageRoot.resolve({crate_dir});
+    final targetDir = input.outputDirectoryShared.resolve('cargo/');
+    final triple = _rustTarget(code);
+    // Override with `hooks: user_defines: {package}: cargo_profile: dev` in the app's pubspec
+    final profile = input.userDefines['cargo_profile'] as String? ?? 'release';
+
+    final result = await Process.run({binary}, [
+      'build', '--lib', '--profile', profile, '--target', triple,
+{features}      '--manifest-path', crateDir.resolve('Cargo.toml').toFilePath(),
+      '--target-dir', targetDir.toFilePath(),
+    ]);
+    if (result.exitCode != 0) {{
+      throw Exception('cargo build failed for $triple:\n${{result.stderr}}');
+    }}
+
+    final profileDir = profile == 'dev' ? 'debug' : profile;
+    final library = targetDir.resolve('$triple/$profileDir/${{_libraryFileName(code.targetOS)}}');
+    // Dart prefixes the name with "package:<packageName>/", giving the ID the bindings use
+    output.assets.code.add(
+      CodeAsset(
+        package: input.packageName,
+        name: {asset_id},
+        linkMode: DynamicLoadingBundled(),
+        file: library,
+      ),
+    );
+    output.dependencies.add(crateDir.resolve('Cargo.toml'));
+    output.dependencies.add(crateDir.resolve('src/'));
+  }});
+}}
+
+String _libraryFileName(OS os) {{
+  if (os == OS.windows) {{
+    return '{cdylib}.dll';
+  }}
+  if (os == OS.macOS || os == OS.iOS) {{
+    return 'lib{cdylib}.dylib';
+  }}
+  return 'lib{cdylib}.so';
+}}
+
+String _rustTarget(CodeConfig code) {{
+  final os = code.targetOS;
+  final arch = code.targetArchitecture;
+  final String? triple;
+  if (os == OS.android) {{
+    triple = {{
+      Architecture.arm64: 'aarch64-linux-android',
+      Architecture.arm: 'armv7-linux-androideabi',
+      Architecture.x64: 'x86_64-linux-android',
+      Architecture.ia32: 'i686-linux-android',
+    }}[arch];
+  }} else if (os == OS.iOS) {{
+    final simulator = code.iOS.targetSdk == IOSSdk.iPhoneSimulator;
+    triple = {{
+      Architecture.arm64: simulator ? 'aarch64-apple-ios-sim' : 'aarch64-apple-ios',
+      Architecture.x64: 'x86_64-apple-ios',
+    }}[arch];
+  }} else if (os == OS.macOS) {{
+    triple = {{
+      Architecture.arm64: 'aarch64-apple-darwin',
+      Architecture.x64: 'x86_64-apple-darwin',
+    }}[arch];
+  }} else if (os == OS.linux) {{
+    triple = {{
+      Architecture.arm64: 'aarch64-unknown-linux-gnu',
+      Architecture.arm: 'armv7-unknown-linux-gnueabihf',
+      Architecture.x64: 'x86_64-unknown-linux-gnu',
+      Architecture.riscv64: 'riscv64gc-unknown-linux-gnu',
+    }}[arch];
+  }} else if (os == OS.windows) {{
+    triple = {{
+      Architecture.arm64: 'aarch64-pc-windows-msvc',
+      Architecture.x64: 'x86_64-pc-windows-msvc',
+      Architecture.ia32: 'i686-pc-windows-msvc',
+    }}[arch];
+  }} else {{
+    triple = null;
+  }}
+  if (triple == null) {{
+    throw UnsupportedError('No Rust target for $os on $arch');
+  }}
+  return triple;
+}}
+"#,
+            package = self.name,
+            asset_id = dart_string(&self.asset_id),
+            cdylib = self.cdylib_name,
+        )
+    }
+
+    /// `analysis_options.yaml`, enabling the recommended lints the bindings are checked against.
+    pub fn analysis_options(&self) -> String {
+        "include: package:lints/recommended.yaml\n".to_owned()
+    }
+
+    /// `lib/<package>.dart`, exporting the bindings of every component. `None` when a component's
+    /// bindings are that library already.
+    pub fn library(&self) -> Option<String> {
+        if self.namespaces.contains(&self.name) {
+            return None;
+        }
+        let mut library = String::new();
+        for namespace in &self.namespaces {
+            library.push_str(&format!("export '{namespace}.dart';\n"));
+        }
+        Some(library)
+    }
+
+    /// `test/<package>_test.dart`, checking that each component loads the native library.
+    pub fn example_test(&self) -> String {
+        let mut test = String::from("import 'package:test/test.dart';\n");
+        for namespace in &self.namespaces {
+            test.push_str(&format!(
+                "import 'package:{}/{namespace}.dart' as {namespace};\n",
+                self.name
+            ));
+        }
+        test.push_str("\nvoid main() {\n");
+        for namespace in &self.namespaces {
+            test.push_str(&format!(
+                "  test('{namespace} loads the native library', () {{\n    {namespace}.ensureInitialized();\n  }});\n"
+            ));
+        }
+        test.push_str("}\n");
+        test
+    }
+
+    /// Every scaffold file, keyed by its path relative to the package root.
+    pub fn files(&self) -> BTreeMap<Utf8PathBuf, String> {
+        let mut files = BTreeMap::new();
+        files.insert("pubspec.yaml".into(), self.pubspec());
+        files.insert("analysis_options.yaml".into(), self.analysis_options());
+        if let Some(build_hook) = self.build_hook() {
+            files.insert("hook/build.dart".into(), build_hook);
+        }
+        if let Some(library) = self.library() {
+            files.insert(format!("lib/{}.dart", self.name).into(), library);
+        }
+        files.insert(
+            format!("test/{}_test.dart", self.name).into(),
+            self.example_test(),
+        );
+        files
+    }
+
+    /// Write the scaffold files below `package_dir`.
+    pub fn write(&self, package_dir: &Utf8Path) -> Result<()> {
+        for (path, contents) in self.files() {
+            let path = package_dir.join(path);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(path, contents)?;
+        }
+        Ok(())
+    }
+}
+
+/// `value` as a single-quoted Dart string literal.
+fn dart_string(value: &str) -> String {
+    let mut literal = String::from("'");
+    for c in value.chars() {
+        match c {
+            '\\' | '\'' | '$' => {
+                literal.push('\\');
+                literal.push(c);
+            }
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('\'');
+    literal
+}
@@ -6,12 +6,14 @@ use crate::gen::{
 use genco::lang::dart;
 
 use super::paste;
+use crate::gen::imports::{uses, DART_TYPED_DATA};
 
 impl_code_type_for_primitive!(BooleanCodeType, "bool", "Bool");
 
 impl Renderable for BooleanCodeType {
     fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
         quote! {
+            $(uses([DART_TYPED_DATA]))
             class FfiConverterBool {
 
                 static bool lift( int value) {
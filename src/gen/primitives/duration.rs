@@ -4,6 +4,7 @@ use crate::gen::{
 };
 
 use super::paste;
+use crate::gen::imports::{uses, DART_TYPED_DATA};
 use genco::lang::dart;
 
 impl_code_type_for_primitive!(DurationCodeType, "Duration", "Duration");
@@ -11,6 +12,7 @@ impl_code_type_for_primitive!(DurationCodeType, "Duration", "Duration");
 impl Renderable for DurationCodeType {
     fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
         quote! {
+            $(uses([DART_TYPED_DATA]))
             class FfiConverterDuration {
                 static Duration lift( RustBuffer buf) {
                     return FfiConverterDuration.read(buf.asUint8List()).value;
@@ -5,20 +5,20 @@ macro_rules! impl_code_type_for_primitive {
             pub struct $T;
 
             impl crate::gen::CodeType for $T  {
-                fn type_label(&self,) -> String {
+                fn type_label(&self, _names: &crate::gen::naming::Names) -> String {
                     $class_name.into()
                 }
 
-                fn literal(&self, literal: &uniffi_bindgen::pipeline::general::nodes::Literal) -> String {
+                fn literal(&self, _names: &crate::gen::naming::Names, literal: &uniffi_bindgen::pipeline::general::nodes::Literal) -> anyhow::Result<String> {
                     $crate::gen::primitives::render_literal(&literal)
                 }
 
-                fn canonical_name(&self,) -> String {
+                fn canonical_name(&self, _names: &crate::gen::naming::Names) -> String {
                     $canonical_name.into()
                 }
 
-                fn ffi_converter_name(&self) -> String {
-                    format!("FfiConverter{}", self.canonical_name())
+                fn ffi_converter_name(&self, names: &crate::gen::naming::Names) -> String {
+                    format!("FfiConverter{}", self.canonical_name(names))
                 }
             }
         }
@@ -28,11 +28,15 @@ macro_rules! impl_code_type_for_primitive {
 macro_rules! impl_renderable_for_primitive {
     (BytesCodeType, $class_name:literal, $canonical_name:literal) => {
         impl Renderable for BytesCodeType {
-            fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                let cl_name = &self.ffi_converter_name();
-                let type_signature = &self.type_label();
+            fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+                let names = type_helper.get_names();
+                let cl_name = &self.ffi_converter_name(names);
+                let type_signature = &self.type_label(names);
+
+                let typed_data = uses([DART_TYPED_DATA]);
 
                 quote! {
+                    $typed_data
                     class $cl_name {
                         static $type_signature lift(RustBuffer value) {
                             return $cl_name.read(value.asUint8List()).value;
@@ -69,7 +73,8 @@ macro_rules! impl_renderable_for_primitive {
     };
     ($T:ty, $class_name:literal, $canonical_name:literal, $allocation_size:literal) => {
         impl Renderable for $T {
-            fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+            fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+                let names = type_helper.get_names();
                 use crate::gen::code_type::CodeType;
                 let endian = (if $canonical_name.contains("Float") {
                     ", Endian.little"
@@ -77,13 +82,16 @@ macro_rules! impl_renderable_for_primitive {
                     ""
                 });
 
-                let cl_name = &self.ffi_converter_name();
-                let type_signature = &self.type_label();
+                let cl_name = &self.ffi_converter_name(names);
+                let type_signature = &self.type_label(names);
                 let conversion_name = &$canonical_name
                                     .replace("UInt", "Uint")
                                     .replace("Double", "Float");
 
+                let typed_data = uses([DART_TYPED_DATA]);
+
                 quote! {
+                    $typed_data
                     class $cl_name {
                         // According to generated funtion signatures, we won't need to convert number types
                         static $type_signature lift($type_signature value) => value;
@@ -112,17 +120,21 @@ macro_rules! impl_renderable_for_primitive {
     };
     ($T:ty, $class_name:literal, $canonical_name:literal, $allocation_size:literal, $min_value:literal, $max_value:literal, $type_name:literal) => {
         impl Renderable for $T {
-            fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                let cl_name = &self.ffi_converter_name();
-                let type_signature = &self.type_label();
+            fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+                let names = type_helper.get_names();
+                let cl_name = &self.ffi_converter_name(names);
+                let type_signature = &self.type_label(names);
                 let conversion_name = &$canonical_name
                     .replace("UInt", "Uint")
                     .replace("Double", "Float");
 
                 let error_message =
-                    format!("\"Value out of range for {}: \" + value.toString()", $type_name);
+                    format!("\"Value out of range for {}: $value\"", $type_name);
+
+                let typed_data = uses([DART_TYPED_DATA]);
 
                 quote! {
+                    $typed_data
                     class $cl_name {
                         static $type_signature lift($type_signature value) => value;
 
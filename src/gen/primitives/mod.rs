@@ -4,8 +4,10 @@ mod boolean;
 mod duration;
 mod string;
 
+use crate::gen::imports::{uses, DART_TYPED_DATA};
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 use crate::gen::CodeType;
+use anyhow::{bail, Result};
 use genco::prelude::*;
 use paste::paste;
 use uniffi_bindgen::pipeline::general::nodes::{Literal, Radix, Type, TypeNode};
@@ -14,8 +16,8 @@ pub use boolean::BooleanCodeType;
 pub use duration::DurationCodeType;
 pub use string::StringCodeType;
 
-fn render_literal(literal: &Literal) -> String {
-    fn typed_number(type_node: &TypeNode, num_str: String) -> String {
+fn render_literal(literal: &Literal) -> Result<String> {
+    fn typed_number(type_node: &TypeNode, num_str: String) -> Result<String> {
         match &type_node.ty {
             Type::Int8
             | Type::UInt8
@@ -26,14 +28,14 @@ fn render_literal(literal: &Literal) -> String {
             | Type::UInt64
             | Type::Float32
             | Type::Float64
-            | Type::Duration => num_str,
-            _ => panic!("Unexpected literal: {num_str} is not a number"),
+            | Type::Duration => Ok(num_str),
+            _ => bail!("`{num_str}` is not a valid `{:?}`", type_node.ty),
         }
     }
 
     match literal {
-        Literal::Boolean(v) => format!("{v}"),
-        Literal::String(s) => format!("'{s}'"),
+        Literal::Boolean(v) => Ok(format!("{v}")),
+        Literal::String(s) => Ok(format!("'{s}'")),
         Literal::Int(i, radix, type_node) => typed_number(
             type_node,
             match radix {
@@ -51,7 +53,7 @@ fn render_literal(literal: &Literal) -> String {
             },
         ),
         Literal::Float(string, type_node) => typed_number(type_node, string.clone()),
-        _ => unreachable!("Literal"),
+        _ => bail!("`{literal:?}` is not a primitive literal"),
     }
 }
 
@@ -96,11 +98,13 @@ impl_renderable_for_primitive!(Float64CodeType, "double", "Double64", 8);
 
 // Keep u64 on the legacy int path for now; full upper-bound validation lands with BigInt support.
 impl Renderable for UInt64CodeType {
-    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        let cl_name = &self.ffi_converter_name();
-        let type_signature = &self.type_label();
+    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        let names = type_helper.get_names();
+        let cl_name = &self.ffi_converter_name(names);
+        let type_signature = &self.type_label(names);
 
         quote! {
+            $(uses([DART_TYPED_DATA]))
             class $cl_name {
                 static $type_signature lift($type_signature value) => value;
 
@@ -110,7 +114,7 @@ impl Renderable for UInt64CodeType {
 
                 static $type_signature lower($type_signature value) {
                     if (value < 0) {
-                        throw ArgumentError("Value out of range for u64: " + value.toString());
+                        throw ArgumentError($[str](Value out of range for u64: $value));
                     }
                     return value;
                 }
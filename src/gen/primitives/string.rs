@@ -1,15 +1,17 @@
+use crate::gen::naming::Names;
 use crate::gen::{
     quote,
     render::{Renderable, TypeHelperRenderer},
 };
 
+use crate::gen::imports::{uses, DART_CONVERT, DART_TYPED_DATA};
 use crate::gen::CodeType;
 use genco::lang::dart;
 
 #[derive(Debug)]
 pub struct StringCodeType;
 impl CodeType for StringCodeType {
-    fn type_label(&self) -> String {
+    fn type_label(&self, _names: &Names) -> String {
         "String".to_owned()
     }
 }
@@ -17,6 +19,7 @@ impl CodeType for StringCodeType {
 impl Renderable for StringCodeType {
     fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
         quote! {
+            $(uses([DART_CONVERT, DART_TYPED_DATA]))
             class FfiConverterString {
                 static String lift( RustBuffer buf) {
                     // reading the entire buffer, the len is where the string finishes
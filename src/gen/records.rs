@@ -1,10 +1,11 @@
+use super::imports::{uses, DART_TYPED_DATA};
 use super::oracle::{AsCodeType, DartCodeOracle};
 use super::render::{Renderable, TypeHelperRenderer};
 use super::types::generate_type;
+use crate::gen::naming::Names;
 use crate::gen::CodeType;
 use genco::prelude::*;
 use uniffi_bindgen::interface::{AsType, Record};
-use uniffi_bindgen::pipeline::general::nodes::Literal;
 
 #[derive(Debug)]
 pub struct RecordCodeType {
@@ -21,42 +22,44 @@ impl RecordCodeType {
 }
 
 impl CodeType for RecordCodeType {
-    fn type_label(&self) -> String {
-        DartCodeOracle::class_name(&self.id)
+    fn type_label(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(names, &self.id, DartCodeOracle::class_name(names, &self.id))
     }
 
-    fn canonical_name(&self) -> String {
-        self.id.to_string()
+    fn canonical_name(&self, names: &Names) -> String {
+        DartCodeOracle::class_name(names, &self.id)
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
-        todo!("literal not implemented for RecordCodeType");
+    fn ffi_converter_name(&self, names: &Names) -> String {
+        DartCodeOracle::qualified(
+            names,
+            &self.id,
+            format!("FfiConverter{}", self.canonical_name(names)),
+        )
     }
 }
 
 impl Renderable for RecordCodeType {
     fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        if type_helper.check(&self.id) {
-            quote!()
-        } else if let Some(record_) = type_helper.get_record(&self.id) {
+        if let Some(record_) = type_helper.get_record(&self.id) {
             generate_record(record_, type_helper)
         } else {
-            todo!("render_type_helper not implemented for unknown record type");
+            // Records of other components come with their converter from their own bindings
+            quote!()
         }
     }
 }
 
 pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let cls_name = &DartCodeOracle::class_name(obj.name());
-    let ffi_conv_name = &DartCodeOracle::class_name(&obj.as_codetype().ffi_converter_name());
-    for f in obj.fields() {
-        type_helper.include_once_check(&f.as_codetype().canonical_name(), &f.as_type());
-    }
+    let names = type_helper.get_names();
+    let cls_name = &DartCodeOracle::class_name(names, obj.name());
+    let ffi_conv_name = &obj.as_codetype().ffi_converter_name(names);
     quote! {
+        $(uses([DART_TYPED_DATA]))
         class $cls_name {
-            $(for f in obj.fields() => final $(generate_type(&f.as_type())) $(DartCodeOracle::var_name(f.name()));)
+            $(for f in obj.fields() => final $(generate_type(names, &f.as_type())) $(DartCodeOracle::member_name(names, obj.name(), f.name()));)
 
-            $(cls_name)($(for f in obj.fields() => this.$(DartCodeOracle::var_name(f.name())), ));
+            $(cls_name)($(for f in obj.fields() => this.$(DartCodeOracle::member_name(names, obj.name(), f.name())), ));
         }
 
         class $ffi_conv_name {
@@ -65,36 +68,36 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
             }
 
             static LiftRetVal<$cls_name> read( Uint8List buf) {
-                int new_offset = buf.offsetInBytes;
+                int newOffset = buf.offsetInBytes;
 
                 $(for f in obj.fields() =>
-                    final $(DartCodeOracle::var_name(f.name()))_lifted = $(f.as_type().as_codetype().ffi_converter_name()).read(Uint8List.view(buf.buffer, new_offset));
-                    final $(DartCodeOracle::var_name(f.name())) = $(DartCodeOracle::var_name(f.name()))_lifted.value;
-                    new_offset += $(DartCodeOracle::var_name(f.name()))_lifted.bytesRead;
+                    final $(DartCodeOracle::member_name(names, obj.name(), f.name()))Lifted = $(f.as_type().as_codetype().ffi_converter_name(names)).read(Uint8List.view(buf.buffer, newOffset));
+                    final $(DartCodeOracle::member_name(names, obj.name(), f.name())) = $(DartCodeOracle::member_name(names, obj.name(), f.name()))Lifted.value;
+                    newOffset += $(DartCodeOracle::member_name(names, obj.name(), f.name()))Lifted.bytesRead;
                 )
                 return LiftRetVal($(cls_name)(
-                    $(for f in obj.fields() => $(DartCodeOracle::var_name(f.name())),)
-                ), new_offset - buf.offsetInBytes);
+                    $(for f in obj.fields() => $(DartCodeOracle::member_name(names, obj.name(), f.name())),)
+                ), newOffset - buf.offsetInBytes);
             }
 
             static RustBuffer lower( $cls_name value) {
-                final total_length = $(for f in obj.fields() => $(f.as_type().as_codetype().ffi_converter_name()).allocationSize(value.$(DartCodeOracle::var_name(f.name()))) + ) 0;
-                final buf = Uint8List(total_length);
+                final totalLength = $(for f in obj.fields() => $(f.as_type().as_codetype().ffi_converter_name(names)).allocationSize(value.$(DartCodeOracle::member_name(names, obj.name(), f.name()))) + ) 0;
+                final buf = Uint8List(totalLength);
                 write(value, buf);
                 return toRustBuffer(buf);
             }
 
             static int write( $cls_name value, Uint8List buf) {
-                int new_offset = buf.offsetInBytes;
+                int newOffset = buf.offsetInBytes;
 
                 $(for f in obj.fields() =>
-                new_offset += $(f.as_type().as_codetype().ffi_converter_name()).write(value.$(DartCodeOracle::var_name(f.name())), Uint8List.view(buf.buffer, new_offset));
+                newOffset += $(f.as_type().as_codetype().ffi_converter_name(names)).write(value.$(DartCodeOracle::member_name(names, obj.name(), f.name())), Uint8List.view(buf.buffer, newOffset));
                 )
-                return new_offset - buf.offsetInBytes;
+                return newOffset - buf.offsetInBytes;
             }
 
             static int allocationSize($cls_name value) {
-                return $(for f in obj.fields() => $(f.as_type().as_codetype().ffi_converter_name()).allocationSize(value.$(DartCodeOracle::var_name(f.name()))) + ) 0;
+                return $(for f in obj.fields() => $(f.as_type().as_codetype().ffi_converter_name(names)).allocationSize(value.$(DartCodeOracle::member_name(names, obj.name(), f.name()))) + ) 0;
             }
         }
     }
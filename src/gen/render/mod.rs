@@ -1,20 +1,31 @@
+use super::objects;
 use super::{callback_interface, compounds, custom, enums, primitives, records};
-use super::{objects, oracle::AsCodeType};
 use genco::{lang::dart, quote};
 use uniffi_bindgen::interface::{AsType, Enum, Object, Record, Type};
 use uniffi_bindgen::ComponentInterface;
 
+use crate::gen::imports::{uses, DART_TYPED_DATA};
+use crate::gen::naming::Names;
+use crate::gen::Config;
+
 pub trait Renderer<T> {
     fn render(&self) -> T;
 }
 
 pub trait TypeHelperRenderer {
     fn get_ci(&self) -> &ComponentInterface;
-    fn include_once_check(&self, name: &str, ty: &Type) -> bool;
-    fn check(&self, name: &str) -> bool;
     /// Marks a name as included and returns true if it was already present (should skip generation).
-    /// Use this for types that don't have a corresponding Type enum variant.
+    /// Use this for definitions that aren't converters, which are collected up front.
     fn include_once_by_name(&self, name: &str) -> bool;
+    /// Emits a definition shared by several types (e.g. FFI structs) next to the helpers, so it
+    /// is reachable from each of them when the bindings are split into multiple files.
+    fn add_support_definition(&self, definition: dart::Tokens);
+    /// Whether the function or type named `item` is left out because it uses an unsupported
+    /// construct (see `skip_unsupported`).
+    fn is_skipped(&self, item: &str) -> bool;
+    fn get_config(&self) -> &Config;
+    /// The Dart names of the component's items.
+    fn get_names(&self) -> &Names;
 
     fn get_object(&self, name: &str) -> Option<&Object>;
     fn get_enum(&self, name: &str) -> Option<&Enum>;
@@ -22,7 +33,7 @@ pub trait TypeHelperRenderer {
 }
 
 pub trait Renderable {
-    fn render_type(&self, ty: &Type, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    fn render_type(&self, ty: &Type, names: &Names) -> dart::Tokens {
         use super::oracle::DartCodeOracle;
 
         let type_name = match ty {
@@ -37,30 +48,28 @@ pub trait Renderable {
             Type::Float32 | Type::Float64 => quote!(double),
             Type::String => quote!(String),
             Type::Boolean => quote!(bool),
-            Type::Bytes => quote!(Uint8List),
-            Type::Object { name, .. } => quote!($name),
-            Type::Optional { inner_type } => quote!($(&self.render_type(inner_type, type_helper))?),
+            Type::Bytes => quote!($(uses([DART_TYPED_DATA]))Uint8List),
+            Type::Object { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
+            Type::Optional { inner_type } => quote!($(&self.render_type(inner_type, names))?),
             Type::Sequence { inner_type } => {
-                quote!(List<$(&self.render_type(inner_type, type_helper))>)
+                quote!(List<$(&self.render_type(inner_type, names))>)
             }
             Type::Map {
                 key_type,
                 value_type,
             } => {
-                quote!(Map<$(&self.render_type(key_type, type_helper)), $(&self.render_type(value_type, type_helper))>)
+                quote!(Map<$(&self.render_type(key_type, names)), $(&self.render_type(value_type, names))>)
             }
-            Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
-            Type::Record { name, .. } => quote!($name),
-            Type::Custom { name, .. } => quote!($name),
+            Type::Enum { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
+            Type::Record { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
+            Type::Custom { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
             Type::Duration => quote!(Duration),
-            Type::CallbackInterface { name, .. } => quote!($name),
-            _ => todo!("Type::{:?}", ty),
+            Type::CallbackInterface { name, .. } => {
+                quote!($(DartCodeOracle::type_name(names, name)))
+            }
+            _ => unreachable!("Unsupported Type::{:?}", ty),
         };
 
-        if !type_helper.include_once_check(&ty.as_codetype().canonical_name(), ty) {
-            println!("{} Added", &ty.as_codetype().canonical_name());
-        }
-
         type_name
     }
 
@@ -89,22 +98,16 @@ impl<T: AsType> AsRenderable for T {
             Type::Duration => Box::new(primitives::DurationCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Object { name, imp, .. } => Box::new(objects::ObjectCodeType::new(name, imp)),
-            Type::Optional { inner_type } => Box::new(compounds::OptionalCodeType::new(
-                self.as_type(),
-                *inner_type,
-            )),
-            Type::Sequence { inner_type } => Box::new(compounds::SequenceCodeType::new(
-                self.as_type(),
-                *inner_type,
-            )),
+            Type::Optional { inner_type } => {
+                Box::new(compounds::OptionalCodeType::new(*inner_type))
+            }
+            Type::Sequence { inner_type } => {
+                Box::new(compounds::SequenceCodeType::new(*inner_type))
+            }
             Type::Map {
                 key_type,
                 value_type,
-            } => Box::new(compounds::MapCodeType::new(
-                self.as_type(),
-                *key_type,
-                *value_type,
-            )),
+            } => Box::new(compounds::MapCodeType::new(*key_type, *value_type)),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record { name, .. } => Box::new(records::RecordCodeType::new(name)),
             Type::Custom {
@@ -112,10 +115,10 @@ impl<T: AsType> AsRenderable for T {
                 module_path,
                 builtin,
             } => Box::new(custom::CustomCodeType::new(name, module_path, builtin)),
-            Type::CallbackInterface { name, .. } => Box::new(
-                callback_interface::CallbackInterfaceCodeType::new(name, self.as_type()),
-            ),
-            _ => todo!("Renderable for Type::{:?}", self.as_type()),
+            Type::CallbackInterface { name, .. } => {
+                Box::new(callback_interface::CallbackInterfaceCodeType::new(name))
+            }
+            _ => unreachable!("Unsupported Type::{:?}", self.as_type()),
         }
     }
 }
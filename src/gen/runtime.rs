@@ -0,0 +1,246 @@
+use genco::prelude::*;
+
+use super::imports::{uses, DART_FFI, DART_TYPED_DATA, PACKAGE_FFI};
+
+/// File name of the shared runtime library emitted when `shared_runtime` is enabled.
+pub const RUNTIME_FILE_NAME: &str = "uniffi_runtime.dart";
+
+/// The parts of the Dart runtime that don't depend on a component: FFI structs, error types,
+/// converter base classes and handle maps. Anything calling into a component's scaffolding
+/// (`RustBuffer` allocation, `rustCall`, async polling) is rendered per component instead.
+pub fn generate_runtime_definitions() -> dart::Tokens {
+    quote! {
+        $(uses([DART_FFI, DART_TYPED_DATA, PACKAGE_FFI]))
+        class UniffiInternalError implements Exception {
+            static const int bufferOverflow = 0;
+            static const int incompleteData = 1;
+            static const int unexpectedOptionalTag = 2;
+            static const int unexpectedEnumCase = 3;
+            static const int unexpectedNullPointer = 4;
+            static const int unexpectedRustCallStatusCode = 5;
+            static const int unexpectedRustCallError = 6;
+            static const int unexpectedStaleHandle = 7;
+            static const int rustPanic = 8;
+
+            final int errorCode;
+            final String? panicMessage;
+
+            const UniffiInternalError(this.errorCode, this.panicMessage);
+
+            static UniffiInternalError panicked(String message) {
+            return UniffiInternalError(rustPanic, message);
+            }
+
+            @override
+            String toString() {
+            switch (errorCode) {
+                case bufferOverflow:
+                return "UniFfi::BufferOverflow";
+                case incompleteData:
+                return "UniFfi::IncompleteData";
+                case unexpectedOptionalTag:
+                return "UniFfi::UnexpectedOptionalTag";
+                case unexpectedEnumCase:
+                return "UniFfi::UnexpectedEnumCase";
+                case unexpectedNullPointer:
+                return "UniFfi::UnexpectedNullPointer";
+                case unexpectedRustCallStatusCode:
+                return "UniFfi::UnexpectedRustCallStatusCode";
+                case unexpectedRustCallError:
+                return "UniFfi::UnexpectedRustCallError";
+                case unexpectedStaleHandle:
+                return "UniFfi::UnexpectedStaleHandle";
+                case rustPanic:
+                return $[str](UniFfi::rustPanic: $panicMessage);
+                default:
+                return $[str](UniFfi::UnknownError: $errorCode);
+            }
+            }
+        }
+
+        sealed class UniffiContractMismatchException implements Exception {
+            const UniffiContractMismatchException();
+        }
+
+        class UniffiContractVersionMismatchException extends UniffiContractMismatchException {
+            final int bindingsVersion;
+            final int scaffoldingVersion;
+
+            const UniffiContractVersionMismatchException({
+            required this.bindingsVersion,
+            required this.scaffoldingVersion,
+            });
+
+            @override
+            String toString() {
+            return $[str](UniFfi::ContractVersionMismatch: bindings version $bindingsVersion, scaffolding version $scaffoldingVersion);
+            }
+        }
+
+        class UniffiApiChecksumMismatchException extends UniffiContractMismatchException {
+            final List<String> mismatchedChecksums;
+
+            const UniffiApiChecksumMismatchException(this.mismatchedChecksums);
+
+            @override
+            String toString() {
+            return $[str](UniFfi::ApiChecksumMismatch: $(mismatchedChecksums.join(", ")));
+            }
+        }
+
+        const int uniffiCallSuccess = 0;
+        const int uniffiCallError = 1;
+        const int uniffiCallUnexpectedError = 2;
+
+        final class RustCallStatus extends Struct {
+            @Int8()
+            external int code;
+
+            external RustBuffer errorBuf;
+        }
+
+        abstract class UniffiRustCallStatusErrorHandler {
+            Exception lift(RustBuffer errorBuf);
+        }
+
+        final class RustBuffer extends Struct {
+            @Uint64()
+            external int capacity;
+
+            @Uint64()
+            external int len;
+
+            external Pointer<Uint8> data;
+
+            Uint8List asUint8List() {
+            final dataList = data.asTypedList(len);
+            final byteData = ByteData.sublistView(dataList);
+            return Uint8List.view(byteData.buffer);
+            }
+
+            @override
+            String toString() {
+            return $(r#""RustBuffer{capacity: $capacity, len: $len, data: $data}""#);
+            }
+        }
+
+        final class ForeignBytes extends Struct {
+            @Int32()
+            external int len;
+            external Pointer<Uint8> data;
+
+            void free() {
+            calloc.free(data);
+            }
+        }
+
+        class LiftRetVal<T> {
+            final T value;
+            final int bytesRead;
+            const LiftRetVal(this.value, this.bytesRead);
+
+            LiftRetVal<T> copyWithOffset(int offset) {
+                return LiftRetVal(value, bytesRead + offset);
+            }
+        }
+
+        abstract class FfiConverter<D, F> {
+            const FfiConverter();
+
+            D lift(F value);
+            F lower(D value);
+            D read(ByteData buffer, int offset);
+            void write(D value, ByteData buffer, int offset);
+            int size(D value);
+        }
+
+        mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
+            @override
+            T lift(T value) => value;
+
+            @override
+            T lower(T value) => value;
+        }
+
+        Uint8List createUint8ListFromInt(int value) {
+            int length = value.bitLength ~/ 8 + 1;
+
+            // Ensure the length is either 4 or 8
+            if (length != 4 && length != 8) {
+            length = (value < 0x100000000) ? 4 : 8;
+            }
+
+            Uint8List uint8List = Uint8List(length);
+
+            for (int i = length - 1; i >= 0; i--) {
+            uint8List[i] = value & 0xFF;
+            value >>= 8;
+            }
+
+            return uint8List;
+        }
+
+        const int uniffiRustFuturePollReady = 0;
+        const int uniffiRustFuturePollMaybeReady = 1;
+
+        typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
+
+        typedef UniffiForeignFutureFree = Void Function(Uint64);
+        typedef UniffiForeignFutureFreeDart = void Function(int);
+
+        final class UniffiForeignFuture extends Struct {
+            @Uint64()
+            external int handle;
+
+            external Pointer<NativeFunction<UniffiForeignFutureFree>> free;
+        }
+
+        // Maps Dart objects handed to Rust (callback implementations, foreign futures) to
+        // handles. Every isolate has its own map, so an object can only be looked up in the
+        // isolate that inserted it. Maps given a `reserve` function take their handles from
+        // Rust, which keeps them unique across isolates; the others count up on their own, so
+        // their handles only mean something in the inserting isolate.
+        // As of uniffi 0.30, foreign handles must always have the lowest bit set
+        // This is achieved here with an odd number sequence.
+        class UniffiHandleMap<T> {
+            final Map<int, T> _map = {};
+            final int Function()? _reserve;
+            int _counter = 1;
+
+            UniffiHandleMap([this._reserve]);
+
+            int insert(T obj) {
+            final reserve = _reserve;
+            final int handle;
+            if (reserve != null) {
+                handle = reserve();
+            } else {
+                handle = _counter;
+                _counter += 2;
+            }
+            _map[handle] = obj;
+            return handle;
+            }
+
+            T get(int handle) {
+            final obj = _map[handle];
+            if (obj == null) {
+                throw UniffiInternalError(
+                    UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+            }
+            return obj;
+            }
+
+            void remove(int handle) {
+            if (maybeRemove(handle) == null) {
+                throw UniffiInternalError(
+                    UniffiInternalError.unexpectedStaleHandle, "Handle not found");
+            }
+            }
+
+            T? maybeRemove(int handle) {
+            return _map.remove(handle);
+            }
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeSet;
+
+use genco::prelude::*;
+use heck::ToSnakeCase;
+use uniffi_bindgen::interface::{AsType, Callable, Type};
+use uniffi_bindgen::ComponentInterface;
+
+use crate::gen::naming::Names;
+use crate::gen::oracle::DartCodeOracle;
+
+/// File holding everything that isn't a type definition: runtime, helpers, functions and externs.
+pub const CORE_FILE_NAME: &str = "uniffi_core.dart";
+
+/// Name of the file a record, enum, object or callback interface is written to.
+pub fn type_file_name(names: &Names, ty: &Type) -> String {
+    let name = ty.name().expect("only named types get their own file");
+    format!(
+        "{}.dart",
+        DartCodeOracle::class_name(names, name).to_snake_case()
+    )
+}
+
+/// Files of the other local types that the definition of `ty` refers to.
+pub fn type_dependencies(names: &Names, ci: &ComponentInterface, ty: &Type) -> BTreeSet<String> {
+    let mut referenced = Vec::new();
+    match ty {
+        Type::Record { name, .. } => {
+            if let Some(rec) = ci.get_record_definition(name) {
+                referenced.extend(rec.fields().iter().map(|f| f.as_type()));
+            }
+        }
+        Type::Enum { name, .. } => {
+            if let Some(enum_) = ci.get_enum_definition(name) {
+                for variant in enum_.variants() {
+                    referenced.extend(variant.fields().iter().map(|f| f.as_type()));
+                }
+            }
+        }
+        Type::Object { name, .. } => {
+            if let Some(obj) = ci.get_object_definition(name) {
+                for cons in obj.constructors() {
+                    referenced.extend(callable_types(cons));
+                }
+                for meth in obj.methods() {
+                    referenced.extend(callable_types(meth));
+                }
+                // Implemented trait interfaces appear in the `implements` clause
+                referenced.extend(obj.trait_impls().iter().map(|t| t.trait_ty.clone()));
+            }
+        }
+        Type::CallbackInterface { name, .. } => {
+            if let Some(cbi) = ci.get_callback_interface_definition(name) {
+                for meth in cbi.methods() {
+                    referenced.extend(callable_types(meth));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut files = BTreeSet::new();
+    for dep in &referenced {
+        collect_named_types(names, ci, dep, &mut files);
+    }
+    files.remove(&type_file_name(names, ty));
+    files
+}
+
+/// Argument, return and error types of a function, method or constructor.
+pub fn callable_types(callable: &impl Callable) -> Vec<Type> {
+    callable
+        .arguments()
+        .iter()
+        .map(|arg| arg.as_type())
+        .chain(callable.return_type().cloned())
+        .chain(callable.throws_type().cloned())
+        .collect()
+}
+
+fn collect_named_types(
+    names: &Names,
+    ci: &ComponentInterface,
+    ty: &Type,
+    files: &mut BTreeSet<String>,
+) {
+    match ty {
+        Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+            collect_named_types(names, ci, inner_type, files)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+        } => {
+            collect_named_types(names, ci, key_type, files);
+            collect_named_types(names, ci, value_type, files);
+        }
+        Type::Record { .. }
+        | Type::Enum { .. }
+        | Type::Object { .. }
+        | Type::CallbackInterface { .. }
+            if !ci.is_external(ty) =>
+        {
+            files.insert(type_file_name(names, ty));
+        }
+        // Custom types are typedefs in the core file
+        _ => {}
+    }
+}
+
+/// Library re-exporting all files of a split component.
+pub fn generate_barrel(paths: &[String]) -> dart::Tokens {
+    quote! {
+        $(for path in paths => $(format!("export \"{path}\""));)
+    }
+}
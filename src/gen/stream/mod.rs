@@ -1,14 +1,45 @@
 use genco::prelude::*;
-use uniffi_bindgen::interface::Object;
+use uniffi_bindgen::interface::{Object, Type};
+use uniffi_bindgen::ComponentInterface;
 
 use crate::gen::oracle::DartCodeOracle;
-use crate::gen::render::TypeHelperRenderer;
 
-pub fn generate_stream(obj: &Object, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let obj_name = obj.name();
-    let fn_name = DartCodeOracle::fn_name(&obj_name.replace("StreamExt", ""));
+/// Prefix `#[export_stream]` gives the function creating the handle of a stream.
+const CONSTRUCTOR_PREFIX: &str = "create_stream_";
+
+/// A stream exported with `#[export_stream]`: the macro wraps the stream function `name` in an
+/// object polled through its `next` method, which `create_stream_<name>` returns.
+pub struct ExportedStream {
+    name: String,
+    constructor: String,
+}
+
+impl ExportedStream {
+    /// The exported stream `obj` is the handle of, if any, found through its constructor function.
+    pub fn of(ci: &ComponentInterface, obj: &Object) -> Option<Self> {
+        obj.methods().iter().find(|meth| meth.name() == "next")?;
+        ci.function_definitions().iter().find_map(|fun| {
+            let name = fun.name().strip_prefix(CONSTRUCTOR_PREFIX)?;
+            match fun.return_type() {
+                Some(Type::Object { name: ret, .. }) if ret == obj.name() => Some(Self {
+                    name: name.to_string(),
+                    constructor: fun.name().to_string(),
+                }),
+                _ => None,
+            }
+        })
+    }
+
+    /// Name of the Dart function returning the stream.
+    pub fn fn_name(&self) -> String {
+        DartCodeOracle::fn_name(&self.name)
+    }
+}
+
+pub fn generate_stream(stream: &ExportedStream) -> dart::Tokens {
+    let fn_name = stream.fn_name();
     let obj_var_name = &DartCodeOracle::var_name(&fn_name);
-    let create_obj_fn_name = format!("createStream{}", &obj_name.replace("StreamExt", ""));
+    let create_obj_fn_name = DartCodeOracle::fn_name(&stream.constructor);
 
     quote! {
         $fn_name() async* {
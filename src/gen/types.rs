@@ -1,56 +1,87 @@
+use std::cell::RefCell;
 use std::collections::{BTreeSet, HashSet};
-use std::{cell::RefCell, collections::HashMap};
 
 use genco::prelude::*;
 use uniffi_bindgen::interface::AsType;
 use uniffi_bindgen::{interface::Type, ComponentInterface};
 
+use super::diagnostics::Unsupported;
+use super::imports::{uses, DART_ASYNC, DART_FFI, DART_TYPED_DATA, PACKAGE_FFI};
+use super::naming::Names;
 use super::render::{AsRenderable, Renderer, TypeHelperRenderer};
-use super::{enums, functions, objects, oracle::AsCodeType, records};
+use super::{enums, functions, logging, objects, records, runtime};
 use crate::gen::oracle::DartCodeOracle;
+use crate::gen::Config;
 
 type FunctionDefinition = dart::Tokens;
 
 pub struct TypeHelpersRenderer<'a> {
     ci: &'a ComponentInterface,
-    include_once_names: RefCell<HashMap<String, Type>>,
+    config: &'a Config,
+    names: &'a Names,
     // Tracks ad-hoc "include once" names that don't map to a concrete `Type`
     include_once_custom: RefCell<HashSet<String>>,
+    support_definitions: RefCell<Vec<dart::Tokens>>,
+    unsupported: Unsupported,
 }
 
 impl<'a> TypeHelpersRenderer<'a> {
-    pub fn new(ci: &'a ComponentInterface) -> Self {
+    pub fn new(ci: &'a ComponentInterface, config: &'a Config, names: &'a Names) -> Self {
         Self {
             ci,
-            include_once_names: RefCell::new(HashMap::new()),
+            config,
+            names,
             include_once_custom: RefCell::new(HashSet::new()),
+            support_definitions: RefCell::new(Vec::new()),
+            unsupported: Unsupported::check(ci, config),
         }
     }
 
-    pub fn get_include_names(&self) -> HashMap<String, Type> {
-        self.include_once_names.clone().into_inner()
+    pub fn unsupported(&self) -> &Unsupported {
+        &self.unsupported
+    }
+
+    /// First pass: every builtin, compound and custom type the component refers to, each of
+    /// which needs exactly one converter. Named types get theirs next to their definition.
+    pub fn collect_helper_types(&self) -> BTreeSet<Type> {
+        self.ci
+            .iter_local_types()
+            // `checkCallStatus` lifts panic messages
+            .chain([&Type::String])
+            .filter(|ty| {
+                !matches!(
+                    ty,
+                    Type::Record { .. }
+                        | Type::Enum { .. }
+                        | Type::Object { .. }
+                        | Type::CallbackInterface { .. }
+                ) && !self.unsupported.skips_type(ty)
+            })
+            .cloned()
+            .collect()
     }
 }
 
 impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
-    // Checks if the type imports for each type have already been added
-    fn include_once_check(&self, name: &str, ty: &Type) -> bool {
-        let mut map = self.include_once_names.borrow_mut();
-        let found = map.insert(name.to_string(), ty.clone()).is_some();
-        drop(map);
-        found
+    fn include_once_by_name(&self, name: &str) -> bool {
+        let mut set = self.include_once_custom.borrow_mut();
+        !set.insert(name.to_string())
     }
 
-    fn check(&self, name: &str) -> bool {
-        let map = self.include_once_names.borrow();
-        let contains = map.contains_key(&name.to_string());
-        drop(map);
-        contains
+    fn is_skipped(&self, item: &str) -> bool {
+        self.unsupported.is_skipped(item)
     }
 
-    fn include_once_by_name(&self, name: &str) -> bool {
-        let mut set = self.include_once_custom.borrow_mut();
-        !set.insert(name.to_string())
+    fn add_support_definition(&self, definition: dart::Tokens) {
+        self.support_definitions.borrow_mut().push(definition);
+    }
+
+    fn get_config(&self) -> &Config {
+        self.config
+    }
+
+    fn get_names(&self) -> &Names {
+        self.names
     }
 
     fn get_object(&self, name: &str) -> Option<&uniffi_bindgen::interface::Object> {
@@ -70,151 +101,144 @@ impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
     }
 }
 
-impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
-    // TODO: Implimient a two pass system where the first pass will render the main code, and the second pass will render the helper code
-    // this is so the generator knows what helper code to include.
+/// The pieces of a component's bindings, kept apart so they can be written to one or several files.
+pub struct RenderedBindings {
+    /// Import directives needed by every library of the component.
+    pub imports: dart::Tokens,
+    /// Re-export of the shared runtime, if one is used.
+    pub runtime_export: dart::Tokens,
+    /// Records, enums, objects and callback interfaces, each with its converter.
+    pub type_definitions: Vec<(Type, dart::Tokens)>,
+    /// Runtime, call helpers and the converters for builtin and compound types.
+    pub helpers: dart::Tokens,
+    pub functions: FunctionDefinition,
+}
 
-    fn render(&self) -> (dart::Tokens, dart::Tokens) {
+impl TypeHelpersRenderer<'_> {
+    pub fn render_parts(&self) -> RenderedBindings {
         // Render all the types and their helpers
-        let types_definitions = quote! {
-            $( for rec in self.ci.record_definitions() => $(records::generate_record(rec, self)))
-
-            $( for enm in self.ci.enum_definitions() => $(enums::generate_enum(enm, self)))
-            $( for obj in self.ci.object_definitions() => $(objects::generate_object(obj, self)))
-        };
+        let mut type_definitions = Vec::new();
+        for rec in self.ci.record_definitions() {
+            if self.is_skipped(rec.name()) {
+                continue;
+            }
+            type_definitions.push((rec.as_type(), records::generate_record(rec, self)));
+        }
+        for enm in self.ci.enum_definitions() {
+            if self.is_skipped(enm.name()) {
+                continue;
+            }
+            type_definitions.push((enm.as_type(), enums::generate_enum(enm, self)));
+        }
+        for obj in self.ci.object_definitions() {
+            if self.is_skipped(obj.name()) {
+                continue;
+            }
+            type_definitions.push((obj.as_type(), objects::generate_object(obj, self)));
+        }
+        for callback in self.ci.callback_interface_definitions() {
+            if self.is_skipped(callback.name()) {
+                continue;
+            }
+            let ty = callback.as_type();
+            let tokens = ty.as_renderable().render_type_helper(self);
+            type_definitions.push((ty, tokens));
+        }
 
         // Render all unique imports, sorted alphabetically
         let modules_to_import = self
             .ci
             .iter_external_types()
             .map(|ty| {
-                self.ci
+                let namespace = self
+                    .ci
                     .namespace_for_type(ty)
-                    .expect("external type should have module_path")
+                    .expect("external type should have module_path");
+                let crate_name = ty
+                    .module_path()
+                    .and_then(|path| path.split("::").next())
+                    .unwrap_or(namespace);
+                (
+                    namespace,
+                    self.config.external_import_uri(crate_name, namespace),
+                )
             })
             .collect::<BTreeSet<_>>();
-        // The second import statement uses a library prefix, to distinguish conflicting identifiers e.g. RustBuffer vs. ext.RustBuffer
+        // Other components declare the same runtime (`RustBuffer`, ...), so their libraries are
+        // only imported with a prefix and everything used from them is qualified
         let imports: dart::Tokens = quote!(
-            $( for imp in modules_to_import {
-                $(format!("import \"{}.dart\"", imp));
-                $(format!("import \"{}.dart\"", imp)) as $imp;
+            $( for (namespace, uri) in modules_to_import {
+                $(format!("import \"{uri}\"")) as $namespace;
             })
         );
 
         // let function_definitions = quote!($( for fun in self.ci.function_definitions() => $(functions::generate_function("this", fun, self))));
 
         let function_definitions = quote!(
-            $(for fun in self.ci.function_definitions() =>
+            $(for fun in self.ci.function_definitions().iter().filter(|fun| !self.is_skipped(fun.name())) =>
                 $(functions::generate_function(fun, self))
+                $(functions::generate_background_function(fun, self))
 
             )
+            $(logging::generate_log_stream(self))
         );
 
-        // Ensure callback interfaces are registered for helper generation
-        for callback in self.ci.callback_interface_definitions() {
-            self.include_once_check(
-                &callback.as_type().as_codetype().canonical_name(),
-                &callback.as_type(),
-            );
-        }
-
-        // Let's include the string converter
-        self.include_once_check(&Type::String.as_codetype().canonical_name(), &Type::String);
+        // Second pass: one converter per collected type
         let helpers_definitions = quote! {
-            $(for (_, ty) in self.get_include_names().iter() => $(ty.as_renderable().render_type_helper(self)) )
+            $(for ty in self.collect_helper_types() => $(ty.as_renderable().render_type_helper(self)))
         };
 
-        let types_helper_code = quote! {
-            import "dart:async";
-            import "dart:convert";
-            import "dart:ffi";
-            import "dart:io" show Platform, File, Directory;
-            import "dart:isolate";
-            import "dart:typed_data";
-            import "package:ffi/ffi.dart";
-            $(imports)
-
-            $(types_definitions)
-
-
-            class UniffiInternalError implements Exception {
-                static const int bufferOverflow = 0;
-                static const int incompleteData = 1;
-                static const int unexpectedOptionalTag = 2;
-                static const int unexpectedEnumCase = 3;
-                static const int unexpectedNullPointer = 4;
-                static const int unexpectedRustCallStatusCode = 5;
-                static const int unexpectedRustCallError = 6;
-                static const int unexpectedStaleHandle = 7;
-                static const int rustPanic = 8;
-
-                final int errorCode;
-                final String? panicMessage;
-
-                const UniffiInternalError(this.errorCode, this.panicMessage);
-
-                static UniffiInternalError panicked(String message) {
-                return UniffiInternalError(rustPanic, message);
-                }
-
-                @override
-                String toString() {
-                switch (errorCode) {
-                    case bufferOverflow:
-                    return "UniFfi::BufferOverflow";
-                    case incompleteData:
-                    return "UniFfi::IncompleteData";
-                    case unexpectedOptionalTag:
-                    return "UniFfi::UnexpectedOptionalTag";
-                    case unexpectedEnumCase:
-                    return "UniFfi::UnexpectedEnumCase";
-                    case unexpectedNullPointer:
-                    return "UniFfi::UnexpectedNullPointer";
-                    case unexpectedRustCallStatusCode:
-                    return "UniFfi::UnexpectedRustCallStatusCode";
-                    case unexpectedRustCallError:
-                    return "UniFfi::UnexpectedRustCallError";
-                    case unexpectedStaleHandle:
-                    return "UniFfi::UnexpectedStaleHandle";
-                    case rustPanic:
-                    return $[str](UniFfi::rustPanic: $panicMessage);
-                    default:
-                    return $[str](UniFfi::UnknownError: $errorCode);
-                }
-                }
-            }
-
-            const int CALL_SUCCESS = 0;
-            const int CALL_ERROR = 1;
-            const int CALL_UNEXPECTED_ERROR = 2;
+        // With a shared runtime the asset-independent helpers come from (and are re-exported
+        // from) a separate library instead of being inlined into every component.
+        let (runtime_import, runtime_export, runtime_definitions) = if self.config.shared_runtime()
+        {
+            let uri = self.config.runtime_import();
+            (
+                quote!($(format!("import \"{uri}\""));),
+                quote!($(format!("export \"{uri}\""));),
+                quote!(),
+            )
+        } else {
+            (quote!(), quote!(), runtime::generate_runtime_definitions())
+        };
 
-            final class RustCallStatus extends Struct {
-                @Int8()
-                external int code;
+        // Every call into the scaffolding goes through these helpers, so they run the contract
+        // checks on first use
+        let ensure_initialized = if self.config.contract_checks() {
+            quote!(_uniffiEnsureInitialized();)
+        } else {
+            quote!()
+        };
 
-                external RustBuffer errorBuf;
+        // Libraries of the SDK and `package:ffi` are imported by each file as needed
+        let imports = quote! {
+            $(runtime_import)
+            $(imports)
+        };
 
-                //Pointer<RustCallStatus> asPointer() => Pointer<RustCallStatus>.fromAddress(address);
-            }
+        let helpers = quote! {
+            $(uses([DART_ASYNC, DART_FFI, DART_TYPED_DATA, PACKAGE_FFI]))
+            $(runtime_definitions)
 
             void checkCallStatus(UniffiRustCallStatusErrorHandler errorHandler, Pointer<RustCallStatus> status) {
 
-                if (status.ref.code == CALL_SUCCESS) {
+                if (status.ref.code == uniffiCallSuccess) {
                 return;
-                } else if (status.ref.code == CALL_ERROR) {
+                } else if (status.ref.code == uniffiCallError) {
                 throw errorHandler.lift(status.ref.errorBuf);
-                } else if (status.ref.code == CALL_UNEXPECTED_ERROR) {
+                } else if (status.ref.code == uniffiCallUnexpectedError) {
                 if (status.ref.errorBuf.len > 0) {
                     throw UniffiInternalError.panicked(FfiConverterString.lift(status.ref.errorBuf));
                 } else {
                     throw UniffiInternalError.panicked("Rust panic");
                 }
                 } else {
-                throw UniffiInternalError.panicked("Unexpected RustCallStatus code: ${status.ref.code}");
+                throw UniffiInternalError.panicked($(r#""Unexpected RustCallStatus code: ${status.ref.code}""#));
                 }
             }
 
             T rustCall<T>(T Function(Pointer<RustCallStatus>) callback, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+                $(&ensure_initialized)
                 final status = calloc<RustCallStatus>();
                 try {
                     final result = callback(status);
@@ -227,6 +251,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
 
             // New version that separates FFI call from lifting to avoid deserializing garbage on error
             T rustCallWithLifter<T, F>(F Function(Pointer<RustCallStatus>) ffiCall, T Function(F) lifter, [UniffiRustCallStatusErrorHandler? errorHandler]) {
+                $(&ensure_initialized)
                 final status = calloc<RustCallStatus>();
                 try {
                     final rawResult = ffiCall(status);
@@ -245,49 +270,9 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
             }
 
-            abstract class UniffiRustCallStatusErrorHandler {
-                Exception lift(RustBuffer errorBuf);
-            }
-
-            final class RustBuffer extends Struct {
-                @Uint64()
-                external int capacity;
-
-                @Uint64()
-                external int len;
-
-                external Pointer<Uint8> data;
-
-                static RustBuffer alloc(int size) {
-                    return rustCall((status) => $(self.ci.ffi_rustbuffer_alloc().name())(size, status));
-                }
-
-                static RustBuffer fromBytes(ForeignBytes bytes) {
-                    return rustCall((status) => $(self.ci.ffi_rustbuffer_from_bytes().name())(bytes, status));
-                }
-
-                // static RustBuffer from(Pointer<Uint8> bytes, int len) {
-                //   final foreignBytes = ForeignBytes(len: len, data: bytes);
-                //   return rustCall((status) => _UniffiLib.instance.ffi_uniffi_futures_rustbuffer_from_bytes(foreignBytes));
-                // }
-
+            extension _UniffiRustBufferOps on RustBuffer {
                 void free() {
-                    rustCall((status) => $(self.ci.ffi_rustbuffer_free().name())(this, status));
-                }
-
-                RustBuffer reserve(int additionalCapacity) {
-                return rustCall((status) => $(self.ci.ffi_rustbuffer_reserve().name())(this, additionalCapacity, status));
-                }
-
-                Uint8List asUint8List() {
-                final dataList = data.asTypedList(len);
-                final byteData = ByteData.sublistView(dataList);
-                return Uint8List.view(byteData.buffer);
-                }
-
-                @override
-                String toString() {
-                return "RustBuffer{capacity: $capacity, len: $len, data: $data}";
+                    rustCall((status) => $(DartCodeOracle::ffi_function_name(self.ci.ffi_rustbuffer_free().name()))(this, status));
                 }
             }
 
@@ -301,81 +286,11 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 final bytes = calloc<ForeignBytes>();
                 bytes.ref.len = length;
                 bytes.ref.data = frameData;
-                return RustBuffer.fromBytes(bytes.ref);
-            }
-
-            final class ForeignBytes extends Struct {
-                @Int32()
-                external int len;
-                external Pointer<Uint8> data;
-
-                //ForeignBytes({required this.len, required this.data});
-
-                // factory ForeignBytes.fromTypedData(Uint8List typedData) {
-                //   final data = calloc<Uint8>(typedData.length);
-                //   final dataList = data.asTypedList(typedData.length);
-                //   dataList.setAll(0, typedData);
-                //   return ForeignBytes(len: typedData.length, data: data);
-                // }
-
-                void free() {
-                calloc.free(data);
-                }
-            }
-
-            class LiftRetVal<T> {
-                final T value;
-                final int bytesRead;
-                const LiftRetVal(this.value, this.bytesRead);
-
-                LiftRetVal<T> copyWithOffset(int offset) {
-                    return LiftRetVal(value, bytesRead + offset);
-                }
-            }
-
-            abstract class FfiConverter<D, F> {
-                const FfiConverter();
-
-                D lift(F value);
-                F lower(D value);
-                D read(ByteData buffer, int offset);
-                void write(D value, ByteData buffer, int offset);
-                int size(D value);
-            }
-
-            mixin FfiConverterPrimitive<T> on FfiConverter<T, T> {
-                @override
-                T lift(T value) => value;
-
-                @override
-                T lower(T value) => value;
-            }
-
-            Uint8List createUint8ListFromInt(int value) {
-                int length = value.bitLength ~/ 8 + 1;
-
-                // Ensure the length is either 4 or 8
-                if (length != 4 && length != 8) {
-                length = (value < 0x100000000) ? 4 : 8;
-                }
-
-                Uint8List uint8List = Uint8List(length);
-
-                for (int i = length - 1; i >= 0; i--) {
-                uint8List[i] = value & 0xFF;
-                value >>= 8;
-                }
-
-                return uint8List;
+                return rustCall((status) => $(DartCodeOracle::ffi_function_name(self.ci.ffi_rustbuffer_from_bytes().name()))(bytes.ref, status));
             }
 
             $(helpers_definitions)
 
-            const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
-            const int UNIFFI_RUST_FUTURE_POLL_MAYBE_READY = 1;
-
-            typedef UniffiRustFutureContinuationCallback = Void Function(Uint64, Int8);
-
             final _uniffiRustFutureContinuationHandles = UniffiHandleMap<Completer<int>>();
 
             Future<T> uniffiRustCallAsync<T, F>(
@@ -386,6 +301,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 T Function(F) liftFunc, [
                 UniffiRustCallStatusErrorHandler? errorHandler,
             ]) async {
+                $(&ensure_initialized)
                 final rustFuture = rustFutureFunc();
                 final completer = Completer<int>();
                 final handle = _uniffiRustFutureContinuationHandles.insert(completer);
@@ -402,13 +318,13 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
 
                 void onResponse(int data, int pollResult) {
-                    if (pollResult == UNIFFI_RUST_FUTURE_POLL_READY) {
+                    if (pollResult == uniffiRustFuturePollReady) {
                         final readyCompleter =
                             _uniffiRustFutureContinuationHandles.maybeRemove(data);
                         if (readyCompleter != null && !readyCompleter.isCompleted) {
                             readyCompleter.complete(pollResult);
                         }
-                    } else if (pollResult == UNIFFI_RUST_FUTURE_POLL_MAYBE_READY) {
+                    } else if (pollResult == uniffiRustFuturePollMaybeReady) {
                         repoll();
                     } else {
                         final errorCompleter =
@@ -416,7 +332,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                         if (errorCompleter != null && !errorCompleter.isCompleted) {
                             errorCompleter.completeError(
                                 UniffiInternalError.panicked(
-                                    "Unexpected poll result from Rust future: $pollResult",
+                                    $[str](Unexpected poll result from Rust future: $pollResult),
                                 ),
                             );
                         }
@@ -449,74 +365,60 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
             }
 
-            typedef UniffiForeignFutureFree = Void Function(Uint64);
-            typedef UniffiForeignFutureFreeDart = void Function(int);
-
-            class _UniffiForeignFutureState {
+            class UniffiForeignFutureState {
                 bool cancelled = false;
             }
 
-            final _uniffiForeignFutureHandleMap = UniffiHandleMap<_UniffiForeignFutureState>();
+            final uniffiForeignFutureHandleMap = UniffiHandleMap<UniffiForeignFutureState>();
 
             void _uniffiForeignFutureFree(int handle) {
-                final state = _uniffiForeignFutureHandleMap.maybeRemove(handle);
+                final state = uniffiForeignFutureHandleMap.maybeRemove(handle);
                 if (state != null) {
                     state.cancelled = true;
                 }
             }
 
             final Pointer<NativeFunction<UniffiForeignFutureFree>>
-                _uniffiForeignFutureFreePointer =
+                uniffiForeignFutureFreePointer =
                     Pointer.fromFunction<UniffiForeignFutureFree>(_uniffiForeignFutureFree);
 
-            final class UniffiForeignFuture extends Struct {
-                @Uint64()
-                external int handle;
-
-                external Pointer<NativeFunction<UniffiForeignFutureFree>> free;
-            }
+            $(for definition in self.support_definitions.borrow().iter() => $definition)
+        };
 
-            // As of uniffi 0.30, foreign handles must always have the lowest bit set
-            // This is achieved here with an odd number sequence.
-            class UniffiHandleMap<T> {
-                final Map<int, T> _map = {};
-                int _counter = 1;
-
-                int insert(T obj) {
-                final handle = _counter;
-                _counter += 2;
-                _map[handle] = obj;
-                return handle;
-                }
+        RenderedBindings {
+            imports,
+            runtime_export,
+            type_definitions,
+            helpers,
+            functions: function_definitions,
+        }
+    }
+}
 
-                T get(int handle) {
-                final obj = _map[handle];
-                if (obj == null) {
-                    throw UniffiInternalError(
-                        UniffiInternalError.unexpectedStaleHandle, "Handle not found");
-                }
-                return obj;
-                }
+impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
+    fn render(&self) -> (dart::Tokens, dart::Tokens) {
+        let RenderedBindings {
+            imports,
+            runtime_export,
+            type_definitions,
+            helpers,
+            functions,
+        } = self.render_parts();
 
-                void remove(int handle) {
-                if (maybeRemove(handle) == null) {
-                    throw UniffiInternalError(
-                        UniffiInternalError.unexpectedStaleHandle, "Handle not found");
-                }
-                }
+        let types_helper_code = quote! {
+            $imports
+            $runtime_export
 
-                T? maybeRemove(int handle) {
-                return _map.remove(handle);
-                }
-            }
+            $(for (_, definition) in type_definitions => $definition)
 
+            $helpers
         };
 
-        (types_helper_code, function_definitions)
+        (types_helper_code, functions)
     }
 }
 
-pub fn generate_type(ty: &Type) -> dart::Tokens {
+pub fn generate_type(names: &Names, ty: &Type) -> dart::Tokens {
     match ty {
         Type::UInt8
         | Type::UInt32
@@ -528,19 +430,22 @@ pub fn generate_type(ty: &Type) -> dart::Tokens {
         | Type::UInt64 => quote!(int),
         Type::Float32 | Type::Float64 => quote!(double),
         Type::String => quote!(String),
-        Type::Bytes => quote!(Uint8List),
-        Type::Object { name, .. } => quote!($name),
+        Type::Bytes => quote!($(uses([DART_TYPED_DATA]))Uint8List),
+        Type::Object { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
         Type::Boolean => quote!(bool),
-        Type::Optional { inner_type } => quote!($(generate_type(inner_type))?),
-        Type::Sequence { inner_type } => quote!(List<$(generate_type(inner_type))>),
+        Type::Optional { inner_type } => quote!($(generate_type(names, inner_type))?),
+        Type::Sequence { inner_type } => quote!(List<$(generate_type(names, inner_type))>),
         Type::Map {
             key_type,
             value_type,
-        } => quote!(Map<$(generate_type(key_type)), $(generate_type(value_type))>),
-        Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
+        } => quote!(Map<$(generate_type(names, key_type)), $(generate_type(names, value_type))>),
+        Type::Enum { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
         Type::Duration => quote!(Duration),
-        Type::Record { name, .. } => quote!($name),
-        Type::Custom { name, .. } => quote!($name),
-        _ => todo!("Type::{:?}", ty),
+        Type::Record { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
+        Type::Custom { name, .. } => quote!($(DartCodeOracle::type_name(names, name))),
+        Type::CallbackInterface { name, .. } => {
+            quote!($(DartCodeOracle::type_name(names, name)))
+        }
+        _ => unreachable!("Unsupported Type::{:?}", ty),
     }
 }
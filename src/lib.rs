@@ -1,9 +1,15 @@
 #[cfg(feature = "build")]
 mod build;
+#[doc(hidden)]
+pub mod dispatch;
+#[cfg(feature = "logging")]
+pub mod logging;
 #[cfg(feature = "bindgen-tests")]
 pub mod testing;
 #[cfg(feature = "build")]
-pub use build::generate_scaffolding;
+pub use build::{
+    generate_dependency_bindings, generate_dependency_bindings_from_library, generate_scaffolding,
+};
 
 pub mod gen;
 
@@ -0,0 +1,186 @@
+//! Forwarding of Rust `log` records to Dart.
+//!
+//! Call [`export_logging!`](crate::export_logging) once in the crate that builds the cdylib. It
+//! exports a `DartLogSink` callback interface together with `install_dart_log_sink` and
+//! `uninstall_dart_log_sink`, and the generated Dart bindings expose the records as a
+//! `rustLogRecords()` stream.
+//!
+//! `tracing` events are forwarded as well when the `tracing` crate's `log` feature is enabled
+//! and no other subscriber is installed.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+pub use log;
+
+type Sink = Arc<dyn Fn(&log::Record<'_>) + Send + Sync>;
+
+static SINK: RwLock<Option<Sink>> = RwLock::new(None);
+static LOGGER_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+struct Forwarder;
+
+static FORWARDER: Forwarder = Forwarder;
+
+impl log::Log for Forwarder {
+    fn enabled(&self, _metadata: &log::Metadata<'_>) -> bool {
+        SINK.read().map(|sink| sink.is_some()).unwrap_or(false)
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        // Clone the sink so it isn't called with the lock held.
+        let sink = SINK.read().ok().and_then(|sink| sink.clone());
+        if let Some(sink) = sink {
+            sink(record);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Routes every `log` record up to `max_level` to `sink`, replacing any previously set sink.
+///
+/// The forwarding logger is registered with the `log` crate on first use; this fails if the
+/// application already installed a different logger.
+pub fn set_sink(
+    sink: impl Fn(&log::Record<'_>) + Send + Sync + 'static,
+    max_level: log::LevelFilter,
+) -> Result<(), log::SetLoggerError> {
+    // Holding the write lock serializes concurrent first-time installs.
+    let mut current = SINK.write().unwrap_or_else(|e| e.into_inner());
+    if !LOGGER_INSTALLED.load(Ordering::Acquire) {
+        log::set_logger(&FORWARDER)?;
+        LOGGER_INSTALLED.store(true, Ordering::Release);
+    }
+
+    *current = Some(Arc::new(sink));
+    log::set_max_level(max_level);
+    Ok(())
+}
+
+/// Stops forwarding records. The logger stays registered but drops everything.
+pub fn clear_sink() {
+    *SINK.write().unwrap_or_else(|e| e.into_inner()) = None;
+    log::set_max_level(log::LevelFilter::Off);
+}
+
+/// Collects the structured key-value pairs of a record, formatted with `Display`.
+pub fn record_fields(record: &log::Record<'_>) -> HashMap<String, String> {
+    struct Collector(HashMap<String, String>);
+
+    impl<'kvs> log::kv::VisitSource<'kvs> for Collector {
+        fn visit_pair(
+            &mut self,
+            key: log::kv::Key<'kvs>,
+            value: log::kv::Value<'kvs>,
+        ) -> Result<(), log::kv::Error> {
+            self.0.insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    let mut collector = Collector(HashMap::new());
+    let _ = record.key_values().visit(&mut collector);
+    collector.0
+}
+
+/// Exports the types and functions the Dart log forwarding glue is generated from.
+///
+/// Invoke it once at the crate root, next to `uniffi::setup_scaffolding!()` or
+/// `uniffi::include_scaffolding!()`.
+#[macro_export]
+macro_rules! export_logging {
+    () => {
+        #[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum DartLogLevel {
+            Error,
+            Warn,
+            Info,
+            Debug,
+            Trace,
+        }
+
+        impl From<$crate::logging::log::Level> for DartLogLevel {
+            fn from(level: $crate::logging::log::Level) -> Self {
+                match level {
+                    $crate::logging::log::Level::Error => Self::Error,
+                    $crate::logging::log::Level::Warn => Self::Warn,
+                    $crate::logging::log::Level::Info => Self::Info,
+                    $crate::logging::log::Level::Debug => Self::Debug,
+                    $crate::logging::log::Level::Trace => Self::Trace,
+                }
+            }
+        }
+
+        impl From<DartLogLevel> for $crate::logging::log::LevelFilter {
+            fn from(level: DartLogLevel) -> Self {
+                match level {
+                    DartLogLevel::Error => Self::Error,
+                    DartLogLevel::Warn => Self::Warn,
+                    DartLogLevel::Info => Self::Info,
+                    DartLogLevel::Debug => Self::Debug,
+                    DartLogLevel::Trace => Self::Trace,
+                }
+            }
+        }
+
+        /// Why log records can't be forwarded to Dart.
+        #[derive(uniffi::Error, Debug)]
+        pub enum DartLogError {
+            /// The application registered a different `log` logger before.
+            LoggerAlreadyInstalled,
+        }
+
+        impl ::std::fmt::Display for DartLogError {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str("another `log` logger is already installed")
+            }
+        }
+
+        impl ::std::error::Error for DartLogError {}
+
+        #[derive(uniffi::Record, Clone, Debug)]
+        pub struct DartLogRecord {
+            pub level: DartLogLevel,
+            pub target: String,
+            pub message: String,
+            pub fields: ::std::collections::HashMap<String, String>,
+        }
+
+        /// Receives forwarded log records. Records may be emitted from any Rust thread, so the
+        /// Dart implementation is always dispatched through the isolate's event loop.
+        #[$crate::thread_safe]
+        #[uniffi::export(with_foreign)]
+        pub trait DartLogSink: Send + Sync {
+            fn log(&self, record: DartLogRecord);
+        }
+
+        /// Forwards `log` records up to `max_level` to `sink`, replacing any previous sink. Fails
+        /// if the application registered a different `log` logger.
+        #[uniffi::export]
+        pub fn install_dart_log_sink(
+            sink: ::std::sync::Arc<dyn DartLogSink>,
+            max_level: DartLogLevel,
+        ) -> ::std::result::Result<(), DartLogError> {
+            $crate::logging::set_sink(
+                move |record| {
+                    sink.log(DartLogRecord {
+                        level: record.level().into(),
+                        target: record.target().to_string(),
+                        message: record.args().to_string(),
+                        fields: $crate::logging::record_fields(record),
+                    })
+                },
+                max_level.into(),
+            )
+            .map_err(|_| DartLogError::LoggerAlreadyInstalled)
+        }
+
+        /// Stops forwarding log records to Dart.
+        #[uniffi::export]
+        pub fn uninstall_dart_log_sink() {
+            $crate::logging::clear_sink();
+        }
+    };
+}
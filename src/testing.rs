@@ -2,11 +2,13 @@ use crate::gen;
 use anyhow::{bail, Result};
 use camino::{Utf8Path, Utf8PathBuf};
 use camino_tempfile::tempdir;
-use std::fs::{copy, create_dir_all, File};
-use std::io::Write;
+use std::collections::BTreeMap;
+use std::fs::{copy, create_dir_all};
 use std::process::Command;
 use std::thread;
 use std::time::Duration;
+use uniffi_bindgen::cargo_metadata::CrateConfigSupplier;
+use uniffi_bindgen::{macro_metadata, ComponentInterface};
 use uniffi_testing::UniFFITestHelper;
 
 // A source to compile for a test
@@ -16,6 +18,99 @@ pub struct CompileSource {
     pub config_path: Option<Utf8PathBuf>,
 }
 
+/// What a test generates bindings from
+enum Source {
+    /// A UDL file, with an optional config file overriding the crate's `uniffi.toml`
+    Udl {
+        udl_path: Utf8PathBuf,
+        config_path: Option<Utf8PathBuf>,
+    },
+    /// The metadata in the fixture's cdylib, covering every crate linked into it. Each crate is
+    /// configured by its own `uniffi.toml`.
+    Library,
+}
+
+impl Source {
+    /// A UDL source, with paths relative to the fixture directory
+    fn udl(udl_path: &str, config_path: Option<&str>) -> Result<Self> {
+        let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+        Ok(Self::Udl {
+            udl_path: script_path.join(udl_path),
+            config_path: config_path.map(|path| script_path.join(path)),
+        })
+    }
+
+    /// The config the fixture's bindings are generated with
+    fn config(&self, test_helper: &UniFFITestHelper) -> Result<gen::Config> {
+        let config_path = match self {
+            Self::Udl { config_path, .. } => config_path.as_deref(),
+            Self::Library => None,
+        };
+        let cdylib_path = test_helper.cdylib_path()?;
+        let Some(cdylib_name) = uniffi_bindgen::library_mode::calc_cdylib_name(&cdylib_path) else {
+            bail!("`{cdylib_path}` isn't a cdylib");
+        };
+        gen::load_config(
+            &Utf8Path::new(".").canonicalize_utf8()?,
+            config_path,
+            cdylib_name,
+        )
+    }
+
+    fn generate(
+        &self,
+        test_helper: &UniFFITestHelper,
+        out_dir: &Utf8Path,
+        try_format_code: bool,
+    ) -> Result<()> {
+        let cdylib_path = test_helper.cdylib_path()?;
+        match self {
+            Self::Udl {
+                udl_path,
+                config_path,
+            } => uniffi_bindgen::generate_external_bindings(
+                &gen::DartBindingGenerator,
+                udl_path,
+                config_path.as_deref(),
+                Some(out_dir),
+                Some(&cdylib_path),
+                None,
+                try_format_code,
+            ),
+            Self::Library => gen::generate_library_bindings(
+                &cdylib_path,
+                None, // every crate in the component graph
+                None,
+                Some(out_dir),
+                try_format_code,
+            ),
+        }
+    }
+
+    /// The generated files, unformatted, generated in memory.
+    fn sources(&self, test_helper: &UniFFITestHelper) -> Result<BTreeMap<Utf8PathBuf, Vec<u8>>> {
+        match self {
+            Self::Udl { udl_path, .. } => {
+                // The UDL items, plus any exported with proc-macros, as `uniffi-bindgen` collects them
+                let udl = std::fs::read_to_string(udl_path)?;
+                let mut ci = ComponentInterface::from_webidl(&udl, test_helper.crate_name())?;
+                macro_metadata::add_to_ci_from_library(&mut ci, &test_helper.cdylib_path()?)?;
+                gen::generate_sources(&ci, &self.config(test_helper)?)
+            }
+            Self::Library => gen::generate_library_sources(
+                &test_helper.cdylib_path()?,
+                &CrateConfigSupplier::from(test_helper.cargo_metadata()),
+            ),
+        }
+        .map(|sources| {
+            sources
+                .into_iter()
+                .map(|(path, source)| (path, source.into_bytes()))
+                .collect()
+        })
+    }
+}
+
 /// Test execution options
 #[derive(Debug, Clone, Default)]
 pub struct TestConfig {
@@ -83,7 +178,18 @@ pub fn run_test_with_config(
     config_path: Option<&str>,
     test_config: &TestConfig,
 ) -> Result<()> {
-    run_test_impl(fixture, udl_path, config_path, test_config)
+    run_test_impl(fixture, &Source::udl(udl_path, config_path)?, test_config)
+}
+
+/// Run a test of a fixture without a UDL file, generating bindings from the metadata in its
+/// cdylib (default options, env vars honored)
+pub fn run_test_library_mode(fixture: &str) -> Result<()> {
+    run_test_library_mode_with_config(fixture, &TestConfig::from_env())
+}
+
+/// Run a library mode test with explicit configuration
+pub fn run_test_library_mode_with_config(fixture: &str, test_config: &TestConfig) -> Result<()> {
+    run_test_impl(fixture, &Source::Library, test_config)
 }
 
 /// Run a test with an explicit output directory (convenience wrapper)
@@ -97,16 +203,11 @@ pub fn run_test_with_output_dir(
         custom_output_dir: custom_output_dir.map(|p| p.to_owned()),
         ..Default::default()
     };
-    run_test_impl(fixture, udl_path, config_path, &config)
+    run_test_impl(fixture, &Source::udl(udl_path, config_path)?, &config)
 }
 
 /// Test execution (core implementation)
-fn run_test_impl(
-    fixture: &str,
-    udl_path: &str,
-    config_path: Option<&str>,
-    test_config: &TestConfig,
-) -> Result<()> {
+fn run_test_impl(fixture: &str, source: &Source, test_config: &TestConfig) -> Result<()> {
     // Resolve project root (cargo may change CWD when running tests)
     let project_root = find_project_root()?;
 
@@ -137,124 +238,34 @@ fn run_test_impl(
         out_dir
     };
 
-    let udl_path = Utf8Path::new(".").canonicalize_utf8()?.join(udl_path);
-    let config_path = if let Some(path) = config_path {
-        Some(Utf8Path::new(".").canonicalize_utf8()?.join(path))
-    } else {
-        None
-    };
-
     println!("{out_dir}");
 
     if test_config.no_delete {
         println!("Test files will be preserved after completion (no-delete mode)");
     }
 
-    // Get package name from config (default to "uniffi")
-    let package_name = if let Some(config_path) = config_path.as_deref() {
-        // Try to read package name from config
-        if let Ok(config_content) = std::fs::read_to_string(config_path) {
-            if let Ok(config_toml) = toml::from_str::<toml::Value>(&config_content) {
-                config_toml
-                    .get("bindings")
-                    .and_then(|b| b.get("dart"))
-                    .and_then(|d| d.get("package_name"))
-                    .and_then(|p| p.as_str())
-                    .map(|s| s.to_string())
-                    .unwrap_or_else(|| "uniffi".to_string())
-            } else {
-                "uniffi".to_string()
-            }
-        } else {
-            "uniffi".to_string()
-        }
-    } else {
-        "uniffi".to_string()
-    };
-
-    let mut pubspec = File::create(out_dir.join("pubspec.yaml"))?;
-    pubspec.write_all(
-        format!(
-            r#"
-name: {}
-description: testing module for uniffi
-version: 1.0.0
-
-environment:
-  sdk: '>=3.10.0'
-dev_dependencies:
-  test: ^1.24.3
-dependencies:
-  ffi: ^2.0.1
-  code_assets: any
-  hooks: any
-"#,
-            package_name
-        )
-        .as_bytes(),
-    )?;
-    pubspec.flush()?;
     let test_outdir = out_dir.join("test");
     create_dir_all(&test_outdir)?;
 
-    // Create hook directory for Native Assets
-    let hook_dir = out_dir.join("hook");
-    create_dir_all(&hook_dir)?;
-
-    // Copy cdylib and get its path
-    test_helper.copy_cdylib_to_out_dir(&out_dir)?;
-    let cdylib_path = test_helper.cdylib_path()?;
-    let cdylib_filename = cdylib_path.file_name().unwrap();
-
-    // Get the asset ID (should match what's in generated Dart code)
-    // Format: "uniffi:{cdylib_name}" (without package prefix - that's added by Dart automatically)
-    let cdylib_stem = cdylib_path.file_stem().unwrap().trim_start_matches("lib"); // Remove "lib" prefix on Unix
-
-    // Create Native Assets build hook using modern hooks package
-    let mut build_hook = File::create(hook_dir.join("build.dart"))?;
-    build_hook.write_all(
+    // Copy cdylib next to the pubspec, where the build hook bundles it from
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(&out_dir)?;
+    let package = gen::Package::new(&source.config(&test_helper)?, Vec::<String>::new())
+        .with_native_library(cdylib_path.file_name().unwrap())
+        .with_description("testing module for uniffi");
+    std::fs::write(out_dir.join("pubspec.yaml"), package.pubspec())?;
+    // Only the generated code is held to the lints, not the fixture's tests
+    std::fs::write(
+        out_dir.join("analysis_options.yaml"),
         format!(
-            r#"import 'dart:io';
-import 'package:code_assets/code_assets.dart';
-import 'package:hooks/hooks.dart';
-
-// Generated by uniffi-dart – this is the full asset ID that Dart will use
-// Dart automatically prefixes the 'name' with 'package:$packageName/', so we construct the full ID here
-String _makeAssetId(String packageName) => 'package:$packageName/uniffi:{}';
-
-void main(List<String> args) async {{
-  await build(args, (input, output) async {{
-    // The native library is in the package root (copied by test infrastructure)
-    final libPath = input.packageRoot.resolve('{}');
-
-    // Register the asset - Dart will automatically prefix the name with package:$packageName/
-    // So if name is "uniffi:hello_world", the full ID becomes "package:uniffi/uniffi:hello_world"
-    output.assets.code.add(
-      CodeAsset(
-        package: input.packageName,
-        name: 'uniffi:{}',  // Dart prefixes this with "package:$packageName/"
-        linkMode: DynamicLoadingBundled(),
-        file: libPath,
-      ),
-    );
-
-    // Verify the asset ID matches what's in generated code
-    print('Registered asset: ${{_makeAssetId(input.packageName)}}');
-  }});
-}}
-"#,
-            cdylib_stem, cdylib_filename, cdylib_stem
-        )
-        .as_bytes(),
-    )?;
-    build_hook.flush()?;
-    gen::generate_dart_bindings(
-        &udl_path,
-        config_path.as_deref(),
-        Some(&out_dir),
-        &test_helper.cdylib_path()?,
-        false, // library_mode
+            "{}analyzer:\n  exclude:\n    - test/**\n",
+            package.analysis_options()
+        ),
     )?;
+    if let Some(build_hook) = package.build_hook() {
+        create_dir_all(out_dir.join("hook"))?;
+        std::fs::write(out_dir.join("hook/build.dart"), build_hook)?;
+    }
+    source.generate(&test_helper, &out_dir, true)?;
 
     // Copy fixture test files to output directory
     let test_glob_pattern = "test/*.dart";
@@ -280,6 +291,25 @@ void main(List<String> args) async {{
         }
     }
 
+    // The generated code must be free of analyzer diagnostics, infos included. Unlike
+    // `dart test`, `dart analyze` doesn't resolve the dependencies itself
+    let mut pub_get_command = Command::new("dart");
+    pub_get_command.current_dir(&out_dir).arg("pub").arg("get");
+    if !pub_get_command.spawn()?.wait()?.success() {
+        bail!("running `dart pub get` failed ({:?})", pub_get_command);
+    }
+    let mut analyze_command = Command::new("dart");
+    analyze_command
+        .current_dir(&out_dir)
+        .arg("analyze")
+        .arg("--fatal-infos");
+    if !analyze_command.spawn()?.wait()?.success() {
+        bail!(
+            "running `dart` to analyze the generated bindings failed ({:?})",
+            analyze_command
+        );
+    }
+
     // Run tests
     let mut command = Command::new("dart");
     command.current_dir(&out_dir).arg("test");
@@ -299,6 +329,329 @@ void main(List<String> args) async {{
     Ok(())
 }
 
+/// Generate a fixture's bindings twice and fail if any generated file differs between the runs.
+///
+/// Catches output that depends on hash iteration order, which makes checked-in bindings churn.
+pub fn assert_deterministic_output(
+    fixture: &str,
+    udl_path: &str,
+    config_path: Option<&str>,
+) -> Result<()> {
+    assert_deterministic_output_impl(fixture, &Source::udl(udl_path, config_path)?)
+}
+
+/// [`assert_deterministic_output`] for a fixture without a UDL file.
+pub fn assert_deterministic_output_library_mode(fixture: &str) -> Result<()> {
+    assert_deterministic_output_impl(fixture, &Source::Library)
+}
+
+fn assert_deterministic_output_impl(fixture: &str, source: &Source) -> Result<()> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+
+    let mut runs = Vec::new();
+    for _ in 0..2 {
+        let out_dir = tempdir()?;
+        source.generate(&test_helper, out_dir.path(), true)?;
+        runs.push(read_generated_files(out_dir.path())?);
+    }
+
+    let (first, second) = (&runs[0], &runs[1]);
+    if first.keys().ne(second.keys()) {
+        bail!(
+            "generated files differ between runs: {:?} vs {:?}",
+            first.keys().collect::<Vec<_>>(),
+            second.keys().collect::<Vec<_>>()
+        );
+    }
+    for (path, contents) in first {
+        if second[path] != *contents {
+            bail!("generated `{path}` differs between runs");
+        }
+    }
+    Ok(())
+}
+
+/// Generate a fixture's bindings and fail unless generation is rejected with an error mentioning
+/// each of `expected`.
+pub fn assert_generation_fails(
+    fixture: &str,
+    udl_path: &str,
+    config_path: Option<&str>,
+    expected: &[&str],
+) -> Result<()> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+    let udl_path = script_path.join(udl_path);
+    let config_path = config_path.map(|path| script_path.join(path));
+    let out_dir = tempdir()?;
+
+    let result = gen::generate_dart_bindings(
+        &udl_path,
+        config_path.as_deref(),
+        Some(out_dir.path()),
+        &test_helper.cdylib_path()?,
+        false, // library_mode
+    );
+    expect_generation_error(fixture, result, expected)
+}
+
+/// [`assert_generation_fails`] for a fixture without a UDL file, with `config_path` merged over
+/// its `uniffi.toml`.
+pub fn assert_generation_fails_library_mode(
+    fixture: &str,
+    config_path: &str,
+    expected: &[&str],
+) -> Result<()> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let config_path = Utf8Path::new(".").canonicalize_utf8()?.join(config_path);
+    let out_dir = tempdir()?;
+
+    let result = gen::generate_library_bindings(
+        &test_helper.cdylib_path()?,
+        None,
+        Some(&config_path),
+        Some(out_dir.path()),
+        false,
+    );
+    expect_generation_error(fixture, result, expected)
+}
+
+fn expect_generation_error(fixture: &str, result: Result<()>, expected: &[&str]) -> Result<()> {
+    let Err(error) = result else {
+        bail!("generating bindings for `{fixture}` unexpectedly succeeded");
+    };
+    let message = format!("{error:#}");
+    for expected in expected {
+        if !message.contains(expected) {
+            bail!("expected the error to mention {expected:?}, got:\n{message}");
+        }
+    }
+    Ok(())
+}
+
+/// Generate a fixture's bindings in memory, keyed by path relative to the output directory, for
+/// tests asserting on the generated code.
+pub fn generated_sources(
+    fixture: &str,
+    udl_path: &str,
+    config_path: Option<&str>,
+) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    generated_sources_impl(fixture, &Source::udl(udl_path, config_path)?)
+}
+
+/// [`generated_sources`] for a fixture without a UDL file.
+pub fn generated_sources_library_mode(fixture: &str) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    generated_sources_impl(fixture, &Source::Library)
+}
+
+/// Write the bindings of a fixture without a UDL file with [`gen::generate_library_bindings`],
+/// limited to `crates` and with `config_path` merged over each crate's `uniffi.toml`, and read
+/// them back keyed by path relative to the output directory.
+pub fn written_sources_library_mode(
+    fixture: &str,
+    crates: Option<&[&str]>,
+    config_path: Option<&str>,
+) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+    let config_path = config_path.map(|path| script_path.join(path));
+    let out_dir = tempdir()?;
+
+    gen::generate_library_bindings(
+        &test_helper.cdylib_path()?,
+        crates,
+        config_path.as_deref(),
+        Some(out_dir.path()),
+        false,
+    )?;
+    read_generated_sources(out_dir.path())
+}
+
+/// [`written_sources_library_mode`] without an output directory: the bindings are written next
+/// to a copy of the fixture's cdylib and read back relative to its directory.
+pub fn written_sources_next_to_cdylib(fixture: &str) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let cdylib_dir = tempdir()?;
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(cdylib_dir.path())?;
+
+    gen::generate_library_bindings(&cdylib_path, None, None, None, false)?;
+    read_generated_sources(cdylib_dir.path())
+}
+
+fn read_generated_sources(out_dir: &Utf8Path) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    read_generated_files(out_dir)?
+        .into_iter()
+        .map(|(path, contents)| Ok((path, String::from_utf8(contents)?)))
+        .collect()
+}
+
+fn generated_sources_impl(fixture: &str, source: &Source) -> Result<BTreeMap<Utf8PathBuf, String>> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    source
+        .sources(&test_helper)?
+        .into_iter()
+        .map(|(path, contents)| Ok((path, String::from_utf8(contents)?)))
+        .collect()
+}
+
+/// Golden files of a fixture live in this directory, next to its `Cargo.toml`.
+const GOLDEN_DIR: &str = "golden";
+
+/// Golden files of the package scaffold generated around a fixture's bindings.
+const GOLDEN_PACKAGE_DIR: &str = "golden_package";
+
+/// Generate a fixture's bindings and compare them with the golden files committed in its
+/// `golden/` directory. Doesn't need a Dart SDK, since `dart format` isn't run on the output.
+///
+/// Env overrides:
+/// - UNIFFI_DART_UPDATE_SNAPSHOTS: (re)write the golden files instead of comparing
+pub fn assert_snapshot(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Result<()> {
+    assert_snapshot_impl(fixture, &Source::udl(udl_path, config_path)?)
+}
+
+/// [`assert_snapshot`] for a fixture without a UDL file.
+pub fn assert_snapshot_library_mode(fixture: &str) -> Result<()> {
+    assert_snapshot_impl(fixture, &Source::Library)
+}
+
+/// Compare bindings generated some other way (by a build script, for instance) with the golden
+/// files of `fixture`, like [`assert_snapshot`].
+pub fn assert_matches_golden(
+    fixture: &str,
+    generated: &BTreeMap<Utf8PathBuf, String>,
+) -> Result<()> {
+    let generated = generated
+        .iter()
+        .map(|(path, contents)| (path.clone(), contents.clone().into_bytes()))
+        .collect();
+    compare_with_golden(fixture, GOLDEN_DIR, &generated)
+}
+
+/// Generate a package around the bindings of a fixture without a UDL file with
+/// [`gen::generate_package`] and compare its scaffold with the golden files in the fixture's
+/// `golden_package/` directory, like [`assert_snapshot`]. The bindings in `lib/` are covered by
+/// [`assert_snapshot_library_mode`] and the bundled native library isn't compared.
+pub fn assert_package_snapshot(fixture: &str) -> Result<()> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let package_dir = tempdir()?;
+    let package = gen::generate_package(&test_helper.cdylib_path()?, None, package_dir.path())?;
+
+    let scaffold = package
+        .files()
+        .into_keys()
+        .map(|path| {
+            let contents = std::fs::read(package_dir.path().join(&path))?;
+            Ok((path, contents))
+        })
+        .collect::<Result<_>>()?;
+    compare_with_golden(fixture, GOLDEN_PACKAGE_DIR, &scaffold)
+}
+
+fn assert_snapshot_impl(fixture: &str, source: &Source) -> Result<()> {
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    compare_with_golden(fixture, GOLDEN_DIR, &source.sources(&test_helper)?)
+}
+
+fn compare_with_golden(
+    fixture: &str,
+    golden_dir: &str,
+    generated: &BTreeMap<Utf8PathBuf, Vec<u8>>,
+) -> Result<()> {
+    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+    let golden_dir = script_path.join(golden_dir);
+    if std::env::var("UNIFFI_DART_UPDATE_SNAPSHOTS").is_ok() {
+        if golden_dir.exists() {
+            std::fs::remove_dir_all(&golden_dir)?;
+        }
+        for (path, contents) in generated {
+            let path = golden_dir.join(path);
+            if let Some(parent) = path.parent() {
+                create_dir_all(parent)?;
+            }
+            std::fs::write(path, contents)?;
+        }
+        return Ok(());
+    }
+
+    let golden = if golden_dir.exists() {
+        read_files(&golden_dir, "**/*")?
+    } else {
+        BTreeMap::new()
+    };
+    let mut mismatches = Vec::new();
+    for (path, contents) in generated {
+        match golden.get(path) {
+            None => mismatches.push(format!("`{path}` has no golden file")),
+            Some(expected) if expected != contents => mismatches.push(format!(
+                "`{path}` differs from its golden file at {}",
+                describe_difference(expected, contents)
+            )),
+            Some(_) => {}
+        }
+    }
+    for path in golden.keys().filter(|path| !generated.contains_key(*path)) {
+        mismatches.push(format!("`{path}` is no longer generated"));
+    }
+    if !mismatches.is_empty() {
+        bail!(
+            "bindings of `{fixture}` don't match `{golden_dir}` (rerun with UNIFFI_DART_UPDATE_SNAPSHOTS=1 to accept the changes):\n  {}",
+            mismatches.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// Where `generated` first departs from `expected`, with a little of the text around it, since
+/// most of the generated code ends up on a single line.
+fn describe_difference(expected: &[u8], generated: &[u8]) -> String {
+    let offset = expected
+        .iter()
+        .zip(generated)
+        .position(|(a, b)| a != b)
+        .unwrap_or(expected.len().min(generated.len()));
+    let line_start = expected[..offset]
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index + 1);
+    let line = expected[..offset]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1;
+    let column = offset - line_start + 1;
+    let excerpt = |bytes: &[u8]| {
+        let end = (offset + 40).min(bytes.len());
+        let start = offset.saturating_sub(40).min(end);
+        String::from_utf8_lossy(&bytes[start..end]).into_owned()
+    };
+    format!(
+        "line {line}, column {column}: expected `{}`, generated `{}`",
+        excerpt(expected),
+        excerpt(generated)
+    )
+}
+
+/// Reads every generated Dart file below `out_dir`, keyed by its relative path.
+fn read_generated_files(out_dir: &Utf8Path) -> Result<BTreeMap<Utf8PathBuf, Vec<u8>>> {
+    read_files(out_dir, "**/*.dart")
+}
+
+/// The files below `dir` matching `pattern`, keyed by path relative to `dir`.
+fn read_files(dir: &Utf8Path, pattern: &str) -> Result<BTreeMap<Utf8PathBuf, Vec<u8>>> {
+    let mut files = BTreeMap::new();
+    for file in glob::glob(dir.join(pattern).as_str())?.filter_map(Result::ok) {
+        let Some(file) = Utf8Path::from_path(&file) else {
+            bail!("non-UTF8 path {}", file.display());
+        };
+        if !file.is_file() {
+            continue;
+        }
+        let relative = file.strip_prefix(dir)?.to_owned();
+        files.insert(relative, std::fs::read(file)?);
+    }
+    Ok(files)
+}
+
 /// Locate the workspace root:
 /// - CARGO_WORKSPACE_ROOT if set
 /// - ascend until a Cargo.toml with [workspace]
@@ -335,6 +688,54 @@ fn find_project_root() -> Result<Utf8PathBuf> {
         .map(|p| p.to_owned())
 }
 
+/// The UDL files of the crate in the current directory and of the local crates it depends on,
+/// each with the crate's `uniffi.toml` if it has one.
+///
+/// Proc-macro only crates have no UDL file and contribute nothing; library mode picks their
+/// items up from the cdylib instead.
 pub fn get_compile_sources() -> Result<Vec<CompileSource>> {
-    todo!("Not implemented")
+    let metadata = cargo_metadata::MetadataCommand::new().exec()?;
+    let manifest_path = Utf8Path::new(".").canonicalize_utf8()?.join("Cargo.toml");
+    let Some(root) = metadata
+        .packages
+        .iter()
+        .find(|package| package.manifest_path == manifest_path)
+    else {
+        bail!("no package found for `{manifest_path}`");
+    };
+    let Some(resolve) = &metadata.resolve else {
+        bail!("cargo metadata has no dependency graph");
+    };
+
+    // Walk the dependency graph, staying within crates that live on disk next to this one
+    let mut seen = std::collections::BTreeSet::new();
+    let mut pending = vec![&root.id];
+    let mut sources = Vec::new();
+    while let Some(id) = pending.pop() {
+        if !seen.insert(id) {
+            continue;
+        }
+        let package = &metadata[id];
+        if package.source.is_some() {
+            continue;
+        }
+        let crate_root = package
+            .manifest_path
+            .parent()
+            .expect("manifest has a parent");
+        let config_path = Some(crate_root.join("uniffi.toml")).filter(|path| path.exists());
+        for udl_path in glob::glob(crate_root.join("src/*.udl").as_str())?.filter_map(Result::ok) {
+            let Ok(udl_path) = Utf8PathBuf::from_path_buf(udl_path) else {
+                bail!("non-UTF8 UDL path in `{crate_root}`");
+            };
+            sources.push(CompileSource {
+                udl_path,
+                config_path: config_path.clone(),
+            });
+        }
+        if let Some(node) = resolve.nodes.iter().find(|node| node.id == *id) {
+            pending.extend(&node.dependencies);
+        }
+    }
+    Ok(sources)
 }
@@ -1,7 +1,10 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use stringcase::pascal_case;
-use syn::{parse::Parse, parse_macro_input, Ident, ItemFn, LitStr, Type};
+use syn::{
+    parse::Parse, parse_macro_input, parse_quote, FnArg, Ident, ImplItem, Item, ItemFn, ItemTrait,
+    LitStr, Pat, ReturnType, TraitItem, Type,
+};
 
 struct StreamAttr {
     item_type: Type,
@@ -95,3 +98,262 @@ pub fn export_stream(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Requests an additional `Future`-returning `...InBackground` Dart variant that runs the call on
+/// a helper isolate. Applies to an exported function, or to every method of an exported `impl`
+/// block. Must be placed above `#[uniffi::export]`: the request travels to the Dart generator as
+/// a docstring line in the exported metadata, left out of rustdoc builds.
+#[proc_macro_attribute]
+pub fn run_in_background(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`run_in_background` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let marker: syn::Attribute =
+        parse_quote!(#[cfg_attr(not(doc), doc = "uniffi-dart: run_in_background")]);
+    let mut item = parse_macro_input!(item as Item);
+    match &mut item {
+        Item::Fn(func) => func.attrs.push(marker),
+        Item::Impl(imp) => {
+            for impl_item in &mut imp.items {
+                if let ImplItem::Method(method) = impl_item {
+                    method.attrs.push(marker.clone());
+                }
+            }
+        }
+        other => {
+            return syn::Error::new_spanned(
+                other,
+                "`run_in_background` can only be applied to functions and impl blocks",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+
+    TokenStream::from(quote!(#item))
+}
+
+/// Lets Rust call the Dart implementations of a callback interface from any thread and isolate.
+/// Applies to a trait exported with `#[uniffi::export(callback_interface)]` or
+/// `#[uniffi::export(with_foreign)]`, or declared as a callback interface in UDL, and must be
+/// placed above the other attributes of the trait. Traits exported with the proc-macros are
+/// marked through a docstring line left out of rustdoc builds; UDL ones must also be listed in
+/// the `thread_safe_callbacks` config.
+///
+/// Several isolates can hand implementations to Rust at the same time, and each object's calls
+/// go to the isolate it came from. Calls from a thread other than that isolate's are posted to
+/// it, and wait for it to run them unless the method returns nothing and can't fail.
+#[proc_macro_attribute]
+pub fn thread_safe(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`thread_safe` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let mut item = parse_macro_input!(item as ItemTrait);
+    let dispatch = match thread_safe_dispatch(&item) {
+        Ok(dispatch) => dispatch,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    item.attrs
+        .push(parse_quote!(#[cfg_attr(not(doc), doc = "uniffi-dart: thread_safe")]));
+
+    TokenStream::from(quote! {
+        #item
+        #dispatch
+    })
+}
+
+/// The trampolines making up the vtable of a `thread_safe` trait, and the functions Dart
+/// registers its implementations, reserves handles and completes posted calls with.
+fn thread_safe_dispatch(item: &ItemTrait) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let prefix = format!(
+        "uniffi_dart_{crate_name}_{}",
+        item.ident.to_string().to_lowercase()
+    );
+    let register_fn = format_ident!("{prefix}_register");
+    let new_handle_fn = format_ident!("{prefix}_new_handle");
+    let signal_fn = format_ident!("{prefix}_signal");
+
+    let mut trampolines = Vec::new();
+    let mut trampoline_names = Vec::new();
+    for trait_item in &item.items {
+        let TraitItem::Method(method) = trait_item else {
+            continue;
+        };
+        let sig = &method.sig;
+        let mut arg_names = Vec::new();
+        let mut arg_types = Vec::new();
+        for input in &sig.inputs {
+            let FnArg::Typed(arg) = input else {
+                continue;
+            };
+            let Pat::Ident(pat) = &*arg.pat else {
+                return Err(syn::Error::new_spanned(
+                    &arg.pat,
+                    "`thread_safe` needs named arguments",
+                ));
+            };
+            let ty = &arg.ty;
+            arg_names.push(pat.ident.clone());
+            arg_types.push(quote!(<#ty as ::uniffi::Lift<crate::UniFfiTag>>::FfiType));
+        }
+        let return_type = match &sig.output {
+            ReturnType::Default => quote!(()),
+            ReturnType::Type(_, ty) => quote!(#ty),
+        };
+        let lift_return_type =
+            quote!(<#return_type as ::uniffi::LiftReturn<crate::UniFfiTag>>::ReturnType);
+        let (out_params, out_types, out_args) = if sig.asyncness.is_some() {
+            (
+                quote! {
+                    uniffi_callback: ::uniffi::ForeignFutureCallback<#lift_return_type>,
+                    uniffi_callback_data: u64,
+                    uniffi_out_dropped_callback: &mut ::uniffi::ForeignFutureDroppedCallbackStruct,
+                },
+                quote! {
+                    ::uniffi::ForeignFutureCallback<#lift_return_type>,
+                    u64,
+                    &mut ::uniffi::ForeignFutureDroppedCallbackStruct,
+                },
+                quote!(
+                    uniffi_callback,
+                    uniffi_callback_data,
+                    uniffi_out_dropped_callback,
+                ),
+            )
+        } else {
+            (
+                quote! {
+                    uniffi_out_return: &mut #lift_return_type,
+                    uniffi_out_call_status: &mut ::uniffi::RustCallStatus,
+                },
+                quote!(&mut #lift_return_type, &mut ::uniffi::RustCallStatus,),
+                quote!(uniffi_out_return, uniffi_out_call_status,),
+            )
+        };
+        // Without anything to hand back, the call is always queued without waiting for it
+        let queued = sig.asyncness.is_none()
+            && match &sig.output {
+                ReturnType::Default => true,
+                ReturnType::Type(_, ty) => {
+                    matches!(&**ty, Type::Tuple(unit) if unit.elems.is_empty())
+                }
+            };
+        // Slot 0 of the Dart functions is `free`
+        let slot = trampoline_names.len() + 1;
+        let name = format_ident!("uniffi_dispatch_{}", sig.ident);
+        if queued {
+            trampolines.push(quote! {
+                extern "C" fn #name(
+                    uniffi_handle: u64,
+                    #(#arg_names: #arg_types,)*
+                    #out_params
+                ) {
+                    let listener: extern "C" fn(u64, #(#arg_types,)* #out_types) =
+                        unsafe { ::std::mem::transmute(INTERFACE.listener(uniffi_handle, #slot)) };
+                    listener(uniffi_handle, #(#arg_names,)* #out_args);
+                }
+            });
+            trampoline_names.push(name);
+            continue;
+        }
+        trampolines.push(quote! {
+            extern "C" fn #name(
+                uniffi_handle: u64,
+                #(#arg_names: #arg_types,)*
+                #out_params
+            ) {
+                match INTERFACE.target(uniffi_handle, #slot) {
+                    ::uniffi_dart::dispatch::Target::Direct(direct) => {
+                        let direct: extern "C" fn(u64, #(#arg_types,)* #out_types) =
+                            unsafe { ::std::mem::transmute(direct) };
+                        direct(uniffi_handle, #(#arg_names,)* #out_args);
+                    }
+                    ::uniffi_dart::dispatch::Target::Listener(listener) => {
+                        let listener: extern "C" fn(
+                            u64,
+                            #(#arg_types,)*
+                            #out_types
+                            *const ::uniffi_dart::dispatch::Waiter,
+                        ) = unsafe { ::std::mem::transmute(listener) };
+                        let (waiter, signal) = ::uniffi_dart::dispatch::Waiter::new();
+                        listener(uniffi_handle, #(#arg_names,)* #out_args signal);
+                        waiter.wait();
+                    }
+                }
+            }
+        });
+        trampoline_names.push(name);
+    }
+
+    Ok(quote! {
+        const _: () = {
+            static INTERFACE: ::uniffi_dart::dispatch::Interface =
+                ::uniffi_dart::dispatch::Interface::new();
+
+            extern "C" fn uniffi_dispatch_free(uniffi_handle: u64) {
+                if let Some(free) = INTERFACE.release_handle(uniffi_handle) {
+                    let free: extern "C" fn(u64) = unsafe { ::std::mem::transmute(free) };
+                    free(uniffi_handle);
+                }
+            }
+
+            extern "C" fn uniffi_dispatch_clone(uniffi_handle: u64) -> u64 {
+                INTERFACE.clone_handle(uniffi_handle)
+            }
+
+            #(#trampolines)*
+
+            /// Registers the Dart functions of the calling isolate and writes the vtable to hand
+            /// to Rust to `vtable`. Returns 0, or 1 if the running Dart SDK can't be supported.
+            #[allow(clippy::missing_safety_doc)]
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn #register_fn(
+                api: *mut ::std::ffi::c_void,
+                direct: *const *const ::std::ffi::c_void,
+                listeners: *const *const ::std::ffi::c_void,
+                vtable: *mut *const ::std::ffi::c_void,
+            ) -> i8 {
+                let trampolines: &[*const ::std::ffi::c_void] = &[
+                    uniffi_dispatch_free as *const ::std::ffi::c_void,
+                    uniffi_dispatch_clone as *const ::std::ffi::c_void,
+                    #(#trampoline_names as *const ::std::ffi::c_void,)*
+                ];
+                // The slots of the Dart functions leave out `clone`, which Rust handles itself
+                if !unsafe { INTERFACE.register(api, direct, listeners, trampolines.len() - 1) } {
+                    return 1;
+                }
+                unsafe {
+                    ::std::ptr::copy_nonoverlapping(trampolines.as_ptr(), vtable, trampolines.len())
+                };
+                0
+            }
+
+            /// Hands out the handle of an object of the calling isolate, which receives the calls
+            /// to it.
+            #[unsafe(no_mangle)]
+            pub extern "C" fn #new_handle_fn() -> u64 {
+                INTERFACE.new_handle()
+            }
+
+            /// Completes a call posted by a trampoline.
+            #[allow(clippy::missing_safety_doc)]
+            #[unsafe(no_mangle)]
+            pub unsafe extern "C" fn #signal_fn(waiter: *const ::uniffi_dart::dispatch::Waiter) {
+                unsafe { ::uniffi_dart::dispatch::signal(waiter) };
+            }
+        };
+    })
+}